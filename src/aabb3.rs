@@ -0,0 +1,309 @@
+use super::float::Float;
+use super::Vec3;
+
+/// Aabb3 is an axis-aligned bounding box in three dimensions, given by its
+/// minimum and maximum corners. A box whose minimum is greater than its maximum
+/// along any axis is empty.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Aabb3, Vec3};
+///
+/// let b = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 4.0, 6.0));
+/// assert_eq!(b.center(), Vec3(1.0, 2.0, 3.0));
+/// assert!(b.contains(Vec3(1.0, 1.0, 1.0)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Aabb3<T> {
+    /// The minimum corner of the box.
+    pub min: Vec3<T>,
+
+    /// The maximum corner of the box.
+    pub max: Vec3<T>,
+}
+
+impl<T: Float> Aabb3<T> {
+    /// new returns the box with the given minimum and maximum corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Vec3};
+    ///
+    /// let b = Aabb3::new(Vec3(-1.0, -1.0, -1.0), Vec3(1.0, 1.0, 1.0));
+    /// assert_eq!(b.max, Vec3(1.0, 1.0, 1.0));
+    /// ```
+    pub fn new(min: Vec3<T>, max: Vec3<T>) -> Self {
+        Aabb3 {
+            min,
+            max,
+        }
+    }
+
+    /// from_center_half_extents returns the box with the given center, and
+    /// half of its size along each axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Vec3};
+    ///
+    /// let b = Aabb3::from_center_half_extents(Vec3(1.0, 1.0, 1.0), Vec3(1.0, 2.0, 3.0));
+    /// assert_eq!(b, Aabb3::new(Vec3(0.0, -1.0, -2.0), Vec3(2.0, 3.0, 4.0)));
+    /// ```
+    pub fn from_center_half_extents(center: Vec3<T>, half_extents: Vec3<T>) -> Self {
+        Aabb3::new(center - half_extents, center + half_extents)
+    }
+
+    /// from_points returns the smallest box containing all of the given points,
+    /// or None if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Vec3};
+    ///
+    /// let points = vec![Vec3(1.0, 5.0, -1.0), Vec3(-2.0, 0.0, 3.0), Vec3(0.0, 1.0, 0.0)];
+    /// let b = Aabb3::from_points(points).unwrap();
+    /// assert_eq!(b, Aabb3::new(Vec3(-2.0, 0.0, -1.0), Vec3(1.0, 5.0, 3.0)));
+    ///
+    /// assert_eq!(Aabb3::<f64>::from_points(vec![]), None);
+    /// ```
+    pub fn from_points<I: IntoIterator<Item = Vec3<T>>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Aabb3::new(first, first), |b, p| b.extend(p)))
+    }
+
+    /// is_empty tells if this box is empty, i.e. if its minimum is greater
+    /// than its maximum along any axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Vec3};
+    ///
+    /// assert!(!Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, 0.0)).is_empty());
+    /// assert!(Aabb3::new(Vec3(0.0, 1.0, 0.0), Vec3(1.0, 0.0, 1.0)).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.min.any_greater(self.max)
+    }
+
+    /// union returns the smallest box containing both this box and the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Vec3};
+    ///
+    /// let a = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0));
+    /// let b = Aabb3::new(Vec3(2.0, -1.0, 0.0), Vec3(3.0, 0.5, 1.0));
+    /// assert_eq!(a.union(b), Aabb3::new(Vec3(0.0, -1.0, 0.0), Vec3(3.0, 1.0, 1.0)));
+    /// ```
+    pub fn union(&self, other: Self) -> Self {
+        Aabb3::new(self.min.min(other.min), self.max.max(other.max))
+    }
+
+    /// intersection returns the box where this box and the other overlap, or
+    /// None if they do not. Boxes that only touch overlap in a flat box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Vec3};
+    ///
+    /// let a = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 2.0, 2.0));
+    /// let b = Aabb3::new(Vec3(1.0, 1.0, 1.0), Vec3(3.0, 3.0, 3.0));
+    /// let c = Aabb3::new(Vec3(5.0, 5.0, 5.0), Vec3(6.0, 6.0, 6.0));
+    /// assert_eq!(a.intersection(b), Some(Aabb3::new(Vec3(1.0, 1.0, 1.0), Vec3(2.0, 2.0, 2.0))));
+    /// assert_eq!(a.intersection(c), None);
+    /// ```
+    pub fn intersection(&self, other: Self) -> Option<Self> {
+        let b = Aabb3::new(self.min.max(other.min), self.max.min(other.max));
+        if b.is_empty() { None } else { Some(b) }
+    }
+
+    /// extend returns the smallest box containing both this box and the given
+    /// point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Vec3};
+    ///
+    /// let b = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0));
+    /// assert_eq!(b.extend(Vec3(2.0, 0.5, -1.0)),
+    ///            Aabb3::new(Vec3(0.0, 0.0, -1.0), Vec3(2.0, 1.0, 1.0)));
+    /// ```
+    pub fn extend(&self, point: Vec3<T>) -> Self {
+        Aabb3::new(self.min.min(point), self.max.max(point))
+    }
+
+    /// expand returns this box grown by the given amount in every direction
+    /// (or shrunk, if the amount is negative).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Vec3};
+    ///
+    /// let b = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0));
+    /// assert_eq!(b.expand(0.5), Aabb3::new(Vec3(-0.5, -0.5, -0.5), Vec3(1.5, 1.5, 1.5)));
+    /// ```
+    pub fn expand(&self, amount: T) -> Self {
+        let v = Vec3(amount, amount, amount);
+        Aabb3::new(self.min - v, self.max + v)
+    }
+
+    /// contains tells if the given point is inside this box, or on its
+    /// boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Vec3};
+    ///
+    /// let b = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0));
+    /// assert!(b.contains(Vec3(1.0, 0.5, 0.0)));
+    /// assert!(!b.contains(Vec3(1.5, 0.5, 0.0)));
+    /// ```
+    pub fn contains(&self, point: Vec3<T>) -> bool {
+        !self.min.any_greater(point) && !self.max.any_less(point)
+    }
+
+    /// closest_point returns the point inside this box (or on its boundary)
+    /// closest to the given point, which is the point itself if it is inside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Vec3};
+    ///
+    /// let b = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0));
+    /// assert_eq!(b.closest_point(Vec3(2.0, 0.5, -1.0)), Vec3(1.0, 0.5, 0.0));
+    /// assert_eq!(b.closest_point(Vec3(0.5, 0.5, 0.5)), Vec3(0.5, 0.5, 0.5));
+    /// ```
+    pub fn closest_point(&self, point: Vec3<T>) -> Vec3<T> {
+        point.max(self.min).min(self.max)
+    }
+
+    /// center returns the center of this box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Vec3};
+    ///
+    /// let b = Aabb3::new(Vec3(0.0, 2.0, 4.0), Vec3(2.0, 4.0, 6.0));
+    /// assert_eq!(b.center(), Vec3(1.0, 3.0, 5.0));
+    /// ```
+    pub fn center(&self) -> Vec3<T> {
+        let two = T::one() + T::one();
+        (self.min + self.max).div_scalar(two)
+    }
+
+    /// size returns the size of this box along each axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Vec3};
+    ///
+    /// let b = Aabb3::new(Vec3(0.0, 2.0, 4.0), Vec3(1.0, 4.0, 7.0));
+    /// assert_eq!(b.size(), Vec3(1.0, 2.0, 3.0));
+    /// ```
+    pub fn size(&self) -> Vec3<T> {
+        self.max - self.min
+    }
+
+    /// half_extents returns half the size of this box along each axis, i.e. the
+    /// distance from its center to its faces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Vec3};
+    ///
+    /// let b = Aabb3::new(Vec3(0.0, 2.0, 4.0), Vec3(2.0, 6.0, 10.0));
+    /// assert_eq!(b.half_extents(), Vec3(1.0, 2.0, 3.0));
+    /// ```
+    pub fn half_extents(&self) -> Vec3<T> {
+        let two = T::one() + T::one();
+        self.size().div_scalar(two)
+    }
+
+    /// corners returns the eight corners of this box. The corner at index `i`
+    /// takes its X coordinate from the maximum corner if bit 0 of `i` is set
+    /// and from the minimum corner otherwise, and likewise its Y coordinate
+    /// from bit 1 and its Z coordinate from bit 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Vec3};
+    ///
+    /// let b = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 2.0, 3.0));
+    /// let c = b.corners();
+    /// assert_eq!(c[0], b.min);
+    /// assert_eq!(c[3], Vec3(1.0, 2.0, 0.0));
+    /// assert_eq!(c[7], b.max);
+    /// ```
+    pub fn corners(&self) -> [Vec3<T>; 8] {
+        let (a, b) = (self.min, self.max);
+        [Vec3(a.0, a.1, a.2),
+         Vec3(b.0, a.1, a.2),
+         Vec3(a.0, b.1, a.2),
+         Vec3(b.0, b.1, a.2),
+         Vec3(a.0, a.1, b.2),
+         Vec3(b.0, a.1, b.2),
+         Vec3(a.0, b.1, b.2),
+         Vec3(b.0, b.1, b.2)]
+    }
+
+    /// transformed returns the smallest axis-aligned box containing this box
+    /// transformed by the linear map with the given matrix columns (e.g. the
+    /// axes of a basis, which may be scaled or sheared) and then translated,
+    /// e.g. to place the local bounds of an object in world space. It uses
+    /// Arvo's method, which is equivalent to (but cheaper than) bounding the
+    /// transformed corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Basis3, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let rot = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(90.0)).unwrap();
+    /// let b = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 1.0, 1.0));
+    /// let t = b.transformed([rot.x(), rot.y(), rot.z()], Vec3(0.0, 0.0, 5.0));
+    /// assert!(t.min.almost_equal(Vec3(-1.0, 0.0, 5.0), 1e-12));
+    /// assert!(t.max.almost_equal(Vec3(0.0, 2.0, 6.0), 1e-12));
+    ///
+    /// // Scaling by two along X, and shearing Y along X.
+    /// let m = [Vec3(2.0, 0.0, 0.0), Vec3(1.0, 1.0, 0.0), Vec3(0.0, 0.0, 1.0)];
+    /// let t = b.transformed(m, Vec3(0.0, 0.0, 0.0));
+    /// assert_eq!(t, Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(5.0, 1.0, 1.0)));
+    ///
+    /// // The same box as bounding the transformed corners.
+    /// let m = [Vec3(1.0, 2.0, -1.0), Vec3(0.5, -3.0, 2.0), Vec3(0.0, 1.0, 4.0)];
+    /// let f = |c: Vec3<f64>| m[0].mul_scalar(c.0) + m[1].mul_scalar(c.1) + m[2].mul_scalar(c.2);
+    /// let t = b.transformed(m, Vec3(1.0, 2.0, 3.0));
+    /// let c = Aabb3::from_points(b.corners().iter().map(|&c| f(c) + Vec3(1.0, 2.0, 3.0)));
+    /// assert_eq!(Some(t), c);
+    /// ```
+    pub fn transformed(&self, columns: [Vec3<T>; 3], translation: Vec3<T>) -> Self {
+        let (min, max) = (self.min, self.max);
+        let mut out = Aabb3::new(translation, translation);
+        for &(column, lo, hi) in [(columns[0], min.0, max.0),
+                                  (columns[1], min.1, max.1),
+                                  (columns[2], min.2, max.2)]
+            .iter() {
+            let (a, b) = (column.mul_scalar(lo), column.mul_scalar(hi));
+            out.min = out.min + a.min(b);
+            out.max = out.max + a.max(b);
+        }
+        out
+    }
+}
@@ -0,0 +1,53 @@
+/// Axis identifies one of the three cartesian coordinate axes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis {
+    /// index returns the index of the vector component that this axis
+    /// represents (i.e. zero for X, one for Y, and two for Z).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Axis;
+    ///
+    /// assert_eq!(Axis::Y.index(), 1);
+    /// ```
+    pub fn index(self) -> usize {
+        match self {
+            Axis::X => 0,
+            Axis::Y => 1,
+            Axis::Z => 2,
+        }
+    }
+}
+
+/// Axis2 identifies one of the two cartesian coordinate axes of the plane.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Axis2 {
+    X,
+    Y,
+}
+
+impl Axis2 {
+    /// index returns the index of the vector component that this axis
+    /// represents (i.e. zero for X, and one for Y).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Axis2;
+    ///
+    /// assert_eq!(Axis2::Y.index(), 1);
+    /// ```
+    pub fn index(self) -> usize {
+        match self {
+            Axis2::X => 0,
+            Axis2::Y => 1,
+        }
+    }
+}
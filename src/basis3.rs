@@ -0,0 +1,474 @@
+use core::fmt;
+use core::ops::Mul;
+
+use super::float::Float;
+use super::math;
+use super::lerp::{Lerp, Slerp};
+use super::unit::ToRad;
+use super::{Vec3, Vec4};
+
+#[cfg(feature = "rand")]
+use rand::Rng;
+#[cfg(feature = "rand")]
+use rand::distributions::{Distribution, Standard};
+
+// The tolerance used by checked constructors when testing whether axes are
+// orthonormal. It is loose enough to accept axes computed in single precision.
+const TOLERANCE: f64 = 1.0e-5;
+
+/// orthonormal_basis returns a tangent and bitangent which together with the
+/// unit normal `n` form a right-handed orthonormal basis (tangent, bitangent,
+/// normal), using the revised method of Frisvad by Duff et al. (Building an
+/// Orthonormal Basis, Revisited). It has no singularities, but is
+/// discontinuous where the Z component of `n` changes sign.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{orthonormal_basis, Vec3};
+///
+/// let n = Vec3(2.0f64, -3.0, 6.0).normalize().unwrap();
+/// let (t, b) = orthonormal_basis(n);
+/// assert!(t.dot(n).abs() < 1e-12 && b.dot(n).abs() < 1e-12 && t.dot(b).abs() < 1e-12);
+/// assert!(t.cross(b).almost_equal(n, 1e-12));
+///
+/// let (t, b) = orthonormal_basis(Vec3(0.0, 0.0, -1.0));
+/// assert_eq!((t, b), (Vec3(1.0, 0.0, 0.0), Vec3(0.0, -1.0, 0.0)));
+/// ```
+pub fn orthonormal_basis<T: Float>(n: Vec3<T>) -> (Vec3<T>, Vec3<T>) {
+    let sign = if n.2 >= T::zero() { T::one() } else { -T::one() };
+    let a = -T::one() / (sign + n.2);
+    let b = n.0 * n.1 * a;
+    (Vec3(T::one() + sign * n.0 * n.0 * a, sign * b, -sign * n.0),
+     Vec3(b, sign + n.1 * n.1 * a, -n.1))
+}
+
+/// Basis3 is a pure rotation between two coordinate frames, represented by the
+/// axes of the local frame expressed in the parent frame. The axes always form
+/// a right-handed orthonormal basis, which is enforced at construction; thus
+/// the inverse of a basis is cheap to compute (by transposition).
+///
+/// Basis3 is `#[repr(C)]`, with the axes x, y and z in order, so it has the same
+/// layout as a column-major 3x3 matrix.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Basis3, Vec3};
+///
+/// let b = Basis3::from_axes(Vec3(0.0, 1.0, 0.0),
+///                           Vec3(-1.0, 0.0, 0.0),
+///                           Vec3(0.0, 0.0, 1.0)).unwrap();
+/// assert_eq!(b * Vec3(1.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Basis3<T> {
+    x: Vec3<T>,
+    y: Vec3<T>,
+    z: Vec3<T>,
+}
+
+impl<T: Float> Basis3<T> {
+    /// identity returns the basis whose axes are the axes of the parent frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Vec3};
+    ///
+    /// let v = Vec3(1.0, 2.0, 3.0);
+    /// assert_eq!(Basis3::identity() * v, v);
+    /// ```
+    pub fn identity() -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Basis3 {
+            x: Vec3(one, zero, zero),
+            y: Vec3(zero, one, zero),
+            z: Vec3(zero, zero, one),
+        }
+    }
+
+    /// from_axes returns the basis with the given axes, or None if they do not
+    /// form a right-handed orthonormal basis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Vec3};
+    ///
+    /// let (x, y, z) = (Vec3(1.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0), Vec3(0.0, 0.0, 1.0));
+    /// assert_eq!(Basis3::from_axes(x, y, z), Some(Basis3::identity()));
+    ///
+    /// // Left-handed, scaled and skewed axes are rejected.
+    /// assert_eq!(Basis3::from_axes(x, y, -z), None);
+    /// assert_eq!(Basis3::from_axes(x, y, z.mul_scalar(2.0)), None);
+    /// assert_eq!(Basis3::from_axes(x, Vec3(0.5, 1.0, 0.0), z), None);
+    /// ```
+    pub fn from_axes(x: Vec3<T>, y: Vec3<T>, z: Vec3<T>) -> Option<Self> {
+        let tol = T::from(TOLERANCE).unwrap();
+        let unit = |v: Vec3<T>| (v.length_sq() - T::one()).abs() <= tol;
+        let perpendicular = |a: Vec3<T>, b: Vec3<T>| a.dot(b).abs() <= tol;
+        if !unit(x) || !unit(y) || !unit(z) {
+            return None;
+        }
+        if !perpendicular(x, y) || !perpendicular(y, z) || !perpendicular(z, x) {
+            return None;
+        }
+        if x.cross(y).dot(z) < T::zero() {
+            return None;
+        }
+        Some(Basis3 { x, y, z })
+    }
+
+    /// from_axes_unchecked returns the basis with the given axes, which the
+    /// caller guarantees form a right-handed orthonormal basis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Vec3};
+    ///
+    /// let b = Basis3::from_axes_unchecked(Vec3(1.0, 0.0, 0.0),
+    ///                                     Vec3(0.0, 1.0, 0.0),
+    ///                                     Vec3(0.0, 0.0, 1.0));
+    /// assert_eq!(b, Basis3::identity());
+    /// ```
+    pub fn from_axes_unchecked(x: Vec3<T>, y: Vec3<T>, z: Vec3<T>) -> Self {
+        Basis3 { x, y, z }
+    }
+
+    /// from_axis_angle returns the basis rotating counter-clockwise by the
+    /// given angle about the given axis (which need not be normalized), or None
+    /// if the axis is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let b = Basis3::from_axis_angle(Vec3(0.0, 0.0, 2.0), Deg(90.0)).unwrap();
+    /// assert!((b * Vec3(1.0, 0.0, 0.0)).almost_equal(Vec3(0.0, 1.0, 0.0), 1e-12));
+    /// assert_eq!(Basis3::from_axis_angle(Vec3(0.0, 0.0, 0.0), Deg(90.0)), None);
+    /// ```
+    pub fn from_axis_angle<A>(axis: Vec3<T>, angle: A) -> Option<Self>
+        where A: ToRad<Output = T>
+    {
+        let Vec3(x, y, z) = axis.normalize()?;
+        let (s, c) = math::sin_cos(angle.to_rad().0);
+        let t = T::one() - c;
+        Some(Basis3 {
+            x: Vec3(t * x * x + c, t * x * y + s * z, t * x * z - s * y),
+            y: Vec3(t * x * y - s * z, t * y * y + c, t * y * z + s * x),
+            z: Vec3(t * x * z + s * y, t * y * z - s * x, t * z * z + c),
+        })
+    }
+
+    /// from_normal returns a basis whose z axis is the given normal (which need
+    /// not be normalized) and whose x and y axes are the tangent and bitangent
+    /// from `orthonormal_basis`, or None if the normal is zero. It is useful
+    /// for moving directions into and out of shading space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Vec3};
+    ///
+    /// let b = Basis3::from_normal(Vec3(0.0, 3.0, 0.0)).unwrap();
+    /// assert!(b.z().almost_equal(Vec3(0.0, 1.0, 0.0), 1e-12));
+    /// assert!((b * Vec3(0.0, 0.0, 1.0)).almost_equal(b.z(), 1e-12));
+    /// assert_eq!(Basis3::from_normal(Vec3(0.0, 0.0, 0.0)), None);
+    /// ```
+    pub fn from_normal(normal: Vec3<T>) -> Option<Self> {
+        normal.normalize().map(|z| {
+            let (x, y) = orthonormal_basis(z);
+            Basis3 { x, y, z }
+        })
+    }
+
+    /// x returns the X axis of this basis, expressed in the parent frame.
+    pub fn x(&self) -> Vec3<T> {
+        self.x
+    }
+
+    /// y returns the Y axis of this basis, expressed in the parent frame.
+    pub fn y(&self) -> Vec3<T> {
+        self.y
+    }
+
+    /// z returns the Z axis of this basis, expressed in the parent frame.
+    pub fn z(&self) -> Vec3<T> {
+        self.z
+    }
+
+    /// inverse returns the basis performing the opposite rotation, which is
+    /// the transpose of this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let b = Basis3::from_axis_angle(Vec3(1.0, 2.0, 3.0), Deg(40.0)).unwrap();
+    /// let v = Vec3(4.0, 5.0, 6.0);
+    /// assert!((b.inverse() * (b * v)).almost_equal(v, 1e-12));
+    /// ```
+    pub fn inverse(self) -> Self {
+        Basis3 {
+            x: Vec3(self.x.0, self.y.0, self.z.0),
+            y: Vec3(self.x.1, self.y.1, self.z.1),
+            z: Vec3(self.x.2, self.y.2, self.z.2),
+        }
+    }
+
+    /// transform returns the vector `v`, given in the local frame, expressed
+    /// in the parent frame. It is equivalent to `self * v`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Vec3};
+    ///
+    /// let v = Vec3(1.0, 2.0, 3.0);
+    /// assert_eq!(Basis3::identity().transform(v), v);
+    /// ```
+    pub fn transform(self, v: Vec3<T>) -> Vec3<T> {
+        self.x.mul_scalar(v.0) + self.y.mul_scalar(v.1) + self.z.mul_scalar(v.2)
+    }
+
+    /// inverse_transform returns the vector `v`, given in the parent frame,
+    /// expressed in the local frame. It is equivalent to (but cheaper than)
+    /// `self.inverse() * v`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let b = Basis3::from_axis_angle(Vec3(0.0, 1.0, 0.0), Deg(30.0)).unwrap();
+    /// let v = Vec3(4.0, 5.0, 6.0);
+    /// assert!(b.inverse_transform(v).almost_equal(b.inverse() * v, 1e-12));
+    /// ```
+    pub fn inverse_transform(self, v: Vec3<T>) -> Vec3<T> {
+        Vec3(self.x.dot(v), self.y.dot(v), self.z.dot(v))
+    }
+
+    // to_quat returns the unit quaternion (x, y, z, w) representing this
+    // rotation, using Shepperd's method.
+    pub(crate) fn to_quat(self) -> Vec4<T> {
+        let (x, y, z) = (self.x, self.y, self.z);
+        let (one, quarter) = (T::one(), T::from(0.25).unwrap());
+        let trace = x.0 + y.1 + z.2;
+        if trace > T::zero() {
+            let s = math::sqrt(trace + one) * T::from(2).unwrap();
+            Vec4((y.2 - z.1) / s, (z.0 - x.2) / s, (x.1 - y.0) / s, quarter * s)
+        } else if x.0 > y.1 && x.0 > z.2 {
+            let s = math::sqrt(one + x.0 - y.1 - z.2) * T::from(2).unwrap();
+            Vec4(quarter * s, (y.0 + x.1) / s, (z.0 + x.2) / s, (y.2 - z.1) / s)
+        } else if y.1 > z.2 {
+            let s = math::sqrt(one + y.1 - x.0 - z.2) * T::from(2).unwrap();
+            Vec4((y.0 + x.1) / s, quarter * s, (z.1 + y.2) / s, (z.0 - x.2) / s)
+        } else {
+            let s = math::sqrt(one + z.2 - x.0 - y.1) * T::from(2).unwrap();
+            Vec4((z.0 + x.2) / s, (z.1 + y.2) / s, quarter * s, (x.1 - y.0) / s)
+        }
+    }
+
+    // from_quat returns the rotation represented by the unit quaternion
+    // (x, y, z, w).
+    pub(crate) fn from_quat(q: Vec4<T>) -> Self {
+        let Vec4(x, y, z, w) = q;
+        let (one, two) = (T::one(), T::from(2).unwrap());
+        Basis3 {
+            x: Vec3(one - two * (y * y + z * z), two * (x * y + w * z), two * (x * z - w * y)),
+            y: Vec3(two * (x * y - w * z), one - two * (x * x + z * z), two * (y * z + w * x)),
+            z: Vec3(two * (x * z + w * y), two * (y * z - w * x), one - two * (x * x + y * y)),
+        }
+    }
+}
+
+impl<T: Float> Mul<Vec3<T>> for Basis3<T> {
+    type Output = Vec3<T>;
+
+    /// mul transforms the vector from the local frame into the parent frame
+    /// (see `transform`).
+    fn mul(self, rhs: Vec3<T>) -> Vec3<T> {
+        self.transform(rhs)
+    }
+}
+
+impl<T: Float> Mul for Basis3<T> {
+    type Output = Self;
+
+    /// mul composes two rotations, such that `(a * b) * v == a * (b * v)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let a = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(90.0)).unwrap();
+    /// let b = Basis3::from_axis_angle(Vec3(1.0, 0.0, 0.0), Deg(90.0)).unwrap();
+    /// let v = Vec3(1.0, 2.0, 3.0);
+    /// assert!(((a * b) * v).almost_equal(a * (b * v), 1e-12));
+    /// ```
+    fn mul(self, rhs: Self) -> Self {
+        Basis3 {
+            x: self.transform(rhs.x),
+            y: self.transform(rhs.y),
+            z: self.transform(rhs.z),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Basis3<T> {
+    /// fmt formats the basis as its three axes, applying the formatter's flags
+    /// (width, precision and so on) to each of their components. The alternate
+    /// form (`{:#}`) instead writes the rows of the matrix on separate lines,
+    /// such that the axes are its columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Basis3;
+    ///
+    /// let b = Basis3::<f64>::identity();
+    /// assert_eq!(format!("{}", b), "Basis3(Vec3(1, 0, 0), Vec3(0, 1, 0), Vec3(0, 0, 1))");
+    /// assert_eq!(format!("{:#5.2}", b), "Basis3(
+    ///     [ 1.00,  0.00,  0.00],
+    ///     [ 0.00,  1.00,  0.00],
+    ///     [ 0.00,  0.00,  1.00],
+    /// )");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !f.alternate() {
+            write!(f, "Basis3(")?;
+            fmt::Display::fmt(&self.x, f)?;
+            write!(f, ", ")?;
+            fmt::Display::fmt(&self.y, f)?;
+            write!(f, ", ")?;
+            fmt::Display::fmt(&self.z, f)?;
+            return write!(f, ")");
+        }
+        writeln!(f, "Basis3(")?;
+        for row in &[(&self.x.0, &self.y.0, &self.z.0),
+                     (&self.x.1, &self.y.1, &self.z.1),
+                     (&self.x.2, &self.y.2, &self.z.2)] {
+            write!(f, "    [")?;
+            fmt::Display::fmt(row.0, f)?;
+            write!(f, ", ")?;
+            fmt::Display::fmt(row.1, f)?;
+            write!(f, ", ")?;
+            fmt::Display::fmt(row.2, f)?;
+            writeln!(f, "],")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<T: Float> Slerp<T> for Basis3<T> {
+    /// slerp interpolates between two rotations along the shortest arc.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Slerp, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let axis = Vec3(1.0, 2.0, 3.0);
+    /// let a = Basis3::from_axis_angle(axis, Deg(10.0)).unwrap();
+    /// let b = Basis3::from_axis_angle(axis, Deg(70.0)).unwrap();
+    /// let c = Basis3::from_axis_angle(axis, Deg(25.0)).unwrap();
+    /// let v = Vec3(4.0, 5.0, 6.0);
+    /// assert!((a.slerp(b, 0.25) * v).almost_equal(c * v, 1e-12));
+    /// ```
+    fn slerp(self, other: Self, t: T) -> Self {
+        let a = self.to_quat();
+        let mut b = other.to_quat();
+
+        // Take the shortest arc, as q and -q represent the same rotation.
+        let mut cos = a.dot(b);
+        if cos < T::zero() {
+            b = -b;
+            cos = -cos;
+        }
+        let q = if cos > T::one() - T::from(1.0e-6).unwrap() {
+            Lerp::lerp(a, b, t)
+        } else {
+            let theta = math::acos(cos);
+            let sin = math::sin(theta);
+            let wa = math::sin((T::one() - t) * theta) / sin;
+            let wb = math::sin(t * theta) / sin;
+            a.mul_scalar(wa) + b.mul_scalar(wb)
+        };
+        Basis3::from_quat(q.normalize().unwrap_or(a))
+    }
+}
+
+impl<T: Float> Lerp<T> for Basis3<T> {
+    /// lerp interpolates between two rotations by normalized linear
+    /// interpolation of their quaternions along the shortest arc. It is cheaper
+    /// than `slerp` and follows the same path, but not at a constant angular
+    /// velocity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Lerp, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let axis = Vec3(1.0, 2.0, 3.0);
+    /// let a = Basis3::from_axis_angle(axis, Deg(10.0)).unwrap();
+    /// let b = Basis3::from_axis_angle(axis, Deg(70.0)).unwrap();
+    /// let c = Basis3::from_axis_angle(axis, Deg(40.0)).unwrap();
+    /// let v = Vec3(4.0, 5.0, 6.0);
+    /// assert!((a.lerp(b, 0.5) * v).almost_equal(c * v, 1e-12));
+    /// assert!((a.lerp(b, 0.0) * v).almost_equal(a * v, 1e-12));
+    /// ```
+    fn lerp(self, other: Self, t: T) -> Self {
+        let a = self.to_quat();
+        let mut b = other.to_quat();
+        if a.dot(b) < T::zero() {
+            b = -b;
+        }
+        Basis3::from_quat(Lerp::lerp(a, b, t).normalize().unwrap_or(a))
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T: Float> Basis3<T>
+    where Standard: Distribution<T>
+{
+    /// random returns a random rotation, uniformly distributed over all
+    /// rotations, using the method of Shoemake (Uniform Random Rotations,
+    /// Graphics Gems III). Requires the `rand` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate rand;
+    /// extern crate fiz_math;
+    ///
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    /// use fiz_math::{Basis3, Float, Vec3};
+    ///
+    /// # fn main() {
+    /// let mut rng = StdRng::seed_from_u64(1);
+    /// let b: Basis3<f64> = Basis3::random(&mut rng);
+    /// assert!((b * Vec3(1.0, 2.0, 3.0)).length().almost_equal(14.0f64.sqrt(), 1e-12));
+    /// # }
+    /// ```
+    pub fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let (u1, u2, u3): (T, T, T) = (rng.gen(), rng.gen(), rng.gen());
+        let tau = T::from(2.0 * ::core::f64::consts::PI).unwrap();
+        let (r1, r2) = (math::sqrt(T::one() - u1), math::sqrt(u1));
+        let (s2, c2) = math::sin_cos(tau * u2);
+        let (s3, c3) = math::sin_cos(tau * u3);
+        Basis3::from_quat(Vec4(r1 * s2, r1 * c2, r2 * s3, r2 * c3))
+    }
+}
@@ -0,0 +1,532 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use super::{Aabb3, Basis3, Ray3, Sphere, Triangle3, Vec3, Vec4};
+use super::math;
+
+/// Isa identifies the instruction set used by the batch functions, as selected
+/// at runtime by `isa`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Isa {
+    /// Simd128 is the baseline instruction set of the target, which uses 128-bit
+    /// vectors where the target guarantees them (SSE2 on x86-64, NEON on
+    /// AArch64) and scalar code elsewhere.
+    Simd128,
+
+    /// Avx2 uses 256-bit vectors (AVX2 and FMA on x86).
+    Avx2,
+
+    /// Avx512 uses 512-bit vectors (AVX-512F on x86).
+    Avx512,
+}
+
+// ISA caches the detected instruction set: zero if it has not been detected
+// yet, or one plus the index of the variant.
+static ISA: AtomicUsize = AtomicUsize::new(0);
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn detect() -> Isa {
+    if is_x86_feature_detected!("avx512f") {
+        Isa::Avx512
+    } else if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+        Isa::Avx2
+    } else {
+        Isa::Simd128
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+fn detect() -> Isa {
+    Isa::Simd128
+}
+
+/// isa returns the widest instruction set supported by the running CPU, which
+/// is used by the batch functions of this module. It is detected once and
+/// cached.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::batch::{isa, Isa};
+///
+/// let isa = isa();
+/// println!("batch math uses {:?}", isa);
+/// ```
+pub fn isa() -> Isa {
+    match ISA.load(Ordering::Relaxed) {
+        1 => Isa::Simd128,
+        2 => Isa::Avx2,
+        3 => Isa::Avx512,
+        _ => {
+            let isa = detect();
+            let n = match isa {
+                Isa::Simd128 => 1,
+                Isa::Avx2 => 2,
+                Isa::Avx512 => 3,
+            };
+            ISA.store(n, Ordering::Relaxed);
+            isa
+        }
+    }
+}
+
+// multiversion defines a public function whose body is compiled once for each
+// instruction set, and which dispatches to the best one supported by the CPU
+// at runtime. The body must only rely on the compiler's auto-vectorization.
+macro_rules! multiversion {
+    ($(#[$attr:meta])* $vis:vis fn $name:ident($($arg:ident: $ty:ty),*) $body:block) => {
+        $(#[$attr])*
+        $vis fn $name($($arg: $ty),*) {
+            #[inline(always)]
+            fn kernel($($arg: $ty),*) $body
+
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[target_feature(enable = "avx2,fma")]
+            unsafe fn avx2($($arg: $ty),*) {
+                kernel($($arg),*)
+            }
+
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[target_feature(enable = "avx512f")]
+            unsafe fn avx512($($arg: $ty),*) {
+                kernel($($arg),*)
+            }
+
+            match isa() {
+                // Safe because isa only selects instruction sets that the CPU
+                // supports.
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Isa::Avx512 => unsafe { avx512($($arg),*) },
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                Isa::Avx2 => unsafe { avx2($($arg),*) },
+                _ => kernel($($arg),*),
+            }
+        }
+    }
+}
+
+multiversion! {
+    /// dot_batches stores the dot product of each pair of vectors of `a` and
+    /// `b` in `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices are of different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    /// use fiz_math::batch::dot_batches;
+    ///
+    /// let a = [Vec3(1.0, 2.0, 3.0), Vec3(0.0, 1.0, 0.0)];
+    /// let b = [Vec3(4.0, 5.0, 6.0), Vec3(1.0, 0.0, 0.0)];
+    /// let mut out = [0.0; 2];
+    /// dot_batches(&a, &b, &mut out);
+    /// assert_eq!(out, [32.0, 0.0]);
+    /// ```
+    pub fn dot_batches(a: &[Vec3<f32>], b: &[Vec3<f32>], out: &mut [f32]) {
+        assert!(a.len() == b.len() && a.len() == out.len(),
+                "dot_batches: slices of different lengths");
+        for ((a, b), out) in a.iter().zip(b).zip(out.iter_mut()) {
+            *out = a.0 * b.0 + a.1 * b.1 + a.2 * b.2;
+        }
+    }
+}
+
+multiversion! {
+    /// transform_points rotates each of the points by the basis and then
+    /// translates it, in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Vec3};
+    /// use fiz_math::unit::Deg;
+    /// use fiz_math::batch::transform_points;
+    ///
+    /// let b = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(90.0)).unwrap();
+    /// let mut points = [Vec3(1.0, 0.0, 0.0), Vec3(0.0, 2.0, 0.0)];
+    /// transform_points(&b, Vec3(0.0, 0.0, 5.0), &mut points);
+    /// assert!(points[0].almost_equal(Vec3(0.0, 1.0, 5.0), 1e-6));
+    /// assert!(points[1].almost_equal(Vec3(-2.0, 0.0, 5.0), 1e-6));
+    /// ```
+    pub fn transform_points(basis: &Basis3<f32>, translation: Vec3<f32>, points: &mut [Vec3<f32>]) {
+        let (x, y, z) = (basis.x(), basis.y(), basis.z());
+        for p in points.iter_mut() {
+            let Vec3(px, py, pz) = *p;
+            *p = Vec3(x.0 * px + y.0 * py + z.0 * pz + translation.0,
+                      x.1 * px + y.1 * py + z.1 * pz + translation.1,
+                      x.2 * px + y.2 * py + z.2 * pz + translation.2);
+        }
+    }
+}
+
+multiversion! {
+    /// dot4_batches stores the dot product of each pair of vectors of `a` and
+    /// `b` in `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices are of different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    /// use fiz_math::batch::dot4_batches;
+    ///
+    /// let a = [Vec4(1.0, 2.0, 3.0, 4.0), Vec4(0.0, 1.0, 0.0, 0.0)];
+    /// let b = [Vec4(5.0, 6.0, 7.0, 8.0), Vec4(1.0, 0.0, 0.0, 1.0)];
+    /// let mut out = [0.0; 2];
+    /// dot4_batches(&a, &b, &mut out);
+    /// assert_eq!(out, [70.0, 0.0]);
+    /// ```
+    pub fn dot4_batches(a: &[Vec4<f32>], b: &[Vec4<f32>], out: &mut [f32]) {
+        assert!(a.len() == b.len() && a.len() == out.len(),
+                "dot4_batches: slices of different lengths");
+        for ((a, b), out) in a.iter().zip(b).zip(out.iter_mut()) {
+            *out = a.0 * b.0 + a.1 * b.1 + a.2 * b.2 + a.3 * b.3;
+        }
+    }
+}
+
+multiversion! {
+    /// length4_batches stores the length of each of the vectors in `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices are of different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    /// use fiz_math::batch::length4_batches;
+    ///
+    /// let v = [Vec4(1.0, 2.0, 2.0, 4.0), Vec4(0.0, 0.0, -3.0, 0.0)];
+    /// let mut out = [0.0; 2];
+    /// length4_batches(&v, &mut out);
+    /// assert_eq!(out, [5.0, 3.0]);
+    /// ```
+    pub fn length4_batches(v: &[Vec4<f32>], out: &mut [f32]) {
+        assert!(v.len() == out.len(), "length4_batches: slices of different lengths");
+        for (v, out) in v.iter().zip(out.iter_mut()) {
+            *out = math::sqrt(v.0 * v.0 + v.1 * v.1 + v.2 * v.2 + v.3 * v.3);
+        }
+    }
+}
+
+multiversion! {
+    /// transform_vec4s applies the affine transform made of the basis and the
+    /// translation to each of the homogeneous vectors, in place: the xyz part of
+    /// each is rotated by the basis and translated by `w` times the translation,
+    /// so that points (with a `w` of one) are moved and directions (with a `w` of
+    /// zero) are only rotated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Vec3, Vec4};
+    /// use fiz_math::unit::Deg;
+    /// use fiz_math::batch::transform_vec4s;
+    ///
+    /// let b = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(90.0)).unwrap();
+    /// let mut v = [Vec4(1.0, 0.0, 0.0, 1.0), Vec4(0.0, 2.0, 0.0, 0.0)];
+    /// transform_vec4s(&b, Vec3(0.0, 0.0, 5.0), &mut v);
+    /// assert!(v[0].almost_equal(Vec4(0.0, 1.0, 5.0, 1.0), 1e-6));
+    /// assert!(v[1].almost_equal(Vec4(-2.0, 0.0, 0.0, 0.0), 1e-6));
+    /// ```
+    pub fn transform_vec4s(basis: &Basis3<f32>, translation: Vec3<f32>, v: &mut [Vec4<f32>]) {
+        let (x, y, z) = (basis.x(), basis.y(), basis.z());
+        for v in v.iter_mut() {
+            let Vec4(vx, vy, vz, vw) = *v;
+            *v = Vec4(x.0 * vx + y.0 * vy + z.0 * vz + translation.0 * vw,
+                      x.1 * vx + y.1 * vy + z.1 * vz + translation.1 * vw,
+                      x.2 * vx + y.2 * vy + z.2 * vz + translation.2 * vw,
+                      vw);
+        }
+    }
+}
+
+multiversion! {
+    /// cull_aabbs stores in `visible` whether each of the axis-aligned boxes
+    /// (given by the corresponding `mins` and `maxs` corners) is at least
+    /// partially inside all of the planes. Each plane is given as a normal and
+    /// distance `Vec4(nx, ny, nz, d)`, with the inside being where
+    /// `n.dot(p) + d >= 0`. Boxes that straddle a plane are considered visible.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mins`, `maxs` and `visible` are of different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Vec3, Vec4};
+    /// use fiz_math::batch::cull_aabbs;
+    ///
+    /// // Keep everything with x >= 0.
+    /// let planes = [Vec4(1.0, 0.0, 0.0, 0.0)];
+    /// let mins = [Vec3(1.0, 0.0, 0.0), Vec3(-3.0, 0.0, 0.0), Vec3(-1.0, 0.0, 0.0)];
+    /// let maxs = [Vec3(2.0, 1.0, 1.0), Vec3(-2.0, 1.0, 1.0), Vec3(1.0, 1.0, 1.0)];
+    /// let mut visible = [false; 3];
+    /// cull_aabbs(&planes, &mins, &maxs, &mut visible);
+    /// assert_eq!(visible, [true, false, true]);
+    /// ```
+    pub fn cull_aabbs(planes: &[Vec4<f32>],
+                      mins: &[Vec3<f32>],
+                      maxs: &[Vec3<f32>],
+                      visible: &mut [bool]) {
+        assert!(mins.len() == maxs.len() && mins.len() == visible.len(),
+                "cull_aabbs: slices of different lengths");
+        for ((min, max), visible) in mins.iter().zip(maxs).zip(visible.iter_mut()) {
+            let mut inside = true;
+            for p in planes {
+                // The corner furthest along the plane normal.
+                let cx = if p.0 >= 0.0 { max.0 } else { min.0 };
+                let cy = if p.1 >= 0.0 { max.1 } else { min.1 };
+                let cz = if p.2 >= 0.0 { max.2 } else { min.2 };
+                inside &= p.0 * cx + p.1 * cy + p.2 * cz + p.3 >= 0.0;
+            }
+            *visible = inside;
+        }
+    }
+}
+
+/// RayPacket is a list of rays stored in structure-of-arrays form, i.e. with
+/// each component of their origins and directions in a separate list, such
+/// that the intersection tests of the packet vectorize well. Each test stores
+/// in `t` the distance along each ray to its first hit, or infinity if it
+/// misses, with the same results as the corresponding methods of `Ray3`.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Ray3, Sphere, Vec3};
+/// use fiz_math::batch::RayPacket;
+///
+/// let rays: Vec<_> = (0..8)
+///     .map(|i| Ray3::new(Vec3(i as f32 * 0.5, 0.0, -5.0), Vec3(0.0, 0.0, 1.0)))
+///     .collect();
+/// let packet = RayPacket::from_rays(&rays);
+/// let sphere = Sphere::new(Vec3(0.0, 0.0, 0.0), 1.0);
+///
+/// let mut t = [0.0; 8];
+/// packet.intersect_sphere(&sphere, &mut t);
+/// for (ray, &t) in rays.iter().zip(t.iter()) {
+///     assert_eq!(ray.intersect_sphere(&sphere).map_or(std::f32::INFINITY, |h| h.0), t);
+/// }
+/// assert_eq!(t[0], 4.0);
+/// assert_eq!(t[3], std::f32::INFINITY);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RayPacket {
+    /// The X components of the origins of the rays.
+    pub ox: Vec<f32>,
+
+    /// The Y components of the origins of the rays.
+    pub oy: Vec<f32>,
+
+    /// The Z components of the origins of the rays.
+    pub oz: Vec<f32>,
+
+    /// The X components of the directions of the rays.
+    pub dx: Vec<f32>,
+
+    /// The Y components of the directions of the rays.
+    pub dy: Vec<f32>,
+
+    /// The Z components of the directions of the rays.
+    pub dz: Vec<f32>,
+}
+
+impl RayPacket {
+    /// new returns an empty packet.
+    pub fn new() -> Self {
+        RayPacket::default()
+    }
+
+    /// from_rays returns the packet of the given rays.
+    pub fn from_rays(rays: &[Ray3<f32>]) -> Self {
+        let mut p = RayPacket::new();
+        for r in rays {
+            p.push(r);
+        }
+        p
+    }
+
+    /// push appends the given ray to this packet.
+    pub fn push(&mut self, ray: &Ray3<f32>) {
+        self.ox.push(ray.origin.0);
+        self.oy.push(ray.origin.1);
+        self.oz.push(ray.origin.2);
+        self.dx.push(ray.dir.0);
+        self.dy.push(ray.dir.1);
+        self.dz.push(ray.dir.2);
+    }
+
+    /// len returns the number of rays in this packet.
+    pub fn len(&self) -> usize {
+        self.ox.len()
+    }
+
+    /// is_empty tells if this packet holds no rays.
+    pub fn is_empty(&self) -> bool {
+        self.ox.is_empty()
+    }
+
+    /// ray returns the i'th ray of this packet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if i is out of bounds.
+    pub fn ray(&self, i: usize) -> Ray3<f32> {
+        Ray3::new(Vec3(self.ox[i], self.oy[i], self.oz[i]),
+                  Vec3(self.dx[i], self.dy[i], self.dz[i]))
+    }
+
+    // check panics unless the packet and t are consistent.
+    fn check(&self, t: &[f32]) {
+        let n = self.len();
+        assert!([self.oy.len(), self.oz.len(), self.dx.len(), self.dy.len(), self.dz.len(),
+                 t.len()]
+                    .iter()
+                    .all(|&m| m == n),
+                "RayPacket: components and output of different lengths");
+    }
+
+    /// intersect_aabb stores in t the distance along each ray to where it
+    /// enters the given box (zero if it starts inside it), or infinity if it
+    /// misses it; see `Ray3::intersects_aabb`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if t or the components of the packet are of different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Ray3, Vec3};
+    /// use fiz_math::batch::RayPacket;
+    ///
+    /// let b = Aabb3::new(Vec3(-1.0, -1.0, -1.0), Vec3(1.0, 1.0, 1.0));
+    /// let packet = RayPacket::from_rays(&[
+    ///     Ray3::new(Vec3(-5.0, 0.0, 0.0), Vec3(2.0, 0.0, 0.0)),
+    ///     Ray3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, 1.0)),
+    ///     Ray3::new(Vec3(-5.0, 1.0, 0.0), Vec3(1.0, 0.0, 0.0)),
+    ///     Ray3::new(Vec3(-5.0, 2.0, 0.0), Vec3(1.0, 0.0, 0.0)),
+    /// ]);
+    /// let mut t = [0.0; 4];
+    /// packet.intersect_aabb(&b, &mut t);
+    /// assert_eq!(t, [2.0, 0.0, 4.0, std::f32::INFINITY]);
+    /// ```
+    pub fn intersect_aabb(&self, aabb: &Aabb3<f32>, t: &mut [f32]) {
+        self.check(t);
+        packet_aabb(self, aabb, t);
+    }
+
+    /// intersect_sphere stores in t the distance along each ray to where it
+    /// first hits the surface of the given sphere, or infinity if it misses
+    /// it; see `Ray3::intersect_sphere`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if t or the components of the packet are of different lengths.
+    pub fn intersect_sphere(&self, sphere: &Sphere<f32>, t: &mut [f32]) {
+        self.check(t);
+        packet_sphere(self, sphere, t);
+    }
+
+    /// intersect_triangle stores in t the distance along each ray to where it
+    /// hits the given triangle, or infinity if it misses it; see
+    /// `Ray3::intersect_triangle`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if t or the components of the packet are of different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Ray3, Triangle3, Vec3};
+    /// use fiz_math::batch::RayPacket;
+    ///
+    /// let tri = Triangle3::new(Vec3(0.0, 0.0, 0.0), Vec3(4.0, 0.0, 0.0), Vec3(0.0, 4.0, 0.0));
+    /// let packet = RayPacket::from_rays(&[
+    ///     Ray3::new(Vec3(1.0, 2.0, 5.0), Vec3(0.0, 0.0, -1.0)),
+    ///     Ray3::new(Vec3(1.0, 2.0, -5.0), Vec3(0.0, 0.0, 1.0)),
+    ///     Ray3::new(Vec3(3.0, 3.0, 5.0), Vec3(0.0, 0.0, -1.0)),
+    /// ]);
+    /// let mut t = [0.0; 3];
+    /// packet.intersect_triangle(&tri, true, &mut t);
+    /// assert_eq!(t, [5.0, std::f32::INFINITY, std::f32::INFINITY]);
+    /// packet.intersect_triangle(&tri, false, &mut t);
+    /// assert_eq!(t, [5.0, 5.0, std::f32::INFINITY]);
+    /// ```
+    pub fn intersect_triangle(&self, tri: &Triangle3<f32>, cull_backface: bool, t: &mut [f32]) {
+        self.check(t);
+        packet_triangle(self, tri, cull_backface, t);
+    }
+}
+
+// slab returns the range of distances along a ray, with the origin o and
+// direction d along one axis, between the planes at min and max. When the ray
+// is parallel to the slab the distances are infinite, or NaN for a plane the
+// origin is on (which the callers' min and max ignore).
+#[inline(always)]
+fn slab(o: f32, d: f32, min: f32, max: f32) -> (f32, f32) {
+    let inv = 1.0 / d;
+    let (near, far) = if inv < 0.0 { (max, min) } else { (min, max) };
+    ((near - o) * inv, (far - o) * inv)
+}
+
+multiversion! {
+    fn packet_aabb(p: &RayPacket, aabb: &Aabb3<f32>, t: &mut [f32]) {
+        let (min, max) = (aabb.min, aabb.max);
+        for (i, t) in t.iter_mut().enumerate() {
+            let (x0, x1) = slab(p.ox[i], p.dx[i], min.0, max.0);
+            let (y0, y1) = slab(p.oy[i], p.dy[i], min.1, max.1);
+            let (z0, z1) = slab(p.oz[i], p.dz[i], min.2, max.2);
+            let t_min = 0.0f32.max(x0).max(y0).max(z0);
+            let t_max = f32::INFINITY.min(x1).min(y1).min(z1);
+            *t = if t_min <= t_max { t_min } else { f32::INFINITY };
+        }
+    }
+}
+
+multiversion! {
+    fn packet_sphere(p: &RayPacket, sphere: &Sphere<f32>, t: &mut [f32]) {
+        let (c, r2) = (sphere.center, sphere.radius * sphere.radius);
+        for (i, t) in t.iter_mut().enumerate() {
+            let (mx, my, mz) = (p.ox[i] - c.0, p.oy[i] - c.1, p.oz[i] - c.2);
+            let (dx, dy, dz) = (p.dx[i], p.dy[i], p.dz[i]);
+            let a = dx * dx + dy * dy + dz * dz;
+            let b = mx * dx + my * dy + mz * dz;
+            let disc = b * b - a * (mx * mx + my * my + mz * mz - r2);
+            let sqrt = math::sqrt(disc.max(0.0));
+            let (t0, t1) = ((-b - sqrt) / a, (-b + sqrt) / a);
+            let hit = a != 0.0 && disc >= 0.0 && t1 >= 0.0;
+            *t = if !hit { f32::INFINITY } else if t0 >= 0.0 { t0 } else { t1 };
+        }
+    }
+}
+
+multiversion! {
+    fn packet_triangle(p: &RayPacket, tri: &Triangle3<f32>, cull_backface: bool, t: &mut [f32]) {
+        let (e1, e2) = (tri.b - tri.a, tri.c - tri.a);
+        for (i, t) in t.iter_mut().enumerate() {
+            let d = Vec3(p.dx[i], p.dy[i], p.dz[i]);
+            let s = Vec3(p.ox[i], p.oy[i], p.oz[i]) - tri.a;
+            let pv = d.cross(e2);
+            let det = e1.dot(pv);
+            let inv = 1.0 / det;
+            let u = s.dot(pv) * inv;
+            let q = s.cross(e1);
+            let v = d.dot(q) * inv;
+            let hit_t = e2.dot(q) * inv;
+            let hit = det != 0.0 && !(cull_backface && det < 0.0) && (0.0..=1.0).contains(&u) &&
+                      v >= 0.0 && u + v <= 1.0 && hit_t >= 0.0;
+            *t = if hit { hit_t } else { f32::INFINITY };
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use prelude::*;
+
+use super::float::Float;
+use super::math;
+use super::lerp::Lerp;
+use super::vector::Vector;
+
+// The maximum depth of recursive subdivision when measuring a curve, bounding
+// the work done for tiny tolerances.
+const MAX_DEPTH: u32 = 16;
+
+/// CubicBezier is a cubic Bezier curve with the control points `p0` through
+/// `p3`, which starts at `p0` heading towards `p1` and ends at `p3` coming from
+/// `p2`. The points may be of any vector type (e.g. `Vec2` or `Vec3`).
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{CubicBezier, Vec2};
+///
+/// let c = CubicBezier::new(Vec2(0.0, 0.0), Vec2(0.0, 1.0), Vec2(1.0, 1.0), Vec2(1.0, 0.0));
+/// assert_eq!(c.eval(0.0), Vec2(0.0, 0.0));
+/// assert_eq!(c.eval(0.5), Vec2(0.5, 0.75));
+/// assert_eq!(c.eval(1.0), Vec2(1.0, 0.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CubicBezier<V> {
+    pub p0: V,
+    pub p1: V,
+    pub p2: V,
+    pub p3: V,
+}
+
+impl<T, V> CubicBezier<V>
+    where T: Float,
+          V: Vector<Scalar = T> + Lerp<T>
+{
+    /// new returns a new curve with the given control points.
+    pub fn new(p0: V, p1: V, p2: V, p3: V) -> Self {
+        CubicBezier {
+            p0,
+            p1,
+            p2,
+            p3,
+        }
+    }
+
+    /// eval returns the point on this curve at the parameter `t` in the range
+    /// 0.0 - 1.0, using de Casteljau's algorithm.
+    pub fn eval(&self, t: T) -> V {
+        let (a, b, c) = (self.p0.lerp(self.p1, t),
+                         self.p1.lerp(self.p2, t),
+                         self.p2.lerp(self.p3, t));
+        let (d, e) = (a.lerp(b, t), b.lerp(c, t));
+        d.lerp(e, t)
+    }
+
+    /// derivative returns the derivative of this curve with respect to `t` at
+    /// the parameter `t`, i.e. the tangent scaled by the speed of the curve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{CubicBezier, Vec2};
+    ///
+    /// let c = CubicBezier::new(Vec2(0.0, 0.0), Vec2(0.0, 1.0), Vec2(1.0, 1.0), Vec2(1.0, 0.0));
+    /// assert_eq!(c.derivative(0.0), Vec2(0.0, 3.0));
+    /// assert_eq!(c.derivative(0.5), Vec2(1.5, 0.0));
+    /// ```
+    pub fn derivative(&self, t: T) -> V {
+        let three = T::from(3.0).unwrap();
+        let (a, b, c) = (self.p1 - self.p0, self.p2 - self.p1, self.p3 - self.p2);
+        let (d, e) = (a.lerp(b, t), b.lerp(c, t));
+        d.lerp(e, t).mul_scalar(three)
+    }
+
+    /// split splits this curve at the parameter `t`, returning the curves
+    /// covering the parameter ranges 0.0 - `t` and `t` - 1.0 respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{CubicBezier, Vec2};
+    ///
+    /// let c = CubicBezier::new(Vec2(0.0, 0.0), Vec2(0.0, 1.0), Vec2(1.0, 1.0), Vec2(1.0, 0.0));
+    /// let (a, b) = c.split(0.5);
+    /// assert_eq!(a.p3, c.eval(0.5));
+    /// assert_eq!(a.eval(0.5), c.eval(0.25));
+    /// assert_eq!(b.eval(0.5), c.eval(0.75));
+    /// ```
+    pub fn split(&self, t: T) -> (Self, Self) {
+        let (a, b, c) = (self.p0.lerp(self.p1, t),
+                         self.p1.lerp(self.p2, t),
+                         self.p2.lerp(self.p3, t));
+        let (d, e) = (a.lerp(b, t), b.lerp(c, t));
+        let f = d.lerp(e, t);
+        (CubicBezier::new(self.p0, a, d, f), CubicBezier::new(f, e, c, self.p3))
+    }
+
+    /// length returns the arc length of this curve, approximated to a relative
+    /// error of about 1e-6 (or better) by adaptive subdivision, comparing the
+    /// lengths of the chord and control polygon of each piece (Gravesen).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{CubicBezier, Float, Vec2};
+    ///
+    /// let c = CubicBezier::new(Vec2(0.0, 0.0), Vec2(1.0, 1.0), Vec2(2.0, 2.0), Vec2(3.0, 3.0));
+    /// assert!(c.length().almost_equal(18.0f64.sqrt(), 1e-12));
+    ///
+    /// // A close approximation of a quarter of the unit circle.
+    /// let k = 0.5519150244935105707435627;
+    /// let c = CubicBezier::new(Vec2(1.0, 0.0), Vec2(1.0, k), Vec2(k, 1.0), Vec2(0.0, 1.0));
+    /// assert!(c.length().almost_equal(std::f64::consts::FRAC_PI_2, 1e-4));
+    /// ```
+    pub fn length(&self) -> T {
+        let tolerance = T::from(1.0e-6).unwrap();
+        self.length_within(tolerance * self.polygon_length(), 0)
+    }
+
+    // polygon_length returns the length of the control polygon of this curve.
+    fn polygon_length(&self) -> T {
+        self.p0.distance(self.p1) + self.p1.distance(self.p2) + self.p2.distance(self.p3)
+    }
+
+    // length_within returns the length of this curve to within the given
+    // absolute tolerance.
+    fn length_within(&self, tolerance: T, depth: u32) -> T {
+        let chord = self.p0.distance(self.p3);
+        let polygon = self.polygon_length();
+        if polygon - chord <= tolerance || depth >= MAX_DEPTH {
+            let two = T::from(2.0).unwrap();
+            return (chord + chord + two * polygon) / (two + two);
+        }
+        let half = T::from(0.5).unwrap();
+        let (a, b) = self.split(half);
+        a.length_within(tolerance * half, depth + 1) + b.length_within(tolerance * half, depth + 1)
+    }
+}
+
+/// QuadraticBezier is a quadratic Bezier curve with the control points `p0`
+/// through `p2`, which starts at `p0` heading towards `p1` and ends at `p2`.
+/// The points may be of any vector type (e.g. `Vec2` or `Vec3`).
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{QuadraticBezier, Vec2};
+///
+/// let q = QuadraticBezier::new(Vec2(0.0, 0.0), Vec2(1.0, 2.0), Vec2(2.0, 0.0));
+/// assert_eq!(q.eval(0.0), Vec2(0.0, 0.0));
+/// assert_eq!(q.eval(0.5), Vec2(1.0, 1.0));
+/// assert_eq!(q.eval(1.0), Vec2(2.0, 0.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct QuadraticBezier<V> {
+    pub p0: V,
+    pub p1: V,
+    pub p2: V,
+}
+
+impl<T, V> QuadraticBezier<V>
+    where T: Float,
+          V: Vector<Scalar = T> + Lerp<T>
+{
+    /// new returns a new curve with the given control points.
+    pub fn new(p0: V, p1: V, p2: V) -> Self {
+        QuadraticBezier {
+            p0,
+            p1,
+            p2,
+        }
+    }
+
+    /// eval returns the point on this curve at the parameter `t` in the range
+    /// 0.0 - 1.0, using de Casteljau's algorithm.
+    pub fn eval(&self, t: T) -> V {
+        self.p0.lerp(self.p1, t).lerp(self.p1.lerp(self.p2, t), t)
+    }
+
+    /// derivative returns the derivative of this curve with respect to `t` at
+    /// the parameter `t`, i.e. the tangent scaled by the speed of the curve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{QuadraticBezier, Vec2};
+    ///
+    /// let q = QuadraticBezier::new(Vec2(0.0, 0.0), Vec2(1.0, 2.0), Vec2(2.0, 0.0));
+    /// assert_eq!(q.derivative(0.0), Vec2(2.0, 4.0));
+    /// assert_eq!(q.derivative(0.5), Vec2(2.0, 0.0));
+    /// ```
+    pub fn derivative(&self, t: T) -> V {
+        let two = T::from(2.0).unwrap();
+        (self.p1 - self.p0).lerp(self.p2 - self.p1, t).mul_scalar(two)
+    }
+
+    /// bounding_box returns the minimum and maximum corners of the tightest
+    /// axis-aligned box containing this curve, which (unlike the box of the
+    /// control points) accounts for the extremum of each component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{QuadraticBezier, Vec2};
+    ///
+    /// let q = QuadraticBezier::new(Vec2(0.0, 0.0), Vec2(1.0, 2.0), Vec2(2.0, 0.0));
+    /// assert_eq!(q.bounding_box(), (Vec2(0.0, 0.0), Vec2(2.0, 1.0)));
+    /// ```
+    pub fn bounding_box(&self) -> (V, V) {
+        let (zero, one) = (V::splat(T::zero()), V::splat(T::one()));
+
+        // Each component is extreme where its derivative is zero; components
+        // which are linear (or constant) yield infinite or NaN parameters, which
+        // clamp to an end of the curve.
+        let a = self.p0 - self.p1;
+        let t = (a / (a + (self.p2 - self.p1))).max(zero).min(one);
+        let u = one - t;
+        let e = u * u * self.p0 + (u * t).mul_scalar(T::from(2.0).unwrap()) * self.p1 +
+                t * t * self.p2;
+        (self.p0.min(self.p2).min(e), self.p0.max(self.p2).max(e))
+    }
+
+    /// closest_point returns the point on this curve closest to `p`, and its
+    /// parameter `t`. It solves the cubic equation for the parameters at which
+    /// the curve is perpendicular to the direction to `p`, and compares them to
+    /// the ends of the curve.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{QuadraticBezier, Vec2};
+    ///
+    /// let q = QuadraticBezier::new(Vec2(0.0f64, 0.0), Vec2(1.0, 2.0), Vec2(2.0, 0.0));
+    /// let (point, t) = q.closest_point(Vec2(1.0, 3.0));
+    /// assert_eq!((point, t), (Vec2(1.0, 1.0), 0.5));
+    ///
+    /// let (point, t) = q.closest_point(Vec2(-1.0, -1.0));
+    /// assert_eq!((point, t), (Vec2(0.0, 0.0), 0.0));
+    ///
+    /// // Near the base, the closest points are on the sides of the arch.
+    /// let (point, t) = q.closest_point(Vec2(1.0, 0.0));
+    /// assert!((t - 0.5).abs() > 0.1 && (point - q.eval(t)).length() < 1e-12);
+    /// assert!(((point - Vec2(1.0, 0.0)).dot(q.derivative(t))).abs() < 1e-12);
+    /// ```
+    pub fn closest_point(&self, p: V) -> (V, T) {
+        // With the curve written as p0 + 2tA + t^2B, the squared distance is
+        // stationary where (p0 - p + 2tA + t^2B).(A + tB) is zero.
+        let a = self.p1 - self.p0;
+        let b = self.p2 - self.p1 - a;
+        let m = self.p0 - p;
+        let three = T::from(3.0).unwrap();
+        let roots = cubic_roots(b.dot(b),
+                                three * a.dot(b),
+                                a.dot(a) + a.dot(a) + m.dot(b),
+                                m.dot(a));
+
+        let (zero, one) = (T::zero(), T::one());
+        let mut best = (self.p0, zero, m.dot(m));
+        for t in roots.into_iter().chain(Some(one)) {
+            let t = t.max(zero).min(one);
+            let point = self.eval(t);
+            let d = (point - p).dot(point - p);
+            if d < best.2 {
+                best = (point, t, d);
+            }
+        }
+        (best.0, best.1)
+    }
+}
+
+// cubic_roots returns the real roots of the polynomial ax^3 + bx^2 + cx + d,
+// falling back to the quadratic or linear formulae when the leading
+// coefficients are (relatively) zero.
+fn cubic_roots<T: Float>(a: T, b: T, c: T, d: T) -> Vec<T> {
+    let (zero, two, three) = (T::zero(), T::from(2.0).unwrap(), T::from(3.0).unwrap());
+    let scale = b.abs().max(c.abs()).max(d.abs());
+    if a.abs() <= scale * T::epsilon() * T::from(16.0).unwrap() {
+        if b == zero {
+            return if c == zero { vec![] } else { vec![-d / c] };
+        }
+        let disc = c * c - two * two * b * d;
+        if disc < zero {
+            return vec![];
+        }
+        // Avoid cancellation by computing the larger root first.
+        let q = -(c + math::sqrt(disc).copysign(c)) / two;
+        return if q == zero { vec![zero] } else { vec![q / b, d / q] };
+    }
+
+    // Solve the depressed cubic x^3 + px + q, where t = x - b/3.
+    let (b, c, d) = (b / a, c / a, d / a);
+    let shift = b / three;
+    let p = c - b * shift;
+    let q = (two * shift * shift - c) * shift + d;
+    let disc = q * q / (two * two) + p * p * p / (three * three * three);
+    let roots = if disc > zero {
+        let s = math::sqrt(disc);
+        vec![math::cbrt(-q / two + s) + math::cbrt(-q / two - s)]
+    } else if p == zero {
+        vec![zero]
+    } else {
+        let r = math::sqrt(-p / three);
+        let phi = math::acos((-q / (two * r * r * r)).max(-T::one()).min(T::one()));
+        let third = T::from(2.0 * ::core::f64::consts::PI / 3.0).unwrap();
+        (0..3).map(|k| two * r * math::cos(phi / three + third * T::from(k).unwrap())).collect()
+    };
+
+    // Polish each root with a step of Newton's method.
+    roots.into_iter()
+        .map(|x| {
+            let t = x - shift;
+            let f = ((t + b) * t + c) * t + d;
+            let df = (three * t + two * b) * t + c;
+            if df == zero { t } else { t - f / df }
+        })
+        .collect()
+}
@@ -0,0 +1,99 @@
+use prelude::*;
+
+use super::float::Float;
+use super::lerp::Lerp;
+use super::vector::Vector;
+
+/// BSpline is a B-spline curve with a clamped, uniform knot vector, which
+/// starts at its first control point and ends at its last, and otherwise
+/// approximates the control polygon smoothly (quadratic and cubic B-splines
+/// are continuous in their first and second derivatives respectively). Unlike
+/// a Bezier curve, moving a control point affects only the nearby part of the
+/// curve, which suits long sequences of points.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{BSpline, Vec2};
+///
+/// let points = [Vec2(0.0, 0.0), Vec2(1.0, 2.0), Vec2(3.0, 2.0), Vec2(4.0, 0.0)];
+/// let s = BSpline::new(&points, 2).unwrap();
+/// assert_eq!(s.eval(0.0), Vec2(0.0, 0.0));
+/// assert_eq!(s.eval(0.5), Vec2(2.0, 2.0));
+/// assert_eq!(s.eval(1.0), Vec2(4.0, 0.0));
+///
+/// // There must be more control points than the degree.
+/// assert!(BSpline::new(&points[..2], 2).is_none());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct BSpline<V> {
+    points: Vec<V>,
+    degree: usize,
+}
+
+impl<T, V> BSpline<V>
+    where T: Float,
+          V: Vector<Scalar = T> + Lerp<T>
+{
+    /// new returns the B-spline of the given degree (e.g. 2 for quadratic or 3
+    /// for cubic) with the given control points, or None if the degree is zero
+    /// or there are not more control points than the degree.
+    pub fn new(points: &[V], degree: usize) -> Option<Self> {
+        if degree == 0 || points.len() <= degree {
+            return None;
+        }
+        Some(BSpline {
+            points: points.to_vec(),
+            degree,
+        })
+    }
+
+    /// points returns the control points of this spline.
+    pub fn points(&self) -> &[V] {
+        &self.points
+    }
+
+    /// degree returns the degree of this spline.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    // knot returns the i'th knot of the clamped, uniform knot vector, whose
+    // first and last knots are repeated degree + 1 times.
+    fn knot(&self, i: usize) -> T {
+        let spans = self.points.len() - self.degree;
+        T::from(i.saturating_sub(self.degree).min(spans)).unwrap()
+    }
+
+    /// eval returns the point on this spline at the parameter `t` in the range
+    /// 0.0 - 1.0 (which is clamped), using de Boor's algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{BSpline, Float, Vec2};
+    ///
+    /// // With as many control points as a Bezier curve of the same degree, the
+    /// // spline is that curve.
+    /// let points = [Vec2(0.0, 0.0), Vec2(0.0, 1.0), Vec2(1.0, 1.0), Vec2(1.0, 0.0)];
+    /// let s = BSpline::new(&points, 3).unwrap();
+    /// assert!(s.eval(0.5).almost_equal(Vec2(0.5, 0.75), 1e-12));
+    /// ```
+    pub fn eval(&self, t: T) -> V {
+        let p = self.degree;
+        let spans = self.points.len() - p;
+        let u = t.max(T::zero()).min(T::one()) * T::from(spans).unwrap();
+
+        // k is the index of the knot span containing u.
+        let k = u.floor().to_usize().unwrap_or(0).min(spans - 1) + p;
+        let mut d = self.points[k - p..k + 1].to_vec();
+        for r in 1..p + 1 {
+            for j in (r..p + 1).rev() {
+                let lo = self.knot(j + k - p);
+                let alpha = (u - lo) / (self.knot(j + 1 + k - r) - lo);
+                d[j] = d[j - 1].lerp(d[j], alpha);
+            }
+        }
+        d[p]
+    }
+}
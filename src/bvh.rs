@@ -0,0 +1,271 @@
+use core::cmp::Ordering;
+use prelude::*;
+
+use super::float::Float;
+use super::intersects::aabb_aabb;
+use super::{Aabb3, Ray3, Vec3};
+
+// The maximum number of primitives in a leaf, and the number of bins used to
+// evaluate the surface area heuristic when splitting a node.
+const MAX_LEAF_SIZE: usize = 4;
+const BINS: usize = 12;
+
+// Node is a node of a Bvh. Leaves hold the primitives indices[first..first +
+// count]; interior nodes (with a count of zero) have their left child
+// immediately after them and their right child at first.
+#[derive(Copy, Clone, Debug)]
+struct Node<T> {
+    aabb: Aabb3<T>,
+    first: usize,
+    count: usize,
+}
+
+// area returns the surface area of the box.
+fn area<T: Float>(b: &Aabb3<T>) -> T {
+    let s = b.size();
+    (s.0 * s.1 + s.1 * s.2 + s.2 * s.0) * T::from(2.0).unwrap()
+}
+
+// sweep accumulates the bins in the given order, returning for each bin the
+// surface area heuristic cost (area times count) of it and the bins before it,
+// and their primitive count.
+fn sweep<T, I>(bins: &[Option<(Aabb3<T>, usize)>], order: I) -> Vec<(T, usize)>
+    where T: Float,
+          I: Iterator<Item = usize>
+{
+    let mut acc: Option<(Aabb3<T>, usize)> = None;
+    let mut out = vec![(T::zero(), 0); bins.len()];
+    for b in order {
+        if let Some((aabb, n)) = bins[b] {
+            acc = Some(match acc {
+                Some((a, m)) => (a.union(aabb), m + n),
+                None => (aabb, n),
+            });
+        }
+        out[b] = acc.map_or((T::zero(), 0), |(a, n)| (area(&a) * T::from(n).unwrap(), n));
+    }
+    out
+}
+
+/// Bvh is a bounding volume hierarchy over a list of axis-aligned boxes (e.g.
+/// the bounds of the primitives of a scene), which accelerates finding the
+/// boxes hit by a ray or overlapping a region. Queries return the indices of
+/// the boxes in the list that the hierarchy was built from.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Aabb3, Bvh, Ray3, Vec3};
+///
+/// let boxes: Vec<_> = (0..100)
+///     .map(|i| {
+///         let min = Vec3(i as f64 * 2.0, 0.0, 0.0);
+///         Aabb3::new(min, min + Vec3(1.0, 1.0, 1.0))
+///     })
+///     .collect();
+/// let bvh = Bvh::new(&boxes);
+///
+/// let region = Aabb3::new(Vec3(9.5, 0.0, 0.0), Vec3(12.5, 1.0, 1.0));
+/// let mut hits = bvh.aabb_query(&region);
+/// hits.sort();
+/// assert_eq!(hits, vec![5, 6]);
+///
+/// let ray = Ray3::new(Vec3(40.5, 0.5, -5.0), Vec3(0.0, 0.0, 1.0));
+/// assert_eq!(bvh.ray_query(&ray), vec![20]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Bvh<T> {
+    nodes: Vec<Node<T>>,
+    indices: Vec<usize>,
+    aabbs: Vec<Aabb3<T>>,
+}
+
+impl<T: Float> Bvh<T> {
+    /// new builds a hierarchy over the given boxes, splitting nodes according
+    /// to the surface area heuristic (SAH), evaluated over a fixed number of
+    /// bins along the longest axis of each node.
+    pub fn new(aabbs: &[Aabb3<T>]) -> Self {
+        let mut bvh = Bvh {
+            nodes: Vec::with_capacity(2 * aabbs.len()),
+            indices: (0..aabbs.len()).collect(),
+            aabbs: aabbs.to_vec(),
+        };
+        if !aabbs.is_empty() {
+            let centers: Vec<Vec3<T>> = aabbs.iter().map(|b| b.center()).collect();
+            bvh.build(aabbs, &centers, 0, aabbs.len());
+        }
+        bvh
+    }
+
+    // bounds returns the union of f(i) for the primitives i in the given
+    // (non-empty) range of indices.
+    fn bounds<F: Fn(usize) -> Aabb3<T>>(&self, f: F, start: usize, end: usize) -> Aabb3<T> {
+        self.indices[start + 1..end]
+            .iter()
+            .fold(f(self.indices[start]), |b, &i| b.union(f(i)))
+    }
+
+    // build adds the node for the primitives indices[start..end] and its
+    // descendants, returning its index.
+    fn build(&mut self,
+             aabbs: &[Aabb3<T>],
+             centers: &[Vec3<T>],
+             start: usize,
+             end: usize)
+             -> usize {
+        let index = self.nodes.len();
+        let aabb = self.bounds(|i| aabbs[i], start, end);
+        self.nodes.push(Node {
+            aabb,
+            first: start,
+            count: end - start,
+        });
+        if end - start <= MAX_LEAF_SIZE {
+            return index;
+        }
+        let mid = match self.split(aabbs, centers, start, end) {
+            Some(mid) => mid,
+            None => return index,
+        };
+        self.build(aabbs, centers, start, mid);
+        let right = self.build(aabbs, centers, mid, end);
+        self.nodes[index].first = right;
+        self.nodes[index].count = 0;
+        index
+    }
+
+    // split partitions indices[start..end] in two, returning the index that
+    // separates them, or None if the primitives cannot be split (as their
+    // centers coincide).
+    fn split(&mut self,
+             aabbs: &[Aabb3<T>],
+             centers: &[Vec3<T>],
+             start: usize,
+             end: usize)
+             -> Option<usize> {
+        let Aabb3 { min, max } = self.bounds(|i| Aabb3::new(centers[i], centers[i]), start, end);
+        let extent = max - min;
+        let axis = if extent.0 >= extent.1 && extent.0 >= extent.2 {
+            0
+        } else if extent.1 >= extent.2 {
+            1
+        } else {
+            2
+        };
+        let coord = |v: Vec3<T>| [v.0, v.1, v.2][axis];
+        let (lo, size) = (coord(min), coord(extent));
+        if size.partial_cmp(&T::zero()) != Some(Ordering::Greater) {
+            return None;
+        }
+
+        // Bin the primitives by center, then choose the boundary between bins
+        // that minimizes the sum of the area times the primitive count of each
+        // side. The first and last bins hold the extreme centers, so neither
+        // side is ever empty.
+        let scale = T::from(BINS).unwrap() / size;
+        let bin_of = |i: usize| {
+            let b = ((coord(centers[i]) - lo) * scale).to_usize().unwrap_or(0);
+            if b >= BINS { BINS - 1 } else { b }
+        };
+        let mut bins: Vec<Option<(Aabb3<T>, usize)>> = vec![None; BINS];
+        for &i in &self.indices[start..end] {
+            let b = &mut bins[bin_of(i)];
+            *b = Some(match *b {
+                Some((aabb, n)) => (aabb.union(aabbs[i]), n + 1),
+                None => (aabbs[i], 1),
+            });
+        }
+        let left = sweep(&bins, 0..BINS);
+        let right = sweep(&bins, (0..BINS).rev());
+        let cost = |b: usize| left[b].0 + right[b + 1].0;
+        let best = (1..BINS - 1).fold(0, |best, b| if cost(b) < cost(best) { b } else { best });
+
+        let mut mid = start;
+        for k in start..end {
+            if bin_of(self.indices[k]) <= best {
+                self.indices.swap(k, mid);
+                mid += 1;
+            }
+        }
+        Some(mid)
+    }
+
+    /// ray_query returns the indices of the boxes hit by the given ray, in no
+    /// particular order.
+    pub fn ray_query(&self, ray: &Ray3<T>) -> Vec<usize> {
+        self.query(|aabb| ray.intersects_aabb(aabb).is_some())
+    }
+
+    /// aabb_query returns the indices of the boxes that overlap (or touch) the
+    /// given box, in no particular order.
+    pub fn aabb_query(&self, aabb: &Aabb3<T>) -> Vec<usize> {
+        self.query(|b| aabb_aabb(b, aabb))
+    }
+
+    // query returns the indices of the boxes for which hit is true, given that
+    // if it is true for a box it is also true for all boxes containing it.
+    fn query<F: Fn(&Aabb3<T>) -> bool>(&self, hit: F) -> Vec<usize> {
+        let mut out = Vec::new();
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(n) = stack.pop() {
+            let node = &self.nodes[n];
+            if !hit(&node.aabb) {
+                continue;
+            }
+            if node.count == 0 {
+                stack.push(node.first);
+                stack.push(n + 1);
+                continue;
+            }
+            for &i in &self.indices[node.first..node.first + node.count] {
+                if hit(&self.aabbs[i]) {
+                    out.push(i);
+                }
+            }
+        }
+        out
+    }
+
+    /// refit updates the hierarchy for new boxes, e.g. after the primitives
+    /// have moved, without changing its structure. This is much faster than
+    /// rebuilding it, but queries become slower as the boxes move further
+    /// from where they were when it was built.
+    ///
+    /// # Panics
+    ///
+    /// refit panics if the number of boxes differs from the number the
+    /// hierarchy was built from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Bvh, Vec3};
+    ///
+    /// let mut boxes = vec![Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0)); 10];
+    /// let mut bvh = Bvh::new(&boxes);
+    /// let region = Aabb3::new(Vec3(5.0, 5.0, 5.0), Vec3(6.0, 6.0, 6.0));
+    /// assert!(bvh.aabb_query(&region).is_empty());
+    ///
+    /// boxes[3] = Aabb3::new(Vec3(4.5, 4.5, 4.5), Vec3(5.5, 5.5, 5.5));
+    /// bvh.refit(&boxes);
+    /// assert_eq!(bvh.aabb_query(&region), vec![3]);
+    /// ```
+    pub fn refit(&mut self, aabbs: &[Aabb3<T>]) {
+        assert_eq!(aabbs.len(), self.aabbs.len());
+        self.aabbs.copy_from_slice(aabbs);
+
+        // Children come after their parents, so a reverse sweep updates them
+        // first.
+        for n in (0..self.nodes.len()).rev() {
+            let node = self.nodes[n];
+            self.nodes[n].aabb = if node.count == 0 {
+                self.nodes[n + 1].aabb.union(self.nodes[node.first].aabb)
+            } else {
+                self.bounds(|i| aabbs[i], node.first, node.first + node.count)
+            };
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use bytemuck::{NoUninit, Pod, Zeroable};
+
+use super::{Basis3, Color, F16, Fixed, Vec2, Vec3, Vec3A, Vec4};
+
+// Vectors are #[repr(C)] with components of a single type, so they have no
+// padding, and are plain old data whenever their components are; e.g. a
+// &[Vec4<f32>] can be cast to a &[u8] for uploading to a GPU buffer.
+
+unsafe impl<T: Zeroable> Zeroable for Vec2<T> {}
+unsafe impl<T: Pod> Pod for Vec2<T> {}
+
+unsafe impl<T: Zeroable> Zeroable for Vec3<T> {}
+unsafe impl<T: Pod> Pod for Vec3<T> {}
+
+unsafe impl<T: Zeroable> Zeroable for Vec4<T> {}
+unsafe impl<T: Pod> Pod for Vec4<T> {}
+
+// Colors are laid out like a Vec4.
+unsafe impl<T: Zeroable> Zeroable for Color<T> {}
+unsafe impl<T: Pod> Pod for Color<T> {}
+
+// Vec3A's padding is an explicit (zero) component, so it has no padding bytes.
+unsafe impl Zeroable for Vec3A {}
+unsafe impl Pod for Vec3A {}
+
+// F16 is a transparent u16, so vectors of it can be cast to packed u16s.
+unsafe impl Zeroable for F16 {}
+unsafe impl Pod for F16 {}
+
+// Fixed is a transparent integer.
+unsafe impl<I: Zeroable, const FRAC: u32> Zeroable for Fixed<I, FRAC> {}
+unsafe impl<I: Pod, const FRAC: u32> Pod for Fixed<I, FRAC> {}
+
+// A basis can be cast to bytes, but not from them (nor zeroed), since not every
+// bit pattern is a rotation.
+unsafe impl<T: NoUninit> NoUninit for Basis3<T> {}
@@ -0,0 +1,130 @@
+use super::float::Float;
+use super::{Aabb3, Segment3, Sphere, Vec3};
+
+/// Capsule is a solid capsule in three dimensions: the points within the given
+/// radius of a line segment.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Capsule, Segment3, Vec3};
+///
+/// // A character two units tall standing at the origin.
+/// let c = Capsule::new(Segment3::new(Vec3(0.0, 0.5, 0.0), Vec3(0.0, 1.5, 0.0)), 0.5);
+/// assert!(c.contains_point(Vec3(0.0, 1.9, 0.0)));
+/// assert!(!c.contains_point(Vec3(0.5, 1.9, 0.0)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Capsule<T> {
+    /// The segment at the core of the capsule.
+    pub segment: Segment3<T>,
+
+    /// The radius of the capsule.
+    pub radius: T,
+}
+
+impl<T: Float> Capsule<T> {
+    /// new returns the capsule of the given radius around the segment.
+    pub fn new(segment: Segment3<T>, radius: T) -> Self {
+        Capsule {
+            segment,
+            radius,
+        }
+    }
+
+    /// contains_point tells if the given point is inside this capsule, or on
+    /// its surface.
+    pub fn contains_point(&self, point: Vec3<T>) -> bool {
+        let c = self.segment.closest_point_to(point);
+        (point - c).length_sq() <= self.radius * self.radius
+    }
+
+    /// closest_point returns the point inside this capsule (or on its surface)
+    /// closest to the given point, which is the point itself if it is inside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Capsule, Segment3, Vec3};
+    ///
+    /// let c = Capsule::new(Segment3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 2.0, 0.0)), 1.0);
+    /// assert_eq!(c.closest_point(Vec3(3.0, 1.0, 0.0)), Vec3(1.0, 1.0, 0.0));
+    /// assert_eq!(c.closest_point(Vec3(0.0, 5.0, 0.0)), Vec3(0.0, 3.0, 0.0));
+    /// ```
+    pub fn closest_point(&self, point: Vec3<T>) -> Vec3<T> {
+        let c = self.segment.closest_point_to(point);
+        Sphere::new(c, self.radius).closest_point(point)
+    }
+
+    /// intersects_sphere tells if this capsule and the sphere overlap
+    /// (including touching).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Capsule, Segment3, Sphere, Vec3};
+    ///
+    /// let c = Capsule::new(Segment3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 2.0, 0.0)), 1.0);
+    /// assert!(c.intersects_sphere(&Sphere::new(Vec3(2.0, 1.0, 0.0), 1.0)));
+    /// assert!(!c.intersects_sphere(&Sphere::new(Vec3(2.0, 4.0, 0.0), 1.0)));
+    /// ```
+    pub fn intersects_sphere(&self, sphere: &Sphere<T>) -> bool {
+        let r = self.radius + sphere.radius;
+        let c = self.segment.closest_point_to(sphere.center);
+        (sphere.center - c).length_sq() <= r * r
+    }
+
+    /// intersects_capsule tells if this capsule and the other overlap
+    /// (including touching).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Capsule, Segment3, Vec3};
+    ///
+    /// let a = Capsule::new(Segment3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 2.0, 0.0)), 0.5);
+    /// let b = Capsule::new(Segment3::new(Vec3(-1.0, 1.0, 0.9), Vec3(1.0, 1.0, 0.9)), 0.5);
+    /// assert!(a.intersects_capsule(&b));
+    /// ```
+    pub fn intersects_capsule(&self, other: &Self) -> bool {
+        let r = self.radius + other.radius;
+        let (p, q) = self.segment.closest_points(&other.segment);
+        (q - p).length_sq() <= r * r
+    }
+
+    /// intersects_aabb tells if this capsule and the given axis-aligned box
+    /// overlap (including touching).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Capsule, Segment3, Vec3};
+    ///
+    /// let b = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0));
+    /// let c = Capsule::new(Segment3::new(Vec3(2.0, -1.0, 0.5), Vec3(2.0, 2.0, 0.5)), 1.0);
+    /// assert!(c.intersects_aabb(&b));
+    /// let c = Capsule::new(Segment3::new(Vec3(2.0, -1.0, 0.5), Vec3(3.0, -1.0, 0.5)), 1.0);
+    /// assert!(!c.intersects_aabb(&b));
+    /// ```
+    pub fn intersects_aabb(&self, aabb: &Aabb3<T>) -> bool {
+        // The distance from the box to a point moving along the segment is a
+        // convex function of the parameter of the point, so its minimum can be
+        // found by ternary search.
+        let dist_sq = |t: T| {
+            let p = self.segment.at(t);
+            (aabb.closest_point(p) - p).length_sq()
+        };
+        let (mut lo, mut hi) = (T::zero(), T::one());
+        let third = T::one() / T::from(3).unwrap();
+        for _ in 0..64 {
+            let (m1, m2) = (lo + (hi - lo) * third, hi - (hi - lo) * third);
+            if dist_sq(m1) <= dist_sq(m2) {
+                hi = m2;
+            } else {
+                lo = m1;
+            }
+        }
+        let t = (lo + hi) / T::from(2).unwrap();
+        dist_sq(t) <= self.radius * self.radius
+    }
+}
@@ -0,0 +1,128 @@
+use core::mem;
+use core::slice;
+
+use super::{Color, Vec2, Vec3, Vec4};
+
+/// Components is implemented by the vector types which consist of nothing but
+/// `COUNT` components of type `Component`, such that a slice of vectors can be
+/// viewed as a flat slice of components and back without copying (see
+/// `cast_slice` and `try_cast_slice`).
+///
+/// # Safety
+///
+/// Implementors must have the same size, alignment and layout as
+/// `[Self::Component; Self::COUNT]`, i.e. be `#[repr(C)]` with no padding.
+pub unsafe trait Components: Sized {
+    /// Component is the type of the components of this vector.
+    type Component;
+
+    /// COUNT is the number of components of this vector.
+    const COUNT: usize;
+}
+
+// Vectors and colors are #[repr(C)] with components of a single type, so they
+// have the same layout as an array of them.
+
+unsafe impl<T> Components for Vec2<T> {
+    type Component = T;
+    const COUNT: usize = 2;
+}
+
+unsafe impl<T> Components for Vec3<T> {
+    type Component = T;
+    const COUNT: usize = 3;
+}
+
+unsafe impl<T> Components for Vec4<T> {
+    type Component = T;
+    const COUNT: usize = 4;
+}
+
+unsafe impl<T> Components for Color<T> {
+    type Component = T;
+    const COUNT: usize = 4;
+}
+
+/// cast_slice views a slice of vectors as the flat slice of their components,
+/// without copying, e.g. to hand a vertex buffer to an API taking `&[f32]`.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{cast_slice, Vec3};
+///
+/// let v = [Vec3(1.0, 2.0, 3.0), Vec3(4.0, 5.0, 6.0)];
+/// assert_eq!(cast_slice(&v), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+/// ```
+pub fn cast_slice<V: Components>(v: &[V]) -> &[V::Component] {
+    unsafe { slice::from_raw_parts(v.as_ptr() as *const V::Component, v.len() * V::COUNT) }
+}
+
+/// cast_slice_mut is like `cast_slice`, but for mutable slices.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{cast_slice_mut, Vec2};
+///
+/// let mut v = [Vec2(1, 2), Vec2(3, 4)];
+/// for c in cast_slice_mut(&mut v) {
+///     *c *= 10;
+/// }
+/// assert_eq!(v, [Vec2(10, 20), Vec2(30, 40)]);
+/// ```
+pub fn cast_slice_mut<V: Components>(v: &mut [V]) -> &mut [V::Component] {
+    unsafe { slice::from_raw_parts_mut(v.as_mut_ptr() as *mut V::Component, v.len() * V::COUNT) }
+}
+
+// fits tells if a slice of components starting at ptr, of the given length, can
+// be viewed as a slice of vectors of type V. The alignment of a vector is that
+// of its components, so only the length can be off in practice.
+fn fits<V: Components>(ptr: *const V::Component, len: usize) -> bool {
+    len % V::COUNT == 0 && ptr as usize % mem::align_of::<V>() == 0
+}
+
+/// try_cast_slice views a flat slice of components as a slice of vectors,
+/// without copying, e.g. to read back a vertex buffer given as `&[f32]`. It
+/// returns None if the length of the slice is not a multiple of the number of
+/// components of a vector.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{try_cast_slice, Vec3};
+///
+/// let c = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+/// let v: &[Vec3<f64>] = try_cast_slice(&c).unwrap();
+/// assert_eq!(v, &[Vec3(1.0, 2.0, 3.0), Vec3(4.0, 5.0, 6.0)]);
+///
+/// assert!(try_cast_slice::<Vec3<f64>>(&c[..5]).is_none());
+/// ```
+pub fn try_cast_slice<V: Components>(c: &[V::Component]) -> Option<&[V]> {
+    if !fits::<V>(c.as_ptr(), c.len()) {
+        return None;
+    }
+    Some(unsafe { slice::from_raw_parts(c.as_ptr() as *const V, c.len() / V::COUNT) })
+}
+
+/// try_cast_slice_mut is like `try_cast_slice`, but for mutable slices.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{try_cast_slice_mut, Vec2};
+///
+/// let mut c = [1, 2, 3, 4];
+/// for v in try_cast_slice_mut::<Vec2<i32>>(&mut c).unwrap() {
+///     *v = Vec2(v.1, v.0);
+/// }
+/// assert_eq!(c, [2, 1, 4, 3]);
+///
+/// assert!(try_cast_slice_mut::<Vec2<i32>>(&mut c[..3]).is_none());
+/// ```
+pub fn try_cast_slice_mut<V: Components>(c: &mut [V::Component]) -> Option<&mut [V]> {
+    if !fits::<V>(c.as_ptr(), c.len()) {
+        return None;
+    }
+    Some(unsafe { slice::from_raw_parts_mut(c.as_mut_ptr() as *mut V, c.len() / V::COUNT) })
+}
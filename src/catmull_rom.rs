@@ -0,0 +1,164 @@
+use prelude::*;
+
+use super::float::Float;
+use super::math;
+use super::lerp::Lerp;
+use super::vector::Vector;
+
+/// CatmullRom is a Catmull-Rom spline passing through a list of points, using
+/// centripetal parameterization (i.e. the parameter advances by the square
+/// root of the distance between points), which avoids cusps and
+/// self-intersections within segments. The tangents at the ends are
+/// extrapolated linearly from the first and last segments.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{CatmullRom, Vec2};
+///
+/// let points = [Vec2(0.0, 0.0), Vec2(1.0, 1.0), Vec2(2.0, 0.0), Vec2(3.0, 1.0)];
+/// let s = CatmullRom::new(&points).unwrap();
+/// assert_eq!(s.eval(0.0), points[0]);
+/// assert_eq!(s.eval(1.0), points[3]);
+/// assert!(s.eval(1.0 / 3.0).almost_equal(points[1], 1e-12));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatmullRom<V: Vector> {
+    points: Vec<V>,
+    knots: Vec<V::Scalar>,
+}
+
+impl<T, V> CatmullRom<V>
+    where T: Float,
+          V: Vector<Scalar = T> + Lerp<T>
+{
+    /// new returns the spline through the given points, or None if there are
+    /// fewer than two distinct points. Consecutive duplicate points are
+    /// skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{CatmullRom, Vec2};
+    ///
+    /// let s = CatmullRom::new(&[Vec2(0.0, 0.0), Vec2(0.0, 0.0), Vec2(1.0, 0.0)]).unwrap();
+    /// assert_eq!(s.points(), &[Vec2(0.0, 0.0), Vec2(1.0, 0.0)]);
+    /// assert_eq!(CatmullRom::new(&[Vec2(0.0, 0.0), Vec2(0.0, 0.0)]), None);
+    /// ```
+    pub fn new(points: &[V]) -> Option<Self> {
+        let mut out: Vec<V> = Vec::with_capacity(points.len());
+        let mut knots: Vec<T> = Vec::with_capacity(points.len());
+        for &p in points {
+            let knot = match out.last() {
+                None => T::zero(),
+                Some(&last) => {
+                    let step = math::sqrt(last.distance(p));
+                    if step == T::zero() {
+                        continue;
+                    }
+                    knots[knots.len() - 1] + step
+                }
+            };
+            out.push(p);
+            knots.push(knot);
+        }
+        if out.len() < 2 {
+            return None;
+        }
+        Some(CatmullRom {
+            points: out,
+            knots,
+        })
+    }
+
+    /// points returns the points which this spline passes through.
+    pub fn points(&self) -> &[V] {
+        &self.points
+    }
+
+    // controls returns the knots and control points of the segment containing
+    // the (unnormalized) parameter u, extrapolating the missing neighbors of
+    // the end segments linearly.
+    fn controls(&self, u: T) -> ([T; 4], [V; 4]) {
+        let (mut i, mut hi) = (0, self.knots.len() - 1);
+        while hi - i > 1 {
+            let mid = (i + hi) / 2;
+            if self.knots[mid] <= u {
+                i = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let (t1, t2) = (self.knots[i], self.knots[i + 1]);
+        let (p1, p2) = (self.points[i], self.points[i + 1]);
+        let (t0, p0) = if i > 0 {
+            (self.knots[i - 1], self.points[i - 1])
+        } else {
+            (t1 - (t2 - t1), p1.lerp(p2, -T::one()))
+        };
+        let (t3, p3) = if i + 2 < self.points.len() {
+            (self.knots[i + 2], self.points[i + 2])
+        } else {
+            (t2 + (t2 - t1), p2.lerp(p1, -T::one()))
+        };
+        ([t0, t1, t2, t3], [p0, p1, p2, p3])
+    }
+
+    // parameter maps t in the range 0.0 - 1.0 to the range of the knots.
+    fn parameter(&self, t: T) -> T {
+        t.max(T::zero()).min(T::one()) * self.knots[self.knots.len() - 1]
+    }
+
+    /// eval returns the point on this spline at the parameter `t` in the range
+    /// 0.0 - 1.0 (which is clamped), covering the whole spline. The points are
+    /// reached at parameters proportional to their centripetal knots, rather
+    /// than evenly.
+    pub fn eval(&self, t: T) -> V {
+        let u = self.parameter(t);
+        let (t, p) = self.controls(u);
+        let s = |a: usize, b: usize| (u - t[a]) / (t[b] - t[a]);
+
+        // The Barry-Goldman pyramidal formulation.
+        let a1 = p[0].lerp(p[1], s(0, 1));
+        let a2 = p[1].lerp(p[2], s(1, 2));
+        let a3 = p[2].lerp(p[3], s(2, 3));
+        let b1 = a1.lerp(a2, s(0, 2));
+        let b2 = a2.lerp(a3, s(1, 3));
+        b1.lerp(b2, s(1, 2))
+    }
+
+    /// tangent returns the derivative of this spline with respect to `t` at the
+    /// parameter `t` (see `eval`), i.e. the tangent scaled by the speed of the
+    /// spline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{CatmullRom, Vec2};
+    ///
+    /// // Evenly spaced collinear points are traversed at a constant speed.
+    /// let s = CatmullRom::new(&[Vec2(0.0, 0.0), Vec2(1.0, 0.0), Vec2(2.0, 0.0)]).unwrap();
+    /// assert!(s.tangent(0.0).almost_equal(Vec2(2.0, 0.0), 1e-12));
+    /// assert!(s.tangent(0.3).almost_equal(Vec2(2.0, 0.0), 1e-12));
+    /// ```
+    pub fn tangent(&self, t: T) -> V {
+        let u = self.parameter(t);
+        let (t, p) = self.controls(u);
+        let s = |a: usize, b: usize| (u - t[a]) / (t[b] - t[a]);
+        let ds = |a: usize, b: usize| T::one() / (t[b] - t[a]);
+
+        // Differentiate each level of the pyramid by the product rule.
+        let a1 = p[0].lerp(p[1], s(0, 1));
+        let a2 = p[1].lerp(p[2], s(1, 2));
+        let a3 = p[2].lerp(p[3], s(2, 3));
+        let da1 = (p[1] - p[0]).mul_scalar(ds(0, 1));
+        let da2 = (p[2] - p[1]).mul_scalar(ds(1, 2));
+        let da3 = (p[3] - p[2]).mul_scalar(ds(2, 3));
+        let b1 = a1.lerp(a2, s(0, 2));
+        let b2 = a2.lerp(a3, s(1, 3));
+        let db1 = da1.lerp(da2, s(0, 2)) + (a2 - a1).mul_scalar(ds(0, 2));
+        let db2 = da2.lerp(da3, s(1, 3)) + (a3 - a2).mul_scalar(ds(1, 3));
+        let d = db1.lerp(db2, s(1, 2)) + (b2 - b1).mul_scalar(ds(1, 2));
+        d.mul_scalar(self.knots[self.knots.len() - 1])
+    }
+}
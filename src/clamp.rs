@@ -18,11 +18,11 @@ pub trait Clamp{
     /// ```
     /// use fiz_math::Clamp;
     ///
-    /// assert_eq!(11.clamp(0, 10), 10);
-    /// assert_eq!(20.clamp(0, 15), 15);
+    /// assert_eq!(Clamp::clamp(11, 0, 10), 10);
+    /// assert_eq!(Clamp::clamp(20, 0, 15), 15);
     ///
-    /// assert_eq!((-10).clamp(0, 10), 0);
-    /// assert_eq!(0.clamp(1, 10), 1);
+    /// assert_eq!(Clamp::clamp(-10, 0, 10), 0);
+    /// assert_eq!(Clamp::clamp(0, 1, 10), 1);
     /// ```
     fn clamp(self, min: Self::Elem, max: Self::Elem) -> Self;
 }
@@ -0,0 +1,195 @@
+use super::float::Float;
+use super::{Aabb3, Obb, Plane, Segment3, Sphere, Triangle3, Vec3};
+
+/// closest_point_on_aabb returns the point on the surface of the axis-aligned
+/// box closest to the given point, and the distance between the two. Unlike
+/// `Aabb3::closest_point`, points inside the box are moved to its nearest
+/// face.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{closest_point_on_aabb, Aabb3, Vec3};
+///
+/// let b = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(4.0, 4.0, 4.0));
+/// assert_eq!(closest_point_on_aabb(&b, Vec3(7.0, 2.0, 8.0)), (Vec3(4.0, 2.0, 4.0), 5.0));
+/// assert_eq!(closest_point_on_aabb(&b, Vec3(1.0, 2.0, 3.5)), (Vec3(1.0, 2.0, 4.0), 0.5));
+/// ```
+pub fn closest_point_on_aabb<T: Float>(aabb: &Aabb3<T>, point: Vec3<T>) -> (Vec3<T>, T) {
+    if !aabb.contains(point) {
+        let p = aabb.closest_point(point);
+        return (p, (point - p).length());
+    }
+
+    // Inside: snap the coordinate nearest to a face onto that face.
+    let mut p = [point.0, point.1, point.2];
+    let min = [aabb.min.0, aabb.min.1, aabb.min.2];
+    let max = [aabb.max.0, aabb.max.1, aabb.max.2];
+    let (mut axis, mut face, mut dist) = (0, min[0], T::infinity());
+    for i in 0..3 {
+        if p[i] - min[i] < dist {
+            axis = i;
+            face = min[i];
+            dist = p[i] - min[i];
+        }
+        if max[i] - p[i] < dist {
+            axis = i;
+            face = max[i];
+            dist = max[i] - p[i];
+        }
+    }
+    p[axis] = face;
+    (Vec3(p[0], p[1], p[2]), dist)
+}
+
+/// closest_point_on_obb returns the point on the surface of the oriented box
+/// closest to the given point, and the distance between the two.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{closest_point_on_obb, Basis3, Float, Obb, Vec3};
+/// use fiz_math::unit::Deg;
+///
+/// let rot = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(90.0)).unwrap();
+/// let b = Obb::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 1.0, 1.0), rot);
+/// let (p, dist): (_, f64) = closest_point_on_obb(&b, Vec3(0.0, 5.0, 0.0));
+/// assert!(p.almost_equal(Vec3(0.0, 2.0, 0.0), 1e-12));
+/// assert!(dist.almost_equal(3.0, 1e-12));
+/// ```
+pub fn closest_point_on_obb<T: Float>(obb: &Obb<T>, point: Vec3<T>) -> (Vec3<T>, T) {
+    let local = obb.rotation.inverse_transform(point - obb.center);
+    let h = obb.half_extents;
+    let (p, dist) = closest_point_on_aabb(&Aabb3::new(-h, h), local);
+    (obb.center + obb.rotation * p, dist)
+}
+
+/// closest_point_on_sphere returns the point on the surface of the sphere
+/// closest to the given point, and the distance between the two. For the
+/// center of the sphere, any point on its surface is closest, and the one in
+/// the positive X direction is returned.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{closest_point_on_sphere, Sphere, Vec3};
+///
+/// let s = Sphere::new(Vec3(1.0, 0.0, 0.0), 2.0);
+/// assert_eq!(closest_point_on_sphere(&s, Vec3(1.0, 5.0, 0.0)), (Vec3(1.0, 2.0, 0.0), 3.0));
+/// assert_eq!(closest_point_on_sphere(&s, Vec3(1.0, 0.0, 0.5)), (Vec3(1.0, 0.0, 2.0), 1.5));
+/// assert_eq!(closest_point_on_sphere(&s, s.center), (Vec3(3.0, 0.0, 0.0), 2.0));
+/// ```
+pub fn closest_point_on_sphere<T: Float>(sphere: &Sphere<T>, point: Vec3<T>) -> (Vec3<T>, T) {
+    let d = point - sphere.center;
+    let dir = d.normalize().unwrap_or(Vec3(T::one(), T::zero(), T::zero()));
+    (sphere.center + dir.mul_scalar(sphere.radius), (d.length() - sphere.radius).abs())
+}
+
+/// closest_point_on_triangle returns the point on the triangle closest to the
+/// given point, and the distance between the two.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{closest_point_on_triangle, Triangle3, Vec3};
+///
+/// let t = Triangle3::new(Vec3(0.0, 0.0, 0.0), Vec3(4.0, 0.0, 0.0), Vec3(0.0, 4.0, 0.0));
+///
+/// // Above the face, beside an edge, and beyond a corner.
+/// assert_eq!(closest_point_on_triangle(&t, Vec3(1.0, 1.0, 3.0)), (Vec3(1.0, 1.0, 0.0), 3.0));
+/// assert_eq!(closest_point_on_triangle(&t, Vec3(2.0, -3.0, 0.0)), (Vec3(2.0, 0.0, 0.0), 3.0));
+/// assert_eq!(closest_point_on_triangle(&t, Vec3(-3.0, -4.0, 0.0)), (Vec3(0.0, 0.0, 0.0), 5.0));
+/// ```
+pub fn closest_point_on_triangle<T: Float>(tri: &Triangle3<T>, point: Vec3<T>) -> (Vec3<T>, T) {
+    let p = closest_point_triangle(tri, point);
+    (p, (point - p).length())
+}
+
+// closest_point_triangle returns the point on the triangle closest to p.
+fn closest_point_triangle<T: Float>(tri: &Triangle3<T>, p: Vec3<T>) -> Vec3<T> {
+    let (u, v, w) = triangle_barycentric(tri, p);
+    tri.a.mul_scalar(u) + tri.b.mul_scalar(v) + tri.c.mul_scalar(w)
+}
+
+// triangle_barycentric returns the barycentric coordinates of the point on the
+// triangle closest to p. It is ClosestPtPointTriangle from Ericson's Real-Time
+// Collision Detection, which finds the Voronoi region of the triangle that the
+// point is in; coordinates outside of that region are exactly zero.
+pub fn triangle_barycentric<T: Float>(tri: &Triangle3<T>, p: Vec3<T>) -> (T, T, T) {
+    let (zero, one) = (T::zero(), T::one());
+    let (a, b, c) = (tri.a, tri.b, tri.c);
+    let ab = b - a;
+    let ac = c - a;
+
+    let ap = p - a;
+    let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+    if d1 <= zero && d2 <= zero {
+        return (one, zero, zero);
+    }
+
+    let bp = p - b;
+    let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+    if d3 >= zero && d4 <= d3 {
+        return (zero, one, zero);
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= zero && d1 >= zero && d3 <= zero {
+        let v = d1 / (d1 - d3);
+        return (one - v, v, zero);
+    }
+
+    let cp = p - c;
+    let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+    if d6 >= zero && d5 <= d6 {
+        return (zero, zero, one);
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= zero && d2 >= zero && d6 <= zero {
+        let w = d2 / (d2 - d6);
+        return (one - w, zero, w);
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return (zero, one - w, w);
+    }
+
+    let denom = one / (va + vb + vc);
+    let (v, w) = (vb * denom, vc * denom);
+    (one - v - w, v, w)
+}
+
+/// closest_point_on_segment returns the point on the segment closest to the
+/// given point, and the distance between the two.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{closest_point_on_segment, Segment3, Vec3};
+///
+/// let s = Segment3::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 0.0, 0.0));
+/// assert_eq!(closest_point_on_segment(&s, Vec3(1.0, 3.0, 4.0)), (Vec3(1.0, 0.0, 0.0), 5.0));
+/// ```
+pub fn closest_point_on_segment<T: Float>(segment: &Segment3<T>, point: Vec3<T>) -> (Vec3<T>, T) {
+    let p = segment.closest_point_to(point);
+    (p, (point - p).length())
+}
+
+/// closest_point_on_plane returns the point on the plane closest to the given
+/// point, and the (euclidean) distance between the two.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{closest_point_on_plane, Plane, Vec3};
+///
+/// let p = Plane::new(Vec3(0.0, 2.0, 0.0), -2.0);
+/// assert_eq!(closest_point_on_plane(&p, Vec3(3.0, -2.0, 1.0)), (Vec3(3.0, 1.0, 1.0), 3.0));
+/// ```
+pub fn closest_point_on_plane<T: Float>(plane: &Plane<T>, point: Vec3<T>) -> (Vec3<T>, T) {
+    let p = plane.project_point(point);
+    (p, (point - p).length())
+}
@@ -0,0 +1,137 @@
+use super::float::Float;
+use super::axis::Axis;
+use super::{Vec2, Vec3};
+
+/// CubeFace identifies one of the six faces of a cube (e.g. of a cubemap, or a
+/// voxel). The faces are ordered as is conventional for cubemap layers: +X,
+/// -X, +Y, -Y, +Z, -Z.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CubeFace {
+    PosX,
+    NegX,
+    PosY,
+    NegY,
+    PosZ,
+    NegZ,
+}
+
+impl CubeFace {
+    /// index returns the conventional cubemap layer index (0-5) of this face.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::CubeFace;
+    ///
+    /// assert_eq!(CubeFace::PosX.index(), 0);
+    /// assert_eq!(CubeFace::NegZ.index(), 5);
+    /// ```
+    pub fn index(self) -> usize {
+        match self {
+            CubeFace::PosX => 0,
+            CubeFace::NegX => 1,
+            CubeFace::PosY => 2,
+            CubeFace::NegY => 3,
+            CubeFace::PosZ => 4,
+            CubeFace::NegZ => 5,
+        }
+    }
+
+    /// from_index returns the face with the given cubemap layer index, or None
+    /// if the index is not in the range 0-5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::CubeFace;
+    ///
+    /// assert_eq!(CubeFace::from_index(3), Some(CubeFace::NegY));
+    /// assert_eq!(CubeFace::from_index(6), None);
+    /// ```
+    pub fn from_index(i: usize) -> Option<CubeFace> {
+        match i {
+            0 => Some(CubeFace::PosX),
+            1 => Some(CubeFace::NegX),
+            2 => Some(CubeFace::PosY),
+            3 => Some(CubeFace::NegY),
+            4 => Some(CubeFace::PosZ),
+            5 => Some(CubeFace::NegZ),
+            _ => None,
+        }
+    }
+
+    /// axis returns the axis that this face is perpendicular to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Axis, CubeFace};
+    ///
+    /// assert_eq!(CubeFace::NegY.axis(), Axis::Y);
+    /// ```
+    pub fn axis(self) -> Axis {
+        match self {
+            CubeFace::PosX | CubeFace::NegX => Axis::X,
+            CubeFace::PosY | CubeFace::NegY => Axis::Y,
+            CubeFace::PosZ | CubeFace::NegZ => Axis::Z,
+        }
+    }
+}
+
+/// cube_face_and_uv returns the cubemap face that the direction `dir` points
+/// at, along with the texture coordinates (in the range 0-1) of the point on
+/// that face. The orientation of each face follows the OpenGL and Direct3D
+/// cubemap conventions.
+///
+/// The direction need not be normalized, but it must not be zero.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Vec2, Vec3, CubeFace, cube_face_and_uv};
+///
+/// assert_eq!(cube_face_and_uv(Vec3(2.0, 0.0, 0.0)), (CubeFace::PosX, Vec2(0.5, 0.5)));
+/// assert_eq!(cube_face_and_uv(Vec3(0.5, -1.0, 1.0)), (CubeFace::NegY, Vec2(0.75, 0.0)));
+/// ```
+pub fn cube_face_and_uv<T: Float>(dir: Vec3<T>) -> (CubeFace, Vec2<T>) {
+    let Vec3(x, y, z) = dir;
+    let (face, sc, tc, ma) = match dir.dominant_axis() {
+        Axis::X if x >= T::zero() => (CubeFace::PosX, -z, -y, x),
+        Axis::X => (CubeFace::NegX, z, -y, -x),
+        Axis::Y if y >= T::zero() => (CubeFace::PosY, x, z, y),
+        Axis::Y => (CubeFace::NegY, x, -z, -y),
+        Axis::Z if z >= T::zero() => (CubeFace::PosZ, x, -y, z),
+        Axis::Z => (CubeFace::NegZ, -x, -y, -z),
+    };
+    let half = T::from(0.5).unwrap();
+    (face, Vec2((sc / ma + T::one()) * half, (tc / ma + T::one()) * half))
+}
+
+/// cube_face_uv_to_dir is the inverse of `cube_face_and_uv`: it returns the
+/// direction pointing at the texture coordinates `uv` on the given cubemap
+/// face. The returned direction is not normalized; its dominant component has
+/// a magnitude of one.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Vec2, Vec3, CubeFace, cube_face_and_uv, cube_face_uv_to_dir};
+///
+/// let dir = cube_face_uv_to_dir(CubeFace::NegZ, Vec2(0.25, 0.75));
+/// assert_eq!(dir, Vec3(0.5, -0.5, -1.0));
+/// assert_eq!(cube_face_and_uv(dir), (CubeFace::NegZ, Vec2(0.25, 0.75)));
+/// ```
+pub fn cube_face_uv_to_dir<T: Float>(face: CubeFace, uv: Vec2<T>) -> Vec3<T> {
+    let two = T::from(2.0).unwrap();
+    let sc = uv.0 * two - T::one();
+    let tc = uv.1 * two - T::one();
+    let one = T::one();
+    match face {
+        CubeFace::PosX => Vec3(one, -tc, -sc),
+        CubeFace::NegX => Vec3(-one, -tc, sc),
+        CubeFace::PosY => Vec3(sc, one, tc),
+        CubeFace::NegY => Vec3(sc, -one, -tc),
+        CubeFace::PosZ => Vec3(sc, -tc, one),
+        CubeFace::NegZ => Vec3(-sc, -tc, -one),
+    }
+}
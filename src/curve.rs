@@ -0,0 +1,294 @@
+use prelude::*;
+
+use super::float::Float;
+use super::lerp::Lerp;
+use super::vector::Vector;
+use super::{BSpline, CatmullRom, CubicBezier, Hermite, QuadraticBezier};
+
+/// Curve is implemented by parametric curves defined over the parameter range
+/// 0.0 - 1.0, allowing generic code (e.g. `ArcLength`) to operate on any of
+/// them.
+pub trait Curve<T: Float> {
+    /// Point is the type of the points of the curve.
+    type Point: Vector<Scalar = T>;
+
+    /// eval returns the point on the curve at the parameter `t` in the range
+    /// 0.0 - 1.0.
+    fn eval(&self, t: T) -> Self::Point;
+
+    /// chord_error returns the distance by which the part of the curve between
+    /// the parameters `t0` and `t1` deviates from the chord between its ends.
+    /// The default implementation estimates it from evenly spaced points along
+    /// the part, which may slightly underestimate it (or miss features smaller
+    /// than the spacing).
+    fn chord_error(&self, t0: T, t1: T) -> T {
+        let (a, b) = (self.eval(t0), self.eval(t1));
+        let step = (t1 - t0) / T::from(SAMPLES).unwrap();
+        (1..SAMPLES).fold(T::zero(), |error, i| {
+            let p = self.eval(t0 + step * T::from(i).unwrap());
+            error.max(segment_distance(p, a, b))
+        })
+    }
+
+    /// flatten returns an iterator over points approximating the curve by line
+    /// segments, from the start of the curve to its end, whose `chord_error`
+    /// is no more than `tolerance` (thus the error is bounded for Bezier
+    /// curves, and estimated for others). The curve is subdivided adaptively,
+    /// so straighter parts have fewer points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{CubicBezier, Curve, Vec2};
+    ///
+    /// let c = CubicBezier::new(Vec2(0.0, 0.0), Vec2(0.0, 1.0), Vec2(1.0, 1.0), Vec2(1.0, 0.0));
+    /// let points: Vec<_> = c.flatten(0.01).collect();
+    /// assert_eq!(points[0], c.p0);
+    /// assert_eq!(points[points.len() - 1], c.p3);
+    /// assert!(points.len() > 4);
+    ///
+    /// // Straight curves are a single segment.
+    /// let c = CubicBezier::new(Vec2(0.0, 0.0), Vec2(1.0, 1.0), Vec2(2.0, 2.0), Vec2(3.0, 3.0));
+    /// assert_eq!(c.flatten(0.01).collect::<Vec<_>>(), vec![c.p0, c.p3]);
+    /// ```
+    fn flatten(&self, tolerance: T) -> Flatten<'_, T, Self>
+        where Self: Sized
+    {
+        Flatten {
+            curve: self,
+            tolerance,
+            start: None,
+            stack: Vec::new(),
+        }
+    }
+}
+
+// The number of parts into which the default Curve::chord_error divides a
+// curve to estimate its deviation from its chord.
+const SAMPLES: usize = 8;
+
+// The maximum depth of subdivision when flattening a curve, bounding the work
+// done for tiny (or non-positive) tolerances.
+const MAX_DEPTH: u32 = 16;
+
+// segment_distance returns the distance from p to the line segment ab.
+fn segment_distance<T: Float, V: Vector<Scalar = T>>(p: V, a: V, b: V) -> T {
+    let (ab, ap) = (b - a, p - a);
+    let len_sq = ab.dot(ab);
+    if len_sq == T::zero() {
+        return ap.length();
+    }
+    let t = (ap.dot(ab) / len_sq).max(T::zero()).min(T::one());
+    (ap - ab.mul_scalar(t)).length()
+}
+
+/// Flatten is an iterator over the points of a flattened curve, returned by
+/// `Curve::flatten`.
+pub struct Flatten<'a, T: Float, C: 'a + Curve<T>> {
+    curve: &'a C,
+    tolerance: T,
+
+    // start is the parameter of the point last returned, and stack holds the
+    // parameters and points of the ends of the parts still to be flattened,
+    // with the nearest on top.
+    start: Option<T>,
+    stack: Vec<(T, C::Point, u32)>,
+}
+
+impl<'a, T: Float, C: Curve<T>> Iterator for Flatten<'a, T, C> {
+    type Item = C::Point;
+
+    fn next(&mut self) -> Option<C::Point> {
+        let t0 = match self.start {
+            Some(t0) => t0,
+            None => {
+                let (zero, one) = (T::zero(), T::one());
+                self.start = Some(zero);
+                self.stack.push((one, self.curve.eval(one), 0));
+                return Some(self.curve.eval(zero));
+            }
+        };
+        while let Some((t1, p1, depth)) = self.stack.pop() {
+            if depth >= MAX_DEPTH || self.curve.chord_error(t0, t1) <= self.tolerance {
+                self.start = Some(t1);
+                return Some(p1);
+            }
+            let mid = (t0 + t1) / T::from(2.0).unwrap();
+            self.stack.push((t1, p1, depth + 1));
+            self.stack.push((mid, self.curve.eval(mid), depth + 1));
+        }
+        None
+    }
+}
+
+impl<T: Float, V: Vector<Scalar = T> + Lerp<T>> Curve<T> for CubicBezier<V> {
+    type Point = V;
+
+    fn eval(&self, t: T) -> V {
+        CubicBezier::eval(self, t)
+    }
+
+    /// chord_error returns a bound on the deviation of the part of this curve
+    /// between `t0` and `t1` from its chord (parameterized linearly), which is
+    /// 3/4 of the largest second difference of its control points.
+    fn chord_error(&self, t0: T, t1: T) -> T {
+        // Blossoming yields the control points of the part.
+        let blossom = |u: T, v: T, w: T| {
+            let (a, b, c) = (self.p0.lerp(self.p1, u),
+                             self.p1.lerp(self.p2, u),
+                             self.p2.lerp(self.p3, u));
+            let (d, e) = (a.lerp(b, v), b.lerp(c, v));
+            d.lerp(e, w)
+        };
+        let (p0, p1) = (blossom(t0, t0, t0), blossom(t0, t0, t1));
+        let (p2, p3) = (blossom(t0, t1, t1), blossom(t1, t1, t1));
+        let a = (p0 - p1) + (p2 - p1);
+        let b = (p1 - p2) + (p3 - p2);
+        a.length().max(b.length()) * T::from(0.75).unwrap()
+    }
+}
+
+impl<T: Float, V: Vector<Scalar = T> + Lerp<T>> Curve<T> for QuadraticBezier<V> {
+    type Point = V;
+
+    fn eval(&self, t: T) -> V {
+        QuadraticBezier::eval(self, t)
+    }
+
+    /// chord_error returns the deviation of the part of this curve between `t0`
+    /// and `t1` from its chord (parameterized linearly), which is 1/4 of the
+    /// second difference of its control points.
+    fn chord_error(&self, t0: T, t1: T) -> T {
+        // Blossoming yields the control points of the part.
+        let blossom = |u: T, v: T| self.p0.lerp(self.p1, u).lerp(self.p1.lerp(self.p2, u), v);
+        let (p0, p1, p2) = (blossom(t0, t0), blossom(t0, t1), blossom(t1, t1));
+        ((p0 - p1) + (p2 - p1)).length() * T::from(0.25).unwrap()
+    }
+}
+
+impl<T: Float, V: Vector<Scalar = T>> Curve<T> for Hermite<V> {
+    type Point = V;
+
+    fn eval(&self, t: T) -> V {
+        Hermite::eval(self, t)
+    }
+}
+
+impl<T: Float, V: Vector<Scalar = T> + Lerp<T>> Curve<T> for CatmullRom<V> {
+    type Point = V;
+
+    fn eval(&self, t: T) -> V {
+        CatmullRom::eval(self, t)
+    }
+}
+
+impl<T: Float, V: Vector<Scalar = T> + Lerp<T>> Curve<T> for BSpline<V> {
+    type Point = V;
+
+    fn eval(&self, t: T) -> V {
+        BSpline::eval(self, t)
+    }
+}
+
+/// ArcLength wraps a curve with a table of its cumulative arc length, sampled
+/// at evenly spaced parameters, such that points can be found by their
+/// distance along the curve (e.g. for motion at a constant speed). Distances
+/// are approximated by the lengths of the chords between the samples, and
+/// interpolated linearly in between them.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{ArcLength, CubicBezier, Float, Vec2};
+///
+/// // A straight line whose parameter speeds up and slows down.
+/// let c = CubicBezier::new(Vec2(0.0, 0.0), Vec2(0.0, 0.0), Vec2(3.0, 0.0), Vec2(3.0, 0.0));
+/// assert!(c.eval(0.25).0 < 0.75 - 0.1);
+///
+/// let a = ArcLength::new(c, 256);
+/// assert!(a.length().almost_equal(3.0, 1e-12));
+/// assert!(a.eval_at_distance(0.75).almost_equal(Vec2(0.75, 0.0), 1e-4));
+/// assert!(a.eval_at_distance(2.0).almost_equal(Vec2(2.0, 0.0), 1e-4));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArcLength<C, T> {
+    curve: C,
+    lengths: Vec<T>,
+}
+
+impl<T: Float, C: Curve<T>> ArcLength<C, T> {
+    /// new returns the arc length table of the given curve, sampled at the
+    /// given number of segments (at least one).
+    pub fn new(curve: C, segments: usize) -> Self {
+        let segments = segments.max(1);
+        let mut lengths = Vec::with_capacity(segments + 1);
+        let (mut total, mut prev) = (T::zero(), curve.eval(T::zero()));
+        lengths.push(total);
+        for i in 1..segments + 1 {
+            let p = curve.eval(T::from(i).unwrap() / T::from(segments).unwrap());
+            total = total + p.distance(prev);
+            lengths.push(total);
+            prev = p;
+        }
+        ArcLength {
+            curve,
+            lengths,
+        }
+    }
+
+    /// curve returns the curve which this table measures.
+    pub fn curve(&self) -> &C {
+        &self.curve
+    }
+
+    /// length returns the (approximate) total length of the curve.
+    pub fn length(&self) -> T {
+        self.lengths[self.lengths.len() - 1]
+    }
+
+    /// reparameterize returns the parameter of the curve at the fraction `u` of
+    /// its length, in the range 0.0 - 1.0 (which is clamped).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{ArcLength, Float, QuadraticBezier};
+    ///
+    /// // A one-dimensional curve from 0 to 1 which lingers near 0.
+    /// let a = ArcLength::new(QuadraticBezier::new(0.0, 0.0, 1.0), 1000);
+    /// assert!(a.reparameterize(0.25).almost_equal(0.5, 1e-3));
+    /// assert_eq!(a.reparameterize(1.0), 1.0);
+    /// ```
+    pub fn reparameterize(&self, u: T) -> T {
+        let (zero, one) = (T::zero(), T::one());
+        let u = u.max(zero).min(one);
+        let total = self.length();
+        if total == zero {
+            return u;
+        }
+        let s = u * total;
+
+        // Find the last sample at or before the distance.
+        let (mut i, mut hi) = (0, self.lengths.len() - 1);
+        while hi - i > 1 {
+            let mid = (i + hi) / 2;
+            if self.lengths[mid] <= s {
+                i = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let (a, b) = (self.lengths[i], self.lengths[i + 1]);
+        let f = if b > a { (s - a) / (b - a) } else { zero };
+        let segments = T::from(self.lengths.len() - 1).unwrap();
+        (T::from(i).unwrap() + f.min(one)) / segments
+    }
+
+    /// eval_at_distance returns the point at the distance `s` along the curve
+    /// from its start, which is clamped to the length of the curve.
+    pub fn eval_at_distance(&self, s: T) -> C::Point {
+        let total = self.length();
+        let u = if total > T::zero() { s / total } else { T::zero() };
+        self.curve.eval(self.reparameterize(u))
+    }
+}
@@ -0,0 +1,118 @@
+use super::float::Float;
+use super::gjk;
+use super::{Aabb3, Segment3, Sphere, Vec3};
+
+/// Cylinder is a solid, capped cylinder in three dimensions: the points within
+/// the given radius of its axis, between the planes through the ends of the
+/// axis perpendicular to it.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Cylinder, Segment3, Vec3};
+///
+/// let c = Cylinder::new(Segment3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 2.0, 0.0)), 1.0);
+/// assert!(c.contains_point(Vec3(0.9, 1.9, 0.0)));
+/// assert!(!c.contains_point(Vec3(0.0, 2.1, 0.0)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cylinder<T> {
+    /// The axis of the cylinder, from the center of one cap to the center of
+    /// the other.
+    pub axis: Segment3<T>,
+
+    /// The radius of the cylinder.
+    pub radius: T,
+}
+
+impl<T: Float> Cylinder<T> {
+    /// new returns the cylinder of the given radius around the axis.
+    pub fn new(axis: Segment3<T>, radius: T) -> Self {
+        Cylinder {
+            axis,
+            radius,
+        }
+    }
+
+    /// contains_point tells if the given point is inside this cylinder, or on
+    /// its surface.
+    pub fn contains_point(&self, point: Vec3<T>) -> bool {
+        let d = self.axis.b - self.axis.a;
+        let v = point - self.axis.a;
+        let (h_sq, y) = (d.length_sq(), v.dot(d));
+        if y < T::zero() || y > h_sq {
+            return false;
+        }
+        // The squared distance from the axis, scaled by h_sq to avoid a
+        // division.
+        v.length_sq() * h_sq - y * y <= self.radius * self.radius * h_sq
+    }
+
+    /// closest_point returns the point inside this cylinder (or on its
+    /// surface) closest to the given point, which is the point itself if it is
+    /// inside. Degenerate cylinders (whose axis is a single point) are treated
+    /// as a point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Cylinder, Segment3, Vec3};
+    ///
+    /// let c = Cylinder::new(Segment3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 2.0, 0.0)), 1.0);
+    /// assert_eq!(c.closest_point(Vec3(3.0, 1.0, 0.0)), Vec3(1.0, 1.0, 0.0));
+    /// assert_eq!(c.closest_point(Vec3(0.5, 5.0, 0.0)), Vec3(0.5, 2.0, 0.0));
+    /// assert_eq!(c.closest_point(Vec3(0.0, -1.0, 4.0)), Vec3(0.0, 0.0, 1.0));
+    /// ```
+    pub fn closest_point(&self, point: Vec3<T>) -> Vec3<T> {
+        let n = match (self.axis.b - self.axis.a).normalize() {
+            Some(n) => n,
+            None => return self.axis.a,
+        };
+        let h = self.axis.length();
+        let v = point - self.axis.a;
+        let y = v.dot(n);
+        let radial = v - n.mul_scalar(y);
+        let rho = radial.length();
+        let radial = if rho > self.radius {
+            radial.mul_scalar(self.radius / rho)
+        } else {
+            radial
+        };
+        self.axis.a + n.mul_scalar(y.max(T::zero()).min(h)) + radial
+    }
+
+    /// intersects_sphere tells if this cylinder and the sphere overlap
+    /// (including touching).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Cylinder, Segment3, Sphere, Vec3};
+    ///
+    /// let c = Cylinder::new(Segment3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 2.0, 0.0)), 1.0);
+    /// assert!(c.intersects_sphere(&Sphere::new(Vec3(0.0, 2.5, 0.0), 0.5)));
+    /// // A sphere off the rim, which a capsule would reach but the cylinder does not.
+    /// assert!(!c.intersects_sphere(&Sphere::new(Vec3(1.4, 2.4, 0.0), 0.5)));
+    /// ```
+    pub fn intersects_sphere(&self, sphere: &Sphere<T>) -> bool {
+        sphere.contains_point(self.closest_point(sphere.center))
+    }
+
+    /// intersects_aabb tells if this cylinder and the given axis-aligned box
+    /// overlap (including touching), to within a small tolerance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Cylinder, Segment3, Vec3};
+    ///
+    /// let b = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0));
+    /// let c = Cylinder::new(Segment3::new(Vec3(2.0, 0.0, 0.5), Vec3(2.0, 1.0, 0.5)), 1.1);
+    /// assert!(c.intersects_aabb(&b));
+    /// let c = Cylinder::new(Segment3::new(Vec3(2.0, 1.5, 0.5), Vec3(2.0, 3.0, 0.5)), 1.1);
+    /// assert!(!c.intersects_aabb(&b));
+    /// ```
+    pub fn intersects_aabb(&self, aabb: &Aabb3<T>) -> bool {
+        gjk::intersects(self, aabb)
+    }
+}
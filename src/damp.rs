@@ -0,0 +1,85 @@
+use super::float::Float;
+use super::math;
+use super::lerp::Lerp;
+use super::vector::Vector;
+
+/// smooth_damp moves `current` towards `target` like a critically damped
+/// spring, taking about `smooth_time` to get there, and returns the new value
+/// after the time step `dt`. The `velocity` is the state of the spring, which
+/// must be kept between calls (starting at zero). It never overshoots the
+/// target, and is stable for any time step, making it suitable for following
+/// cameras and other motion that must be smooth when the target moves.
+///
+/// It is implemented according to Game Programming Gems 4, chapter 1.10, and
+/// works on both scalars and vectors.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{smooth_damp, Vec2};
+///
+/// let (mut v, mut velocity) = (Vec2(0.0, 0.0), Vec2(0.0, 0.0));
+/// for _ in 0..120 {
+///     v = smooth_damp(v, Vec2(10.0, 5.0), &mut velocity, 0.25, 1.0 / 60.0);
+/// }
+/// assert!((v - Vec2(10.0, 5.0)).length() < 0.01);
+///
+/// // A scalar never passes its target.
+/// let (mut x, mut velocity) = (0.0, 0.0);
+/// for _ in 0..100 {
+///     x = smooth_damp(x, 1.0, &mut velocity, 0.1, 0.05);
+///     assert!(x <= 1.0);
+/// }
+/// ```
+pub fn smooth_damp<T, V>(current: V, target: V, velocity: &mut V, smooth_time: T, dt: T) -> V
+    where T: Float,
+          V: Vector<Scalar = T>
+{
+    let omega = T::from(2.0).unwrap() / smooth_time.max(T::from(1.0e-4).unwrap());
+
+    // A Taylor approximation of exp(-x), which is accurate for small steps and
+    // remains stable (positive and decreasing) for large ones.
+    let x = omega * dt;
+    let decay = T::one() /
+                (T::one() + x + T::from(0.48).unwrap() * x * x +
+                 T::from(0.235).unwrap() * x * x * x);
+
+    let change = current - target;
+    let temp = (*velocity + change.mul_scalar(omega)).mul_scalar(dt);
+    *velocity = (*velocity - temp.mul_scalar(omega)).mul_scalar(decay);
+    let out = target + (change + temp).mul_scalar(decay);
+
+    // Prevent overshooting the target.
+    if (target - current).dot(out - target) > T::zero() {
+        *velocity = V::splat(T::zero());
+        return target;
+    }
+    out
+}
+
+/// damp moves `a` towards `b` by exponential decay at the rate `lambda` (per
+/// unit of time) over the time step `dt`, i.e. it returns
+/// `a.lerp(b, 1 - exp(-lambda * dt))`. Unlike lerping by a constant amount
+/// each frame, the result is independent of the frame rate: two steps of `dt`
+/// give the same result as one step of `2 * dt` towards a fixed target.
+///
+/// It works on any type implementing `Lerp`, such as scalars, vectors, units
+/// and rotations (which `Basis3` interpolates by nlerp, so their frame rate
+/// independence is approximate).
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{damp, Float, Vec2};
+///
+/// let (a, b) = (Vec2(0.0, 0.0), Vec2(8.0, 4.0));
+/// let once = damp(a, b, 2.0, 0.5);
+/// let twice = damp(damp(a, b, 2.0, 0.25), b, 2.0, 0.25);
+/// assert!(once.almost_equal(twice, 1e-12));
+///
+/// // After 1/lambda units of time, about 63% of the distance is covered.
+/// assert!(damp(0.0, 1.0, 4.0, 0.25).almost_equal(1.0 - (-1.0f64).exp(), 1e-12));
+/// ```
+pub fn damp<T: Float, V: Lerp<T>>(a: V, b: V, lambda: T, dt: T) -> V {
+    a.lerp(b, T::one() - math::exp(-lambda * dt))
+}
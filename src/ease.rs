@@ -0,0 +1,396 @@
+//! Easing functions, i.e. Robert Penner's standard set of curves for shaping
+//! the progress of animations. Each function maps a time `t` in the range 0.0 -
+//! 1.0 to a progress, such that zero maps to zero and one maps to one. The
+//! `_in` variants start slowly, the `_out` variants end slowly (they are the
+//! `_in` variants reflected), and the `_in_out` variants do both, reaching half
+//! way at a `t` of 0.5. The back and elastic curves overshoot the range.
+//!
+//! # Examples
+//!
+//! ```
+//! use fiz_math::Float;
+//! use fiz_math::ease;
+//!
+//! // Move a camera from 10 to 20 over two seconds, slowing as it arrives.
+//! let position = |seconds: f64| 10.0.lerp(20.0, ease::cubic_out(seconds / 2.0));
+//! assert_eq!(position(0.0), 10.0);
+//! assert_eq!(position(1.0), 18.75);
+//! assert_eq!(position(2.0), 20.0);
+//! ```
+
+use core::f64::consts::{FRAC_PI_2, PI};
+use super::float::Float;
+use super::math;
+
+// The overshoot of the back curves, which is about 10%.
+const BACK: f64 = 1.70158;
+
+// c converts the constant x to T.
+fn c<T: Float>(x: f64) -> T {
+    T::from(x).unwrap()
+}
+
+/// quad_in is a quadratic easing (t^2) which accelerates from zero velocity.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::ease::{quad_in, quad_in_out, quad_out};
+///
+/// assert_eq!(quad_in(0.5), 0.25);
+/// assert_eq!(quad_out(0.5), 0.75);
+/// assert_eq!(quad_in_out(0.25), 0.125);
+/// ```
+pub fn quad_in<T: Float>(t: T) -> T {
+    t * t
+}
+
+/// quad_out is a quadratic easing (t^2) which decelerates to zero velocity.
+pub fn quad_out<T: Float>(t: T) -> T {
+    let u = T::one() - t;
+    T::one() - u * u
+}
+
+/// quad_in_out is a quadratic easing (t^2) which accelerates until half way and
+/// then decelerates.
+pub fn quad_in_out<T: Float>(t: T) -> T {
+    if t < c(0.5) {
+        c::<T>(2.0) * t * t
+    } else {
+        let u = c::<T>(2.0) - (t + t);
+        T::one() - u * u / c(2.0)
+    }
+}
+
+/// cubic_in is a cubic easing (t^3) which accelerates from zero velocity.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::ease::{cubic_in, cubic_in_out, cubic_out};
+///
+/// assert_eq!(cubic_in(0.5), 0.125);
+/// assert_eq!(cubic_out(0.5), 0.875);
+/// assert_eq!(cubic_in_out(0.25), 0.0625);
+/// ```
+pub fn cubic_in<T: Float>(t: T) -> T {
+    t * t * t
+}
+
+/// cubic_out is a cubic easing (t^3) which decelerates to zero velocity.
+pub fn cubic_out<T: Float>(t: T) -> T {
+    let u = T::one() - t;
+    T::one() - u * u * u
+}
+
+/// cubic_in_out is a cubic easing (t^3) which accelerates until half way and
+/// then decelerates.
+pub fn cubic_in_out<T: Float>(t: T) -> T {
+    if t < c(0.5) {
+        c::<T>(4.0) * t * t * t
+    } else {
+        let u = c::<T>(2.0) - (t + t);
+        T::one() - u * u * u / c(2.0)
+    }
+}
+
+/// quart_in is a quartic easing (t^4) which accelerates from zero velocity.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::ease::{quart_in, quart_in_out, quart_out};
+///
+/// assert_eq!(quart_in(0.5), 0.0625);
+/// assert_eq!(quart_out(0.5), 0.9375);
+/// assert_eq!(quart_in_out(0.25), 0.03125);
+/// ```
+pub fn quart_in<T: Float>(t: T) -> T {
+    t * t * t * t
+}
+
+/// quart_out is a quartic easing (t^4) which decelerates to zero velocity.
+pub fn quart_out<T: Float>(t: T) -> T {
+    let u = T::one() - t;
+    T::one() - u * u * u * u
+}
+
+/// quart_in_out is a quartic easing (t^4) which accelerates until half way and
+/// then decelerates.
+pub fn quart_in_out<T: Float>(t: T) -> T {
+    if t < c(0.5) {
+        c::<T>(8.0) * t * t * t * t
+    } else {
+        let u = c::<T>(2.0) - (t + t);
+        T::one() - u * u * u * u / c(2.0)
+    }
+}
+
+/// quint_in is a quintic easing (t^5) which accelerates from zero velocity.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::ease::{quint_in, quint_in_out, quint_out};
+///
+/// assert_eq!(quint_in(0.5), 0.03125);
+/// assert_eq!(quint_out(0.5), 0.96875);
+/// assert_eq!(quint_in_out(0.25), 0.015625);
+/// ```
+pub fn quint_in<T: Float>(t: T) -> T {
+    t * t * t * t * t
+}
+
+/// quint_out is a quintic easing (t^5) which decelerates to zero velocity.
+pub fn quint_out<T: Float>(t: T) -> T {
+    let u = T::one() - t;
+    T::one() - u * u * u * u * u
+}
+
+/// quint_in_out is a quintic easing (t^5) which accelerates until half way and
+/// then decelerates.
+pub fn quint_in_out<T: Float>(t: T) -> T {
+    if t < c(0.5) {
+        c::<T>(16.0) * t * t * t * t * t
+    } else {
+        let u = c::<T>(2.0) - (t + t);
+        T::one() - u * u * u * u * u / c(2.0)
+    }
+}
+
+/// sine_in is a sinusoidal easing (a quarter period of a cosine) which
+/// accelerates from zero velocity.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Float;
+/// use fiz_math::ease::{sine_in, sine_in_out, sine_out};
+///
+/// assert!(sine_in(0.5).almost_equal(1.0 - 0.5f64.sqrt(), 1e-12));
+/// assert!(sine_out(0.5).almost_equal(0.5f64.sqrt(), 1e-12));
+/// assert!(sine_in_out(0.25).almost_equal(0.5 - 0.125f64.sqrt(), 1e-12));
+/// ```
+pub fn sine_in<T: Float>(t: T) -> T {
+    T::one() - math::cos(t * c(FRAC_PI_2))
+}
+
+/// sine_out is a sinusoidal easing (a quarter period of a cosine) which
+/// decelerates to zero velocity.
+pub fn sine_out<T: Float>(t: T) -> T {
+    math::sin(t * c(FRAC_PI_2))
+}
+
+/// sine_in_out is a sinusoidal easing (a quarter period of a cosine) which
+/// accelerates until half way and then decelerates.
+pub fn sine_in_out<T: Float>(t: T) -> T {
+    (T::one() - math::cos(t * c(PI))) / c(2.0)
+}
+
+/// expo_in is an exponential easing (2^(10(t - 1))) which accelerates from zero
+/// velocity.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::ease::{expo_in, expo_in_out, expo_out};
+///
+/// assert_eq!(expo_in(0.5), 1.0 / 32.0);
+/// assert_eq!(expo_out(0.5), 31.0 / 32.0);
+/// assert_eq!(expo_in_out(0.5), 0.5);
+/// ```
+pub fn expo_in<T: Float>(t: T) -> T {
+    if t <= T::zero() {
+        T::zero()
+    } else {
+        math::powf(c::<T>(2.0), c::<T>(10.0) * t - c(10.0))
+    }
+}
+
+/// expo_out is an exponential easing (2^(10(t - 1))) which decelerates to zero
+/// velocity.
+pub fn expo_out<T: Float>(t: T) -> T {
+    if t >= T::one() {
+        T::one()
+    } else {
+        T::one() - math::powf(c::<T>(2.0), c::<T>(-10.0) * t)
+    }
+}
+
+/// expo_in_out is an exponential easing (2^(10(t - 1))) which accelerates until
+/// half way and then decelerates.
+pub fn expo_in_out<T: Float>(t: T) -> T {
+    if t <= T::zero() {
+        T::zero()
+    } else if t >= T::one() {
+        T::one()
+    } else if t < c(0.5) {
+        math::powf(c::<T>(2.0), c::<T>(20.0) * t - c(10.0)) / c(2.0)
+    } else {
+        T::one() - math::powf(c::<T>(2.0), (T::one() - (t + t)) * c(10.0)) / c(2.0)
+    }
+}
+
+/// circ_in is a circular easing (a quarter circle) which accelerates from zero
+/// velocity.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Float;
+/// use fiz_math::ease::{circ_in, circ_in_out, circ_out};
+///
+/// assert!(circ_in(0.6).almost_equal(0.2, 1e-12));
+/// assert!(circ_out(0.4).almost_equal(0.8, 1e-12));
+/// assert!(circ_in_out(0.3).almost_equal(0.1, 1e-12));
+/// ```
+pub fn circ_in<T: Float>(t: T) -> T {
+    T::one() - math::sqrt((T::one() - t * t).max(T::zero()))
+}
+
+/// circ_out is a circular easing (a quarter circle) which decelerates to zero
+/// velocity.
+pub fn circ_out<T: Float>(t: T) -> T {
+    let u = t - T::one();
+    math::sqrt((T::one() - u * u).max(T::zero()))
+}
+
+/// circ_in_out is a circular easing (a quarter circle) which accelerates until
+/// half way and then decelerates.
+pub fn circ_in_out<T: Float>(t: T) -> T {
+    let u = t + t;
+    if t < c(0.5) {
+        (T::one() - math::sqrt((T::one() - u * u).max(T::zero()))) / c(2.0)
+    } else {
+        let u = u - c(2.0);
+        (math::sqrt((T::one() - u * u).max(T::zero())) + T::one()) / c(2.0)
+    }
+}
+
+/// back_in is back easing (a cubic which overshoots by about 10%) which first
+/// pulls back below zero.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::ease::{back_in, back_in_out, back_out};
+///
+/// assert!(back_in(0.2) < 0.0);
+/// assert!(back_out(0.8) > 1.0);
+/// assert_eq!(back_in_out(0.5), 0.5);
+/// ```
+pub fn back_in<T: Float>(t: T) -> T {
+    let c3 = c::<T>(BACK + 1.0);
+    t * t * (c3 * t - c(BACK))
+}
+
+/// back_out is back easing (a cubic which overshoots by about 10%) which
+/// overshoots one before settling.
+pub fn back_out<T: Float>(t: T) -> T {
+    let (c3, u) = (c::<T>(BACK + 1.0), t - T::one());
+    T::one() + u * u * (c3 * u + c(BACK))
+}
+
+/// back_in_out is back easing (a cubic which overshoots by about 10%) which
+/// overshoots at both ends.
+pub fn back_in_out<T: Float>(t: T) -> T {
+    let c2 = c::<T>(BACK * 1.525);
+    let u = t + t;
+    if t < c(0.5) {
+        u * u * ((c2 + T::one()) * u - c2) / c(2.0)
+    } else {
+        let u = u - c(2.0);
+        (u * u * ((c2 + T::one()) * u + c2) + c(2.0)) / c(2.0)
+    }
+}
+
+/// elastic_in is elastic easing (an exponentially growing sine wave, like a
+/// plucked spring) which first pulls back below zero.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::ease::{elastic_in, elastic_in_out, elastic_out};
+///
+/// assert!(elastic_in(0.0) == 0.0 && elastic_in(1.0) == 1.0);
+/// assert!(elastic_out(0.1) > 1.0);
+/// assert_eq!(elastic_in_out(0.5), 0.5);
+/// ```
+pub fn elastic_in<T: Float>(t: T) -> T {
+    if t <= T::zero() || t >= T::one() {
+        return t.max(T::zero()).min(T::one());
+    }
+    let c4 = c::<T>(2.0 * PI / 3.0);
+    -math::powf(c::<T>(2.0), c::<T>(10.0) * t - c(10.0)) *
+    math::sin((c::<T>(10.0) * t - c(10.75)) * c4)
+}
+
+/// elastic_out is elastic easing (an exponentially growing sine wave, like a
+/// plucked spring) which overshoots one before settling.
+pub fn elastic_out<T: Float>(t: T) -> T {
+    if t <= T::zero() || t >= T::one() {
+        return t.max(T::zero()).min(T::one());
+    }
+    let c4 = c::<T>(2.0 * PI / 3.0);
+    math::powf(c::<T>(2.0), c::<T>(-10.0) * t) * math::sin((c::<T>(10.0) * t - c(0.75)) * c4) +
+    T::one()
+}
+
+/// elastic_in_out is elastic easing (an exponentially growing sine wave, like a
+/// plucked spring) which overshoots at both ends.
+pub fn elastic_in_out<T: Float>(t: T) -> T {
+    if t <= T::zero() || t >= T::one() {
+        return t.max(T::zero()).min(T::one());
+    }
+    let c5 = c::<T>(2.0 * PI / 4.5);
+    let s = math::sin((c::<T>(20.0) * t - c(11.125)) * c5);
+    if t < c(0.5) {
+        -math::powf(c::<T>(2.0), c::<T>(20.0) * t - c(10.0)) * s / c(2.0)
+    } else {
+        math::powf(c::<T>(2.0), (T::one() - (t + t)) * c(10.0)) * s / c(2.0) + T::one()
+    }
+}
+
+/// bounce_in is bounce easing which bounces with growing height away from zero.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Float;
+/// use fiz_math::ease::{bounce_in, bounce_in_out, bounce_out};
+///
+/// assert!(bounce_out(1.0 / 2.75).almost_equal(1.0, 1e-12));
+/// assert!(bounce_in(1.0f64 - 1.0 / 2.75).abs() < 1e-12);
+/// assert_eq!(bounce_in_out(0.5), 0.5);
+/// ```
+pub fn bounce_in<T: Float>(t: T) -> T {
+    T::one() - bounce_out(T::one() - t)
+}
+
+/// bounce_out is bounce easing which falls to one like a ball, bouncing to
+/// rest.
+pub fn bounce_out<T: Float>(t: T) -> T {
+    let (n, d) = (c::<T>(7.5625), c::<T>(2.75));
+    if t < T::one() / d {
+        n * t * t
+    } else if t < c::<T>(2.0) / d {
+        let t = t - c::<T>(1.5) / d;
+        n * t * t + c(0.75)
+    } else if t < c::<T>(2.5) / d {
+        let t = t - c::<T>(2.25) / d;
+        n * t * t + c(0.9375)
+    } else {
+        let t = t - c::<T>(2.625) / d;
+        n * t * t + c(0.984375)
+    }
+}
+
+/// bounce_in_out is bounce easing which bounces away from zero until half way
+/// and then bounces to rest at one.
+pub fn bounce_in_out<T: Float>(t: T) -> T {
+    if t < c(0.5) {
+        (T::one() - bounce_out(T::one() - (t + t))) / c(2.0)
+    } else {
+        (T::one() + bounce_out(t + t - T::one())) / c(2.0)
+    }
+}
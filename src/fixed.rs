@@ -0,0 +1,477 @@
+use core::ops::{Add, Sub, Mul, Div, Rem, Neg};
+use core::str::FromStr;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+use num::{self, Num, NumCast, One, PrimInt, Signed, ToPrimitive, Zero};
+
+/// FixedInt is implemented by the signed integer types which can hold the bits
+/// of a `Fixed` number (i8, i16, i32 and i64), and provides the integer type
+/// twice as wide, which holds the intermediate results of multiplication and
+/// division without overflowing.
+pub trait FixedInt: PrimInt + Signed {
+    /// Wide is the signed integer type twice as wide as this one.
+    type Wide: PrimInt + Signed;
+
+    /// widen returns this integer as the wide type.
+    fn widen(self) -> Self::Wide;
+
+    /// narrow returns the wide integer `w` truncated to this type.
+    fn narrow(w: Self::Wide) -> Self;
+}
+
+macro_rules! fixed_int {
+    ($int:ty, $wide:ty) => {
+        impl FixedInt for $int {
+            type Wide = $wide;
+
+            fn widen(self) -> $wide {
+                self as $wide
+            }
+
+            fn narrow(w: $wide) -> $int {
+                debug_assert!(w >= <$int>::min_value() as $wide &&
+                              w <= <$int>::max_value() as $wide,
+                              "Fixed: arithmetic overflow");
+                w as $int
+            }
+        }
+    }
+}
+
+fixed_int!(i8, i16);
+fixed_int!(i16, i32);
+fixed_int!(i32, i64);
+fixed_int!(i64, i128);
+
+/// Fixed is a signed binary fixed-point number, stored as the integer `I` whose
+/// lowest `FRAC` bits are the fraction, e.g. `Fixed<i32, 16>` (see `I16F16`)
+/// has 16 integer and 16 fractional bits, and a resolution of 2⁻¹⁶. `FRAC` must
+/// be less than the number of bits of `I`.
+///
+/// Arithmetic on fixed-point numbers is integer arithmetic, so its results are
+/// identical on every platform, as needed for e.g. lockstep simulation, where
+/// floating point results may differ between compilers and instruction sets.
+/// Multiplication rounds to the nearest representable value (with ties
+/// rounding up) and division truncates towards zero. As with integers,
+/// overflow panics in debug builds, and division by zero panics.
+///
+/// Fixed implements `Num`, so it works inside the vector types (e.g. for their
+/// arithmetic, `dot`, `cross` and `length_sq`), but not `Float`, so the vector
+/// methods which need floating point (e.g. `normalize`) are not available.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{I16F16, Vec2};
+///
+/// let half = I16F16::from_f64(0.5);
+/// assert_eq!(half * half, I16F16::from_f64(0.25));
+/// assert_eq!(I16F16::from_int(1) / I16F16::from_int(3), I16F16::from_bits(21845));
+///
+/// let v = Vec2(I16F16::from_int(3), I16F16::from_int(4));
+/// assert_eq!(v.length_sq().sqrt(), I16F16::from_int(5));
+/// assert_eq!(v.mul_scalar(half), Vec2(I16F16::from_f64(1.5), I16F16::from_int(2)));
+/// assert_eq!("1.25".parse::<I16F16>().unwrap().to_f64(), 1.25);
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Fixed<I, const FRAC: u32>(I);
+
+/// I16F16 is a fixed-point number with 16 integer and 16 fractional bits (also
+/// known as Q16.16), with a range of ±32768 and a resolution of about 1.5e-5.
+pub type I16F16 = Fixed<i32, 16>;
+
+/// I32F32 is a fixed-point number with 32 integer and 32 fractional bits, with
+/// a range of ±2147483648 and a resolution of about 2.3e-10.
+pub type I32F32 = Fixed<i64, 32>;
+
+impl<I: FixedInt, const FRAC: u32> Fixed<I, FRAC> {
+    // scale returns 2^FRAC as a f64, the value of the lowest integer bit.
+    fn scale() -> f64 {
+        (1u128 << FRAC) as f64
+    }
+
+    /// from_bits returns the fixed-point number with the given bits, i.e. with
+    /// the value `bits / 2^FRAC`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::I16F16;
+    ///
+    /// assert_eq!(I16F16::from_bits(0x18000), I16F16::from_f64(1.5));
+    /// ```
+    pub fn from_bits(bits: I) -> Self {
+        Fixed(bits)
+    }
+
+    /// to_bits returns the bits of this number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::I16F16;
+    ///
+    /// assert_eq!(I16F16::from_int(-1).to_bits(), -0x10000);
+    /// ```
+    pub fn to_bits(self) -> I {
+        self.0
+    }
+
+    /// from_int returns the integer `i` as a fixed-point number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::I16F16;
+    ///
+    /// assert_eq!(I16F16::from_int(3).to_f64(), 3.0);
+    /// ```
+    pub fn from_int(i: I) -> Self {
+        Fixed(i << FRAC as usize)
+    }
+
+    /// from_f64 returns `x` rounded to the nearest fixed-point number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` is NaN or out of the range of this type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::I16F16;
+    ///
+    /// assert_eq!(I16F16::from_f64(0.1).to_bits(), 6554);
+    /// assert_eq!(I16F16::from_f64(-2.5).to_f64(), -2.5);
+    /// ```
+    pub fn from_f64(x: f64) -> Self {
+        let bits = num::Float::round(x * Self::scale());
+        Fixed(I::from(bits).expect("Fixed::from_f64: value out of range"))
+    }
+
+    /// to_f64 returns this number as a f64, which is exact if it has at most 53
+    /// significant bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::I16F16;
+    ///
+    /// assert_eq!(I16F16::from_bits(1).to_f64(), 1.0 / 65536.0);
+    /// ```
+    pub fn to_f64(self) -> f64 {
+        self.0.to_f64().unwrap() / Self::scale()
+    }
+
+    /// to_f32 returns this number as a f32, rounded to the nearest f32.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::I16F16;
+    ///
+    /// assert_eq!(I16F16::from_f64(-0.75).to_f32(), -0.75);
+    /// ```
+    pub fn to_f32(self) -> f32 {
+        self.to_f64() as f32
+    }
+
+    /// abs returns the absolute value of this number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::I16F16;
+    ///
+    /// assert_eq!(I16F16::from_f64(-1.5).abs(), I16F16::from_f64(1.5));
+    /// ```
+    pub fn abs(self) -> Self {
+        Fixed(self.0.abs())
+    }
+
+    /// floor returns the largest integer less than or equal to this number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::I16F16;
+    ///
+    /// assert_eq!(I16F16::from_f64(1.5).floor(), I16F16::from_int(1));
+    /// assert_eq!(I16F16::from_f64(-1.5).floor(), I16F16::from_int(-2));
+    /// ```
+    pub fn floor(self) -> Self {
+        let shift = FRAC as usize;
+        Fixed(self.0 >> shift << shift)
+    }
+
+    /// ceil returns the smallest integer greater than or equal to this number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::I16F16;
+    ///
+    /// assert_eq!(I16F16::from_f64(1.5).ceil(), I16F16::from_int(2));
+    /// assert_eq!(I16F16::from_f64(-1.5).ceil(), I16F16::from_int(-1));
+    /// ```
+    pub fn ceil(self) -> Self {
+        -(-self).floor()
+    }
+
+    /// round returns the nearest integer to this number, rounding half-way
+    /// cases up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::I16F16;
+    ///
+    /// assert_eq!(I16F16::from_f64(1.25).round(), I16F16::from_int(1));
+    /// assert_eq!(I16F16::from_f64(1.5).round(), I16F16::from_int(2));
+    /// assert_eq!(I16F16::from_f64(-1.5).round(), I16F16::from_int(-1));
+    /// ```
+    pub fn round(self) -> Self {
+        if FRAC == 0 {
+            return self;
+        }
+        let half = I::one() << (FRAC - 1) as usize;
+        Fixed(self.0 + half).floor()
+    }
+
+    /// sqrt returns the square root of this number, rounded down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this number is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::I16F16;
+    ///
+    /// assert_eq!(I16F16::from_int(9).sqrt(), I16F16::from_int(3));
+    /// assert_eq!(I16F16::from_f64(0.25).sqrt(), I16F16::from_f64(0.5));
+    /// assert_eq!(I16F16::from_int(2).sqrt().to_bits(), 92681);
+    /// ```
+    pub fn sqrt(self) -> Self {
+        assert!(self.0 >= I::zero(), "Fixed::sqrt: negative value");
+
+        // The bits of the result are the integer square root of the bits of
+        // this number scaled by 2^FRAC, computed digit by digit.
+        let mut n = self.0.widen() << FRAC as usize;
+        let mut root = I::Wide::zero();
+        let mut bit = I::Wide::one() << (I::Wide::zero().count_zeros() - 2) as usize;
+        while bit > n {
+            bit = bit >> 2;
+        }
+        while bit != I::Wide::zero() {
+            if n >= root + bit {
+                n = n - (root + bit);
+                root = (root >> 1) + bit;
+            } else {
+                root = root >> 1;
+            }
+            bit = bit >> 2;
+        }
+        Fixed(I::narrow(root))
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Add for Fixed<I, FRAC> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Fixed(self.0 + rhs.0)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Sub for Fixed<I, FRAC> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Fixed(self.0 - rhs.0)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Mul for Fixed<I, FRAC> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let product = self.0.widen() * rhs.0.widen();
+        if FRAC == 0 {
+            return Fixed(I::narrow(product));
+        }
+        let half = I::Wide::one() << (FRAC - 1) as usize;
+        Fixed(I::narrow((product + half) >> FRAC as usize))
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Div for Fixed<I, FRAC> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Fixed(I::narrow((self.0.widen() << FRAC as usize) / rhs.0.widen()))
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Rem for Fixed<I, FRAC> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        Fixed(self.0 % rhs.0)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Neg for Fixed<I, FRAC> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fixed(-self.0)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Zero for Fixed<I, FRAC> {
+    fn zero() -> Self {
+        Fixed(I::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> One for Fixed<I, FRAC> {
+    fn one() -> Self {
+        Fixed::from_int(I::one())
+    }
+}
+
+/// ParseFixedError is the error returned when parsing a `Fixed` number from a
+/// string fails, because it is not a number or is out of range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseFixedError;
+
+impl fmt::Display for ParseFixedError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid fixed-point number")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseFixedError {
+    fn description(&self) -> &str {
+        "invalid fixed-point number"
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> Num for Fixed<I, FRAC> {
+    type FromStrRadixErr = ParseFixedError;
+
+    /// from_str_radix parses a number with an optional sign and fraction (e.g.
+    /// `"-12.375"`) in the given radix, rounding it to the nearest fixed-point
+    /// number, without using floating point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{I16F16, Num};
+    ///
+    /// assert_eq!(I16F16::from_str_radix("-12.375", 10), Ok(I16F16::from_f64(-12.375)));
+    /// assert_eq!(I16F16::from_str_radix("0.1", 10), Ok(I16F16::from_f64(0.1)));
+    /// assert_eq!(I16F16::from_str_radix("ff.8", 16), Ok(I16F16::from_f64(255.5)));
+    /// assert!(I16F16::from_str_radix("40000", 10).is_err());
+    /// assert!(I16F16::from_str_radix("1.2.3", 10).is_err());
+    /// ```
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParseFixedError> {
+        let (negative, s) = match s.as_bytes().first() {
+            Some(&b'-') => (true, &s[1..]),
+            Some(&b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (int, frac) = match s.find('.') {
+            Some(i) => (&s[..i], &s[i + 1..]),
+            None => (s, ""),
+        };
+        if int.is_empty() && frac.is_empty() {
+            return Err(ParseFixedError);
+        }
+
+        let mut bits: u128 = 0;
+        for c in int.chars() {
+            let digit = c.to_digit(radix).ok_or(ParseFixedError)?;
+            bits = bits.checked_mul(radix as u128)
+                .and_then(|b| b.checked_add(digit as u128))
+                .ok_or(ParseFixedError)?;
+        }
+        if bits >= 1 << (126 - FRAC) {
+            return Err(ParseFixedError);
+        }
+        bits <<= FRAC;
+
+        // The fraction is accumulated from its last digit to its first, with
+        // 16 guard bits below the fractional bits, which are then rounded off.
+        let guard = FRAC + 16;
+        let mut fraction: u128 = 0;
+        for c in frac.chars().rev() {
+            let digit = c.to_digit(radix).ok_or(ParseFixedError)?;
+            fraction = (((digit as u128) << guard) + fraction) / radix as u128;
+        }
+        bits += (fraction + (1 << 15)) >> 16;
+
+        let bits = if negative { (bits as i128).wrapping_neg() } else { bits as i128 };
+        I::from(bits).map(Fixed).ok_or(ParseFixedError)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> FromStr for Fixed<I, FRAC> {
+    type Err = ParseFixedError;
+
+    fn from_str(s: &str) -> Result<Self, ParseFixedError> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> fmt::Debug for Fixed<I, FRAC> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&Fixed::to_f64(*self), f)
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> fmt::Display for Fixed<I, FRAC> {
+    /// fmt formats the number as its f64 value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::I16F16;
+    ///
+    /// assert_eq!(format!("{}", I16F16::from_f64(-2.75)), "-2.75");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&Fixed::to_f64(*self), f)
+    }
+}
+
+// Fixed converts to integers by truncating towards zero, like a float.
+impl<I: FixedInt, const FRAC: u32> ToPrimitive for Fixed<I, FRAC> {
+    fn to_i64(&self) -> Option<i64> {
+        (self.0.widen() / (I::Wide::one() << FRAC as usize)).to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        (self.0.widen() / (I::Wide::one() << FRAC as usize)).to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        Some(Fixed::to_f64(*self))
+    }
+}
+
+impl<I: FixedInt, const FRAC: u32> NumCast for Fixed<I, FRAC> {
+    fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+        let bits = num::Float::round(n.to_f64()? * Self::scale());
+        I::from(bits).map(Fixed)
+    }
+}
@@ -1,4 +1,8 @@
-extern crate num;
+use core::cmp::Ordering;
+use core::mem;
+use num;
+
+use super::math;
 
 /// The default epsilon value used for floating point comparisons.
 pub static EPSILON: f64 = 1.0E-8;
@@ -52,6 +56,147 @@ pub trait Float: num::Float{
     /// assert_eq!(0.0.lerp(10.0, 1.0), 10.0);
     /// ```
     fn lerp(self, b: Self, t: Self) -> Self;
+
+    /// inverse_lerp returns the amount `t` by which `self` must be interpolated
+    /// towards `b` to yield `value`, i.e. the inverse of `lerp`. Values outside
+    /// of the range yield a `t` outside of 0.0 - 1.0. If `self` and `b` are
+    /// equal the result is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Float;
+    ///
+    /// assert_eq!(10.0.inverse_lerp(20.0, 12.5), 0.25);
+    /// assert_eq!(10.0.inverse_lerp(20.0, 5.0), -0.5);
+    /// assert_eq!(20.0.inverse_lerp(10.0, 12.5), 0.75);
+    /// ```
+    fn inverse_lerp(self, b: Self, value: Self) -> Self;
+
+    /// remap maps `self` from the range `from` onto the range `to`, such that
+    /// `from.0` yields `to.0` and `from.1` yields `to.1`. It is equivalent to
+    /// `to.0.lerp(to.1, from.0.inverse_lerp(from.1, self))`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Float;
+    ///
+    /// assert_eq!(5.0.remap((0.0, 10.0), (100.0, 200.0)), 150.0);
+    /// assert_eq!(0.25.remap((0.0, 1.0), (1.0, -1.0)), 0.5);
+    /// assert_eq!(20.0.remap((0.0, 10.0), (0.0, 1.0)), 2.0);
+    /// ```
+    fn remap(self, from: (Self, Self), to: (Self, Self)) -> Self;
+
+    /// saturate clamps `self` to the range 0.0 - 1.0. NaN saturates to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Float;
+    ///
+    /// assert_eq!(1.5.saturate(), 1.0);
+    /// assert_eq!((-0.5).saturate(), 0.0);
+    /// assert_eq!(0.25.saturate(), 0.25);
+    /// ```
+    fn saturate(self) -> Self;
+
+    /// smoothstep performs cubic Hermite interpolation from zero to one as
+    /// `self` moves from `edge0` to `edge1`, with zero slope at both edges (as
+    /// in GLSL). Values outside of the edges yield zero or one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Float;
+    ///
+    /// assert_eq!(1.0.smoothstep(2.0, 4.0), 0.0);
+    /// assert_eq!(3.0.smoothstep(2.0, 4.0), 0.5);
+    /// assert_eq!(2.5.smoothstep(2.0, 4.0), 0.15625);
+    /// assert_eq!(5.0.smoothstep(2.0, 4.0), 1.0);
+    /// ```
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self;
+
+    /// smootherstep is like `smoothstep`, but uses Perlin's quintic polynomial
+    /// which also has zero second derivative at both edges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Float;
+    ///
+    /// assert_eq!(3.0.smootherstep(2.0, 4.0), 0.5);
+    /// assert_eq!(2.5.smootherstep(2.0, 4.0), 0.103515625);
+    /// assert_eq!(5.0.smootherstep(2.0, 4.0), 1.0);
+    /// ```
+    fn smootherstep(self, edge0: Self, edge1: Self) -> Self;
+
+    /// fast_rsqrt returns an approximation of the reciprocal square root
+    /// `1 / self.sqrt()`, which is faster than computing it exactly (e.g. for
+    /// normalizing vectors in tight loops, see `Vec3::fast_normalize`).
+    ///
+    /// For single precision types, it refines a hardware estimate (the SSE
+    /// `rsqrtss` instruction) with one Newton-Raphson step, giving a relative
+    /// error below 5e-7 (about 21 bits). Without SSE, a bit-level estimate is
+    /// refined with two steps, giving a relative error below 5e-6 (about 17
+    /// bits). Wider types (e.g. f64), and values which are not positive and
+    /// normal (e.g. zero, subnormals or infinity), are computed exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Float;
+    ///
+    /// assert!(4.0f32.fast_rsqrt().almost_equal(0.5, 5e-6));
+    /// assert!(2.0f32.fast_rsqrt().almost_equal(0.70710677, 5e-6));
+    /// assert_eq!(0.0f32.fast_rsqrt(), std::f32::INFINITY);
+    /// assert_eq!(4.0f64.fast_rsqrt(), 0.5);
+    /// ```
+    fn fast_rsqrt(self) -> Self;
+
+    /// total_cmp compares `self` and `y` under the IEEE 754 totalOrder
+    /// predicate, i.e. -NaN < -inf < ... < -0.0 < 0.0 < ... < inf < NaN. Unlike
+    /// `partial_cmp` it always returns an ordering, so it can be used to sort
+    /// floats with `sort_by`.
+    ///
+    /// On f32 and f64 the inherent `total_cmp` method, which takes references,
+    /// shadows this one; call it through the trait instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use fiz_math::Float;
+    ///
+    /// assert_eq!(Float::total_cmp(1.0, 2.0), Ordering::Less);
+    /// assert_eq!(Float::total_cmp(-0.0, 0.0), Ordering::Less);
+    /// assert_eq!(Float::total_cmp(std::f64::NAN, std::f64::INFINITY), Ordering::Greater);
+    /// assert_eq!(Float::total_cmp(std::f32::NAN, std::f32::NAN), Ordering::Equal);
+    /// ```
+    fn total_cmp(self, y: Self) -> Ordering;
+}
+
+// rsqrt_f32 estimates 1 / x.sqrt() for positive normal x, with the SSE rsqrtss
+// instruction (accurate to 1.5 * 2^-12) refined by one Newton-Raphson step.
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))]
+fn rsqrt_f32(x: f32) -> f32 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{_mm_cvtss_f32, _mm_rsqrt_ss, _mm_set_ss};
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{_mm_cvtss_f32, _mm_rsqrt_ss, _mm_set_ss};
+
+    let y = unsafe { _mm_cvtss_f32(_mm_rsqrt_ss(_mm_set_ss(x))) };
+    y * (1.5 - 0.5 * x * y * y)
+}
+
+// rsqrt_f32 estimates 1 / x.sqrt() for positive normal x, by halving the
+// exponent of x in its bit representation (with the magic constant of Lomont,
+// accurate to about 3.4%) and refining it with two Newton-Raphson steps.
+#[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse")))]
+fn rsqrt_f32(x: f32) -> f32 {
+    let y = f32::from_bits(0x5f375a86 - (x.to_bits() >> 1));
+    let y = y * (1.5 - 0.5 * x * y * y);
+    y * (1.5 - 0.5 * x * y * y)
 }
 
 impl<T: num::Float> Float for T {
@@ -67,4 +212,50 @@ impl<T: num::Float> Float for T {
     fn lerp(self, b: Self, t: Self) -> Self {
         (T::one() - t) * self + t * b
     }
+
+    fn inverse_lerp(self, b: Self, value: Self) -> Self {
+        (value - self) / (b - self)
+    }
+
+    fn remap(self, from: (Self, Self), to: (Self, Self)) -> Self {
+        to.0.lerp(to.1, from.0.inverse_lerp(from.1, self))
+    }
+
+    fn saturate(self) -> Self {
+        self.max(T::zero()).min(T::one())
+    }
+
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+        let t = edge0.inverse_lerp(edge1, self).saturate();
+        t * t * (T::from(3.0).unwrap() - (t + t))
+    }
+
+    fn smootherstep(self, edge0: Self, edge1: Self) -> Self {
+        let t = edge0.inverse_lerp(edge1, self).saturate();
+        let (six, ten, fifteen) = (T::from(6.0).unwrap(), T::from(10.0).unwrap(),
+                                   T::from(15.0).unwrap());
+        t * t * t * (t * (t * six - fifteen) + ten)
+    }
+
+    fn fast_rsqrt(self) -> Self {
+        if mem::size_of::<T>() == mem::size_of::<f32>() {
+            if let Some(x) = self.to_f32() {
+                if x.is_normal() && x > 0.0 {
+                    return T::from(rsqrt_f32(x)).unwrap();
+                }
+            }
+        }
+        math::sqrt(self).recip()
+    }
+
+    fn total_cmp(self, y: Self) -> Ordering {
+        // Flipping all but the sign bit of negative values makes the two's
+        // complement order of the bits match the totalOrder predicate. Every
+        // Float converts to f64 exactly, sign and NaN included.
+        let key = |x: T| {
+            let bits = x.to_f64().unwrap().to_bits() as i64;
+            bits ^ (((bits >> 63) as u64) >> 1) as i64
+        };
+        key(self).cmp(&key(y))
+    }
 }
@@ -0,0 +1,169 @@
+use super::float::Float;
+use super::{Aabb3, Plane, Vec3, Vec4};
+
+/// Frustum is a convex volume bounded by six planes, typically the volume
+/// visible to a camera. The normals of the planes face into the frustum, so
+/// the points inside it are those on the positive side of every plane.
+///
+/// The planes are, in order: left, right, bottom, top, near and far. A frustum
+/// without a far plane (e.g. that of an infinite projection) has a far plane
+/// with a zero normal and a positive distance, which every point is in front
+/// of.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Frustum, Vec3, Vec4};
+///
+/// // The identity matrix views the cube from -1 to 1 along each axis.
+/// let f = Frustum::from_matrix_rows([Vec4(1.0, 0.0, 0.0, 0.0),
+///                                    Vec4(0.0, 1.0, 0.0, 0.0),
+///                                    Vec4(0.0, 0.0, 1.0, 0.0),
+///                                    Vec4(0.0, 0.0, 0.0, 1.0)]).unwrap();
+/// assert!(f.contains_point(Vec3(0.5, -0.5, 0.9)));
+/// assert!(!f.contains_point(Vec3(1.5, 0.0, 0.0)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Frustum<T> {
+    /// The planes bounding the frustum: left, right, bottom, top, near and
+    /// far.
+    pub planes: [Plane<T>; 6],
+}
+
+impl<T: Float> Frustum<T> {
+    /// new returns the frustum bounded by the given (normalized) planes.
+    pub fn new(planes: [Plane<T>; 6]) -> Self {
+        Frustum { planes }
+    }
+
+    /// from_matrix_rows returns the frustum viewed by the given view-projection
+    /// matrix, using the method of Gribb and Hartmann. The matrix is given as
+    /// its four rows, and is assumed to transform column vectors into OpenGL
+    /// clip space (where the visible depth range is -1 to 1). A projection with
+    /// an infinitely distant far plane gives a frustum without a far plane.
+    /// None is returned if the matrix is otherwise degenerate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Frustum, Vec3, Vec4};
+    ///
+    /// // A perspective projection with a 90 degree field of view, looking down
+    /// // the negative Z axis, with near and far planes at 1 and 10.
+    /// let (n, f) = (1.0, 10.0);
+    /// let frustum = Frustum::from_matrix_rows([
+    ///     Vec4(1.0, 0.0, 0.0, 0.0),
+    ///     Vec4(0.0, 1.0, 0.0, 0.0),
+    ///     Vec4(0.0, 0.0, -(f + n) / (f - n), -2.0 * f * n / (f - n)),
+    ///     Vec4(0.0, 0.0, -1.0, 0.0),
+    /// ]).unwrap();
+    ///
+    /// assert!(frustum.contains_point(Vec3(4.0, 0.0, -5.0)));
+    /// assert!(!frustum.contains_point(Vec3(6.0, 0.0, -5.0)));
+    /// assert!(!frustum.contains_point(Vec3(0.0, 0.0, -0.5)));
+    /// assert!(!frustum.contains_point(Vec3(0.0, 0.0, -11.0)));
+    /// assert!(!frustum.contains_point(Vec3(0.0, 0.0, 5.0)));
+    ///
+    /// // The same projection with the far plane at infinity.
+    /// let frustum = Frustum::from_matrix_rows([
+    ///     Vec4(1.0, 0.0, 0.0, 0.0),
+    ///     Vec4(0.0, 1.0, 0.0, 0.0),
+    ///     Vec4(0.0, 0.0, -1.0, -2.0 * n),
+    ///     Vec4(0.0, 0.0, -1.0, 0.0),
+    /// ]).unwrap();
+    ///
+    /// assert!(frustum.contains_point(Vec3(0.0, 0.0, -1.0e6)));
+    /// assert!(!frustum.contains_point(Vec3(0.0, 0.0, -0.5)));
+    /// ```
+    pub fn from_matrix_rows(rows: [Vec4<T>; 4]) -> Option<Self> {
+        let (r0, r1, r2, r3) = (rows[0], rows[1], rows[2], rows[3]);
+        let planes = [r3 + r0, r3 - r0, r3 + r1, r3 - r1, r3 + r2, r3 - r2];
+        let mut out = [Plane::new(Vec3(T::zero(), T::zero(), T::zero()), T::zero()); 6];
+        for (o, p) in out[..5].iter_mut().zip(planes.iter()) {
+            *o = Plane::from_vec4(*p).normalize()?;
+        }
+        // The far plane of an infinite projection has a zero normal and a
+        // positive distance, which can't be normalized but which every point
+        // is in front of.
+        let far = Plane::from_vec4(planes[5]);
+        out[5] = match far.normalize() {
+            Some(p) => p,
+            None if far.d > T::zero() => Plane::new(far.normal, T::one()),
+            None => return None,
+        };
+        Some(Frustum::new(out))
+    }
+
+    /// contains_point tells if the given point is inside this frustum, or on
+    /// its boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Frustum, Plane, Vec3};
+    ///
+    /// // A frustum can also be built from planes, e.g. the six faces of a box.
+    /// let planes = [Plane::new(Vec3(1.0, 0.0, 0.0), 0.0),
+    ///               Plane::new(Vec3(-1.0, 0.0, 0.0), 1.0),
+    ///               Plane::new(Vec3(0.0, 1.0, 0.0), 0.0),
+    ///               Plane::new(Vec3(0.0, -1.0, 0.0), 1.0),
+    ///               Plane::new(Vec3(0.0, 0.0, 1.0), 0.0),
+    ///               Plane::new(Vec3(0.0, 0.0, -1.0), 1.0)];
+    /// let f = Frustum::new(planes);
+    /// assert!(f.contains_point(Vec3(1.0, 0.5, 0.0)));
+    /// assert!(!f.contains_point(Vec3(1.0, 0.5, -0.1)));
+    /// ```
+    pub fn contains_point(&self, point: Vec3<T>) -> bool {
+        self.planes.iter().all(|p| p.signed_distance(point) >= T::zero())
+    }
+
+    /// intersects_sphere tells if the sphere with the given center and radius
+    /// is at least partially inside this frustum. Spheres near the corners of
+    /// the frustum may be reported as intersecting when they are not, which is
+    /// conservative for culling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Frustum, Vec3, Vec4};
+    ///
+    /// let f = Frustum::from_matrix_rows([Vec4(1.0, 0.0, 0.0, 0.0),
+    ///                                    Vec4(0.0, 1.0, 0.0, 0.0),
+    ///                                    Vec4(0.0, 0.0, 1.0, 0.0),
+    ///                                    Vec4(0.0, 0.0, 0.0, 1.0)]).unwrap();
+    /// assert!(f.intersects_sphere(Vec3(1.5, 0.0, 0.0), 0.6));
+    /// assert!(!f.intersects_sphere(Vec3(1.5, 0.0, 0.0), 0.4));
+    /// ```
+    pub fn intersects_sphere(&self, center: Vec3<T>, radius: T) -> bool {
+        self.planes.iter().all(|p| p.signed_distance(center) >= -radius)
+    }
+
+    /// intersects_aabb tells if the given axis-aligned box is at least
+    /// partially inside this frustum. As with `intersects_sphere`, boxes near
+    /// the corners of the frustum may be reported as intersecting when they are
+    /// not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Frustum, Vec3, Vec4};
+    ///
+    /// let f = Frustum::from_matrix_rows([Vec4(1.0, 0.0, 0.0, 0.0),
+    ///                                    Vec4(0.0, 1.0, 0.0, 0.0),
+    ///                                    Vec4(0.0, 0.0, 1.0, 0.0),
+    ///                                    Vec4(0.0, 0.0, 0.0, 1.0)]).unwrap();
+    /// assert!(f.intersects_aabb(&Aabb3::new(Vec3(0.5, 0.5, 0.5), Vec3(2.0, 2.0, 2.0))));
+    /// assert!(!f.intersects_aabb(&Aabb3::new(Vec3(1.5, 0.0, 0.0), Vec3(2.0, 1.0, 1.0))));
+    /// ```
+    pub fn intersects_aabb(&self, aabb: &Aabb3<T>) -> bool {
+        let (min, max) = (aabb.min, aabb.max);
+        self.planes.iter().all(|p| {
+            // The corner furthest along the plane normal.
+            let n = p.normal;
+            let c = Vec3(if n.0 >= T::zero() { max.0 } else { min.0 },
+                         if n.1 >= T::zero() { max.1 } else { min.1 },
+                         if n.2 >= T::zero() { max.2 } else { min.2 });
+            p.signed_distance(c) >= T::zero()
+        })
+    }
+}
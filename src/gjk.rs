@@ -0,0 +1,553 @@
+//! Collision detection between convex shapes with the GJK and EPA algorithms.
+//! Any convex shape can take part by implementing `Support`, which is
+//! implemented here for the crate's primitives and for convex point clouds
+//! given as slices of points.
+//!
+//! # Examples
+//!
+//! ```
+//! use fiz_math::{Aabb3, Sphere, Vec3};
+//! use fiz_math::gjk;
+//!
+//! // A tetrahedron, given by its corners, and a box beside it.
+//! let tetra = [Vec3(0.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0),
+//!              Vec3(0.0, 0.0, 1.0)];
+//! let b = Aabb3::new(Vec3(0.5, 0.5, 0.5), Vec3(2.0, 2.0, 2.0));
+//! assert!(!gjk::intersects(&tetra[..], &b));
+//! assert!(gjk::intersects(&tetra[..], &Sphere::new(Vec3(0.5, 0.5, 0.5), 0.5)));
+//! ```
+
+use prelude::*;
+
+use super::closest::triangle_barycentric;
+use super::float::Float;
+use super::math;
+use super::{Aabb3, Capsule, Cylinder, Obb, Segment3, Sphere, Triangle3, Vec3};
+
+/// Support is implemented by convex shapes, for use by the GJK and EPA
+/// algorithms.
+pub trait Support<T> {
+    /// support returns the point of the shape furthest in the direction d. If
+    /// there are several such points, any one of them may be returned.
+    fn support(&self, d: Vec3<T>) -> Vec3<T>;
+
+    /// center returns a point inside the shape, used to pick the initial search
+    /// direction.
+    fn center(&self) -> Vec3<T>;
+}
+
+/// The convex hull of a non-empty set of points.
+impl<T: Float> Support<T> for [Vec3<T>] {
+    fn support(&self, d: Vec3<T>) -> Vec3<T> {
+        self.iter().fold(self[0], |best, &p| if p.dot(d) > best.dot(d) { p } else { best })
+    }
+
+    fn center(&self) -> Vec3<T> {
+        let sum = self.iter().fold(Vec3(T::zero(), T::zero(), T::zero()), |sum, &p| sum + p);
+        sum.div_scalar(T::from(self.len()).unwrap())
+    }
+}
+
+impl<T: Float> Support<T> for Aabb3<T> {
+    fn support(&self, d: Vec3<T>) -> Vec3<T> {
+        let (min, max) = (self.min, self.max);
+        Vec3(if d.0 >= T::zero() { max.0 } else { min.0 },
+             if d.1 >= T::zero() { max.1 } else { min.1 },
+             if d.2 >= T::zero() { max.2 } else { min.2 })
+    }
+
+    fn center(&self) -> Vec3<T> {
+        Aabb3::center(self)
+    }
+}
+
+impl<T: Float> Support<T> for Obb<T> {
+    fn support(&self, d: Vec3<T>) -> Vec3<T> {
+        let (r, h) = (self.rotation, self.half_extents);
+        let side = |axis: Vec3<T>, h: T| {
+            axis.mul_scalar(if d.dot(axis) >= T::zero() { h } else { -h })
+        };
+        self.center + side(r.x(), h.0) + side(r.y(), h.1) + side(r.z(), h.2)
+    }
+
+    fn center(&self) -> Vec3<T> {
+        self.center
+    }
+}
+
+impl<T: Float> Support<T> for Sphere<T> {
+    fn support(&self, d: Vec3<T>) -> Vec3<T> {
+        match d.normalize() {
+            Some(n) => self.center + n.mul_scalar(self.radius),
+            None => self.center,
+        }
+    }
+
+    fn center(&self) -> Vec3<T> {
+        self.center
+    }
+}
+
+impl<T: Float> Support<T> for Segment3<T> {
+    fn support(&self, d: Vec3<T>) -> Vec3<T> {
+        if self.b.dot(d) > self.a.dot(d) { self.b } else { self.a }
+    }
+
+    fn center(&self) -> Vec3<T> {
+        self.midpoint()
+    }
+}
+
+impl<T: Float> Support<T> for Triangle3<T> {
+    fn support(&self, d: Vec3<T>) -> Vec3<T> {
+        [self.a, self.b, self.c][..].support(d)
+    }
+
+    fn center(&self) -> Vec3<T> {
+        self.centroid()
+    }
+}
+
+impl<T: Float> Support<T> for Capsule<T> {
+    fn support(&self, d: Vec3<T>) -> Vec3<T> {
+        Sphere::new(self.segment.support(d), self.radius).support(d)
+    }
+
+    fn center(&self) -> Vec3<T> {
+        self.segment.midpoint()
+    }
+}
+
+impl<T: Float> Support<T> for Cylinder<T> {
+    fn support(&self, d: Vec3<T>) -> Vec3<T> {
+        let axis = self.axis.b - self.axis.a;
+        let end = if d.dot(axis) >= T::zero() { self.axis.b } else { self.axis.a };
+        let radial = match axis.normalize() {
+            Some(n) => d - n.mul_scalar(d.dot(n)),
+            None => return end,
+        };
+
+        // If d is (nearly) parallel to the axis, the radial part is just
+        // rounding error and any point of the cap is furthest along d.
+        if radial.length_sq() <= d.length_sq() * T::from(1.0e-12).unwrap() {
+            return end;
+        }
+        end + radial.mul_scalar(self.radius / radial.length())
+    }
+
+    fn center(&self) -> Vec3<T> {
+        self.axis.midpoint()
+    }
+}
+
+// The maximum number of iterations of each algorithm. Polytopes converge in a
+// few iterations, but curved shapes may only converge approximately; EPA adds
+// a single point per iteration, and so is given more.
+const MAX_ITERATIONS: usize = 64;
+const MAX_EPA_ITERATIONS: usize = 256;
+
+// minkowski returns the support point of the Minkowski difference a - b, which
+// contains the origin iff the shapes overlap.
+fn minkowski<T, A, B>(a: &A, b: &B, d: Vec3<T>) -> Vec3<T>
+    where T: Float,
+          A: Support<T> + ?Sized,
+          B: Support<T> + ?Sized
+{
+    a.support(d) - b.support(-d)
+}
+
+/// intersects tells if the two convex shapes overlap, using the GJK algorithm
+/// in the boolean form popularized by Casey Muratori. Shapes that (nearly)
+/// touch may be reported either way.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Basis3, Capsule, Obb, Segment3, Vec3};
+/// use fiz_math::gjk::intersects;
+/// use fiz_math::unit::Deg;
+///
+/// let rot = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(45.0)).unwrap();
+/// let b = Obb::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0), rot);
+/// let c = Capsule::new(Segment3::new(Vec3(1.5, 0.0, -5.0), Vec3(1.5, 0.0, 5.0)), 0.1);
+/// assert!(intersects(&b, &c));
+/// let c = Capsule::new(Segment3::new(Vec3(1.5, 1.5, -5.0), Vec3(1.5, 1.5, 5.0)), 0.1);
+/// assert!(!intersects(&b, &c));
+/// ```
+pub fn intersects<T, A, B>(a: &A, b: &B) -> bool
+    where T: Float,
+          A: Support<T> + ?Sized,
+          B: Support<T> + ?Sized
+{
+    enclose(a, b).is_some()
+}
+
+// enclose returns a tetrahedron of support points of the Minkowski difference
+// a - b which encloses the origin, or None if the shapes do not overlap.
+fn enclose<T, A, B>(a: &A, b: &B) -> Option<[Vec3<T>; 4]>
+    where T: Float,
+          A: Support<T> + ?Sized,
+          B: Support<T> + ?Sized
+{
+    let zero = T::zero();
+    let mut dir = a.center() - b.center();
+    if dir.length_sq() == zero {
+        dir = Vec3(T::one(), zero, zero);
+    }
+    let mut c = minkowski(a, b, dir);
+    dir = -c;
+    let mut b_ = minkowski(a, b, dir);
+    if b_.dot(dir) < zero {
+        return None;
+    }
+    dir = (c - b_).cross(-b_).cross(c - b_);
+    let tolerance = T::epsilon() * T::from(64.0).unwrap();
+    let scale = (c - b_).length_sq() * (c - b_).length_sq() * b_.length_sq();
+    if dir.length_sq() <= tolerance * tolerance * scale {
+        // The origin is (nearly) on the segment bc, such that the direction is
+        // just rounding error; pick any direction perpendicular to it.
+        dir = (c - b_).cross(Vec3(T::one(), zero, zero));
+        if dir.length_sq() == zero {
+            dir = (c - b_).cross(Vec3(zero, zero, -T::one()));
+        }
+    }
+    let mut d = Vec3(zero, zero, zero);
+    let mut dim = 2;
+    for _ in 0..MAX_ITERATIONS {
+        let a_ = minkowski(a, b, dir);
+        if a_.dot(dir) < zero {
+            return None;
+        }
+        dim += 1;
+        let ao = -a_;
+        if dim == 3 {
+            // Triangle a, b, c: find the feature closest to the origin.
+            let n = (b_ - a_).cross(c - a_);
+            dim = 2;
+            if (b_ - a_).cross(n).dot(ao) > zero {
+                c = a_;
+                dir = (b_ - a_).cross(ao).cross(b_ - a_);
+                continue;
+            }
+            if n.cross(c - a_).dot(ao) > zero {
+                b_ = a_;
+                dir = (c - a_).cross(ao).cross(c - a_);
+                continue;
+            }
+            dim = 3;
+            if n.dot(ao) > zero {
+                d = c;
+                c = b_;
+                b_ = a_;
+                dir = n;
+            } else {
+                d = b_;
+                b_ = a_;
+                dir = -n;
+            }
+        } else {
+            // Tetrahedron a, b, c, d: the origin is inside unless it is
+            // outside one of the faces adjacent to a. An origin on a face
+            // (within rounding error) is inside, as otherwise the search may
+            // cycle around it.
+            let abc = (b_ - a_).cross(c - a_);
+            let acd = (c - a_).cross(d - a_);
+            let adb = (d - a_).cross(b_ - a_);
+            let outside = |n: Vec3<T>| n.dot(ao) > tolerance * n.length() * ao.length();
+            dim = 3;
+            if outside(abc) {
+                d = c;
+                c = b_;
+                b_ = a_;
+                dir = abc;
+            } else if outside(acd) {
+                b_ = a_;
+                dir = acd;
+            } else if outside(adb) {
+                c = d;
+                d = b_;
+                b_ = a_;
+                dir = adb;
+            } else {
+                return Some([a_, b_, c, d]);
+            }
+        }
+    }
+    None
+}
+
+// Vertex is a vertex of the simplex built by distance: a support point of the
+// Minkowski difference, and the support points of each shape it is made of.
+#[derive(Copy, Clone)]
+struct Vertex<T> {
+    w: Vec3<T>,
+    a: Vec3<T>,
+    b: Vec3<T>,
+}
+
+// Weighted is a subset of the vertices of a simplex, paired with the
+// barycentric coordinates of a point within their convex hull.
+type Weighted<T> = Vec<(Vertex<T>, T)>;
+
+/// distance returns the distance between the two convex shapes and the
+/// closest points on each of them, or None if they overlap, using the GJK
+/// algorithm. For curved shapes the result is approximate, with a relative
+/// error around the square root of the machine epsilon.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Aabb3, Float, Sphere, Vec3};
+/// use fiz_math::gjk::distance;
+///
+/// let s = Sphere::new(Vec3(0.0, 0.0, 0.0), 1.0);
+/// let b = Aabb3::new(Vec3(2.0, -1.0, -1.0), Vec3(3.0, 1.0, 1.0));
+/// let (dist, on_s, on_b): (f64, _, _) = distance(&s, &b).unwrap();
+/// assert!(dist.almost_equal(1.0, 1e-6));
+/// assert!(on_s.almost_equal(Vec3(1.0, 0.0, 0.0), 1e-6));
+/// assert_eq!(on_b.0, 2.0);
+///
+/// assert_eq!(distance(&s, &Aabb3::new(Vec3(0.5, 0.5, 0.5), Vec3(1.0, 1.0, 1.0))), None);
+/// ```
+pub fn distance<T, A, B>(a: &A, b: &B) -> Option<(T, Vec3<T>, Vec3<T>)>
+    where T: Float,
+          A: Support<T> + ?Sized,
+          B: Support<T> + ?Sized
+{
+    let vertex = |d: Vec3<T>| {
+        let (pa, pb) = (a.support(d), b.support(-d));
+        Vertex {
+            w: pa - pb,
+            a: pa,
+            b: pb,
+        }
+    };
+    let tolerance = math::sqrt(T::epsilon());
+
+    let mut dir = b.center() - a.center();
+    if dir.length_sq() == T::zero() {
+        dir = Vec3(T::one(), T::zero(), T::zero());
+    }
+    let mut simplex = vec![(vertex(dir), T::one())];
+    let mut v = simplex[0].0.w;
+    for _ in 0..MAX_ITERATIONS {
+        let scale = simplex.iter().fold(T::zero(), |m, s| m.max(s.0.w.length_sq()));
+        if v.length_sq() <= T::epsilon() * scale {
+            return None;
+        }
+
+        // Stop once the support point in the direction of the origin gets no
+        // (relatively) closer to it than the current closest point.
+        let w = vertex(-v);
+        if v.length_sq() - v.dot(w.w) <= tolerance * v.length_sq() ||
+           simplex.iter().any(|s| s.0.w == w.w) {
+            break;
+        }
+        let mut vertices: Vec<Vertex<T>> = simplex.iter().map(|s| s.0).collect();
+        vertices.push(w);
+        simplex = closest_on_simplex(&vertices)?;
+        v = simplex.iter().fold(Vec3(T::zero(), T::zero(), T::zero()),
+                                |v, s| v + s.0.w.mul_scalar(s.1));
+    }
+
+    let zero = Vec3(T::zero(), T::zero(), T::zero());
+    let on_a = simplex.iter().fold(zero, |p, s| p + s.0.a.mul_scalar(s.1));
+    let on_b = simplex.iter().fold(zero, |p, s| p + s.0.b.mul_scalar(s.1));
+    Some((v.length(), on_a, on_b))
+}
+
+// closest_on_simplex returns the smallest subset of the simplex whose convex
+// hull contains the point of the simplex closest to the origin, paired with
+// the barycentric coordinates of that point, or None if the simplex is a
+// tetrahedron which contains the origin.
+fn closest_on_simplex<T: Float>(s: &[Vertex<T>]) -> Option<Weighted<T>> {
+    let (zero, one) = (T::zero(), T::one());
+    let origin = Vec3(zero, zero, zero);
+    let weighted: Weighted<T> = match s.len() {
+        1 => vec![(s[0], one)],
+        2 => {
+            let ab = s[1].w - s[0].w;
+            let t = if ab.length_sq() == zero {
+                zero
+            } else {
+                (-s[0].w.dot(ab) / ab.length_sq()).max(zero).min(one)
+            };
+            vec![(s[0], one - t), (s[1], t)]
+        }
+        3 => {
+            let (ab, ac) = (s[1].w - s[0].w, s[2].w - s[0].w);
+            let area_sq = ab.cross(ac).length_sq();
+            if area_sq <= T::epsilon() * ab.length_sq() * ac.length_sq() {
+                // Degenerate: the closest of the edges.
+                let edges = [[s[0], s[1]], [s[0], s[2]], [s[1], s[2]]];
+                return edges.iter()
+                    .map(|e| closest_on_simplex(e).unwrap())
+                    .fold(None, |best: Option<(T, Weighted<T>)>, e| {
+                        let d = weighted_point(&e).length_sq();
+                        match best {
+                            Some((bd, _)) if bd <= d => best,
+                            _ => Some((d, e)),
+                        }
+                    })
+                    .map(|b| b.1);
+            }
+            let tri = Triangle3::new(s[0].w, s[1].w, s[2].w);
+            let (u, v, w) = triangle_barycentric(&tri, origin);
+            vec![(s[0], u), (s[1], v), (s[2], w)]
+        }
+        _ => {
+            // The closest point of the faces that have the origin on their
+            // outer side (or of every face, if the tetrahedron is flat).
+            let faces = [(0, 1, 2, 3), (0, 1, 3, 2), (0, 2, 3, 1), (1, 2, 3, 0)];
+            let scale = s.iter().fold(zero, |m, v| m.max(v.w.length()));
+            let mut best: Option<(T, Weighted<T>)> = None;
+            for &(i, j, k, l) in faces.iter() {
+                let n = (s[j].w - s[i].w).cross(s[k].w - s[i].w);
+                let side_origin = -n.dot(s[i].w);
+                let side_other = n.dot(s[l].w - s[i].w);
+                let flat = side_other.abs() <= T::epsilon() * scale * scale * scale;
+                if !flat && side_origin * side_other >= zero {
+                    continue;
+                }
+                let face = closest_on_simplex(&[s[i], s[j], s[k]]).unwrap();
+                let d = weighted_point(&face).length_sq();
+                if best.as_ref().map_or(true, |b| d < b.0) {
+                    best = Some((d, face));
+                }
+            }
+            return best.map(|b| b.1);
+        }
+    };
+    Some(weighted.into_iter().filter(|s| s.1 > zero).collect())
+}
+
+// weighted_point returns the point of the Minkowski difference with the given
+// barycentric coordinates.
+fn weighted_point<T: Float>(s: &[(Vertex<T>, T)]) -> Vec3<T> {
+    s.iter().fold(Vec3(T::zero(), T::zero(), T::zero()), |p, v| p + v.0.w.mul_scalar(v.1))
+}
+
+/// Penetration describes how deeply two convex shapes overlap, as returned by
+/// `penetration`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Penetration<T> {
+    /// The smallest distance that the shapes must be moved apart by to no
+    /// longer overlap.
+    pub depth: T,
+
+    /// The unit direction in which the second shape must be moved (or the
+    /// first shape in the opposite direction) to separate the shapes.
+    pub normal: Vec3<T>,
+}
+
+/// penetration returns how deeply the two convex shapes overlap, or None if
+/// they do not, using the expanding polytope algorithm (EPA). For curved
+/// shapes the depth is approximate, and may be underestimated by up to a
+/// fraction of a percent for deeply overlapping shapes.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Aabb3, Float, Sphere, Vec3};
+/// use fiz_math::gjk::penetration;
+///
+/// let a = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0));
+/// let b = Aabb3::new(Vec3(0.75, 0.2, -0.5), Vec3(1.75, 0.8, 0.5));
+/// let p = penetration(&a, &b).unwrap();
+/// assert!(p.depth.almost_equal(0.25, 1e-12));
+/// assert!(p.normal.almost_equal(Vec3(1.0, 0.0, 0.0), 1e-12));
+///
+/// let s = Sphere::new(Vec3(0.5, 1.2, 0.5), 0.5);
+/// let p = penetration(&a, &s).unwrap();
+/// assert!(p.depth.almost_equal(0.3, 1e-6));
+/// assert!(p.normal.almost_equal(Vec3(0.0, 1.0, 0.0), 1e-6));
+///
+/// assert_eq!(penetration(&a, &Sphere::new(Vec3(0.5, 2.0, 0.5), 0.5)), None);
+/// ```
+pub fn penetration<T, A, B>(a: &A, b: &B) -> Option<Penetration<T>>
+    where T: Float,
+          A: Support<T> + ?Sized,
+          B: Support<T> + ?Sized
+{
+    let tetra = enclose(a, b)?;
+
+    // The polytope, as a list of vertices and of outward facing triangles
+    // with their unit normals and distances from the origin.
+    let mut vertices = tetra.to_vec();
+    let center = (tetra[0] + tetra[1] + tetra[2] + tetra[3]).div_scalar(T::from(4.0).unwrap());
+    let mut faces = Vec::new();
+    for &(i, j, k) in [(0, 1, 2), (0, 3, 1), (0, 2, 3), (1, 3, 2)].iter() {
+        let n = (vertices[j] - vertices[i]).cross(vertices[k] - vertices[i]);
+        let f = if n.dot(vertices[i] - center) >= T::zero() { [i, j, k] } else { [i, k, j] };
+        if let Some(face) = EpaFace::new(&vertices, f) {
+            faces.push(face);
+        }
+    }
+
+    let tolerance = math::sqrt(T::epsilon());
+    let mut best = None;
+    for _ in 0..MAX_EPA_ITERATIONS {
+        let closest = match faces.iter()
+            .min_by(|x, y| x.dist.partial_cmp(&y.dist).unwrap_or(::core::cmp::Ordering::Equal)) {
+            Some(f) => *f,
+            None => break,
+        };
+        best = Some(Penetration {
+            depth: closest.dist.max(T::zero()),
+            normal: closest.normal,
+        });
+
+        // Stop once the polytope reaches the boundary of the Minkowski
+        // difference in the direction of the closest face.
+        let p = minkowski(a, b, closest.normal);
+        if closest.normal.dot(p) - closest.dist <= tolerance * (T::one() + closest.dist) {
+            break;
+        }
+
+        // Replace the faces that the new point can see by faces joining it to
+        // their horizon.
+        let index = vertices.len();
+        vertices.push(p);
+        let mut edges = BTreeSet::new();
+        faces.retain(|f| {
+            if f.normal.dot(p - vertices[f.v[0]]) <= T::zero() {
+                return true;
+            }
+            for k in 0..3 {
+                edges.insert((f.v[k], f.v[(k + 1) % 3]));
+            }
+            false
+        });
+        for &(i, j) in edges.iter() {
+            if !edges.contains(&(j, i)) {
+                if let Some(face) = EpaFace::new(&vertices, [i, j, index]) {
+                    faces.push(face);
+                }
+            }
+        }
+    }
+    best
+}
+
+// EpaFace is a triangle of the polytope built by penetration.
+#[derive(Copy, Clone)]
+struct EpaFace<T> {
+    v: [usize; 3],
+    normal: Vec3<T>,
+    dist: T,
+}
+
+impl<T: Float> EpaFace<T> {
+    // new returns the face with the given vertices, or None if it is
+    // degenerate.
+    fn new(vertices: &[Vec3<T>], v: [usize; 3]) -> Option<Self> {
+        let (a, b, c) = (vertices[v[0]], vertices[v[1]], vertices[v[2]]);
+        (b - a).cross(c - a).normalize().map(|n| {
+            EpaFace {
+                v,
+                normal: n,
+                dist: n.dot(a),
+            }
+        })
+    }
+}
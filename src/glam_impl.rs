@@ -0,0 +1,79 @@
+use glam;
+
+use super::{Basis3, Vec2, Vec3, Vec3A, Vec4};
+
+// glam_vec implements the conversions between a vector type of the given
+// scalar and the corresponding glam type (whose fields are named x, y, z and w).
+macro_rules! glam_vec {
+    ($vec:ident<$t:ty>, $glam:ident, $($i:tt: $field:ident),*) => {
+        impl From<glam::$glam> for $vec<$t> {
+            fn from(v: glam::$glam) -> Self {
+                $vec($(v.$field),*)
+            }
+        }
+
+        impl From<$vec<$t>> for glam::$glam {
+            fn from(v: $vec<$t>) -> Self {
+                glam::$glam::new($(v.$i),*)
+            }
+        }
+    }
+}
+
+glam_vec!(Vec2<f32>, Vec2, 0: x, 1: y);
+glam_vec!(Vec3<f32>, Vec3, 0: x, 1: y, 2: z);
+glam_vec!(Vec4<f32>, Vec4, 0: x, 1: y, 2: z, 3: w);
+glam_vec!(Vec2<f64>, DVec2, 0: x, 1: y);
+glam_vec!(Vec3<f64>, DVec3, 0: x, 1: y, 2: z);
+glam_vec!(Vec4<f64>, DVec4, 0: x, 1: y, 2: z, 3: w);
+
+impl From<glam::Vec3A> for Vec3A {
+    fn from(v: glam::Vec3A) -> Self {
+        Vec3A::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3A> for glam::Vec3A {
+    fn from(v: Vec3A) -> Self {
+        glam::Vec3A::new(v.0, v.1, v.2)
+    }
+}
+
+// glam_basis implements the conversions of Basis3 to the glam matrices (whose
+// columns are its axes) and quaternion of its rotation, and from the latter.
+// The quaternion is normalized, with a zero one giving the identity. Matrices
+// do not convert to Basis3, since they need not be rotations (use
+// Basis3::from_axes to check one).
+macro_rules! glam_basis {
+    ($t:ty, $mat:ident, $mat4:ident, $quat:ident) => {
+        impl From<Basis3<$t>> for glam::$mat {
+            fn from(b: Basis3<$t>) -> Self {
+                glam::$mat::from_cols(b.x().into(), b.y().into(), b.z().into())
+            }
+        }
+
+        impl From<Basis3<$t>> for glam::$mat4 {
+            fn from(b: Basis3<$t>) -> Self {
+                glam::$mat4::from_mat3(b.into())
+            }
+        }
+
+        impl From<Basis3<$t>> for glam::$quat {
+            fn from(b: Basis3<$t>) -> Self {
+                let Vec4(x, y, z, w) = b.to_quat();
+                glam::$quat::from_xyzw(x, y, z, w)
+            }
+        }
+
+        impl From<glam::$quat> for Basis3<$t> {
+            fn from(q: glam::$quat) -> Self {
+                Vec4(q.x, q.y, q.z, q.w)
+                    .normalize()
+                    .map_or(Basis3::identity(), Basis3::from_quat)
+            }
+        }
+    }
+}
+
+glam_basis!(f32, Mat3, Mat4, Quat);
+glam_basis!(f64, DMat3, DMat4, DQuat);
@@ -0,0 +1,361 @@
+//! Layouts of GPU buffers, i.e. the std140 rules of GLSL uniform blocks and the
+//! std430 rules of storage blocks (and push constants), under which e.g. a vec3
+//! is aligned to 16 bytes and, in std140, every array element is too. Getting
+//! these wrong silently garbles the data seen by shaders, so this module
+//! computes them instead.
+//!
+//! `GpuType` is implemented by the scalars, vectors, bases and arrays which have
+//! a GLSL counterpart, and `GpuWriter` writes the members of a block into bytes
+//! at their correct offsets. Alternatively, `#[repr(C)]` structs can mirror a
+//! block directly using `Vec3A` for a vec3 followed by another vec3 or vec4,
+//! and `Padded` for the elements of std140 arrays.
+//!
+//! # Examples
+//!
+//! ```
+//! use fiz_math::Vec3;
+//! use fiz_math::gpu::{GpuWriter, Layout};
+//!
+//! // layout(std140) uniform Light {
+//! //     vec3 position;
+//! //     float intensity;
+//! //     vec3 color;
+//! //     float falloff[2];
+//! // };
+//! let mut w = GpuWriter::new(Layout::Std140);
+//! assert_eq!(w.push(&Vec3(1.0f32, 2.0, 3.0)), 0);
+//! assert_eq!(w.push(&0.5f32), 12);
+//! assert_eq!(w.push(&Vec3(1.0f32, 1.0, 1.0)), 16);
+//! assert_eq!(w.push(&[1.0f32, 0.1]), 32);
+//! let bytes = w.finish();
+//! assert_eq!(bytes.len(), 64);
+//! assert_eq!(&bytes[48..52], &0.1f32.to_le_bytes());
+//!
+//! // The same members as a storage block, whose arrays are tightly packed.
+//! let mut w = GpuWriter::new(Layout::Std430);
+//! w.push(&Vec3(1.0f32, 2.0, 3.0));
+//! w.push(&0.5f32);
+//! w.push(&Vec3(1.0f32, 1.0, 1.0));
+//! assert_eq!(w.push(&[1.0f32, 0.1]), 28);
+//! assert_eq!(w.finish().len(), 48);
+//! ```
+
+use core::mem;
+
+use prelude::*;
+
+use super::float::Float;
+use super::{Basis3, Vec2, Vec3, Vec3A, Vec4};
+
+// round_up returns x rounded up to a multiple of align.
+fn round_up(x: usize, align: usize) -> usize {
+    x.div_ceil(align) * align
+}
+
+/// Layout is a set of rules for the layout of the members of a GPU buffer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// Std140 is the layout of GLSL uniform blocks, in which array elements and
+    /// structs are aligned to (at least) 16 bytes.
+    Std140,
+
+    /// Std430 is the layout of GLSL storage blocks and Vulkan push constants,
+    /// in which array elements and structs are aligned like their members.
+    Std430,
+}
+
+impl Layout {
+    // aggregate returns the alignment of an array element or struct whose
+    // members have the given (largest) alignment.
+    fn aggregate(self, align: usize) -> usize {
+        match self {
+            Layout::Std140 => round_up(align, 16),
+            Layout::Std430 => align,
+        }
+    }
+}
+
+/// GpuType is a type with a counterpart in GLSL, which can be written into a
+/// GPU buffer under either layout.
+///
+/// It is implemented by f32, f64, i32 and u32, by the vectors of these (and
+/// Vec3A) as vecN, dvecN, ivecN and uvecN, by Basis3 of f32 and f64 as a mat3
+/// or dmat3, by fixed-size arrays of any GpuType, and by `Padded`.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Basis3, Vec3};
+/// use fiz_math::gpu::{GpuType, Layout};
+///
+/// assert_eq!(Vec3::<f32>::align(Layout::Std430), 16);
+/// assert_eq!(Vec3::<f32>::size(Layout::Std430), 12);
+/// assert_eq!(<[f32; 4]>::size(Layout::Std140), 64);
+/// assert_eq!(<[f32; 4]>::size(Layout::Std430), 16);
+///
+/// // A mat3 is an array of three vec3 columns, each padded to 16 bytes.
+/// let mut bytes = [0; 48];
+/// Basis3::<f32>::identity().write(Layout::Std140, &mut bytes);
+/// assert_eq!(&bytes[16..20], &0.0f32.to_le_bytes());
+/// assert_eq!(&bytes[20..24], &1.0f32.to_le_bytes());
+/// ```
+pub trait GpuType {
+    /// align returns the base alignment of the type in bytes.
+    fn align(layout: Layout) -> usize;
+
+    /// size returns the size of the type in bytes, which need not be a
+    /// multiple of its alignment (e.g. a vec3 of 12 bytes is aligned to 16).
+    fn size(layout: Layout) -> usize;
+
+    /// write writes this value to the start of `out` in little-endian byte
+    /// order, leaving padding bytes untouched.
+    ///
+    /// # Panics
+    ///
+    /// `out` must be at least `size` bytes long.
+    fn write(&self, layout: Layout, out: &mut [u8]);
+}
+
+// gpu_scalar implements GpuType for a scalar type and the vectors of it, whose
+// vec2 is aligned to twice the scalar and vec3 and vec4 to four times.
+macro_rules! gpu_scalar {
+    ($($t:ty),*) => {$(
+        impl GpuType for $t {
+            fn align(_: Layout) -> usize {
+                mem::size_of::<$t>()
+            }
+
+            fn size(_: Layout) -> usize {
+                mem::size_of::<$t>()
+            }
+
+            fn write(&self, _: Layout, out: &mut [u8]) {
+                out[..mem::size_of::<$t>()].copy_from_slice(&self.to_le_bytes());
+            }
+        }
+
+        gpu_scalar!(@vec $t, Vec2, 2, 2, 0, 1);
+        gpu_scalar!(@vec $t, Vec3, 4, 3, 0, 1, 2);
+        gpu_scalar!(@vec $t, Vec4, 4, 4, 0, 1, 2, 3);
+    )*};
+
+    (@vec $t:ty, $vec:ident, $align:expr, $n:expr, $($i:tt),*) => {
+        impl GpuType for $vec<$t> {
+            fn align(_: Layout) -> usize {
+                $align * mem::size_of::<$t>()
+            }
+
+            fn size(_: Layout) -> usize {
+                $n * mem::size_of::<$t>()
+            }
+
+            fn write(&self, layout: Layout, out: &mut [u8]) {
+                let n = mem::size_of::<$t>();
+                $(self.$i.write(layout, &mut out[$i * n..]);)*
+            }
+        }
+    };
+}
+
+gpu_scalar!(f32, f64, i32, u32);
+
+impl GpuType for Vec3A {
+    fn align(layout: Layout) -> usize {
+        Vec3::<f32>::align(layout)
+    }
+
+    fn size(layout: Layout) -> usize {
+        Vec3::<f32>::size(layout)
+    }
+
+    fn write(&self, layout: Layout, out: &mut [u8]) {
+        self.to_vec3().write(layout, out)
+    }
+}
+
+impl<T: GpuType, const N: usize> GpuType for [T; N] {
+    fn align(layout: Layout) -> usize {
+        layout.aggregate(T::align(layout))
+    }
+
+    fn size(layout: Layout) -> usize {
+        round_up(T::size(layout), Self::align(layout)) * N
+    }
+
+    fn write(&self, layout: Layout, out: &mut [u8]) {
+        let stride = round_up(T::size(layout), Self::align(layout));
+        for (i, x) in self.iter().enumerate() {
+            x.write(layout, &mut out[i * stride..]);
+        }
+    }
+}
+
+// A basis is a matrix of its axes as columns, which is laid out like an array
+// of them, regardless of the layout.
+impl<T: Float> GpuType for Basis3<T>
+    where Vec3<T>: GpuType
+{
+    fn align(_: Layout) -> usize {
+        <[Vec3<T>; 3]>::align(Layout::Std140)
+    }
+
+    fn size(_: Layout) -> usize {
+        <[Vec3<T>; 3]>::size(Layout::Std140)
+    }
+
+    fn write(&self, _: Layout, out: &mut [u8]) {
+        [self.x(), self.y(), self.z()].write(Layout::Std140, out)
+    }
+}
+
+/// Padded is a value aligned and padded to (at least) 16 bytes, like an
+/// element of a std140 array, so that such an array can be mirrored by a
+/// `#[repr(C)]` Rust struct, e.g. `float weights[4]` by a `[Padded<f32>; 4]`.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec2;
+/// use fiz_math::gpu::{GpuType, Layout, Padded};
+/// use std::mem::size_of;
+///
+/// assert_eq!(size_of::<[Padded<f32>; 4]>(), <[f32; 4]>::size(Layout::Std140));
+/// assert_eq!(size_of::<[Padded<Vec2<f32>>; 3]>(), 48);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(C, align(16))]
+pub struct Padded<T>(pub T);
+
+impl<T: GpuType> GpuType for Padded<T> {
+    fn align(layout: Layout) -> usize {
+        Layout::Std140.aggregate(T::align(layout))
+    }
+
+    fn size(layout: Layout) -> usize {
+        round_up(T::size(layout), Self::align(layout))
+    }
+
+    fn write(&self, layout: Layout, out: &mut [u8]) {
+        self.0.write(layout, out)
+    }
+}
+
+/// GpuWriter writes the members of a uniform or storage block, in order, into
+/// bytes at the offsets given by a layout, with zeros for padding.
+#[derive(Clone, Debug)]
+pub struct GpuWriter {
+    layout: Layout,
+    bytes: Vec<u8>,
+    align: usize,
+}
+
+impl GpuWriter {
+    /// new returns a new writer of an empty block with the given layout.
+    pub fn new(layout: Layout) -> Self {
+        GpuWriter {
+            layout,
+            bytes: Vec::new(),
+            align: 1,
+        }
+    }
+
+    // reserve pads the block to the given alignment, appends size zero bytes
+    // for a member and returns its offset.
+    fn reserve(&mut self, align: usize, size: usize) -> usize {
+        let offset = round_up(self.bytes.len(), align);
+        self.bytes.resize(offset + size, 0);
+        self.align = self.align.max(align);
+        offset
+    }
+
+    /// push appends `value` as the next member of the block, and returns its
+    /// offset in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    /// use fiz_math::gpu::{GpuWriter, Layout};
+    ///
+    /// let mut w = GpuWriter::new(Layout::Std430);
+    /// assert_eq!(w.push(&1.0f32), 0);
+    /// assert_eq!(w.push(&Vec2(1.0f32, 2.0)), 8);
+    /// assert_eq!(w.push(&2u32), 16);
+    /// ```
+    pub fn push<T: GpuType>(&mut self, value: &T) -> usize {
+        let offset = self.reserve(T::align(self.layout), T::size(self.layout));
+        value.write(self.layout, &mut self.bytes[offset..]);
+        offset
+    }
+
+    /// push_slice appends `values` as an array member of the block (e.g. the
+    /// variable-length array at the end of a storage block), and returns its
+    /// offset in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    /// use fiz_math::gpu::{GpuWriter, Layout};
+    ///
+    /// let points = vec![Vec3(1.0f32, 2.0, 3.0), Vec3(4.0, 5.0, 6.0)];
+    /// let mut w = GpuWriter::new(Layout::Std430);
+    /// w.push(&(points.len() as u32));
+    /// assert_eq!(w.push_slice(&points), 16);
+    /// assert_eq!(w.finish().len(), 48);
+    /// ```
+    pub fn push_slice<T: GpuType>(&mut self, values: &[T]) -> usize {
+        let align = self.layout.aggregate(T::align(self.layout));
+        let stride = round_up(T::size(self.layout), align);
+        let offset = self.reserve(align, stride * values.len());
+        for (i, x) in values.iter().enumerate() {
+            x.write(self.layout, &mut self.bytes[offset + i * stride..]);
+        }
+        offset
+    }
+
+    /// push_struct appends a struct member to the block, whose own members are
+    /// written by `f`, and returns its offset in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    /// use fiz_math::gpu::{GpuWriter, Layout};
+    ///
+    /// // struct Light { vec3 position; float intensity; };
+    /// // layout(std140) uniform Lights { float ambient; Light lights[2]; };
+    /// let mut w = GpuWriter::new(Layout::Std140);
+    /// w.push(&0.1f32);
+    /// for &(position, intensity) in &[(Vec3(0.0f32, 5.0, 0.0), 1.0f32),
+    ///                                 (Vec3(5.0, 0.0, 0.0), 0.5)] {
+    ///     w.push_struct(|light| {
+    ///         light.push(&position);
+    ///         light.push(&intensity);
+    ///     });
+    /// }
+    /// assert_eq!(w.offset(), 48);
+    /// ```
+    pub fn push_struct<F: FnOnce(&mut GpuWriter)>(&mut self, f: F) -> usize {
+        let mut member = GpuWriter::new(self.layout);
+        f(&mut member);
+        let align = self.layout.aggregate(member.align);
+        let bytes = member.finish();
+        let offset = self.reserve(align, bytes.len());
+        self.bytes[offset..].copy_from_slice(&bytes);
+        offset
+    }
+
+    /// offset returns the offset in bytes of the end of the last member.
+    pub fn offset(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// finish returns the bytes of the block, padded to a multiple of its
+    /// alignment.
+    pub fn finish(mut self) -> Vec<u8> {
+        let size = round_up(self.bytes.len(), self.layout.aggregate(self.align));
+        self.bytes.resize(size, 0);
+        self.bytes
+    }
+}
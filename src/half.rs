@@ -0,0 +1,280 @@
+use core::cmp::Ordering;
+use core::fmt;
+
+use super::{Vec2, Vec3, Vec4};
+
+/// F16 is a half precision (IEEE 754 binary16) floating point number, with a
+/// sign bit, five exponent bits and ten mantissa bits. It is a storage type for
+/// compact vertex formats and textures: arithmetic is done by converting it to
+/// f32, which represents every F16 exactly.
+///
+/// F16 is `#[repr(transparent)]`, so it has the same layout as `u16`, and a
+/// `Vec3<F16>` has the same layout as `[u16; 3]`. Vectors of f32 are converted
+/// to and from half precision with `to_f16` and `to_f32`, and to and from their
+/// packed bits with `to_bits` and `from_bits`.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{F16, Vec2, Vec3, Vec4};
+///
+/// let x = F16::from_f32(0.1);
+/// assert_eq!(x.to_bits(), 0x2e66);
+/// assert_eq!(x.to_f32(), 0.099975586);
+///
+/// // Vertex normals and texture coordinates in eight bytes.
+/// let normal = Vec3(0.0f32, 0.6, -0.8).to_f16();
+/// let uv = Vec2(0.5f32, 0.25).to_f16();
+/// assert_eq!(normal.to_bits(), [0x0000, 0x38cd, 0xba66]);
+/// assert_eq!(Vec2::from_bits(uv.to_bits()).to_f32(), Vec2(0.5, 0.25));
+///
+/// let color = Vec4(1.0f32, 0.5, 0.25, 1.0).to_f16();
+/// assert_eq!(Vec4::<f32>::from(color), Vec4(1.0, 0.5, 0.25, 1.0));
+/// ```
+#[derive(Copy, Clone, Default)]
+#[repr(transparent)]
+pub struct F16(u16);
+
+impl F16 {
+    /// ZERO is positive zero.
+    pub const ZERO: F16 = F16(0x0000);
+
+    /// ONE is one.
+    pub const ONE: F16 = F16(0x3c00);
+
+    /// MAX is the largest finite value, 65504.
+    pub const MAX: F16 = F16(0x7bff);
+
+    /// MIN_POSITIVE is the smallest positive normal value, 2⁻¹⁴.
+    pub const MIN_POSITIVE: F16 = F16(0x0400);
+
+    /// EPSILON is the difference between one and the next larger value, 2⁻¹⁰.
+    pub const EPSILON: F16 = F16(0x1400);
+
+    /// INFINITY is positive infinity.
+    pub const INFINITY: F16 = F16(0x7c00);
+
+    /// NEG_INFINITY is negative infinity.
+    pub const NEG_INFINITY: F16 = F16(0xfc00);
+
+    /// NAN is a quiet NaN.
+    pub const NAN: F16 = F16(0x7e00);
+
+    /// from_bits returns the F16 with the given bit representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::F16;
+    ///
+    /// assert_eq!(F16::from_bits(0x3c00), F16::ONE);
+    /// assert_eq!(F16::from_bits(0xc000).to_f32(), -2.0);
+    /// ```
+    pub fn from_bits(bits: u16) -> Self {
+        F16(bits)
+    }
+
+    /// to_bits returns the bit representation of this number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::F16;
+    ///
+    /// assert_eq!(F16::from_f32(-2.0).to_bits(), 0xc000);
+    /// ```
+    pub fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// from_f32 returns `x` rounded to the nearest F16 (with ties rounding to
+    /// even). Values too large for half precision become infinite, and values
+    /// too small become zero (or subnormal). NaNs stay NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::F16;
+    ///
+    /// assert_eq!(F16::from_f32(1.0), F16::ONE);
+    /// assert_eq!(F16::from_f32(65504.0), F16::MAX);
+    /// assert_eq!(F16::from_f32(1e6), F16::INFINITY);
+    /// assert_eq!(F16::from_f32(1e-9), F16::ZERO);
+    /// assert_eq!(F16::from_f32(1e-7).to_bits(), 0x0002);
+    /// assert!(F16::from_f32(std::f32::NAN).is_nan());
+    /// ```
+    pub fn from_f32(x: f32) -> Self {
+        let bits = x.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let exp = ((bits >> 23) & 0xff) as i32;
+        let man = bits & 0x7f_ffff;
+
+        if exp == 0xff {
+            // Infinity, or NaN, which keeps its top mantissa bits and is made
+            // quiet so that it cannot become infinity.
+            let nan = if man != 0 { 0x200 | (man >> 13) as u16 } else { 0 };
+            return F16(sign | 0x7c00 | nan);
+        }
+
+        // Rebias the exponent, from 127 to 15.
+        let exp = exp - 112;
+        if exp >= 0x1f {
+            return F16(sign | 0x7c00);
+        }
+        if exp <= 0 {
+            // Subnormal: shift the mantissa (with its implicit bit) to the
+            // fixed exponent of 2⁻²⁴, rounding the bits shifted out.
+            if exp < -10 {
+                return F16(sign);
+            }
+            let man = man | 0x80_0000;
+            let shift = (14 - exp) as u32;
+            let (half, rest) = (1 << (shift - 1), man & ((1 << shift) - 1));
+            let mut h = (man >> shift) as u16;
+            if rest > half || (rest == half && h & 1 == 1) {
+                h += 1;
+            }
+            return F16(sign | h);
+        }
+
+        // Normal: drop 13 mantissa bits, rounding them. A carry out of the
+        // mantissa increments the exponent, possibly up to infinity.
+        let mut h = ((exp as u32) << 10 | man >> 13) as u16;
+        let rest = man & 0x1fff;
+        if rest > 0x1000 || (rest == 0x1000 && h & 1 == 1) {
+            h += 1;
+        }
+        F16(sign | h)
+    }
+
+    /// to_f32 returns this number as a f32, which is exact.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::F16;
+    ///
+    /// assert_eq!(F16::MAX.to_f32(), 65504.0);
+    /// assert_eq!(F16::from_bits(0x0001).to_f32(), 2.0f32.powi(-24));
+    /// assert_eq!(F16::NEG_INFINITY.to_f32(), std::f32::NEG_INFINITY);
+    /// ```
+    pub fn to_f32(self) -> f32 {
+        let h = self.0 as u32;
+        let sign = (h & 0x8000) << 16;
+        let exp = (h >> 10) & 0x1f;
+        let man = h & 0x3ff;
+        match exp {
+            0 => {
+                // Zero or subnormal: the mantissa times 2⁻²⁴.
+                let x = man as f32 * (1.0 / 16777216.0);
+                if sign != 0 { -x } else { x }
+            }
+            0x1f => f32::from_bits(sign | 0x7f80_0000 | man << 13),
+            _ => f32::from_bits(sign | (exp + 112) << 23 | man << 13),
+        }
+    }
+
+    /// is_nan tells if this number is NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::F16;
+    ///
+    /// assert!(F16::NAN.is_nan());
+    /// assert!(!F16::INFINITY.is_nan());
+    /// ```
+    pub fn is_nan(self) -> bool {
+        self.0 & 0x7fff > 0x7c00
+    }
+}
+
+impl From<F16> for f32 {
+    fn from(x: F16) -> f32 {
+        x.to_f32()
+    }
+}
+
+impl From<F16> for f64 {
+    fn from(x: F16) -> f64 {
+        x.to_f32() as f64
+    }
+}
+
+// F16 compares as its value (not its bits), so that e.g. NaN is not equal to
+// itself and zero is equal to negative zero.
+impl PartialEq for F16 {
+    fn eq(&self, other: &F16) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl PartialOrd for F16 {
+    fn partial_cmp(&self, other: &F16) -> Option<Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl fmt::Debug for F16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f32(), f)
+    }
+}
+
+impl fmt::Display for F16 {
+    /// fmt formats the number as its f32 value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::F16;
+    ///
+    /// assert_eq!(format!("{}", F16::from_f32(-1.5)), "-1.5");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f32(), f)
+    }
+}
+
+// half_vec implements the conversions between a vector type of f32 and of F16,
+// and between the latter and its packed bits.
+macro_rules! half_vec {
+    ($vec:ident, $n:expr, $($i:tt),*) => {
+        impl $vec<f32> {
+            /// to_f16 returns this vector in half precision, with each component
+            /// rounded to the nearest F16 (see `F16::from_f32`).
+            pub fn to_f16(self) -> $vec<F16> {
+                $vec($(F16::from_f32(self.$i)),*)
+            }
+        }
+
+        impl $vec<F16> {
+            /// to_f32 returns this vector in single precision, which is exact.
+            pub fn to_f32(self) -> $vec<f32> {
+                $vec($(self.$i.to_f32()),*)
+            }
+
+            /// to_bits returns the bit representations of the components of
+            /// this vector, e.g. for writing to a vertex buffer.
+            pub fn to_bits(self) -> [u16; $n] {
+                [$(self.$i.to_bits()),*]
+            }
+
+            /// from_bits returns the vector with components of the given bit
+            /// representations.
+            pub fn from_bits(bits: [u16; $n]) -> Self {
+                $vec($(F16::from_bits(bits[$i])),*)
+            }
+        }
+
+        impl From<$vec<F16>> for $vec<f32> {
+            fn from(v: $vec<F16>) -> Self {
+                v.to_f32()
+            }
+        }
+    }
+}
+
+half_vec!(Vec2, 2, 0, 1);
+half_vec!(Vec3, 3, 0, 1, 2);
+half_vec!(Vec4, 4, 0, 1, 2, 3);
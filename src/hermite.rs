@@ -0,0 +1,63 @@
+use super::float::Float;
+use super::vector::Vector;
+
+/// Hermite is a cubic Hermite curve segment, which starts at `p0` with the
+/// tangent `m0` and ends at `p1` with the tangent `m1`, where the tangents are
+/// derivatives with respect to the parameter in the range 0.0 - 1.0. The points
+/// may be of any vector type (e.g. `Vec2` or `Vec3`).
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Hermite, Vec2};
+///
+/// let h = Hermite::new(Vec2(0.0, 0.0), Vec2(0.0, 2.0), Vec2(2.0, 0.0), Vec2(0.0, -2.0));
+/// assert_eq!(h.eval(0.0), Vec2(0.0, 0.0));
+/// assert_eq!(h.eval(0.5), Vec2(1.0, 0.5));
+/// assert_eq!(h.eval(1.0), Vec2(2.0, 0.0));
+/// assert_eq!(h.derivative(0.0), Vec2(0.0, 2.0));
+/// assert_eq!(h.derivative(1.0), Vec2(0.0, -2.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hermite<V> {
+    pub p0: V,
+    pub m0: V,
+    pub p1: V,
+    pub m1: V,
+}
+
+impl<T, V> Hermite<V>
+    where T: Float,
+          V: Vector<Scalar = T>
+{
+    /// new returns a new segment with the given end points and tangents.
+    pub fn new(p0: V, m0: V, p1: V, m1: V) -> Self {
+        Hermite {
+            p0,
+            m0,
+            p1,
+            m1,
+        }
+    }
+
+    /// eval returns the point on this segment at the parameter `t` in the range
+    /// 0.0 - 1.0.
+    pub fn eval(&self, t: T) -> V {
+        let (one, two, three) = (T::one(), T::from(2.0).unwrap(), T::from(3.0).unwrap());
+        let (t2, t3) = (t * t, t * t * t);
+        let h01 = three * t2 - two * t3;
+        self.p0.mul_scalar(one - h01) + self.m0.mul_scalar(t3 - two * t2 + t) +
+        self.p1.mul_scalar(h01) + self.m1.mul_scalar(t3 - t2)
+    }
+
+    /// derivative returns the derivative of this segment with respect to `t` at
+    /// the parameter `t`.
+    pub fn derivative(&self, t: T) -> V {
+        let (one, two, three) = (T::one(), T::from(2.0).unwrap(), T::from(3.0).unwrap());
+        let t2 = t * t;
+        let dh01 = (three + three) * (t - t2);
+        (self.p1 - self.p0).mul_scalar(dh01) +
+        self.m0.mul_scalar(three * t2 - (two + two) * t + one) +
+        self.m1.mul_scalar(three * t2 - two * t)
+    }
+}
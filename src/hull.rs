@@ -0,0 +1,220 @@
+use core::cmp::Ordering;
+use prelude::*;
+
+use super::float::Float;
+use super::{Vec2, Vec3};
+
+// cross2 returns the z component of the cross product of (a - o) and (b - o),
+// which is positive if o, a, b turn counter-clockwise.
+fn cross2<T: Float>(o: Vec2<T>, a: Vec2<T>, b: Vec2<T>) -> T {
+    (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+}
+
+// push_ccw pushes p onto the chain of hull starting at start, first popping
+// the points that would not make a counter-clockwise turn.
+fn push_ccw<T: Float>(hull: &mut Vec<Vec2<T>>, start: usize, p: Vec2<T>) {
+    while hull.len() >= start + 2 &&
+          cross2(hull[hull.len() - 2], hull[hull.len() - 1], p) <= T::zero() {
+        hull.pop();
+    }
+    hull.push(p);
+}
+
+/// hull2d returns the convex hull of the given points in counter-clockwise
+/// order, starting with the point with the smallest X (and then Y) coordinate,
+/// using Andrew's monotone chain algorithm. Points on the edges of the hull
+/// are not included, and fewer than three points are returned if all of the
+/// points are collinear.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{hull2d, Vec2};
+///
+/// let points = [Vec2(1.0, 1.0), Vec2(2.0, 2.0), Vec2(0.0, 2.0), Vec2(2.0, 0.0),
+///               Vec2(0.0, 0.0), Vec2(1.0, 0.0)];
+/// assert_eq!(hull2d(&points),
+///            vec![Vec2(0.0, 0.0), Vec2(2.0, 0.0), Vec2(2.0, 2.0), Vec2(0.0, 2.0)]);
+///
+/// let line = [Vec2(0.0, 0.0), Vec2(2.0, 2.0), Vec2(1.0, 1.0)];
+/// assert_eq!(hull2d(&line), vec![Vec2(0.0, 0.0), Vec2(2.0, 2.0)]);
+/// ```
+pub fn hull2d<T: Float>(points: &[Vec2<T>]) -> Vec<Vec2<T>> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| {
+        (a.0, a.1).partial_cmp(&(b.0, b.1)).unwrap_or(Ordering::Equal)
+    });
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    // Build the lower hull left to right, then the upper hull right to left.
+    // The last point of each chain is the first point of the other.
+    let mut hull = Vec::with_capacity(sorted.len() + 1);
+    for &p in sorted.iter() {
+        push_ccw(&mut hull, 0, p);
+    }
+    hull.pop();
+    let start = hull.len();
+    for &p in sorted.iter().rev() {
+        push_ccw(&mut hull, start, p);
+    }
+    hull.pop();
+    hull
+}
+
+// Face is a triangle of the hull under construction by hull3d.
+struct Face<T> {
+    v: [usize; 3],
+    normal: Vec3<T>,
+    offset: T,
+    outside: Vec<usize>,
+    alive: bool,
+}
+
+impl<T: Float> Face<T> {
+    fn new(points: &[Vec3<T>], v: [usize; 3]) -> Self {
+        let (a, b, c) = (points[v[0]], points[v[1]], points[v[2]]);
+        let n = (b - a).cross(c - a);
+        let normal = n.normalize().unwrap_or(n);
+        Face {
+            v,
+            normal,
+            offset: normal.dot(a),
+            outside: Vec::new(),
+            alive: true,
+        }
+    }
+
+    fn distance(&self, p: Vec3<T>) -> T {
+        self.normal.dot(p) - self.offset
+    }
+}
+
+// argmax returns the index of the point for which f is greatest.
+fn argmax<T: Float, F: Fn(Vec3<T>) -> T>(points: &[Vec3<T>], f: F) -> usize {
+    (0..points.len()).fold(0, |best, i| if f(points[i]) > f(points[best]) { i } else { best })
+}
+
+/// hull3d returns the convex hull of the given points as triangles, each given
+/// by the indices of its corners in `points` in counter-clockwise order as
+/// seen from outside of the hull, using the quickhull algorithm. Points on the
+/// faces of the hull are not used as corners, and no triangles are returned if
+/// all of the points are coplanar.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{hull3d, Vec3};
+///
+/// // The corners of a cube, plus points inside it and on a face.
+/// let mut points = vec![Vec3(0.5, 0.5, 0.5), Vec3(0.5, 0.5, 1.0)];
+/// for i in 0..8 {
+///     points.push(Vec3((i & 1) as f64, (i >> 1 & 1) as f64, (i >> 2) as f64));
+/// }
+/// let tris = hull3d(&points);
+/// assert_eq!(tris.len(), 12);
+/// assert!(tris.iter().all(|t| t.iter().all(|&i| i >= 2)));
+///
+/// // Each triangle faces away from the center of the cube.
+/// for t in tris.iter() {
+///     let (a, b, c) = (points[t[0]], points[t[1]], points[t[2]]);
+///     assert!((b - a).cross(c - a).dot(a - points[0]) > 0.0);
+/// }
+/// ```
+pub fn hull3d<T: Float>(points: &[Vec3<T>]) -> Vec<[usize; 3]> {
+    if points.len() < 4 {
+        return Vec::new();
+    }
+
+    // The tolerance below which points are considered to be on a face, scaled
+    // to the magnitude of the coordinates.
+    let max_abs = points.iter()
+        .fold(T::zero(), |m, p| m.max(p.0.abs() + p.1.abs() + p.2.abs()));
+    let eps = T::from(3.0).unwrap() * max_abs * T::epsilon();
+
+    // The initial tetrahedron: the extreme points along X, the point farthest
+    // from the line through them, and the point farthest from the plane
+    // through those three.
+    let i0 = argmax(points, |p| -p.0);
+    let i1 = argmax(points, |p| p.0);
+    let dir = points[i1] - points[i0];
+    let i2 = argmax(points, |p| (p - points[i0]).cross(dir).length_sq());
+    let n = dir.cross(points[i2] - points[i0]);
+    let i3 = argmax(points, |p| n.dot(p - points[i0]).abs());
+    let unit_n = match n.normalize() {
+        Some(n) => n,
+        None => return Vec::new(),
+    };
+    if unit_n.dot(points[i3] - points[i0]).abs() <= eps {
+        return Vec::new();
+    }
+
+    let mut faces = Vec::new();
+    let center = (points[i0] + points[i1] + points[i2] + points[i3])
+        .div_scalar(T::from(4.0).unwrap());
+    for &v in [[i0, i1, i2], [i0, i1, i3], [i0, i2, i3], [i1, i2, i3]].iter() {
+        let mut f = Face::new(points, v);
+        if f.distance(center) > T::zero() {
+            f = Face::new(points, [v[0], v[2], v[1]]);
+        }
+        faces.push(f);
+    }
+
+    // assign adds each of the given points to the outside set of the first of
+    // the given faces that it is above.
+    fn assign<T: Float>(points: &[Vec3<T>],
+                        faces: &mut [Face<T>],
+                        candidates: &[usize],
+                        eps: T) {
+        for &p in candidates {
+            for f in faces.iter_mut() {
+                if f.alive && f.distance(points[p]) > eps {
+                    f.outside.push(p);
+                    break;
+                }
+            }
+        }
+    }
+    let all: Vec<usize> = (0..points.len()).collect();
+    assign(points, &mut faces, &all, eps);
+
+    while let Some(current) = faces.iter().position(|f| f.alive && !f.outside.is_empty()) {
+
+        // The point farthest above the face is a corner of the hull.
+        let eye = {
+            let f = &faces[current];
+            let dist = |i: usize| f.distance(points[i]);
+            *f.outside
+                .iter()
+                .max_by(|&&a, &&b| dist(a).partial_cmp(&dist(b)).unwrap_or(Ordering::Equal))
+                .unwrap()
+        };
+
+        // Remove the faces that the eye point can see. The edges of those faces
+        // whose reverse edge is not also visible form the horizon, which is
+        // joined to the eye point by new faces.
+        let mut edges = BTreeSet::new();
+        let mut orphans = Vec::new();
+        for f in faces.iter_mut() {
+            if f.alive && f.distance(points[eye]) > eps {
+                f.alive = false;
+                orphans.append(&mut f.outside);
+                for k in 0..3 {
+                    edges.insert((f.v[k], f.v[(k + 1) % 3]));
+                }
+            }
+        }
+        let first_new = faces.len();
+        for &(a, b) in edges.iter() {
+            if !edges.contains(&(b, a)) {
+                faces.push(Face::new(points, [a, b, eye]));
+            }
+        }
+        orphans.retain(|&p| p != eye);
+        assign(points, &mut faces[first_new..], &orphans, eps);
+    }
+
+    faces.iter().filter(|f| f.alive).map(|f| f.v).collect()
+}
@@ -0,0 +1,185 @@
+use super::float::Float;
+use super::math;
+use super::{Segment2, Vec2};
+
+/// LineIntersection is the result of `intersect_lines_2d`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LineIntersection<T> {
+    /// The lines cross at a single point, which is at the parameter `t` along
+    /// the first line and `u` along the second.
+    Point {
+        /// The point at which the lines cross.
+        point: Vec2<T>,
+
+        /// The parameter of the point along the first line.
+        t: T,
+
+        /// The parameter of the point along the second line.
+        u: T,
+    },
+
+    /// The lines are parallel, and distinct.
+    Parallel,
+
+    /// The lines are the same.
+    Collinear,
+}
+
+/// SegmentIntersection is the result of `intersect_segments_2d`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SegmentIntersection<T> {
+    /// The segments meet at a single point, which is at the parameter `t`
+    /// along the first segment and `u` along the second.
+    Point {
+        /// The point at which the segments meet.
+        point: Vec2<T>,
+
+        /// The parameter of the point along the first segment.
+        t: T,
+
+        /// The parameter of the point along the second segment.
+        u: T,
+    },
+
+    /// The segments are collinear and overlap along the given segment, which
+    /// has the same direction as the first segment.
+    Overlap(Segment2<T>),
+}
+
+// cross returns the z component of the cross product of a and b.
+fn cross<T: Float>(a: Vec2<T>, b: Vec2<T>) -> T {
+    a.0 * b.1 - a.1 * b.0
+}
+
+// Relation is how two lines, each through a segment, relate to each other.
+enum Relation<T> {
+    Crossing(T, T),
+    Parallel,
+    Collinear,
+}
+
+// relate returns how the lines through the two segments relate, and if they
+// cross, the parameters of the crossing along each segment. Lines are
+// considered parallel if the sine of the angle between them is below the
+// square root of the machine epsilon.
+fn relate<T: Float>(a: &Segment2<T>, b: &Segment2<T>) -> Relation<T> {
+    let (r, s, qp) = (a.b - a.a, b.b - b.a, b.a - a.a);
+    let denom = cross(r, s);
+    let eps = math::sqrt(T::epsilon());
+    if denom.abs() > eps * r.length() * s.length() {
+        return Relation::Crossing(cross(qp, s) / denom, cross(qp, r) / denom);
+    }
+    let scale = r.length() + s.length() + qp.length();
+    if cross(qp, r).abs() <= eps * scale * r.length() {
+        Relation::Collinear
+    } else {
+        Relation::Parallel
+    }
+}
+
+/// intersect_lines_2d returns where the infinite lines through the two
+/// segments cross, with the parameters `t` and `u` of the crossing such that
+/// it is at `a.at(t)` and `b.at(u)`. The segments must not be degenerate.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{intersect_lines_2d, LineIntersection, Segment2, Vec2};
+///
+/// let a = Segment2::new(Vec2(0.0, 0.0), Vec2(2.0, 0.0));
+/// let b = Segment2::new(Vec2(3.0, 1.0), Vec2(3.0, 2.0));
+/// assert_eq!(intersect_lines_2d(&a, &b),
+///            LineIntersection::Point { point: Vec2(3.0, 0.0), t: 1.5, u: -1.0 });
+///
+/// let c = Segment2::new(Vec2(0.0, 1.0), Vec2(1.0, 1.0));
+/// let d = Segment2::new(Vec2(5.0, 0.0), Vec2(4.0, 0.0));
+/// assert_eq!(intersect_lines_2d(&a, &c), LineIntersection::Parallel);
+/// assert_eq!(intersect_lines_2d(&a, &d), LineIntersection::Collinear);
+/// ```
+pub fn intersect_lines_2d<T: Float>(a: &Segment2<T>, b: &Segment2<T>) -> LineIntersection<T> {
+    match relate(a, b) {
+        Relation::Crossing(t, u) => {
+            LineIntersection::Point {
+                point: a.at(t),
+                t,
+                u,
+            }
+        }
+        Relation::Parallel => LineIntersection::Parallel,
+        Relation::Collinear => LineIntersection::Collinear,
+    }
+}
+
+/// intersect_segments_2d returns where the two segments meet, or None if they
+/// do not. Collinear segments which overlap along more than a point give the
+/// overlapping part. Degenerate segments (with equal ends) are treated as
+/// points.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{intersect_segments_2d, Segment2, SegmentIntersection, Vec2};
+///
+/// let a = Segment2::new(Vec2(0.0, 0.0), Vec2(4.0, 0.0));
+///
+/// let b = Segment2::new(Vec2(1.0, -1.0), Vec2(1.0, 1.0));
+/// assert_eq!(intersect_segments_2d(&a, &b),
+///            Some(SegmentIntersection::Point { point: Vec2(1.0, 0.0), t: 0.25, u: 0.5 }));
+///
+/// // The lines cross, but beyond the end of b.
+/// let b = Segment2::new(Vec2(1.0, 1.0), Vec2(1.0, 3.0));
+/// assert_eq!(intersect_segments_2d(&a, &b), None);
+///
+/// // Collinear segments, overlapping, touching at an end, and disjoint.
+/// let b = Segment2::new(Vec2(6.0, 0.0), Vec2(2.0, 0.0));
+/// assert_eq!(intersect_segments_2d(&a, &b),
+///            Some(SegmentIntersection::Overlap(Segment2::new(Vec2(2.0, 0.0), Vec2(4.0, 0.0)))));
+/// let b = Segment2::new(Vec2(4.0, 0.0), Vec2(6.0, 0.0));
+/// assert_eq!(intersect_segments_2d(&a, &b),
+///            Some(SegmentIntersection::Point { point: Vec2(4.0, 0.0), t: 1.0, u: 0.0 }));
+/// let b = Segment2::new(Vec2(5.0, 0.0), Vec2(6.0, 0.0));
+/// assert_eq!(intersect_segments_2d(&a, &b), None);
+/// ```
+pub fn intersect_segments_2d<T: Float>(a: &Segment2<T>,
+                                       b: &Segment2<T>)
+                                       -> Option<SegmentIntersection<T>> {
+    let (zero, one) = (T::zero(), T::one());
+    let point = |t, u| Some(SegmentIntersection::Point { point: a.at(t), t, u });
+
+    // Degenerate segments are points, which meet the other segment if they
+    // are (nearly) on it.
+    let eps = math::sqrt(T::epsilon()) * (a.length() + b.length() + (b.a - a.a).length());
+    if a.length_sq() == zero {
+        let u = b.closest_param(a.a);
+        return if (b.at(u) - a.a).length() <= eps { point(zero, u) } else { None };
+    }
+    if b.length_sq() == zero {
+        let t = a.closest_param(b.a);
+        return if (a.at(t) - b.a).length() <= eps { point(t, zero) } else { None };
+    }
+
+    match relate(a, b) {
+        Relation::Crossing(t, u) => {
+            if t >= zero && t <= one && u >= zero && u <= one {
+                point(t, u)
+            } else {
+                None
+            }
+        }
+        Relation::Parallel => None,
+        Relation::Collinear => {
+            // The parameters of the ends of b along a, clipped to a.
+            let r = a.b - a.a;
+            let t0 = (b.a - a.a).dot(r) / r.length_sq();
+            let t1 = (b.b - a.a).dot(r) / r.length_sq();
+            let (lo, hi) = (t0.min(t1).max(zero), t0.max(t1).min(one));
+            if lo > hi {
+                None
+            } else if lo == hi {
+                point(lo, (lo - t0) / (t1 - t0))
+            } else {
+                Some(SegmentIntersection::Overlap(Segment2::new(a.at(lo), a.at(hi))))
+            }
+        }
+    }
+}
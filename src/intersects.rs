@@ -0,0 +1,221 @@
+//! Boolean overlap tests between pairs of primitives. Each test tells if the
+//! two primitives overlap, where touching counts as overlapping. Planes are
+//! treated as infinitely thin, i.e. a primitive overlaps a plane when it
+//! touches or straddles it.
+
+use super::float::Float;
+use super::{Aabb3, Obb, Plane, Sphere, Triangle3, Vec3};
+
+/// sphere_sphere tells if the two spheres overlap.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Sphere, Vec3};
+/// use fiz_math::intersects::sphere_sphere;
+///
+/// let a = Sphere::new(Vec3(0.0, 0.0, 0.0), 1.0);
+/// assert!(sphere_sphere(&a, &Sphere::new(Vec3(3.0, 0.0, 0.0), 2.0)));
+/// assert!(!sphere_sphere(&a, &Sphere::new(Vec3(3.0, 0.0, 0.0), 1.5)));
+/// ```
+pub fn sphere_sphere<T: Float>(a: &Sphere<T>, b: &Sphere<T>) -> bool {
+    a.intersects_sphere(b)
+}
+
+/// sphere_aabb tells if the sphere and the axis-aligned box overlap.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Aabb3, Sphere, Vec3};
+/// use fiz_math::intersects::sphere_aabb;
+///
+/// let b = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0));
+/// assert!(sphere_aabb(&Sphere::new(Vec3(2.0, 0.5, 0.5), 1.0), &b));
+/// assert!(!sphere_aabb(&Sphere::new(Vec3(2.0, 2.0, 2.0), 1.0), &b));
+/// ```
+pub fn sphere_aabb<T: Float>(s: &Sphere<T>, b: &Aabb3<T>) -> bool {
+    s.intersects_aabb(b)
+}
+
+/// sphere_obb tells if the sphere and the oriented box overlap.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Basis3, Obb, Sphere, Vec3};
+/// use fiz_math::intersects::sphere_obb;
+/// use fiz_math::unit::Deg;
+///
+/// let rot = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(45.0)).unwrap();
+/// let b = Obb::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0), rot);
+/// assert!(sphere_obb(&Sphere::new(Vec3(1.8, 0.0, 0.0), 0.5), &b));
+/// assert!(!sphere_obb(&Sphere::new(Vec3(1.2, 1.2, 0.0), 0.5), &b));
+/// ```
+pub fn sphere_obb<T: Float>(s: &Sphere<T>, b: &Obb<T>) -> bool {
+    let local = b.rotation.inverse_transform(s.center - b.center);
+    let h = b.half_extents;
+    sphere_aabb(&Sphere::new(local, s.radius), &Aabb3::new(-h, h))
+}
+
+/// aabb_aabb tells if the two axis-aligned boxes overlap.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Aabb3, Vec3};
+/// use fiz_math::intersects::aabb_aabb;
+///
+/// let a = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0));
+/// assert!(aabb_aabb(&a, &Aabb3::new(Vec3(1.0, 0.5, 0.5), Vec3(2.0, 2.0, 2.0))));
+/// assert!(!aabb_aabb(&a, &Aabb3::new(Vec3(1.5, 0.5, 0.5), Vec3(2.0, 2.0, 2.0))));
+/// ```
+pub fn aabb_aabb<T: Float>(a: &Aabb3<T>, b: &Aabb3<T>) -> bool {
+    !(a.max.any_less(b.min) || a.min.any_greater(b.max))
+}
+
+/// aabb_obb tells if the axis-aligned box and the oriented box overlap.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Aabb3, Basis3, Obb, Vec3};
+/// use fiz_math::intersects::aabb_obb;
+/// use fiz_math::unit::Deg;
+///
+/// let rot = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(45.0)).unwrap();
+/// let b = Obb::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0), rot);
+/// assert!(aabb_obb(&Aabb3::new(Vec3(1.3, -0.1, -0.1), Vec3(2.0, 0.1, 0.1)), &b));
+/// assert!(!aabb_obb(&Aabb3::new(Vec3(1.0, 1.0, -0.1), Vec3(2.0, 2.0, 0.1)), &b));
+/// ```
+pub fn aabb_obb<T: Float>(a: &Aabb3<T>, b: &Obb<T>) -> bool {
+    b.intersects_aabb(a)
+}
+
+/// obb_obb tells if the two oriented boxes overlap.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Basis3, Obb, Vec3};
+/// use fiz_math::intersects::obb_obb;
+///
+/// let a = Obb::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0), Basis3::identity());
+/// let b = Obb::new(Vec3(1.5, 1.5, 0.0), Vec3(1.0, 1.0, 1.0), Basis3::identity());
+/// assert!(obb_obb(&a, &b));
+/// ```
+pub fn obb_obb<T: Float>(a: &Obb<T>, b: &Obb<T>) -> bool {
+    a.intersects_obb(b)
+}
+
+/// plane_sphere tells if the sphere touches or straddles the plane. The plane
+/// normal need not be normalized.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Plane, Sphere, Vec3};
+/// use fiz_math::intersects::plane_sphere;
+///
+/// // The plane y = 2.
+/// let p = Plane::new(Vec3(0.0, 2.0, 0.0), -4.0);
+/// assert!(plane_sphere(&p, &Sphere::new(Vec3(0.0, 3.0, 0.0), 1.0)));
+/// assert!(!plane_sphere(&p, &Sphere::new(Vec3(0.0, -0.5, 0.0), 2.0)));
+/// ```
+pub fn plane_sphere<T: Float>(p: &Plane<T>, s: &Sphere<T>) -> bool {
+    p.signed_distance(s.center).abs() <= s.radius * p.normal.length()
+}
+
+/// plane_aabb tells if the axis-aligned box touches or straddles the plane.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Aabb3, Plane, Vec3};
+/// use fiz_math::intersects::plane_aabb;
+///
+/// // The plane x + y = 3.
+/// let p = Plane::new(Vec3(1.0, 1.0, 0.0), -3.0);
+/// assert!(plane_aabb(&p, &Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 2.0, 2.0))));
+/// assert!(!plane_aabb(&p, &Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0))));
+/// ```
+pub fn plane_aabb<T: Float>(p: &Plane<T>, b: &Aabb3<T>) -> bool {
+    let (n, h) = (p.normal, b.half_extents());
+    let r = h.0 * n.0.abs() + h.1 * n.1.abs() + h.2 * n.2.abs();
+    p.signed_distance(b.center()).abs() <= r
+}
+
+/// plane_obb tells if the oriented box touches or straddles the plane.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Basis3, Obb, Plane, Vec3};
+/// use fiz_math::intersects::plane_obb;
+/// use fiz_math::unit::Deg;
+///
+/// // The plane x = 1.2, which only the rotated box reaches.
+/// let p = Plane::new(Vec3(1.0, 0.0, 0.0), -1.2);
+/// let rot = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(45.0)).unwrap();
+/// let h = Vec3(1.0, 1.0, 1.0);
+/// assert!(plane_obb(&p, &Obb::new(Vec3(0.0, 0.0, 0.0), h, rot)));
+/// assert!(!plane_obb(&p, &Obb::new(Vec3(0.0, 0.0, 0.0), h, Basis3::identity())));
+/// ```
+pub fn plane_obb<T: Float>(p: &Plane<T>, b: &Obb<T>) -> bool {
+    let (n, h, rot) = (p.normal, b.half_extents, b.rotation);
+    let r = h.0 * n.dot(rot.x()).abs() + h.1 * n.dot(rot.y()).abs() +
+            h.2 * n.dot(rot.z()).abs();
+    p.signed_distance(b.center).abs() <= r
+}
+
+/// triangle_aabb tells if the triangle and the axis-aligned box overlap, using
+/// the separating axis test of Akenine-Möller.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Aabb3, Triangle3, Vec3};
+/// use fiz_math::intersects::triangle_aabb;
+///
+/// let b = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0));
+///
+/// // A large triangle cutting through the box, with all corners outside it.
+/// let t = Triangle3::new(Vec3(-5.0, -5.0, 0.5), Vec3(5.0, -5.0, 0.5), Vec3(0.0, 5.0, 0.5));
+/// assert!(triangle_aabb(&t, &b));
+///
+/// // A triangle whose bounds overlap the box, but which passes beside it.
+/// let t = Triangle3::new(Vec3(2.5, 0.0, 0.5), Vec3(0.0, 2.5, 0.5), Vec3(3.0, 3.0, 0.5));
+/// assert!(!triangle_aabb(&t, &b));
+/// ```
+pub fn triangle_aabb<T: Float>(t: &Triangle3<T>, b: &Aabb3<T>) -> bool {
+    let zero = T::zero();
+    let c = b.center();
+    let h = b.half_extents();
+    let h = [h.0, h.1, h.2];
+    let v = [t.a - c, t.b - c, t.c - c];
+    let f = [v[1] - v[0], v[2] - v[1], v[0] - v[2]];
+    let unit = [Vec3(T::one(), zero, zero), Vec3(zero, T::one(), zero), Vec3(zero, zero, T::one())];
+
+    // separated tells if the triangle and the box are separated along axis.
+    let separated = |axis: Vec3<T>| {
+        let p = [v[0].dot(axis), v[1].dot(axis), v[2].dot(axis)];
+        let r = h[0] * axis.0.abs() + h[1] * axis.1.abs() + h[2] * axis.2.abs();
+        p[0].min(p[1]).min(p[2]) > r || p[0].max(p[1]).max(p[2]) < -r
+    };
+
+    // The 9 cross products of the box and triangle edges, the 3 box face
+    // normals, and the triangle normal.
+    for u in unit.iter() {
+        for e in f.iter() {
+            if separated(u.cross(*e)) {
+                return false;
+            }
+        }
+    }
+    for u in unit.iter() {
+        if separated(*u) {
+            return false;
+        }
+    }
+    !separated(f[0].cross(f[1]))
+}
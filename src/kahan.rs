@@ -0,0 +1,175 @@
+use core::iter::FromIterator;
+use core::ops::AddAssign;
+
+use super::float::Float;
+
+/// KahanSum is an accumulator which sums a sequence of numbers with Kahan
+/// summation, keeping the rounding error of each addition in a compensation
+/// term. The error of the total does not grow with the number of terms (as it
+/// does for a naive sum), which matters for long reductions in f32, e.g. over
+/// the points of a large mesh or point cloud.
+///
+/// It uses the second order variant of Kahan-Babuška summation by Klein, which
+/// also compensates terms larger than the running sum, and the rounding errors
+/// of the compensation term itself.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::KahanSum;
+///
+/// let mut naive = 0.0f32;
+/// let mut sum = KahanSum::new();
+/// for _ in 0..1000000 {
+///     naive += 0.1;
+///     sum.add(0.1f32);
+/// }
+/// // Within one ulp, compared to a thousand for the naive sum.
+/// assert!((sum.sum() - 100000.0).abs() <= 0.0079);
+/// assert!((naive - 100000.0).abs() > 900.0);
+///
+/// let sum: KahanSum<f32> = vec![1.0e8, 1.0, -1.0e8].into_iter().collect();
+/// assert_eq!(sum.sum(), 1.0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct KahanSum<T> {
+    sum: T,
+    compensation: T,
+    compensation2: T,
+}
+
+impl<T: Float> KahanSum<T> {
+    /// new returns a new accumulator, whose sum is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::KahanSum;
+    ///
+    /// assert_eq!(KahanSum::<f64>::new().sum(), 0.0);
+    /// ```
+    pub fn new() -> Self {
+        KahanSum {
+            sum: T::zero(),
+            compensation: T::zero(),
+            compensation2: T::zero(),
+        }
+    }
+
+    /// add adds `x` to the sum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::KahanSum;
+    ///
+    /// let mut sum = KahanSum::new();
+    /// sum.add(1.0);
+    /// sum.add(1.0e100);
+    /// sum.add(1.0);
+    /// sum.add(-1.0e100);
+    /// assert_eq!(sum.sum(), 2.0);
+    /// ```
+    pub fn add(&mut self, x: T) {
+        let (sum, err) = fast_two_sum(self.sum, x);
+        let (compensation, err2) = fast_two_sum(self.compensation, err);
+        self.sum = sum;
+        self.compensation = compensation;
+        self.compensation2 = self.compensation2 + err2;
+    }
+
+    /// sum returns the compensated sum of the numbers added so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::KahanSum;
+    ///
+    /// let mut sum = KahanSum::new();
+    /// sum.extend(vec![0.5f32, 0.25, 0.25]);
+    /// assert_eq!(sum.sum(), 1.0);
+    /// ```
+    pub fn sum(&self) -> T {
+        self.sum + (self.compensation + self.compensation2)
+    }
+}
+
+// fast_two_sum returns the sum of a and b, along with the exact rounding error
+// of that sum, which is the low order bits lost from the smaller operand.
+fn fast_two_sum<T: Float>(a: T, b: T) -> (T, T) {
+    let s = a + b;
+    if a.abs() >= b.abs() {
+        (s, (a - s) + b)
+    } else {
+        (s, (b - s) + a)
+    }
+}
+
+impl<T: Float> Default for KahanSum<T> {
+    fn default() -> Self {
+        KahanSum::new()
+    }
+}
+
+impl<T: Float> AddAssign<T> for KahanSum<T> {
+    fn add_assign(&mut self, x: T) {
+        self.add(x);
+    }
+}
+
+impl<T: Float> Extend<T> for KahanSum<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for x in iter {
+            self.add(x);
+        }
+    }
+}
+
+impl<T: Float> FromIterator<T> for KahanSum<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut sum = KahanSum::new();
+        sum.extend(iter);
+        sum
+    }
+}
+
+/// sum_compensated returns the sum of the numbers, computed with Kahan
+/// summation (see `KahanSum`).
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::sum_compensated;
+///
+/// let xs = vec![0.1f32; 1000000];
+/// assert!((sum_compensated(&xs) - 100000.0).abs() <= 0.0079);
+/// assert_eq!(sum_compensated::<f64>(&[]), 0.0);
+/// ```
+pub fn sum_compensated<T: Float>(xs: &[T]) -> T {
+    xs.iter().cloned().collect::<KahanSum<T>>().sum()
+}
+
+/// dot_compensated returns the dot product of the two slices, with the
+/// products summed with Kahan summation (see `KahanSum`). The rounding error
+/// of each product remains, so unlike `reference::dot`, it does not avoid
+/// catastrophic cancellation, but its error does not grow with the length of
+/// the slices.
+///
+/// # Panics
+///
+/// The slices must be of the same length.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::dot_compensated;
+///
+/// let a = vec![0.1f32; 1000000];
+/// let b = vec![2.0f32; 1000000];
+/// assert!((dot_compensated(&a, &b) - 200000.0).abs() <= 0.016);
+/// assert_eq!(dot_compensated(&[1.0, 2.0], &[3.0, 4.0]), 11.0);
+/// ```
+pub fn dot_compensated<T: Float>(a: &[T], b: &[T]) -> T {
+    assert!(a.len() == b.len(), "dot_compensated: slices of different lengths");
+    a.iter().zip(b).map(|(&x, &y)| x * y).collect::<KahanSum<T>>().sum()
+}
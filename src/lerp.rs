@@ -0,0 +1,140 @@
+use core::f64::consts::PI;
+use super::float::Float;
+use super::math;
+use super::{Vec2, Vec3, Vec4};
+
+/// Lerp is implemented by types that can be linearly interpolated, allowing
+/// generic code (e.g. `resample`) to operate on scalars, vectors, units and
+/// rotations alike.
+pub trait Lerp<T> {
+    /// lerp returns the linear interpolation between `self` and `other` by the
+    /// amount `t`, such that a `t` of zero yields `self` and a `t` of one
+    /// yields `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Lerp, Vec2};
+    /// use fiz_math::unit::M;
+    ///
+    /// assert_eq!(Lerp::lerp(2.0, 4.0, 0.5), 3.0);
+    /// assert_eq!(Lerp::lerp(Vec2(0.0, 2.0), Vec2(4.0, 4.0), 0.5), Vec2(2.0, 3.0));
+    /// assert_eq!(Lerp::lerp(M(2.0), M(4.0), 0.5), M(3.0));
+    /// ```
+    fn lerp(self, other: Self, t: T) -> Self;
+}
+
+/// Slerp is implemented by rotations and directions, which are interpolated
+/// along the shortest arc between them at a constant angular velocity.
+pub trait Slerp<T> {
+    /// slerp returns the spherical linear interpolation between `self` and
+    /// `other` by the amount `t`, such that a `t` of zero yields `self` and a
+    /// `t` of one yields `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Slerp, Vec2};
+    ///
+    /// let h = 0.5f64.sqrt();
+    /// let v = Vec2(1.0, 0.0).slerp(Vec2(0.0, 1.0), 0.5);
+    /// assert!(v.almost_equal(Vec2(h, h), 1e-12));
+    /// ```
+    fn slerp(self, other: Self, t: T) -> Self;
+}
+
+impl<T: Float> Lerp<T> for T {
+    fn lerp(self, other: T, t: T) -> T {
+        Float::lerp(self, other, t)
+    }
+}
+
+// Vectors are interpolated component-wise, so vectors of any interpolable
+// component (such as units, including distances which are not `Float`) may be
+// interpolated.
+impl<T: Copy, V: Lerp<T>> Lerp<T> for Vec2<V> {
+    fn lerp(self, other: Self, t: T) -> Self {
+        Vec2(self.0.lerp(other.0, t), self.1.lerp(other.1, t))
+    }
+}
+
+impl<T: Copy, V: Lerp<T>> Lerp<T> for Vec3<V> {
+    fn lerp(self, other: Self, t: T) -> Self {
+        Vec3(self.0.lerp(other.0, t), self.1.lerp(other.1, t), self.2.lerp(other.2, t))
+    }
+}
+
+impl<T: Copy, V: Lerp<T>> Lerp<T> for Vec4<V> {
+    fn lerp(self, other: Self, t: T) -> Self {
+        Vec4(self.0.lerp(other.0, t),
+             self.1.lerp(other.1, t),
+             self.2.lerp(other.2, t),
+             self.3.lerp(other.3, t))
+    }
+}
+
+// slerp_weights returns the weights of the two unit vectors (whose dot product
+// is given) for spherical linear interpolation by the amount t, or None if the
+// vectors are (nearly) parallel or opposite and the weights are unstable.
+fn slerp_weights<T: Float>(cos: T, t: T) -> Option<(T, T)> {
+    let theta = math::acos(cos.max(-T::one()).min(T::one()));
+    let sin = math::sin(theta);
+    if sin < T::from(1.0e-6).unwrap() {
+        return None;
+    }
+    Some((math::sin((T::one() - t) * theta) / sin, math::sin(t * theta) / sin))
+}
+
+impl<T: Float> Slerp<T> for Vec2<T> {
+    /// slerp interpolates between two unit vectors, returning a unit vector.
+    fn slerp(self, other: Self, t: T) -> Self {
+        let cos = self.dot(other);
+        match slerp_weights(cos, t) {
+            Some((a, b)) => self.mul_scalar(a) + other.mul_scalar(b),
+            None if cos > T::zero() => Lerp::lerp(self, other, t).normalize().unwrap_or(self),
+            None => {
+                // Opposite vectors: rotate counter-clockwise.
+                let (s, c) = math::sin_cos(t * T::from(PI).unwrap());
+                self.mul_scalar(c) + Vec2(-self.1, self.0).mul_scalar(s)
+            }
+        }
+    }
+}
+
+impl<T: Float> Slerp<T> for Vec3<T> {
+    /// slerp interpolates between two unit vectors, returning a unit vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Slerp, Vec3, Float};
+    ///
+    /// let a = Vec3(1.0, 0.0, 0.0);
+    /// let b = Vec3(0.0, 0.0, 1.0);
+    /// let v = a.slerp(b, 1.0 / 3.0);
+    /// assert!(v.almost_equal(Vec3(0.75f64.sqrt(), 0.0, 0.5), 1e-12));
+    ///
+    /// // Opposite vectors are interpolated about an arbitrary perpendicular axis.
+    /// let v = a.slerp(-a, 0.5);
+    /// assert!(v.length().equal(1.0));
+    /// assert!(v.dot(a).abs() < 1e-12);
+    /// ```
+    fn slerp(self, other: Self, t: T) -> Self {
+        let cos = self.dot(other);
+        match slerp_weights(cos, t) {
+            Some((a, b)) => self.mul_scalar(a) + other.mul_scalar(b),
+            None if cos > T::zero() => Lerp::lerp(self, other, t).normalize().unwrap_or(self),
+            None => {
+                let (zero, one) = (T::zero(), T::one());
+                let axis = if self.0.abs() < T::from(0.9).unwrap() {
+                    Vec3(one, zero, zero)
+                } else {
+                    Vec3(zero, one, zero)
+                };
+                let perp = self.cross(axis).normalize().unwrap_or(axis);
+                let (s, c) = math::sin_cos(t * T::from(PI).unwrap());
+                self.mul_scalar(c) + perp.mul_scalar(s)
+            }
+        }
+    }
+}
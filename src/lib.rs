@@ -1,22 +1,179 @@
-extern crate num;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("fiz-math requires either the std or the libm feature for float functions");
+
+// no_std crates get core injected at the root; std ones need it declared.
+#[cfg(feature = "std")]
+extern crate core;
+
+#[cfg(not(feature = "std"))]
+#[macro_use]
+extern crate alloc;
+
+extern crate num_traits as num;
 
 // Must re-export num for the unit! macro.
 #[doc(hidden)]
-pub use num as num_export;
+pub extern crate num_traits as num_export;
+
+// Must re-export core for the unit! macro, so that it works in no_std crates.
+#[doc(hidden)]
+pub extern crate core as core_export;
+
+#[cfg(feature = "serde")]
+extern crate serde;
+
+// Must re-export serde for the unit! macro.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub extern crate serde as serde_export;
+
+#[cfg(feature = "rand")]
+extern crate rand;
+
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+
+#[cfg(feature = "deterministic")]
+extern crate libm;
+
+#[cfg(feature = "mint")]
+extern crate mint;
+
+#[cfg(feature = "glam")]
+extern crate glam;
+
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
 
 #[macro_use]mod swizzle;
 #[macro_use]pub mod unit_macro;
 
+mod prelude;
+
+// Must be public for the unit! macro.
+#[doc(hidden)]
+pub mod math;
+
 mod vec2;
 mod vec3;
 mod vec4;
+mod vec3a;
+mod color;
+mod half;
+mod fixed;
 mod float;
 mod clamp;
+mod lerp;
+mod axis;
+mod cube;
+mod resample;
+mod basis3;
+mod ray3;
+mod plane;
+mod aabb3;
+mod rect;
+mod obb;
+mod frustum;
+mod segment;
+mod sphere;
+mod capsule;
+mod cylinder;
+mod triangle;
+mod closest;
+mod hull;
+mod polygon;
+mod intersect2d;
+mod bvh;
+mod morton;
+mod cast;
+#[cfg(feature = "std")]
+mod spatial_hash;
+mod vector;
+mod bezier;
+mod catmull_rom;
+mod hermite;
+mod track;
+mod bspline;
+mod curve;
+mod damp;
+mod kahan;
+mod wide;
 pub mod unit;
+pub mod shading;
+pub mod sampling;
+pub mod media;
+pub mod mapping;
+pub mod intersects;
+pub mod gjk;
+pub mod sdf;
+pub mod ease;
+pub mod sequences;
+pub mod noise;
+pub mod gpu;
+#[cfg(feature = "reference")]
+pub mod reference;
+#[cfg(feature = "batch")]
+pub mod batch;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl;
+#[cfg(feature = "mint")]
+mod mint_impl;
+#[cfg(feature = "glam")]
+mod glam_impl;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
+#[cfg(feature = "rand")]
+pub mod random;
 
 pub use num::{Zero, One, Num};
 pub use self::vec2::Vec2;
 pub use self::vec3::Vec3;
 pub use self::vec4::Vec4;
+pub use self::vec3a::Vec3A;
+pub use self::color::{lerp_oklab, Color};
+pub use self::half::F16;
+pub use self::fixed::{Fixed, FixedInt, I16F16, I32F32, ParseFixedError};
 pub use self::float::{EPSILON, Float};
 pub use self::clamp::Clamp;
+pub use self::lerp::{Lerp, Slerp};
+pub use self::axis::{Axis, Axis2};
+pub use self::cube::{CubeFace, cube_face_and_uv, cube_face_uv_to_dir};
+pub use self::resample::{resample, Interpolation};
+pub use self::basis3::{orthonormal_basis, Basis3};
+pub use self::ray3::{Hit, Ray3};
+pub use self::plane::Plane;
+pub use self::aabb3::Aabb3;
+pub use self::rect::Rect;
+pub use self::obb::Obb;
+pub use self::frustum::Frustum;
+pub use self::segment::{Segment2, Segment3};
+pub use self::sphere::Sphere;
+pub use self::capsule::Capsule;
+pub use self::cylinder::Cylinder;
+pub use self::triangle::Triangle3;
+pub use self::closest::{closest_point_on_aabb, closest_point_on_obb, closest_point_on_plane,
+                        closest_point_on_segment, closest_point_on_sphere,
+                        closest_point_on_triangle};
+pub use self::hull::{hull2d, hull3d};
+pub use self::polygon::{Polygon2, Winding};
+pub use self::intersect2d::{intersect_lines_2d, intersect_segments_2d, LineIntersection,
+                            SegmentIntersection};
+pub use self::bvh::Bvh;
+pub use self::morton::{morton2, morton2_decode, morton3, morton3_decode};
+pub use self::cast::{cast_slice, cast_slice_mut, try_cast_slice, try_cast_slice_mut, Components};
+#[cfg(feature = "std")]
+pub use self::spatial_hash::SpatialHash;
+pub use self::vector::Vector;
+pub use self::bezier::{CubicBezier, QuadraticBezier};
+pub use self::catmull_rom::CatmullRom;
+pub use self::hermite::Hermite;
+pub use self::track::{Key, Track};
+pub use self::bspline::BSpline;
+pub use self::curve::{ArcLength, Curve, Flatten};
+pub use self::damp::{damp, smooth_damp};
+pub use self::kahan::{dot_compensated, sum_compensated, KahanSum};
+pub use self::wide::{Vec3x4, Vec3x8};
@@ -0,0 +1,305 @@
+use core::f64::consts::PI;
+use super::float::Float;
+use super::math;
+use super::{Vec2, Vec3};
+
+// with_sign returns the magnitude of x with the sign of s.
+fn with_sign<T: Float>(x: T, s: T) -> T {
+    if s < T::zero() { -x.abs() } else { x.abs() }
+}
+
+/// concentric_square_to_disk maps the point `u` in the unit square [0, 1]² to
+/// the unit disk, using the concentric mapping of Shirley and Chiu (A Low
+/// Distortion Map Between Disk and Square). The mapping preserves relative
+/// areas, and maps concentric squares to concentric circles.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Vec2, Float};
+/// use fiz_math::mapping::concentric_square_to_disk;
+///
+/// assert_eq!(concentric_square_to_disk(Vec2(0.5, 0.5)), Vec2(0.0, 0.0));
+/// assert_eq!(concentric_square_to_disk(Vec2(1.0, 0.5)), Vec2(1.0, 0.0));
+/// assert!(concentric_square_to_disk(Vec2(1.0, 1.0)).length().equal(1.0));
+/// ```
+pub fn concentric_square_to_disk<T: Float>(u: Vec2<T>) -> Vec2<T> {
+    let two = T::from(2).unwrap();
+    let (x, y) = (u.0 * two - T::one(), u.1 * two - T::one());
+    if x == T::zero() && y == T::zero() {
+        return Vec2(T::zero(), T::zero());
+    }
+    let quarter_pi = T::from(PI / 4.0).unwrap();
+    let (r, theta) = if x.abs() > y.abs() {
+        (x, quarter_pi * (y / x))
+    } else {
+        (y, quarter_pi * two - quarter_pi * (x / y))
+    };
+    let (sin, cos) = math::sin_cos(theta);
+    Vec2(r * cos, r * sin)
+}
+
+/// concentric_disk_to_square is the inverse of `concentric_square_to_disk`: it
+/// maps the point `p` in the unit disk to the unit square [0, 1]².
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec2;
+/// use fiz_math::mapping::{concentric_square_to_disk, concentric_disk_to_square};
+///
+/// let u = Vec2(0.2, 0.9);
+/// let p = concentric_square_to_disk(u);
+/// assert!(concentric_disk_to_square(p).almost_equal(u, 1e-12));
+/// ```
+pub fn concentric_disk_to_square<T: Float>(p: Vec2<T>) -> Vec2<T> {
+    let quarter_pi = T::from(PI / 4.0).unwrap();
+    let r = math::sqrt(p.0 * p.0 + p.1 * p.1);
+    let mut phi = math::atan2(p.1, p.0);
+    if phi < -quarter_pi {
+        phi = phi + T::from(2.0 * PI).unwrap();
+    }
+
+    // Invert the mapping of whichever quadrant of the square the point came
+    // from.
+    let (a, b) = if phi < quarter_pi {
+        (r, phi * r / quarter_pi)
+    } else if phi < T::from(3.0 * PI / 4.0).unwrap() {
+        (-(phi - T::from(PI / 2.0).unwrap()) * r / quarter_pi, r)
+    } else if phi < T::from(5.0 * PI / 4.0).unwrap() {
+        (-r, -(phi - T::from(PI).unwrap()) * r / quarter_pi)
+    } else {
+        ((phi - T::from(3.0 * PI / 2.0).unwrap()) * r / quarter_pi, -r)
+    };
+    let half = T::from(0.5).unwrap();
+    Vec2((a + T::one()) * half, (b + T::one()) * half)
+}
+
+/// equal_area_square_to_sphere maps the point `u` in the unit square [0, 1]²
+/// to a unit direction, using the equal-area octahedral mapping of Clarberg
+/// (Fast Equal-Area Mapping of the (Hemi)Sphere using SIMD). The center of the
+/// square maps to +Z and its corners map to -Z.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Vec2, Vec3};
+/// use fiz_math::mapping::equal_area_square_to_sphere;
+///
+/// assert_eq!(equal_area_square_to_sphere(Vec2(0.5, 0.5)), Vec3(0.0, 0.0, 1.0));
+/// assert_eq!(equal_area_square_to_sphere(Vec2(0.0, 0.0)), Vec3(0.0, 0.0, -1.0));
+/// let d = equal_area_square_to_sphere(Vec2(1.0, 0.5));
+/// assert!(d.almost_equal(Vec3(1.0, 0.0, 0.0), 1e-12));
+/// ```
+pub fn equal_area_square_to_sphere<T: Float>(u: Vec2<T>) -> Vec3<T> {
+    let (one, two) = (T::one(), T::from(2).unwrap());
+    let (x, y) = (u.0 * two - one, u.1 * two - one);
+    let (ax, ay) = (x.abs(), y.abs());
+
+    // The signed distance from the diagonal edges of the inner diamond
+    // determines the hemisphere and the distance r from the pole.
+    let signed_dist = one - (ax + ay);
+    let r = one - signed_dist.abs();
+    let phi = if r == T::zero() {
+        T::from(PI / 4.0).unwrap()
+    } else {
+        ((ay - ax) / r + one) * T::from(PI / 4.0).unwrap()
+    };
+    let z = with_sign(one - r * r, signed_dist);
+    let (sin, cos) = math::sin_cos(phi);
+    let s = r * math::sqrt((two - r * r).max(T::zero()));
+    Vec3(with_sign(cos, x) * s, with_sign(sin, y) * s, z)
+}
+
+/// equal_area_sphere_to_square is the inverse of
+/// `equal_area_square_to_sphere`: it maps the unit direction `d` to the unit
+/// square [0, 1]².
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Vec2, Vec3};
+/// use fiz_math::mapping::{equal_area_square_to_sphere, equal_area_sphere_to_square};
+///
+/// let d = Vec3(0.48, -0.6, -0.64);
+/// let u = equal_area_sphere_to_square(d);
+/// assert!(equal_area_square_to_sphere(u).almost_equal(d, 1e-12));
+/// assert_eq!(equal_area_sphere_to_square(Vec3(0.0, 0.0, 1.0)), Vec2(0.5, 0.5));
+/// ```
+pub fn equal_area_sphere_to_square<T: Float>(d: Vec3<T>) -> Vec2<T> {
+    let one = T::one();
+    let (x, y, z) = (d.0.abs(), d.1.abs(), d.2.abs());
+    let r = math::sqrt((one - z).max(T::zero()));
+
+    // phi is the angle in the first quadrant, scaled to [0, 1].
+    let (a, b) = if x > y { (x, y) } else { (y, x) };
+    let ratio = if a == T::zero() { T::zero() } else { b / a };
+    let mut phi = math::atan(ratio) * T::from(2.0 / PI).unwrap();
+    if x < y {
+        phi = one - phi;
+    }
+
+    let mut v = phi * r;
+    let mut u = r - v;
+    if d.2 < T::zero() {
+        let (nu, nv) = (one - v, one - u);
+        u = nu;
+        v = nv;
+    }
+    let half = T::from(0.5).unwrap();
+    Vec2((with_sign(u, d.0) + one) * half, (with_sign(v, d.1) + one) * half)
+}
+
+/// cylindrical_square_to_sphere maps the point `u` in the unit square [0, 1]²
+/// to a unit direction, using Lambert's cylindrical equal-area projection. The
+/// X coordinate maps to the azimuth about the Z axis, and the Y coordinate
+/// maps linearly to Z.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Vec2, Vec3};
+/// use fiz_math::mapping::cylindrical_square_to_sphere;
+///
+/// assert_eq!(cylindrical_square_to_sphere(Vec2(0.0, 0.5)), Vec3(1.0, 0.0, 0.0));
+/// let d = cylindrical_square_to_sphere(Vec2(0.25, 1.0));
+/// assert!(d.almost_equal(Vec3(0.0, 0.0, 1.0), 1e-12));
+/// ```
+pub fn cylindrical_square_to_sphere<T: Float>(u: Vec2<T>) -> Vec3<T> {
+    let z = u.1 * T::from(2).unwrap() - T::one();
+    let r = math::sqrt((T::one() - z * z).max(T::zero()));
+    let (sin, cos) = math::sin_cos(u.0 * T::from(2.0 * PI).unwrap());
+    Vec3(r * cos, r * sin, z)
+}
+
+/// cylindrical_sphere_to_square is the inverse of
+/// `cylindrical_square_to_sphere`: it maps the unit direction `d` to the unit
+/// square [0, 1)×[0, 1].
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Vec2, Vec3};
+/// use fiz_math::mapping::{cylindrical_square_to_sphere, cylindrical_sphere_to_square};
+///
+/// let d = Vec3(0.48, -0.6, -0.64);
+/// let u = cylindrical_sphere_to_square(d);
+/// assert!(cylindrical_square_to_sphere(u).almost_equal(d, 1e-12));
+/// ```
+pub fn cylindrical_sphere_to_square<T: Float>(d: Vec3<T>) -> Vec2<T> {
+    let two_pi = T::from(2.0 * PI).unwrap();
+    let mut phi = math::atan2(d.1, d.0);
+    if phi < T::zero() {
+        phi = phi + two_pi;
+    }
+    let z = d.2.max(-T::one()).min(T::one());
+    Vec2(phi / two_pi, (z + T::one()) * T::from(0.5).unwrap())
+}
+
+/// encode_octahedral encodes the unit vector `n` as a point in the square
+/// [-1, 1]², by projecting it onto the octahedron |x| + |y| + |z| = 1 and
+/// unfolding the lower half of the octahedron over the corners of the square
+/// (see Cigolle et al., A Survey of Efficient Representations for Independent
+/// Unit Vectors). It is the usual compact encoding of normals in G-buffers and
+/// compressed meshes, whose error is nearly uniform over the sphere. The zero
+/// vector, which has no direction, is encoded as the center of the square.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Vec2, Vec3};
+/// use fiz_math::mapping::encode_octahedral;
+///
+/// assert_eq!(encode_octahedral(Vec3(0.0, 0.0, 1.0)), Vec2(0.0, 0.0));
+/// assert_eq!(encode_octahedral(Vec3(1.0, 0.0, 0.0)), Vec2(1.0, 0.0));
+/// assert_eq!(encode_octahedral(Vec3(0.0, 0.0, -1.0)), Vec2(1.0, 1.0));
+/// assert_eq!(encode_octahedral(Vec3(0.0, 0.0, 0.0)), Vec2(0.0, 0.0));
+/// ```
+pub fn encode_octahedral<T: Float>(n: Vec3<T>) -> Vec2<T> {
+    let l1 = n.0.abs() + n.1.abs() + n.2.abs();
+    if l1 == T::zero() {
+        return Vec2(T::zero(), T::zero());
+    }
+    let (x, y) = (n.0 / l1, n.1 / l1);
+    if n.2 < T::zero() {
+        Vec2(with_sign(T::one() - y.abs(), x), with_sign(T::one() - x.abs(), y))
+    } else {
+        Vec2(x, y)
+    }
+}
+
+/// decode_octahedral is the inverse of `encode_octahedral`: it decodes the
+/// point `e` in the square [-1, 1]² to a unit vector.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::mapping::{decode_octahedral, encode_octahedral};
+///
+/// let n = Vec3(0.48, -0.6, -0.64);
+/// assert!(decode_octahedral(encode_octahedral(n)).almost_equal(n, 1e-12));
+/// ```
+pub fn decode_octahedral<T: Float>(e: Vec2<T>) -> Vec3<T> {
+    let z = T::one() - e.0.abs() - e.1.abs();
+    let v = if z < T::zero() {
+        Vec3(with_sign(T::one() - e.1.abs(), e.0), with_sign(T::one() - e.0.abs(), e.1), z)
+    } else {
+        Vec3(e.0, e.1, z)
+    };
+    // The L1 norm of v is one, so its length is at least 1 / sqrt(3).
+    v.div_scalar(v.length())
+}
+
+// to_snorm16 quantizes x, clamped to the range -1.0 - 1.0, to the nearest
+// 16-bit signed normalized integer, returned in the low 16 bits.
+fn to_snorm16<T: Float>(x: T) -> u32 {
+    let x = x.max(-T::one()).min(T::one()) * T::from(32767.0).unwrap();
+    x.round().to_i16().unwrap() as u16 as u32
+}
+
+// from_snorm16 returns the value of the 16-bit signed normalized integer in the
+// low 16 bits of x.
+fn from_snorm16<T: Float>(x: u32) -> T {
+    let x = T::from(x as u16 as i16).unwrap() / T::from(32767.0).unwrap();
+    x.max(-T::one())
+}
+
+/// encode_octahedral_snorm16 encodes the unit vector `n` like
+/// `encode_octahedral`, and packs the result into 32 bits as two 16-bit signed
+/// normalized integers (X in the low half), i.e. the snorm16x2 vertex and
+/// texture format. The angular error is at most about 0.004 degrees.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::mapping::{decode_octahedral_snorm16, encode_octahedral_snorm16};
+///
+/// assert_eq!(encode_octahedral_snorm16(Vec3(1.0, 0.0, 0.0)), 0x0000_7fff);
+/// assert_eq!(encode_octahedral_snorm16(Vec3(0.0, -1.0, 0.0)), 0x8001_0000);
+///
+/// let n = Vec3(0.48f32, -0.6, -0.64);
+/// let d = decode_octahedral_snorm16::<f32>(encode_octahedral_snorm16(n));
+/// assert!(d.dot(n) > 0.99999);
+/// ```
+pub fn encode_octahedral_snorm16<T: Float>(n: Vec3<T>) -> u32 {
+    let e = encode_octahedral(n);
+    to_snorm16(e.1) << 16 | to_snorm16(e.0)
+}
+
+/// decode_octahedral_snorm16 is the inverse of `encode_octahedral_snorm16`:
+/// it decodes the unit vector packed into the 32 bits `e`.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::mapping::decode_octahedral_snorm16;
+///
+/// assert_eq!(decode_octahedral_snorm16::<f64>(0x0000_7fff), Vec3(1.0, 0.0, 0.0));
+/// assert_eq!(decode_octahedral_snorm16::<f64>(0x0000_0000), Vec3(0.0, 0.0, 1.0));
+/// ```
+pub fn decode_octahedral_snorm16<T: Float>(e: u32) -> Vec3<T> {
+    decode_octahedral(Vec2(from_snorm16(e), from_snorm16(e >> 16)))
+}
@@ -0,0 +1,154 @@
+// math provides the floating point functions used by the crate (and the unit!
+// macro, which is why it is public but hidden). They call the methods of
+// num::Float, or with the deterministic feature, the software implementations
+// of libm, which give bit identical results on every platform, unlike those of
+// the standard library (which vary with the OS, compiler and instruction set).
+//
+// Neither path fuses multiplications and additions (Rust never contracts them),
+// except in mul_add, whose fused result is correctly rounded on every platform.
+
+use num::Float;
+#[cfg(feature = "deterministic")]
+use core::mem;
+
+// is_f32 tells if T is single precision, i.e. a f32 or a unit of one.
+#[cfg(feature = "deterministic")]
+#[inline]
+fn is_f32<T>() -> bool {
+    mem::size_of::<T>() == mem::size_of::<f32>()
+}
+
+// libm1 evaluates the single or double precision libm function on x.
+#[cfg(feature = "deterministic")]
+#[inline]
+fn libm1<T: Float>(x: T, f32: fn(f32) -> f32, f64: fn(f64) -> f64) -> T {
+    if is_f32::<T>() {
+        T::from(f32(x.to_f32().unwrap())).unwrap()
+    } else {
+        T::from(f64(x.to_f64().unwrap())).unwrap()
+    }
+}
+
+// libm2 evaluates the single or double precision libm function on x and y.
+#[cfg(feature = "deterministic")]
+#[inline]
+fn libm2<T: Float>(x: T, y: T, f32: fn(f32, f32) -> f32, f64: fn(f64, f64) -> f64) -> T {
+    if is_f32::<T>() {
+        T::from(f32(x.to_f32().unwrap(), y.to_f32().unwrap())).unwrap()
+    } else {
+        T::from(f64(x.to_f64().unwrap(), y.to_f64().unwrap())).unwrap()
+    }
+}
+
+macro_rules! unary {
+    ($($name:ident => $f32:ident, $f64:ident;)*) => {$(
+        #[cfg(not(feature = "deterministic"))]
+        #[inline]
+        pub fn $name<T: Float>(x: T) -> T {
+            x.$name()
+        }
+
+        #[cfg(feature = "deterministic")]
+        #[inline]
+        pub fn $name<T: Float>(x: T) -> T {
+            libm1(x, ::libm::$f32, ::libm::$f64)
+        }
+    )*}
+}
+
+macro_rules! binary {
+    ($($name:ident => $f32:ident, $f64:ident;)*) => {$(
+        #[cfg(not(feature = "deterministic"))]
+        #[inline]
+        pub fn $name<T: Float>(x: T, y: T) -> T {
+            x.$name(y)
+        }
+
+        #[cfg(feature = "deterministic")]
+        #[inline]
+        pub fn $name<T: Float>(x: T, y: T) -> T {
+            libm2(x, y, ::libm::$f32, ::libm::$f64)
+        }
+    )*}
+}
+
+unary! {
+    sqrt => sqrtf, sqrt;
+    cbrt => cbrtf, cbrt;
+    exp => expf, exp;
+    exp2 => exp2f, exp2;
+    exp_m1 => expm1f, expm1;
+    ln => logf, log;
+    ln_1p => log1pf, log1p;
+    log2 => log2f, log2;
+    log10 => log10f, log10;
+    sin => sinf, sin;
+    cos => cosf, cos;
+    tan => tanf, tan;
+    asin => asinf, asin;
+    acos => acosf, acos;
+    atan => atanf, atan;
+    sinh => sinhf, sinh;
+    cosh => coshf, cosh;
+    tanh => tanhf, tanh;
+}
+
+binary! {
+    powf => powf, pow;
+    hypot => hypotf, hypot;
+    atan2 => atan2f, atan2;
+}
+
+/// log returns the logarithm of `x` in the given base.
+#[inline]
+pub fn log<T: Float>(x: T, base: T) -> T {
+    ln(x) / ln(base)
+}
+
+/// powi returns `x` raised to the integer power `n`.
+#[cfg(not(feature = "deterministic"))]
+#[inline]
+pub fn powi<T: Float>(x: T, n: i32) -> T {
+    x.powi(n)
+}
+
+/// powi returns `x` raised to the integer power `n`.
+#[cfg(feature = "deterministic")]
+#[inline]
+pub fn powi<T: Float>(x: T, n: i32) -> T {
+    powf(x, T::from(n).unwrap())
+}
+
+/// sin_cos returns the sine and cosine of `x`.
+#[cfg(not(feature = "deterministic"))]
+#[inline]
+pub fn sin_cos<T: Float>(x: T) -> (T, T) {
+    x.sin_cos()
+}
+
+/// sin_cos returns the sine and cosine of `x`.
+#[cfg(feature = "deterministic")]
+#[inline]
+pub fn sin_cos<T: Float>(x: T) -> (T, T) {
+    (sin(x), cos(x))
+}
+
+/// mul_add returns `x * a + b`, computed with a single rounding.
+#[cfg(not(feature = "deterministic"))]
+#[inline]
+pub fn mul_add<T: Float>(x: T, a: T, b: T) -> T {
+    x.mul_add(a, b)
+}
+
+/// mul_add returns `x * a + b`, computed with a single rounding.
+#[cfg(feature = "deterministic")]
+#[inline]
+pub fn mul_add<T: Float>(x: T, a: T, b: T) -> T {
+    if is_f32::<T>() {
+        let (x, a, b) = (x.to_f32().unwrap(), a.to_f32().unwrap(), b.to_f32().unwrap());
+        T::from(::libm::fmaf(x, a, b)).unwrap()
+    } else {
+        let (x, a, b) = (x.to_f64().unwrap(), a.to_f64().unwrap(), b.to_f64().unwrap());
+        T::from(::libm::fma(x, a, b)).unwrap()
+    }
+}
@@ -0,0 +1,120 @@
+use super::float::Float;
+use super::math;
+use super::Vec3;
+use super::unit::ToM;
+
+/// optical_depth returns the optical depth of a homogeneous medium with the
+/// extinction coefficient `sigma` (per meter) over the given distance.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::unit::{CM, M};
+/// use fiz_math::media::optical_depth;
+///
+/// assert_eq!(optical_depth(M(2.0), 0.5), 1.0);
+/// assert_eq!(optical_depth(CM(200.0), 0.5), 1.0);
+/// ```
+pub fn optical_depth<D, T>(distance: D, sigma: T) -> T
+    where D: ToM<Output = T>,
+          T: Float
+{
+    sigma * distance.to_m().0
+}
+
+/// transmittance returns the fraction of light that is transmitted through a
+/// homogeneous medium with the extinction coefficient `sigma` (per meter) over
+/// the given distance, according to the Beer-Lambert law.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Float;
+/// use fiz_math::unit::{KM, M};
+/// use fiz_math::media::transmittance;
+///
+/// assert_eq!(transmittance(M(0.0), 0.5), 1.0);
+/// assert!(transmittance(M(2.0), 0.5).equal((-1.0f64).exp()));
+/// assert!(transmittance(KM(1.0), 0.001).equal((-1.0f64).exp()));
+/// ```
+pub fn transmittance<D, T>(distance: D, sigma: T) -> T
+    where D: ToM<Output = T>,
+          T: Float
+{
+    math::exp(-optical_depth(distance, sigma))
+}
+
+/// height_fog_optical_depth returns the optical depth along the line segment
+/// between the points `from` and `to` (in meters, with Y being up) through
+/// exponential height fog. The fog has the extinction coefficient `density`
+/// (per meter) at a height of zero, which decreases by a factor of `e` with
+/// each `scale_height` of altitude.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Vec3, Float};
+/// use fiz_math::unit::M;
+/// use fiz_math::media::height_fog_optical_depth;
+///
+/// // Horizontal segments see constant density.
+/// let a = Vec3(0.0, 10.0, 0.0);
+/// let b = Vec3(100.0, 10.0, 0.0);
+/// let d = height_fog_optical_depth(a, b, 0.02, M(10.0));
+/// assert!(d.equal(100.0 * 0.02 * (-1.0f64).exp()));
+///
+/// // Vertical segments integrate the density analytically.
+/// let a = Vec3(0.0, 0.0, 0.0);
+/// let b = Vec3(0.0, 10.0, 0.0);
+/// let d = height_fog_optical_depth(a, b, 0.02, M(10.0));
+/// assert!(d.equal(0.02 * 10.0 * (1.0 - (-1.0f64).exp())));
+/// ```
+pub fn height_fog_optical_depth<D, T>(from: Vec3<T>,
+                                      to: Vec3<T>,
+                                      density: T,
+                                      scale_height: D)
+                                      -> T
+    where D: ToM<Output = T>,
+          T: Float
+{
+    let falloff = T::one() / scale_height.to_m().0;
+    let length = (to - from).length();
+    let base = density * math::exp(-falloff * from.1);
+    let x = falloff * (to.1 - from.1);
+    if x.abs() < T::from(1e-5).unwrap() {
+        // Use a Taylor series for nearly horizontal segments, where the closed
+        // form divides by (nearly) zero.
+        base * length * (T::one() - x / T::from(2).unwrap())
+    } else {
+        base * length * (T::one() - math::exp(-x)) / x
+    }
+}
+
+/// height_fog_transmittance returns the fraction of light that is transmitted
+/// along the line segment between the points `from` and `to` through
+/// exponential height fog (see `height_fog_optical_depth`).
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::unit::M;
+/// use fiz_math::media::height_fog_transmittance;
+///
+/// let (a, b) = (Vec3(0.0, 0.0, 0.0), Vec3(100.0, 0.0, 0.0));
+/// let low = height_fog_transmittance(a, b, 0.02, M(10.0));
+///
+/// let (a, b) = (Vec3(0.0, 50.0, 0.0), Vec3(100.0, 50.0, 0.0));
+/// let high = height_fog_transmittance(a, b, 0.02, M(10.0));
+/// assert!(low < high);
+/// ```
+pub fn height_fog_transmittance<D, T>(from: Vec3<T>,
+                                      to: Vec3<T>,
+                                      density: T,
+                                      scale_height: D)
+                                      -> T
+    where D: ToM<Output = T>,
+          T: Float
+{
+    math::exp(-height_fog_optical_depth(from, to, density, scale_height))
+}
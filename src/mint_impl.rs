@@ -0,0 +1,108 @@
+use mint;
+
+use super::float::Float;
+use super::{Basis3, Vec2, Vec3, Vec3A, Vec4};
+
+// mint_vec implements the conversions between a vector type and a mint type
+// with the same number of components (whose fields are named x, y, z and w).
+macro_rules! mint_vec {
+    ($vec:ident, $mint:ident, $($i:tt: $field:ident),*) => {
+        impl<T> From<mint::$mint<T>> for $vec<T> {
+            fn from(v: mint::$mint<T>) -> Self {
+                $vec($(v.$field),*)
+            }
+        }
+
+        impl<T> From<$vec<T>> for mint::$mint<T> {
+            fn from(v: $vec<T>) -> Self {
+                mint::$mint { $($field: v.$i),* }
+            }
+        }
+    }
+}
+
+mint_vec!(Vec2, Vector2, 0: x, 1: y);
+mint_vec!(Vec2, Point2, 0: x, 1: y);
+mint_vec!(Vec3, Vector3, 0: x, 1: y, 2: z);
+mint_vec!(Vec3, Point3, 0: x, 1: y, 2: z);
+mint_vec!(Vec4, Vector4, 0: x, 1: y, 2: z, 3: w);
+
+impl<T> mint::IntoMint for Vec2<T> {
+    type MintType = mint::Vector2<T>;
+}
+
+impl<T> mint::IntoMint for Vec3<T> {
+    type MintType = mint::Vector3<T>;
+}
+
+impl<T> mint::IntoMint for Vec4<T> {
+    type MintType = mint::Vector4<T>;
+}
+
+impl mint::IntoMint for Vec3A {
+    type MintType = mint::Vector3<f32>;
+}
+
+impl From<mint::Vector3<f32>> for Vec3A {
+    fn from(v: mint::Vector3<f32>) -> Self {
+        Vec3A::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3A> for mint::Vector3<f32> {
+    fn from(v: Vec3A) -> Self {
+        mint::Vector3 {
+            x: v.0,
+            y: v.1,
+            z: v.2,
+        }
+    }
+}
+
+// Basis3 converts to the matrices and the unit quaternion of its rotation, but
+// only the latter converts back, since a matrix need not be a rotation (use
+// Basis3::from_axes to check one).
+
+impl<T: Float> mint::IntoMint for Basis3<T> {
+    type MintType = mint::ColumnMatrix3<T>;
+}
+
+impl<T: Float> From<Basis3<T>> for mint::ColumnMatrix3<T> {
+    fn from(b: Basis3<T>) -> Self {
+        mint::ColumnMatrix3 {
+            x: b.x().into(),
+            y: b.y().into(),
+            z: b.z().into(),
+        }
+    }
+}
+
+impl<T: Float> From<Basis3<T>> for mint::RowMatrix3<T> {
+    fn from(b: Basis3<T>) -> Self {
+        let b = b.inverse();
+        mint::RowMatrix3 {
+            x: b.x().into(),
+            y: b.y().into(),
+            z: b.z().into(),
+        }
+    }
+}
+
+impl<T: Float> From<Basis3<T>> for mint::Quaternion<T> {
+    fn from(b: Basis3<T>) -> Self {
+        let Vec4(x, y, z, w) = b.to_quat();
+        mint::Quaternion {
+            v: mint::Vector3 { x, y, z },
+            s: w,
+        }
+    }
+}
+
+// The quaternion is normalized, with a zero one giving the identity.
+impl<T: Float> From<mint::Quaternion<T>> for Basis3<T> {
+    fn from(q: mint::Quaternion<T>) -> Self {
+        Vec4(q.v.x, q.v.y, q.v.z, q.s)
+            .normalize()
+            .map_or(Basis3::identity(), Basis3::from_quat)
+    }
+}
@@ -0,0 +1,90 @@
+// spread2 spreads the bits of x apart, inserting a zero bit after each.
+fn spread2(x: u32) -> u64 {
+    let mut x = x as u64;
+    x = (x | (x << 16)) & 0x0000_ffff_0000_ffff;
+    x = (x | (x << 8)) & 0x00ff_00ff_00ff_00ff;
+    x = (x | (x << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    (x | (x << 1)) & 0x5555_5555_5555_5555
+}
+
+// compact2 is the inverse of spread2, gathering every other bit of x.
+fn compact2(x: u64) -> u32 {
+    let mut x = x & 0x5555_5555_5555_5555;
+    x = (x | (x >> 1)) & 0x3333_3333_3333_3333;
+    x = (x | (x >> 2)) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | (x >> 4)) & 0x00ff_00ff_00ff_00ff;
+    x = (x | (x >> 8)) & 0x0000_ffff_0000_ffff;
+    ((x | (x >> 16)) & 0xffff_ffff) as u32
+}
+
+// spread3 spreads the low 21 bits of x apart, inserting two zero bits after
+// each.
+fn spread3(x: u32) -> u64 {
+    let mut x = (x as u64) & 0x1f_ffff;
+    x = (x | (x << 32)) & 0x001f_0000_0000_ffff;
+    x = (x | (x << 16)) & 0x001f_0000_ff00_00ff;
+    x = (x | (x << 8)) & 0x100f_00f0_0f00_f00f;
+    x = (x | (x << 4)) & 0x10c3_0c30_c30c_30c3;
+    (x | (x << 2)) & 0x1249_2492_4924_9249
+}
+
+// compact3 is the inverse of spread3, gathering every third bit of x.
+fn compact3(x: u64) -> u32 {
+    let mut x = x & 0x1249_2492_4924_9249;
+    x = (x | (x >> 2)) & 0x10c3_0c30_c30c_30c3;
+    x = (x | (x >> 4)) & 0x100f_00f0_0f00_f00f;
+    x = (x | (x >> 8)) & 0x001f_0000_ff00_00ff;
+    x = (x | (x >> 16)) & 0x001f_0000_0000_ffff;
+    ((x | (x >> 32)) & 0x1f_ffff) as u32
+}
+
+/// morton2 returns the Morton code (Z-order index) of the given 2D integer
+/// coordinates, which interleaves their bits starting with the least
+/// significant bit of x. Points that are close in space tend to be close in
+/// Morton order, which makes it useful for spatial sorting.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{morton2, morton2_decode};
+///
+/// assert_eq!(morton2(0b11, 0b00), 0b0101);
+/// assert_eq!(morton2(0b00, 0b11), 0b1010);
+/// assert_eq!(morton2(u32::max_value(), u32::max_value()), u64::max_value());
+/// assert_eq!(morton2_decode(morton2(12345, 67890)), (12345, 67890));
+/// ```
+pub fn morton2(x: u32, y: u32) -> u64 {
+    spread2(x) | (spread2(y) << 1)
+}
+
+/// morton2_decode returns the 2D integer coordinates of the given Morton code;
+/// it is the inverse of `morton2`.
+pub fn morton2_decode(code: u64) -> (u32, u32) {
+    (compact2(code), compact2(code >> 1))
+}
+
+/// morton3 returns the Morton code (Z-order index) of the given 3D integer
+/// coordinates, which interleaves their bits starting with the least
+/// significant bit of x. Only the low 21 bits of each coordinate are encoded.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{morton3, morton3_decode};
+///
+/// assert_eq!(morton3(1, 0, 0), 0b001);
+/// assert_eq!(morton3(0, 1, 0), 0b010);
+/// assert_eq!(morton3(0, 0, 1), 0b100);
+/// assert_eq!(morton3(0b11, 0b10, 0b01), 0b011_101);
+/// assert_eq!(morton3_decode(morton3(123456, 7890, 2097151)), (123456, 7890, 2097151));
+/// ```
+pub fn morton3(x: u32, y: u32, z: u32) -> u64 {
+    spread3(x) | (spread3(y) << 1) | (spread3(z) << 2)
+}
+
+/// morton3_decode returns the 3D integer coordinates of the given Morton code;
+/// it is the inverse of `morton3` for coordinates below 2^21.
+pub fn morton3_decode(code: u64) -> (u32, u32, u32) {
+    (compact3(code), compact3(code >> 1), compact3(code >> 2))
+}
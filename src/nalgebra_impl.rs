@@ -0,0 +1,102 @@
+use nalgebra::{Matrix3, Matrix4, Point2, Point3, Quaternion, Rotation3, Scalar, UnitQuaternion,
+               Vector2, Vector3, Vector4};
+
+use super::{Basis3, Vec2, Vec3, Vec4};
+
+// nalgebra_vec implements the conversions between a vector type and the
+// nalgebra vector and point types with the same number of components.
+macro_rules! nalgebra_vec {
+    ($vec:ident, $na:ident, $n:expr, $($i:tt: $c:ident),*) => {
+        impl<T: Scalar> From<$na<T>> for $vec<T> {
+            fn from(v: $na<T>) -> Self {
+                let [$($c),*]: [T; $n] = v.into();
+                $vec($($c),*)
+            }
+        }
+
+        impl<T: Scalar> From<$vec<T>> for $na<T> {
+            fn from(v: $vec<T>) -> Self {
+                $na::new($(v.$i),*)
+            }
+        }
+    }
+}
+
+nalgebra_vec!(Vec2, Vector2, 2, 0: x, 1: y);
+nalgebra_vec!(Vec3, Vector3, 3, 0: x, 1: y, 2: z);
+nalgebra_vec!(Vec4, Vector4, 4, 0: x, 1: y, 2: z, 3: w);
+
+impl<T: Scalar> From<Point2<T>> for Vec2<T> {
+    fn from(p: Point2<T>) -> Self {
+        p.coords.into()
+    }
+}
+
+impl<T: Scalar> From<Vec2<T>> for Point2<T> {
+    fn from(v: Vec2<T>) -> Self {
+        Point2::new(v.0, v.1)
+    }
+}
+
+impl<T: Scalar> From<Point3<T>> for Vec3<T> {
+    fn from(p: Point3<T>) -> Self {
+        p.coords.into()
+    }
+}
+
+impl<T: Scalar> From<Vec3<T>> for Point3<T> {
+    fn from(v: Vec3<T>) -> Self {
+        Point3::new(v.0, v.1, v.2)
+    }
+}
+
+// nalgebra_basis implements the conversions of Basis3 to the nalgebra matrices
+// (whose columns are its axes), and both ways with the rotation and the unit
+// quaternion, which are always rotations (unlike matrices, which do not convert
+// to Basis3; use Basis3::from_axes to check one).
+macro_rules! nalgebra_basis {
+    ($($t:ty),*) => {$(
+        impl From<Basis3<$t>> for Matrix3<$t> {
+            fn from(b: Basis3<$t>) -> Self {
+                Matrix3::from_columns(&[b.x().into(), b.y().into(), b.z().into()])
+            }
+        }
+
+        impl From<Basis3<$t>> for Matrix4<$t> {
+            fn from(b: Basis3<$t>) -> Self {
+                Matrix3::from(b).to_homogeneous()
+            }
+        }
+
+        impl From<Basis3<$t>> for Rotation3<$t> {
+            fn from(b: Basis3<$t>) -> Self {
+                Rotation3::from_matrix_unchecked(b.into())
+            }
+        }
+
+        impl From<Rotation3<$t>> for Basis3<$t> {
+            fn from(r: Rotation3<$t>) -> Self {
+                let m = r.matrix();
+                Basis3::from_axes_unchecked(Vec3(m[(0, 0)], m[(1, 0)], m[(2, 0)]),
+                                            Vec3(m[(0, 1)], m[(1, 1)], m[(2, 1)]),
+                                            Vec3(m[(0, 2)], m[(1, 2)], m[(2, 2)]))
+            }
+        }
+
+        impl From<Basis3<$t>> for UnitQuaternion<$t> {
+            fn from(b: Basis3<$t>) -> Self {
+                let Vec4(x, y, z, w) = b.to_quat();
+                UnitQuaternion::new_unchecked(Quaternion::new(w, x, y, z))
+            }
+        }
+
+        impl From<UnitQuaternion<$t>> for Basis3<$t> {
+            fn from(q: UnitQuaternion<$t>) -> Self {
+                let q = q.into_inner();
+                Basis3::from_quat(Vec4(q.i, q.j, q.k, q.w))
+            }
+        }
+    )*}
+}
+
+nalgebra_basis!(f32, f64);
@@ -0,0 +1,519 @@
+//! Gradient noise, i.e. smooth pseudo-random functions of space for procedural
+//! textures, terrain and animation.
+//!
+//! `Perlin` is Ken Perlin's gradient noise (with the quintic interpolation and
+//! edge gradients of Improving Noise) in two, three and four dimensions. Noise
+//! is deterministic for a given seed, zero at the integer lattice points, and in
+//! the range -1.0 - 1.0. Each function has a `_deriv` variant which also
+//! returns the analytic gradient of the noise.
+//!
+//! The `Noise` trait abstracts over sources of noise, and `Fbm` layers octaves
+//! of any source into fractal noise. `curl_noise_3d` turns a source into a
+//! divergence free vector field.
+//!
+//! # Examples
+//!
+//! ```
+//! use fiz_math::Vec2;
+//! use fiz_math::noise::Perlin;
+//!
+//! // A height map with hills roughly eight units apart.
+//! let perlin = Perlin::new(42);
+//! let height = |x: f64, y: f64| 10.0 * perlin.noise2(Vec2(x, y) / Vec2(8.0, 8.0));
+//! assert_eq!(height(0.0, 0.0), 0.0);
+//! assert!(height(3.0, 5.0).abs() <= 10.0);
+//! ```
+
+use super::float::Float;
+use super::{Vec2, Vec3, Vec4, Vector};
+
+// GRAD2, GRAD3 and GRAD4 are the gradients of the noise functions: the
+// directions to the vertices and edges of the square, and the edges of the cube
+// (with four repeated to make sixteen) and of the tesseract.
+const GRAD2: [[i8; 2]; 8] = [[1, 1], [-1, 1], [1, -1], [-1, -1], [1, 0], [-1, 0], [0, 1], [0, -1]];
+const GRAD3: [[i8; 3]; 16] = [
+    [1, 1, 0], [-1, 1, 0], [1, -1, 0], [-1, -1, 0], [1, 0, 1], [-1, 0, 1], [1, 0, -1],
+    [-1, 0, -1], [0, 1, 1], [0, -1, 1], [0, 1, -1], [0, -1, -1], [1, 1, 0], [0, -1, 1],
+    [-1, 1, 0], [0, -1, -1],
+];
+const GRAD4: [[i8; 4]; 32] = [
+    [0, 1, 1, 1], [0, 1, 1, -1], [0, 1, -1, 1], [0, 1, -1, -1], [0, -1, 1, 1], [0, -1, 1, -1],
+    [0, -1, -1, 1], [0, -1, -1, -1], [1, 0, 1, 1], [1, 0, 1, -1], [1, 0, -1, 1], [1, 0, -1, -1],
+    [-1, 0, 1, 1], [-1, 0, 1, -1], [-1, 0, -1, 1], [-1, 0, -1, -1], [1, 1, 0, 1], [1, 1, 0, -1],
+    [1, -1, 0, 1], [1, -1, 0, -1], [-1, 1, 0, 1], [-1, 1, 0, -1], [-1, -1, 0, 1],
+    [-1, -1, 0, -1], [1, 1, 1, 0], [1, 1, -1, 0], [1, -1, 1, 0], [1, -1, -1, 0], [-1, 1, 1, 0],
+    [-1, 1, -1, 0], [-1, -1, 1, 0], [-1, -1, -1, 0],
+];
+
+/// Perlin is a seeded source of Perlin gradient noise.
+#[derive(Clone)]
+pub struct Perlin {
+    // perm is a random permutation of 0 - 255, repeated twice so that the
+    // lattice coordinates can be added to its entries without wrapping.
+    perm: [u8; 512],
+}
+
+impl Perlin {
+    /// new returns a noise source with the given seed. Different seeds give
+    /// unrelated noise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    /// use fiz_math::noise::Perlin;
+    ///
+    /// let p = Vec3(0.3, 1.7, 2.2);
+    /// assert_eq!(Perlin::new(1).noise3(p), Perlin::new(1).noise3(p));
+    /// assert!(Perlin::new(1).noise3(p) != Perlin::new(2).noise3(p));
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        // Shuffle with the SplitMix64 generator.
+        let mut state = seed;
+        let mut next = || {
+            state = state.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        };
+        let mut perm = [0u8; 512];
+        for (i, p) in perm.iter_mut().take(256).enumerate() {
+            *p = i as u8;
+        }
+        for i in (1..256).rev() {
+            perm.swap(i, (next() % (i as u64 + 1)) as usize);
+        }
+        perm.copy_within(0..256, 256);
+        Perlin { perm }
+    }
+
+    /// noise2 returns the two dimensional noise at `p`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    /// use fiz_math::noise::Perlin;
+    ///
+    /// let perlin = Perlin::new(0);
+    /// assert_eq!(perlin.noise2(Vec2(3.0, -4.0)), 0.0);
+    /// assert!(perlin.noise2(Vec2(0.3f64, 0.6)).abs() <= 1.0);
+    /// ```
+    pub fn noise2<T: Float>(&self, p: Vec2<T>) -> T {
+        self.gradient_noise(&[p.0, p.1], None)
+    }
+
+    /// noise2_deriv returns the two dimensional noise at `p`, and its gradient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Float, Vec2};
+    /// use fiz_math::noise::Perlin;
+    ///
+    /// let perlin = Perlin::new(0);
+    /// let p = Vec2(1.3, 2.6);
+    /// let (n, d) = perlin.noise2_deriv(p);
+    /// assert_eq!(n, perlin.noise2(p));
+    /// let h = 1e-6;
+    /// let dx = (perlin.noise2(p + Vec2(h, 0.0)) - perlin.noise2(p - Vec2(h, 0.0))) / (2.0 * h);
+    /// assert!(d.0.almost_equal(dx, 1e-6));
+    /// ```
+    pub fn noise2_deriv<T: Float>(&self, p: Vec2<T>) -> (T, Vec2<T>) {
+        let mut d = [T::zero(); 2];
+        let n = self.gradient_noise(&[p.0, p.1], Some(&mut d));
+        (n, Vec2(d[0], d[1]))
+    }
+
+    /// noise3 returns the three dimensional noise at `p`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    /// use fiz_math::noise::Perlin;
+    ///
+    /// let perlin = Perlin::new(0);
+    /// assert_eq!(perlin.noise3(Vec3(3.0, -4.0, 5.0)), 0.0);
+    /// assert!(perlin.noise3(Vec3(0.3f64, 0.6, 0.9)).abs() <= 1.0);
+    /// ```
+    pub fn noise3<T: Float>(&self, p: Vec3<T>) -> T {
+        self.gradient_noise(&[p.0, p.1, p.2], None)
+    }
+
+    /// noise3_deriv returns the three dimensional noise at `p`, and its
+    /// gradient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Float, Vec3};
+    /// use fiz_math::noise::Perlin;
+    ///
+    /// let perlin = Perlin::new(0);
+    /// let p = Vec3(1.3, 2.6, -0.4);
+    /// let (n, d) = perlin.noise3_deriv(p);
+    /// assert_eq!(n, perlin.noise3(p));
+    /// let h = Vec3(0.0, 0.0, 1e-6);
+    /// let dz = (perlin.noise3(p + h) - perlin.noise3(p - h)) / 2e-6;
+    /// assert!(d.2.almost_equal(dz, 1e-6));
+    /// ```
+    pub fn noise3_deriv<T: Float>(&self, p: Vec3<T>) -> (T, Vec3<T>) {
+        let mut d = [T::zero(); 3];
+        let n = self.gradient_noise(&[p.0, p.1, p.2], Some(&mut d));
+        (n, Vec3(d[0], d[1], d[2]))
+    }
+
+    /// noise4 returns the four dimensional noise at `p`, e.g. three dimensional
+    /// noise animated over time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    /// use fiz_math::noise::Perlin;
+    ///
+    /// let perlin = Perlin::new(0);
+    /// assert_eq!(perlin.noise4(Vec4(3.0, -4.0, 5.0, 6.0)), 0.0);
+    /// assert!(perlin.noise4(Vec4(0.3f64, 0.6, 0.9, 1.2)).abs() <= 1.0);
+    /// ```
+    pub fn noise4<T: Float>(&self, p: Vec4<T>) -> T {
+        self.gradient_noise(&[p.0, p.1, p.2, p.3], None)
+    }
+
+    /// noise4_deriv returns the four dimensional noise at `p`, and its
+    /// gradient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Float, Vec4};
+    /// use fiz_math::noise::Perlin;
+    ///
+    /// let perlin = Perlin::new(0);
+    /// let p = Vec4(1.3, 2.6, -0.4, 7.1);
+    /// let (n, d) = perlin.noise4_deriv(p);
+    /// assert_eq!(n, perlin.noise4(p));
+    /// let h = Vec4(0.0, 0.0, 0.0, 1e-6);
+    /// let dw = (perlin.noise4(p + h) - perlin.noise4(p - h)) / 2e-6;
+    /// assert!(d.3.almost_equal(dw, 1e-6));
+    /// ```
+    pub fn noise4_deriv<T: Float>(&self, p: Vec4<T>) -> (T, Vec4<T>) {
+        let mut d = [T::zero(); 4];
+        let n = self.gradient_noise(&[p.0, p.1, p.2, p.3], Some(&mut d));
+        (n, Vec4(d[0], d[1], d[2], d[3]))
+    }
+
+    // gradient_noise returns the noise at p (with two to four dimensions), and
+    // if deriv is given, stores its gradient there. It sums the contributions of the
+    // gradients at the corners of the lattice cell containing p, weighted by
+    // the fade curve.
+    fn gradient_noise<T: Float>(&self, p: &[T], mut deriv: Option<&mut [T]>) -> T {
+        let dims = p.len();
+        let zero = T::zero();
+        let (mut cell, mut f, mut u, mut du) = ([0usize; 4], [zero; 4], [zero; 4], [zero; 4]);
+        for i in 0..dims {
+            let floor = p[i].floor();
+            cell[i] = (floor.to_i64().unwrap() & 255) as usize;
+            f[i] = p[i] - floor;
+            u[i] = fade(f[i]);
+            du[i] = fade_deriv(f[i]);
+        }
+        let mut n = zero;
+        for corner in 0..1 << dims {
+            let mut h = 0;
+            for (i, &c) in cell.iter().enumerate().take(dims) {
+                h = self.perm[h + ((c + (corner >> i & 1)) & 255)] as usize;
+            }
+            let (mut g, mut dot, mut w) = ([zero; 4], zero, T::one());
+            for i in 0..dims {
+                g[i] = T::from(match dims {
+                        2 => GRAD2[h & 7][i],
+                        3 => GRAD3[h & 15][i],
+                        _ => GRAD4[h & 31][i],
+                    })
+                    .unwrap();
+                let (d, weight) = if corner >> i & 1 == 1 {
+                    (f[i] - T::one(), u[i])
+                } else {
+                    (f[i], T::one() - u[i])
+                };
+                dot = dot + g[i] * d;
+                w = w * weight;
+            }
+            n = n + w * dot;
+            if let Some(ref mut deriv) = deriv {
+                for i in 0..dims {
+                    // The derivative of the weight with respect to p[i].
+                    let mut dw = if corner >> i & 1 == 1 { du[i] } else { -du[i] };
+                    for (j, &uj) in u.iter().enumerate().take(dims) {
+                        if j != i {
+                            dw = dw * if corner >> j & 1 == 1 { uj } else { T::one() - uj };
+                        }
+                    }
+                    deriv[i] = deriv[i] + dw * dot + w * g[i];
+                }
+            }
+        }
+        let scale = T::from(SCALE[dims - 2]).unwrap();
+        if let Some(deriv) = deriv {
+            for d in deriv.iter_mut() {
+                *d = *d * scale;
+            }
+        }
+        n * scale
+    }
+}
+
+/// Noise is a source of noise over points of type `P` (e.g. `Vec3<f32>`).
+pub trait Noise<P: Vector> {
+    /// noise returns the noise at `p`.
+    fn noise(&self, p: P) -> P::Scalar;
+
+    /// noise_deriv returns the noise at `p`, and its gradient.
+    fn noise_deriv(&self, p: P) -> (P::Scalar, P);
+}
+
+impl<T: Float> Noise<Vec2<T>> for Perlin {
+    fn noise(&self, p: Vec2<T>) -> T {
+        self.noise2(p)
+    }
+
+    fn noise_deriv(&self, p: Vec2<T>) -> (T, Vec2<T>) {
+        self.noise2_deriv(p)
+    }
+}
+
+impl<T: Float> Noise<Vec3<T>> for Perlin {
+    fn noise(&self, p: Vec3<T>) -> T {
+        self.noise3(p)
+    }
+
+    fn noise_deriv(&self, p: Vec3<T>) -> (T, Vec3<T>) {
+        self.noise3_deriv(p)
+    }
+}
+
+impl<T: Float> Noise<Vec4<T>> for Perlin {
+    fn noise(&self, p: Vec4<T>) -> T {
+        self.noise4(p)
+    }
+
+    fn noise_deriv(&self, p: Vec4<T>) -> (T, Vec4<T>) {
+        self.noise4_deriv(p)
+    }
+}
+
+/// FbmMode is the way that `Fbm` shapes each octave of noise before summing
+/// them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FbmMode {
+    /// Standard sums the octaves as they are, giving noise in the range -1.0 -
+    /// 1.0.
+    Standard,
+    /// Ridged sums (1 - |n|)² for each octave n, turning the zero crossings of
+    /// the noise into sharp ridges (e.g. for mountain ranges), in the range 0.0
+    /// - 1.0.
+    Ridged,
+    /// Turbulence sums the absolute value of each octave, turning the zero
+    /// crossings into creases (e.g. for fire and marble), in the range 0.0 -
+    /// 1.0.
+    Turbulence,
+}
+
+/// Fbm is fractal noise (fractional Brownian motion): the sum of octaves of a
+/// source of noise, each at a higher frequency and lower amplitude than the
+/// last. It is built by chaining the settings after `new`, and is itself a
+/// source of noise. The sum is divided by the sum of the amplitudes, so it is
+/// in the same range as the source (or 0.0 - 1.0 for the ridged and turbulence
+/// modes).
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec2;
+/// use fiz_math::noise::{Fbm, FbmMode, Noise, Perlin};
+///
+/// let terrain = Fbm::new(Perlin::new(7)).octaves(5).gain(0.4).mode(FbmMode::Ridged);
+/// let h = terrain.noise(Vec2(0.3, 0.7));
+/// assert!(h >= 0.0 && h <= 1.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Fbm<N, T> {
+    source: N,
+    octaves: u32,
+    lacunarity: T,
+    gain: T,
+    mode: FbmMode,
+}
+
+impl<N, T: Float> Fbm<N, T> {
+    /// new returns fractal noise with six octaves of the given source, a
+    /// lacunarity of 2.0 and a gain of 0.5, in standard mode.
+    pub fn new(source: N) -> Self {
+        Fbm {
+            source,
+            octaves: 6,
+            lacunarity: T::from(2).unwrap(),
+            gain: T::from(0.5).unwrap(),
+            mode: FbmMode::Standard,
+        }
+    }
+
+    /// octaves sets the number of octaves of noise to sum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `octaves` is zero.
+    pub fn octaves(self, octaves: u32) -> Self {
+        assert!(octaves > 0, "Fbm: there must be at least one octave");
+        Fbm { octaves, ..self }
+    }
+
+    /// lacunarity sets the factor by which the frequency increases from each
+    /// octave to the next.
+    pub fn lacunarity(self, lacunarity: T) -> Self {
+        Fbm { lacunarity, ..self }
+    }
+
+    /// gain sets the factor by which the amplitude decreases from each octave
+    /// to the next.
+    pub fn gain(self, gain: T) -> Self {
+        Fbm { gain, ..self }
+    }
+
+    /// mode sets the way that the octaves are shaped before summing them.
+    pub fn mode(self, mode: FbmMode) -> Self {
+        Fbm { mode, ..self }
+    }
+
+    /// source returns the source of noise.
+    pub fn source(&self) -> &N {
+        &self.source
+    }
+}
+
+impl<N, T, P> Noise<P> for Fbm<N, T>
+    where N: Noise<P>,
+          T: Float,
+          P: Vector<Scalar = T>
+{
+    /// noise returns the fractal noise at `p`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    /// use fiz_math::noise::{Fbm, Noise, Perlin};
+    ///
+    /// let fbm = Fbm::new(Perlin::new(1)).octaves(1);
+    /// let p = Vec3(0.2, 0.5, 0.9);
+    /// assert_eq!(fbm.noise(p), Perlin::new(1).noise3(p));
+    /// ```
+    fn noise(&self, p: P) -> T {
+        self.noise_deriv(p).0
+    }
+
+    /// noise_deriv returns the fractal noise at `p`, and its gradient.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Float, Vec2};
+    /// use fiz_math::noise::{Fbm, FbmMode, Noise, Perlin};
+    ///
+    /// let fbm = Fbm::new(Perlin::new(1)).mode(FbmMode::Turbulence);
+    /// let p = Vec2(1.2, 3.4);
+    /// let (n, d) = fbm.noise_deriv(p);
+    /// assert_eq!(n, fbm.noise(p));
+    /// let h = Vec2(1e-7, 0.0);
+    /// assert!(d.0.almost_equal((fbm.noise(p + h) - fbm.noise(p - h)) / 2e-7, 1e-5));
+    /// ```
+    fn noise_deriv(&self, p: P) -> (T, P) {
+        let (zero, one) = (T::zero(), T::one());
+        let (mut sum, mut deriv) = (zero, P::splat(zero));
+        let (mut amplitude, mut frequency, mut total) = (one, one, zero);
+        for _ in 0..self.octaves {
+            let (n, d) = self.source.noise_deriv(p.mul_scalar(frequency));
+            // The shaped octave, and its derivative with respect to n.
+            let (shaped, slope) = match self.mode {
+                FbmMode::Standard => (n, one),
+                FbmMode::Ridged => {
+                    let r = one - n.abs();
+                    (r * r, -(r + r) * n.signum())
+                }
+                FbmMode::Turbulence => (n.abs(), n.signum()),
+            };
+            sum = sum + amplitude * shaped;
+            deriv = deriv + d.mul_scalar(amplitude * slope * frequency);
+            total = total + amplitude;
+            amplitude = amplitude * self.gain;
+            frequency = frequency * self.lacunarity;
+        }
+        (sum / total, deriv.mul_scalar(one / total))
+    }
+}
+
+// CURL_OFFSETS are the offsets at which curl_noise_3d samples its source for
+// the three components of the potential, chosen to be far apart and away from
+// the lattice so that the components are unrelated.
+const CURL_OFFSETS: [[f64; 3]; 3] = [[0.0, 0.0, 0.0],
+                                     [31.416, -47.853, 12.793],
+                                     [-23.719, 19.137, -71.421]];
+
+/// curl_noise_3d returns the curl of a vector potential made from three
+/// unrelated samples of the noise `source` at `p` (using its analytic gradient).
+/// The result is a smooth divergence free vector field, i.e. one which looks
+/// like the flow of an incompressible fluid, for advecting particles (Bridson
+/// et al., Curl-Noise for Procedural Fluid Flow).
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Float, Vec3};
+/// use fiz_math::noise::{curl_noise_3d, Perlin};
+///
+/// let perlin = Perlin::new(3);
+/// let p = Vec3(0.4, 1.3, -2.1);
+/// let v = curl_noise_3d(&perlin, p);
+/// assert!(v.length() > 0.0);
+///
+/// // The field has no divergence.
+/// let h = 1e-5f64;
+/// let (dx, dy, dz) = (Vec3(h, 0.0, 0.0), Vec3(0.0, h, 0.0), Vec3(0.0, 0.0, h));
+/// let div = (curl_noise_3d(&perlin, p + dx).0 - curl_noise_3d(&perlin, p - dx).0 +
+///            curl_noise_3d(&perlin, p + dy).1 - curl_noise_3d(&perlin, p - dy).1 +
+///            curl_noise_3d(&perlin, p + dz).2 - curl_noise_3d(&perlin, p - dz).2) / (2.0 * h);
+/// assert!(div.abs() < 1e-6);
+/// ```
+pub fn curl_noise_3d<T, N>(source: &N, p: Vec3<T>) -> Vec3<T>
+    where T: Float,
+          N: Noise<Vec3<T>>
+{
+    let offset = |i: usize| {
+        let o = CURL_OFFSETS[i];
+        Vec3(T::from(o[0]).unwrap(), T::from(o[1]).unwrap(), T::from(o[2]).unwrap())
+    };
+    let (_, d1) = source.noise_deriv(p + offset(0));
+    let (_, d2) = source.noise_deriv(p + offset(1));
+    let (_, d3) = source.noise_deriv(p + offset(2));
+    Vec3(d3.1 - d2.2, d1.2 - d3.0, d2.0 - d1.1)
+}
+
+// SCALE holds the factors which scale the noise in two, three and four
+// dimensions to the range -1.0 - 1.0: the reciprocals (rounded down) of the
+// largest possible magnitudes of the unscaled noise, which are 1.0, 1.0364 and
+// 1.5366, reached near the middle of a cell whose gradients all point there.
+const SCALE: [f64; 3] = [1.0, 0.9649, 0.6507];
+
+// fade is the quintic interpolation curve 6t⁵ - 15t⁴ + 10t³.
+fn fade<T: Float>(t: T) -> T {
+    t * t * t * (t * (t * T::from(6).unwrap() - T::from(15).unwrap()) + T::from(10).unwrap())
+}
+
+// fade_deriv is the derivative of fade, 30t²(t - 1)².
+fn fade_deriv<T: Float>(t: T) -> T {
+    let s = t * (t - T::one());
+    T::from(30).unwrap() * s * s
+}
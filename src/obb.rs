@@ -0,0 +1,256 @@
+use super::float::Float;
+use super::{Aabb3, Basis3, Vec3};
+
+/// Obb is an oriented bounding box in three dimensions: a box with the given
+/// center and half extents (half of its size along each of its axes), rotated
+/// by the given basis.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Basis3, Obb, Vec3};
+/// use fiz_math::unit::Deg;
+///
+/// let rot = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(45.0)).unwrap();
+/// let b = Obb::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0), rot);
+/// assert!(b.contains_point(Vec3(1.3, 0.0, 0.0)));
+/// assert!(!b.contains_point(Vec3(1.0, 1.0, 0.0)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Obb<T> {
+    /// The center of the box.
+    pub center: Vec3<T>,
+
+    /// Half of the size of the box along each of its axes.
+    pub half_extents: Vec3<T>,
+
+    /// The rotation of the box, whose axes are the axes of the box.
+    pub rotation: Basis3<T>,
+}
+
+impl<T: Float> Obb<T> {
+    /// new returns the box with the given center, half extents and rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Obb, Vec3};
+    ///
+    /// let b = Obb::new(Vec3(1.0, 2.0, 3.0), Vec3(1.0, 1.0, 1.0), Basis3::identity());
+    /// assert_eq!(b.center, Vec3(1.0, 2.0, 3.0));
+    /// ```
+    pub fn new(center: Vec3<T>, half_extents: Vec3<T>, rotation: Basis3<T>) -> Self {
+        Obb {
+            center,
+            half_extents,
+            rotation,
+        }
+    }
+
+    /// from_aabb returns the (unrotated) box covering the given axis-aligned
+    /// box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Basis3, Obb, Vec3};
+    ///
+    /// let b = Obb::from_aabb(&Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 4.0, 6.0)));
+    /// assert_eq!(b, Obb::new(Vec3(1.0, 2.0, 3.0), Vec3(1.0, 2.0, 3.0), Basis3::identity()));
+    /// ```
+    pub fn from_aabb(aabb: &Aabb3<T>) -> Self {
+        Obb::new(aabb.center(), aabb.half_extents(), Basis3::identity())
+    }
+
+    /// contains_point tells if the given point is inside this box, or on its
+    /// boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Obb, Vec3};
+    ///
+    /// let b = Obb::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 2.0, 3.0), Basis3::identity());
+    /// assert!(b.contains_point(Vec3(1.0, -2.0, 0.0)));
+    /// assert!(!b.contains_point(Vec3(0.0, 0.0, 3.5)));
+    /// ```
+    pub fn contains_point(&self, point: Vec3<T>) -> bool {
+        let Vec3(x, y, z) = self.rotation.inverse_transform(point - self.center);
+        let h = self.half_extents;
+        x.abs() <= h.0 && y.abs() <= h.1 && z.abs() <= h.2
+    }
+
+    /// corners returns the eight corners of this box, in the same order as
+    /// `Aabb3::corners` returns the corners of the unrotated box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Obb, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let rot = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(90.0)).unwrap();
+    /// let b = Obb::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 1.0, 1.0), rot);
+    /// let c = b.corners();
+    /// assert!(c[0].almost_equal(Vec3(1.0, -2.0, -1.0), 1e-12));
+    /// assert!(c[7].almost_equal(Vec3(-1.0, 2.0, 1.0), 1e-12));
+    /// ```
+    pub fn corners(&self) -> [Vec3<T>; 8] {
+        let h = self.half_extents;
+        let local = Aabb3::new(-h, h).corners();
+        let mut corners = local;
+        for (c, l) in corners.iter_mut().zip(local.iter()) {
+            *c = self.center + self.rotation * *l;
+        }
+        corners
+    }
+
+    /// aabb returns the smallest axis-aligned box containing this box.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Basis3, Obb, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let rot = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(90.0)).unwrap();
+    /// let b = Obb::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 1.0, 1.0), rot).aabb();
+    /// assert!(b.max.almost_equal(Vec3(1.0, 2.0, 1.0), 1e-12));
+    /// ```
+    pub fn aabb(&self) -> Aabb3<T> {
+        let (r, h) = (self.rotation, self.half_extents);
+        let abs = |v: Vec3<T>| Vec3(v.0.abs(), v.1.abs(), v.2.abs());
+        let e = abs(r.x()).mul_scalar(h.0) + abs(r.y()).mul_scalar(h.1) +
+                abs(r.z()).mul_scalar(h.2);
+        Aabb3::new(self.center - e, self.center + e)
+    }
+
+    /// transform returns this box rotated by the given basis and then
+    /// translated, e.g. to move a box from the local frame of an object into
+    /// world space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Obb, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let rot = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(90.0)).unwrap();
+    /// let b = Obb::new(Vec3(1.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0), Basis3::identity());
+    /// let b = b.transform(&rot, Vec3(0.0, 0.0, 5.0));
+    /// assert!(b.center.almost_equal(Vec3(0.0, 1.0, 5.0), 1e-12));
+    /// assert_eq!(b.rotation, rot);
+    /// ```
+    pub fn transform(&self, basis: &Basis3<T>, translation: Vec3<T>) -> Self {
+        Obb::new(*basis * self.center + translation,
+                 self.half_extents,
+                 *basis * self.rotation)
+    }
+
+    /// intersects_aabb tells if this box and the given axis-aligned box
+    /// overlap (including touching).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Basis3, Obb, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let rot = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(45.0)).unwrap();
+    /// let b = Obb::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0), rot);
+    /// let near = Aabb3::new(Vec3(1.3, -0.1, -0.1), Vec3(2.0, 0.1, 0.1));
+    /// let far = Aabb3::new(Vec3(1.0, 1.0, -0.1), Vec3(2.0, 2.0, 0.1));
+    /// assert!(b.intersects_aabb(&near));
+    /// assert!(!b.intersects_aabb(&far));
+    /// ```
+    pub fn intersects_aabb(&self, aabb: &Aabb3<T>) -> bool {
+        self.intersects_obb(&Obb::from_aabb(aabb))
+    }
+
+    /// intersects_obb tells if this box and the other overlap (including
+    /// touching), using the separating axis test.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Obb, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let rot = Basis3::from_axis_angle(Vec3(1.0, 1.0, 0.0), Deg(30.0)).unwrap();
+    /// let a = Obb::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0), Basis3::identity());
+    /// let b = Obb::new(Vec3(2.2, 0.0, 0.0), Vec3(1.0, 1.0, 1.0), rot);
+    /// let c = Obb::new(Vec3(2.5, 0.0, 0.0), Vec3(1.0, 1.0, 1.0), rot);
+    /// assert!(a.intersects_obb(&b));
+    /// assert!(!a.intersects_obb(&c));
+    ///
+    /// // Boxes whose edges face each other, which are only separated along the
+    /// // cross product of the edges.
+    /// let rx = Basis3::from_axis_angle(Vec3(1.0, 0.0, 0.0), Deg(45.0)).unwrap();
+    /// let ry = Basis3::from_axis_angle(Vec3(0.0, 1.0, 0.0), Deg(45.0)).unwrap();
+    /// let a = Obb::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0), rx);
+    /// let b = Obb::new(Vec3(0.0, 0.0, 2.7), Vec3(1.0, 1.0, 1.0), ry);
+    /// let c = Obb::new(Vec3(0.0, 0.0, 3.0), Vec3(1.0, 1.0, 1.0), ry);
+    /// assert!(a.intersects_obb(&b));
+    /// assert!(!a.intersects_obb(&c));
+    /// ```
+    pub fn intersects_obb(&self, other: &Self) -> bool {
+        // Gottschalk's test, as presented in Ericson's Real-Time Collision
+        // Detection: the boxes are disjoint iff they are separated along one of
+        // the 3 + 3 face normals or 9 pairwise edge cross products.
+        let ra3 = |v: Vec3<T>| [v.0, v.1, v.2];
+        let a = [self.rotation.x(), self.rotation.y(), self.rotation.z()];
+        let b = [other.rotation.x(), other.rotation.y(), other.rotation.z()];
+        let ea = ra3(self.half_extents);
+        let eb = ra3(other.half_extents);
+
+        // The rotation of the other box in the frame of this box, with an
+        // epsilon added to its absolute values to counteract arithmetic error
+        // when edges are parallel (and their cross product is near zero).
+        let eps = T::from(1.0e-6).unwrap();
+        let mut r = [[T::zero(); 3]; 3];
+        let mut abs_r = [[T::zero(); 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                r[i][j] = a[i].dot(b[j]);
+                abs_r[i][j] = r[i][j].abs() + eps;
+            }
+        }
+
+        // The translation between the centers, in the frame of this box.
+        let d = other.center - self.center;
+        let t = [d.dot(a[0]), d.dot(a[1]), d.dot(a[2])];
+
+        // The axes of this box.
+        for i in 0..3 {
+            let rb = eb[0] * abs_r[i][0] + eb[1] * abs_r[i][1] + eb[2] * abs_r[i][2];
+            if t[i].abs() > ea[i] + rb {
+                return false;
+            }
+        }
+
+        // The axes of the other box.
+        for j in 0..3 {
+            let ra = ea[0] * abs_r[0][j] + ea[1] * abs_r[1][j] + ea[2] * abs_r[2][j];
+            let tj = t[0] * r[0][j] + t[1] * r[1][j] + t[2] * r[2][j];
+            if tj.abs() > ra + eb[j] {
+                return false;
+            }
+        }
+
+        // The cross products of the axes of this box (i) with those of the
+        // other box (j).
+        for i in 0..3 {
+            let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+            for j in 0..3 {
+                let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+                let ra = ea[i1] * abs_r[i2][j] + ea[i2] * abs_r[i1][j];
+                let rb = eb[j1] * abs_r[i][j2] + eb[j2] * abs_r[i][j1];
+                let tij = t[i2] * r[i1][j] - t[i1] * r[i2][j];
+                if tij.abs() > ra + rb {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
@@ -0,0 +1,158 @@
+use super::float::Float;
+use super::{Vec3, Vec4};
+
+/// Plane is an infinite plane in three dimensions, given by the points p for
+/// which `normal.dot(p) + d == 0`. The side of the plane the normal points
+/// towards is the positive side.
+///
+/// The constructors of this type always produce a normalized plane (one whose
+/// normal is of unit length), for which `signed_distance` returns euclidean
+/// distances.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Plane, Vec3};
+///
+/// // The plane y = 2, facing up.
+/// let p = Plane::from_point_normal(Vec3(0.0, 2.0, 0.0), Vec3(0.0, 1.0, 0.0)).unwrap();
+/// assert_eq!(p.signed_distance(Vec3(5.0, 5.0, 5.0)), 3.0);
+/// assert_eq!(p.signed_distance(Vec3(5.0, 0.0, 5.0)), -2.0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Plane<T> {
+    /// The normal of the plane.
+    pub normal: Vec3<T>,
+
+    /// The signed distance from the plane to the origin, in units of the
+    /// length of the normal.
+    pub d: T,
+}
+
+impl<T: Float> Plane<T> {
+    /// new returns the plane with the given normal and distance. Unlike the
+    /// other constructors the normal is used as-is, so the plane may not be
+    /// normalized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Plane, Vec3};
+    ///
+    /// let p = Plane::new(Vec3(0.0, 0.0, 2.0), -4.0);
+    /// assert_eq!(p.signed_distance(Vec3(0.0, 0.0, 2.0)), 0.0);
+    /// ```
+    pub fn new(normal: Vec3<T>, d: T) -> Self {
+        Plane {
+            normal,
+            d,
+        }
+    }
+
+    /// from_point_normal returns the plane through the given point with the
+    /// given normal (which need not be normalized), or None if the normal is
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Plane, Vec3};
+    ///
+    /// let p = Plane::from_point_normal(Vec3(1.0, 0.0, 0.0), Vec3(-3.0, 0.0, 0.0)).unwrap();
+    /// assert_eq!(p, Plane::new(Vec3(-1.0, 0.0, 0.0), 1.0));
+    /// assert_eq!(Plane::from_point_normal(Vec3(1.0, 0.0, 0.0), Vec3(0.0, 0.0, 0.0)), None);
+    /// ```
+    pub fn from_point_normal(point: Vec3<T>, normal: Vec3<T>) -> Option<Self> {
+        normal.normalize().map(|n| Plane::new(n, -n.dot(point)))
+    }
+
+    /// from_points returns the plane through the three points, or None if they
+    /// are collinear. The normal faces towards the side from which the points
+    /// appear in counter-clockwise order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Plane, Vec3};
+    ///
+    /// let (a, b, c) = (Vec3(0.0, 0.0, 1.0), Vec3(1.0, 0.0, 1.0), Vec3(0.0, 1.0, 1.0));
+    /// assert_eq!(Plane::from_points(a, b, c), Some(Plane::new(Vec3(0.0, 0.0, 1.0), -1.0)));
+    /// assert_eq!(Plane::from_points(a, b, a + (b - a).mul_scalar(2.0)), None);
+    /// ```
+    pub fn from_points(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Option<Self> {
+        Plane::from_point_normal(a, (b - a).cross(c - a))
+    }
+
+    /// normalize returns this plane scaled such that its normal is of unit
+    /// length, or None if the normal is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Plane, Vec3};
+    ///
+    /// let p = Plane::new(Vec3(0.0, 3.0, 4.0), 10.0).normalize().unwrap();
+    /// assert_eq!(p, Plane::new(Vec3(0.0, 0.6, 0.8), 2.0));
+    /// assert_eq!(Plane::new(Vec3(0.0, 0.0, 0.0), 1.0).normalize(), None);
+    /// ```
+    pub fn normalize(&self) -> Option<Self> {
+        let len = self.normal.length();
+        if len == T::zero() {
+            return None;
+        }
+        Some(Plane::new(self.normal.div_scalar(len), self.d / len))
+    }
+
+    /// signed_distance returns the distance from the plane to the given point,
+    /// in units of the length of the normal: positive if the point is on the
+    /// side the normal faces, negative if it is on the other side.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Plane, Vec3};
+    ///
+    /// let p = Plane::new(Vec3(1.0, 0.0, 0.0), -1.0);
+    /// assert_eq!(p.signed_distance(Vec3(3.0, 7.0, 7.0)), 2.0);
+    /// assert_eq!(p.signed_distance(Vec3(0.0, 7.0, 7.0)), -1.0);
+    /// ```
+    pub fn signed_distance(&self, point: Vec3<T>) -> T {
+        self.normal.dot(point) + self.d
+    }
+
+    /// project_point returns the point on the plane closest to the given point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Plane, Vec3};
+    ///
+    /// let p = Plane::new(Vec3(0.0, 2.0, 0.0), -2.0);
+    /// assert_eq!(p.project_point(Vec3(3.0, 5.0, -1.0)), Vec3(3.0, 1.0, -1.0));
+    /// ```
+    pub fn project_point(&self, point: Vec3<T>) -> Vec3<T> {
+        let t = self.signed_distance(point) / self.normal.length_sq();
+        point - self.normal.mul_scalar(t)
+    }
+
+    /// to_vec4 returns this plane as `Vec4(nx, ny, nz, d)`, the form taken by
+    /// e.g. the batch culling functions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Plane, Vec3, Vec4};
+    ///
+    /// let p = Plane::new(Vec3(0.0, 1.0, 0.0), -2.0);
+    /// assert_eq!(p.to_vec4(), Vec4(0.0, 1.0, 0.0, -2.0));
+    /// assert_eq!(Plane::from_vec4(p.to_vec4()), p);
+    /// ```
+    pub fn to_vec4(&self) -> Vec4<T> {
+        Vec4(self.normal.0, self.normal.1, self.normal.2, self.d)
+    }
+
+    /// from_vec4 returns the plane given as `Vec4(nx, ny, nz, d)`.
+    pub fn from_vec4(v: Vec4<T>) -> Self {
+        Plane::new(Vec3(v.0, v.1, v.2), v.3)
+    }
+}
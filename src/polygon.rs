@@ -0,0 +1,179 @@
+use prelude::*;
+
+use super::float::Float;
+use super::Vec2;
+
+/// Winding is the order in which the vertices of a polygon are traversed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Winding {
+    /// Counter-clockwise, i.e. with positive signed area.
+    CounterClockwise,
+
+    /// Clockwise, i.e. with negative signed area.
+    Clockwise,
+}
+
+/// Polygon2 is a simple polygon in two dimensions, given by its vertices in
+/// order. The last vertex is implicitly connected to the first.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Polygon2, Vec2, Winding};
+///
+/// let p = Polygon2::new(vec![Vec2(0.0, 0.0), Vec2(4.0, 0.0), Vec2(4.0, 3.0)]);
+/// assert_eq!(p.area(), 6.0);
+/// assert_eq!(p.winding(), Some(Winding::CounterClockwise));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polygon2<T> {
+    /// The vertices of the polygon.
+    pub vertices: Vec<Vec2<T>>,
+}
+
+impl<T: Float> Polygon2<T> {
+    /// new returns the polygon with the given vertices.
+    pub fn new(vertices: Vec<Vec2<T>>) -> Self {
+        Polygon2 { vertices }
+    }
+
+    // edge returns the start and end vertices of the i'th edge.
+    fn edge(&self, i: usize) -> (Vec2<T>, Vec2<T>) {
+        let v = &self.vertices;
+        (v[i], v[(i + 1) % v.len()])
+    }
+
+    /// signed_area returns the area of this polygon, which is positive if its
+    /// vertices are in counter-clockwise order and negative otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Polygon2, Vec2};
+    ///
+    /// let p = Polygon2::new(vec![Vec2(0.0, 0.0), Vec2(0.0, 2.0), Vec2(2.0, 2.0), Vec2(2.0, 0.0)]);
+    /// assert_eq!(p.signed_area(), -4.0);
+    /// ```
+    pub fn signed_area(&self) -> T {
+        let mut sum = T::zero();
+        for i in 0..self.vertices.len() {
+            let (a, b) = self.edge(i);
+            sum = sum + a.0 * b.1 - b.0 * a.1;
+        }
+        sum / T::from(2.0).unwrap()
+    }
+
+    /// area returns the (unsigned) area of this polygon.
+    pub fn area(&self) -> T {
+        self.signed_area().abs()
+    }
+
+    /// winding returns the order of the vertices of this polygon, or None if it
+    /// has no area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Polygon2, Vec2, Winding};
+    ///
+    /// let p = Polygon2::new(vec![Vec2(0.0, 0.0), Vec2(0.0, 1.0), Vec2(1.0, 0.0)]);
+    /// assert_eq!(p.winding(), Some(Winding::Clockwise));
+    /// let p = Polygon2::new(vec![Vec2(0.0, 0.0), Vec2(1.0, 1.0), Vec2(2.0, 2.0)]);
+    /// assert_eq!(p.winding(), None);
+    /// ```
+    pub fn winding(&self) -> Option<Winding> {
+        let a = self.signed_area();
+        if a > T::zero() {
+            Some(Winding::CounterClockwise)
+        } else if a < T::zero() {
+            Some(Winding::Clockwise)
+        } else {
+            None
+        }
+    }
+
+    /// centroid returns the center of mass of this polygon, or None if it has
+    /// no area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Polygon2, Vec2};
+    ///
+    /// // An L shape made of three unit squares.
+    /// let p = Polygon2::new(vec![Vec2(0.0, 0.0), Vec2(2.0, 0.0), Vec2(2.0, 1.0),
+    ///                            Vec2(1.0, 1.0), Vec2(1.0, 2.0), Vec2(0.0, 2.0)]);
+    /// let c = p.centroid().unwrap();
+    /// assert!(c.almost_equal(Vec2(5.0 / 6.0, 5.0 / 6.0), 1e-12));
+    /// ```
+    pub fn centroid(&self) -> Option<Vec2<T>> {
+        let area = self.signed_area();
+        if area == T::zero() {
+            return None;
+        }
+        let mut sum = Vec2(T::zero(), T::zero());
+        for i in 0..self.vertices.len() {
+            let (a, b) = self.edge(i);
+            sum = sum + (a + b).mul_scalar(a.0 * b.1 - b.0 * a.1);
+        }
+        Some(sum.div_scalar(T::from(6.0).unwrap() * area))
+    }
+
+    /// is_convex tells if this polygon is convex, i.e. if all of its turns are
+    /// in the same direction. Collinear vertices are allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Polygon2, Vec2};
+    ///
+    /// let p = Polygon2::new(vec![Vec2(0.0, 0.0), Vec2(1.0, 0.0), Vec2(2.0, 0.0), Vec2(1.0, 1.0)]);
+    /// assert!(p.is_convex());
+    ///
+    /// let p = Polygon2::new(vec![Vec2(0.0, 0.0), Vec2(2.0, 0.0), Vec2(1.0, 0.5), Vec2(1.0, 2.0)]);
+    /// assert!(!p.is_convex());
+    /// ```
+    pub fn is_convex(&self) -> bool {
+        let v = &self.vertices;
+        let n = v.len();
+        let (mut pos, mut neg) = (false, false);
+        for i in 0..n {
+            let (a, b, c) = (v[i], v[(i + 1) % n], v[(i + 2) % n]);
+            let turn = (b.0 - a.0) * (c.1 - b.1) - (b.1 - a.1) * (c.0 - b.0);
+            pos = pos || turn > T::zero();
+            neg = neg || turn < T::zero();
+        }
+        !(pos && neg)
+    }
+
+    /// contains_point tells if the given point is inside this polygon, by
+    /// casting a ray from it and counting the edges crossed. Points exactly on
+    /// an edge may be reported either way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Polygon2, Vec2};
+    ///
+    /// // A U shape.
+    /// let p = Polygon2::new(vec![Vec2(0.0, 0.0), Vec2(3.0, 0.0), Vec2(3.0, 3.0), Vec2(2.0, 3.0),
+    ///                            Vec2(2.0, 1.0), Vec2(1.0, 1.0), Vec2(1.0, 3.0), Vec2(0.0, 3.0)]);
+    /// assert!(p.contains_point(Vec2(0.5, 2.0)));
+    /// assert!(p.contains_point(Vec2(1.5, 0.5)));
+    /// assert!(!p.contains_point(Vec2(1.5, 2.0)));
+    /// assert!(!p.contains_point(Vec2(4.0, 1.0)));
+    /// ```
+    pub fn contains_point(&self, point: Vec2<T>) -> bool {
+        let Vec2(x, y) = point;
+        let mut inside = false;
+        for i in 0..self.vertices.len() {
+            // Count the edges that straddle the horizontal line through the
+            // point, to the right of it.
+            let (a, b) = self.edge(i);
+            if (a.1 > y) != (b.1 > y) && x < a.0 + (y - a.1) * (b.0 - a.0) / (b.1 - a.1) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+}
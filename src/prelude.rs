@@ -0,0 +1,17 @@
+// prelude re-exports the allocating types of the standard prelude (and
+// BTreeSet) from std, or from alloc when building without std, for the modules
+// which use them.
+
+#[cfg(feature = "std")]
+pub use std::collections::BTreeSet;
+#[cfg(feature = "std")]
+pub use std::string::{String, ToString};
+#[cfg(feature = "std")]
+pub use std::vec::Vec;
+
+#[cfg(not(feature = "std"))]
+pub use alloc::collections::BTreeSet;
+#[cfg(not(feature = "std"))]
+pub use alloc::string::{String, ToString};
+#[cfg(not(feature = "std"))]
+pub use alloc::vec::Vec;
@@ -0,0 +1,116 @@
+//! Integration with the `rand` crate, enabled by the `rand` feature.
+//!
+//! Vectors can be sampled from the `Standard` distribution, which samples each
+//! component independently (e.g. in the range 0.0 - 1.0 for floats), and from
+//! `Uniform` distributions, which sample uniformly within the box between two
+//! corners. Rotations can be sampled from the `Standard` distribution too (see
+//! `Basis3::random`).
+//!
+//! # Examples
+//!
+//! ```
+//! extern crate rand;
+//! extern crate fiz_math;
+//!
+//! use rand::{Rng, SeedableRng};
+//! use rand::distributions::Uniform;
+//! use rand::rngs::StdRng;
+//! use fiz_math::{Basis3, Vec2, Vec3};
+//!
+//! # fn main() {
+//! let mut rng = StdRng::seed_from_u64(7);
+//! let v: Vec2<f64> = rng.gen();
+//! assert!(v.0 >= 0.0 && v.0 < 1.0 && v.1 >= 0.0 && v.1 < 1.0);
+//!
+//! let area = Uniform::new(Vec3(-1.0, 0.0, 10.0), Vec3(1.0, 0.5, 20.0));
+//! for _ in 0..100 {
+//!     let p = rng.sample(&area);
+//!     assert!(p.0 >= -1.0 && p.0 < 1.0 && p.1 >= 0.0 && p.1 < 0.5 && p.2 >= 10.0 && p.2 < 20.0);
+//! }
+//!
+//! let _: Basis3<f32> = rng.gen();
+//! # }
+//! ```
+
+use rand::Rng;
+use rand::distributions::{Distribution, Standard};
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+
+use super::float::Float;
+use super::{Basis3, Vec2, Vec3, Vec4};
+
+impl<T> Distribution<Vec2<T>> for Standard
+    where Standard: Distribution<T>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec2<T> {
+        Vec2(self.sample(rng), self.sample(rng))
+    }
+}
+
+impl<T> Distribution<Vec3<T>> for Standard
+    where Standard: Distribution<T>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3<T> {
+        Vec3(self.sample(rng), self.sample(rng), self.sample(rng))
+    }
+}
+
+impl<T> Distribution<Vec4<T>> for Standard
+    where Standard: Distribution<T>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec4<T> {
+        Vec4(self.sample(rng), self.sample(rng), self.sample(rng), self.sample(rng))
+    }
+}
+
+impl<T: Float> Distribution<Basis3<T>> for Standard
+    where Standard: Distribution<T>
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Basis3<T> {
+        Basis3::random(rng)
+    }
+}
+
+// uniform_vec implements SampleUniform for a vector type, with a sampler
+// which samples each component independently.
+macro_rules! uniform_vec {
+    ($sampler:ident, $vec:ident, $($field:ident: $i:tt),+) => {
+        /// The sampler of uniformly distributed vectors, used by `Uniform`.
+        #[derive(Clone, Copy, Debug)]
+        pub struct $sampler<T: SampleUniform> {
+            $($field: T::Sampler),+
+        }
+
+        impl<T: SampleUniform> SampleUniform for $vec<T> {
+            type Sampler = $sampler<T>;
+        }
+
+        impl<T: SampleUniform> UniformSampler for $sampler<T> {
+            type X = $vec<T>;
+
+            fn new<B1, B2>(low: B1, high: B2) -> Self
+                where B1: SampleBorrow<$vec<T>> + Sized,
+                      B2: SampleBorrow<$vec<T>> + Sized
+            {
+                let (low, high) = (low.borrow(), high.borrow());
+                $sampler { $($field: T::Sampler::new(&low.$i, &high.$i)),+ }
+            }
+
+            fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+                where B1: SampleBorrow<$vec<T>> + Sized,
+                      B2: SampleBorrow<$vec<T>> + Sized
+            {
+                let (low, high) = (low.borrow(), high.borrow());
+                $sampler { $($field: T::Sampler::new_inclusive(&low.$i, &high.$i)),+ }
+            }
+
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec<T> {
+                $vec($(self.$field.sample(rng)),+)
+            }
+        }
+    }
+}
+
+uniform_vec!(UniformVec2, Vec2, x: 0, y: 1);
+uniform_vec!(UniformVec3, Vec3, x: 0, y: 1, z: 2);
+uniform_vec!(UniformVec4, Vec4, x: 0, y: 1, z: 2, w: 3);
@@ -0,0 +1,290 @@
+use super::float::Float;
+use super::math;
+use super::{Aabb3, Basis3, Plane, Sphere, Triangle3, Vec3};
+
+/// Ray3 is a half-line in three dimensions, starting at an origin and extending
+/// infinitely in a direction. The direction need not be normalized, in which
+/// case distances along the ray are in units of its length.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Ray3, Vec3};
+///
+/// let r = Ray3::new(Vec3(1.0, 0.0, 0.0), Vec3(0.0, 2.0, 0.0));
+/// assert_eq!(r.at(1.5), Vec3(1.0, 3.0, 0.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Ray3<T> {
+    /// The point at which the ray starts.
+    pub origin: Vec3<T>,
+
+    /// The direction in which the ray extends.
+    pub dir: Vec3<T>,
+}
+
+/// Hit describes where a ray hits a triangle, as returned by
+/// `Ray3::intersect_triangle`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hit<T> {
+    /// The distance along the ray, such that the hit point is `ray.at(t)`.
+    pub t: T,
+
+    /// The barycentric weight of the second corner of the triangle, such that
+    /// the hit point is also `triangle.at(u, v)`.
+    pub u: T,
+
+    /// The barycentric weight of the third corner of the triangle.
+    pub v: T,
+}
+
+impl<T: Float> Ray3<T> {
+    /// new returns a ray starting at origin and extending in the direction dir.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Ray3, Vec3};
+    ///
+    /// let r = Ray3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, -1.0));
+    /// assert_eq!(r.dir, Vec3(0.0, 0.0, -1.0));
+    /// ```
+    pub fn new(origin: Vec3<T>, dir: Vec3<T>) -> Self {
+        Ray3 {
+            origin,
+            dir,
+        }
+    }
+
+    /// at returns the point at the distance t along the ray, in units of the
+    /// length of its direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Ray3, Vec3};
+    ///
+    /// let r = Ray3::new(Vec3(0.0, 1.0, 0.0), Vec3(1.0, 0.0, 0.0));
+    /// assert_eq!(r.at(0.0), Vec3(0.0, 1.0, 0.0));
+    /// assert_eq!(r.at(4.0), Vec3(4.0, 1.0, 0.0));
+    /// ```
+    pub fn at(&self, t: T) -> Vec3<T> {
+        self.origin + self.dir.mul_scalar(t)
+    }
+
+    /// normalize returns this ray with its direction normalized, such that
+    /// distances along it are euclidean distances, or None if the direction is
+    /// zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Ray3, Vec3};
+    ///
+    /// let r = Ray3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 3.0, 4.0)).normalize().unwrap();
+    /// assert_eq!(r.dir, Vec3(0.0, 0.6, 0.8));
+    /// assert_eq!(Ray3::new(Vec3(1.0, 0.0, 0.0), Vec3(0.0, 0.0, 0.0)).normalize(), None);
+    /// ```
+    pub fn normalize(&self) -> Option<Self> {
+        self.dir.normalize().map(|dir| Ray3::new(self.origin, dir))
+    }
+
+    /// transform returns this ray rotated by the given basis and then
+    /// translated, e.g. to move a ray from the local frame of an object into
+    /// world space. The length of the direction is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Basis3, Ray3, Vec3};
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let b = Basis3::from_axis_angle(Vec3(0.0, 0.0, 1.0), Deg(90.0)).unwrap();
+    /// let r = Ray3::new(Vec3(1.0, 0.0, 0.0), Vec3(2.0, 0.0, 0.0));
+    /// let r = r.transform(&b, Vec3(0.0, 0.0, 5.0));
+    /// assert!(r.origin.almost_equal(Vec3(0.0, 1.0, 5.0), 1e-12));
+    /// assert!(r.dir.almost_equal(Vec3(0.0, 2.0, 0.0), 1e-12));
+    /// ```
+    pub fn transform(&self, basis: &Basis3<T>, translation: Vec3<T>) -> Self {
+        Ray3::new(*basis * self.origin + translation, *basis * self.dir)
+    }
+
+    /// intersects_aabb returns the range of distances (t_min, t_max) along the
+    /// ray inside the given box, or None if the ray misses it. The range is
+    /// clipped to the ray, such that t_min is zero when the origin is inside
+    /// the box. Rays parallel to a face hit the box only if the origin lies
+    /// within that slab, boundaries included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Ray3, Vec3};
+    ///
+    /// let b = Aabb3::new(Vec3(-1.0, -1.0, -1.0), Vec3(1.0, 1.0, 1.0));
+    ///
+    /// let r = Ray3::new(Vec3(-5.0, 0.0, 0.0), Vec3(2.0, 0.0, 0.0));
+    /// assert_eq!(r.intersects_aabb(&b), Some((2.0, 3.0)));
+    ///
+    /// let r = Ray3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, 1.0));
+    /// assert_eq!(r.intersects_aabb(&b), Some((0.0, 1.0)));
+    ///
+    /// // Parallel to the top face, exactly on it.
+    /// let r = Ray3::new(Vec3(-5.0, 1.0, 0.0), Vec3(1.0, 0.0, 0.0));
+    /// assert_eq!(r.intersects_aabb(&b), Some((4.0, 6.0)));
+    /// let r = Ray3::new(Vec3(-5.0, -1.0, 0.0), Vec3(1.0, -0.0, 0.0));
+    /// assert_eq!(r.intersects_aabb(&b), Some((4.0, 6.0)));
+    ///
+    /// // Pointing away, and passing beside the box.
+    /// assert_eq!(Ray3::new(Vec3(5.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.0)).intersects_aabb(&b), None);
+    /// assert_eq!(Ray3::new(Vec3(-5.0, 2.0, 0.0), Vec3(1.0, 0.0, 0.0)).intersects_aabb(&b), None);
+    /// ```
+    pub fn intersects_aabb(&self, aabb: &Aabb3<T>) -> Option<(T, T)> {
+        let origin = [self.origin.0, self.origin.1, self.origin.2];
+        let dir = [self.dir.0, self.dir.1, self.dir.2];
+        let min = [aabb.min.0, aabb.min.1, aabb.min.2];
+        let max = [aabb.max.0, aabb.max.1, aabb.max.2];
+
+        let mut t_min = T::zero();
+        let mut t_max = T::infinity();
+        for i in 0..3 {
+            // When parallel to the slab inv is infinite, so that the distances
+            // to the planes are infinite (or NaN for a plane the origin is on,
+            // which min and max ignore).
+            let inv = T::one() / dir[i];
+            let (near, far) = if inv < T::zero() { (max[i], min[i]) } else { (min[i], max[i]) };
+            t_min = t_min.max((near - origin[i]) * inv);
+            t_max = t_max.min((far - origin[i]) * inv);
+        }
+        if t_min <= t_max {
+            Some((t_min, t_max))
+        } else {
+            None
+        }
+    }
+
+    /// intersect_sphere returns the distance along the ray and the point at
+    /// which it first hits the surface of the given sphere, or None if it
+    /// misses it. If the origin is inside the sphere the hit is where the ray
+    /// leaves it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Ray3, Sphere, Vec3};
+    ///
+    /// let s = Sphere::new(Vec3(0.0, 0.0, 10.0), 2.0);
+    ///
+    /// let r = Ray3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, 2.0));
+    /// assert_eq!(r.intersect_sphere(&s), Some((4.0, Vec3(0.0, 0.0, 8.0))));
+    ///
+    /// let r = Ray3::new(Vec3(0.0, 0.0, 10.0), Vec3(1.0, 0.0, 0.0));
+    /// assert_eq!(r.intersect_sphere(&s), Some((2.0, Vec3(2.0, 0.0, 10.0))));
+    ///
+    /// let r = Ray3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, -1.0));
+    /// assert_eq!(r.intersect_sphere(&s), None);
+    /// ```
+    pub fn intersect_sphere(&self, sphere: &Sphere<T>) -> Option<(T, Vec3<T>)> {
+        let m = self.origin - sphere.center;
+        let a = self.dir.length_sq();
+        let b = m.dot(self.dir);
+        let c = m.length_sq() - sphere.radius * sphere.radius;
+        let disc = b * b - a * c;
+        if a == T::zero() || disc < T::zero() {
+            return None;
+        }
+
+        let sqrt = math::sqrt(disc);
+        let (t0, t1) = ((-b - sqrt) / a, (-b + sqrt) / a);
+        if t1 < T::zero() {
+            return None;
+        }
+        let t = if t0 >= T::zero() { t0 } else { t1 };
+        Some((t, self.at(t)))
+    }
+
+    /// intersect_plane returns the distance along the ray and the point at
+    /// which it hits the given plane, or None if it is parallel to the plane
+    /// or points away from it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Plane, Ray3, Vec3};
+    ///
+    /// // The plane z = 3.
+    /// let p = Plane::new(Vec3(0.0, 0.0, 1.0), -3.0);
+    ///
+    /// let r = Ray3::new(Vec3(1.0, 0.0, 0.0), Vec3(0.0, 0.0, 2.0));
+    /// assert_eq!(r.intersect_plane(&p), Some((1.5, Vec3(1.0, 0.0, 3.0))));
+    ///
+    /// assert_eq!(Ray3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, -1.0)).intersect_plane(&p), None);
+    /// assert_eq!(Ray3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.0)).intersect_plane(&p), None);
+    /// ```
+    pub fn intersect_plane(&self, plane: &Plane<T>) -> Option<(T, Vec3<T>)> {
+        let denom = plane.normal.dot(self.dir);
+        if denom == T::zero() {
+            return None;
+        }
+        let t = -plane.signed_distance(self.origin) / denom;
+        if t < T::zero() {
+            return None;
+        }
+        Some((t, self.at(t)))
+    }
+
+    /// intersect_triangle returns where the ray hits the given triangle, or
+    /// None if it misses it, using the Möller–Trumbore algorithm. If
+    /// cull_backface is true, hits on the back face of the triangle (see
+    /// `Triangle3`) are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Hit, Ray3, Triangle3, Vec3};
+    ///
+    /// let t = Triangle3::new(Vec3(0.0, 0.0, 0.0), Vec3(4.0, 0.0, 0.0), Vec3(0.0, 4.0, 0.0));
+    ///
+    /// let r = Ray3::new(Vec3(1.0, 2.0, 5.0), Vec3(0.0, 0.0, -1.0));
+    /// let hit = r.intersect_triangle(&t, true).unwrap();
+    /// assert_eq!(hit, Hit { t: 5.0, u: 0.25, v: 0.5 });
+    /// assert_eq!(r.at(hit.t), t.at(hit.u, hit.v));
+    ///
+    /// // From behind, the triangle is only hit without backface culling.
+    /// let r = Ray3::new(Vec3(1.0, 2.0, -5.0), Vec3(0.0, 0.0, 1.0));
+    /// assert_eq!(r.intersect_triangle(&t, true), None);
+    /// assert_eq!(r.intersect_triangle(&t, false), Some(Hit { t: 5.0, u: 0.25, v: 0.5 }));
+    ///
+    /// // Outside of the triangle, and behind the origin.
+    /// let r = Ray3::new(Vec3(3.0, 3.0, 5.0), Vec3(0.0, 0.0, -1.0));
+    /// assert_eq!(r.intersect_triangle(&t, false), None);
+    /// let r = Ray3::new(Vec3(1.0, 1.0, 5.0), Vec3(0.0, 0.0, 1.0));
+    /// assert_eq!(r.intersect_triangle(&t, false), None);
+    /// ```
+    pub fn intersect_triangle(&self, tri: &Triangle3<T>, cull_backface: bool) -> Option<Hit<T>> {
+        let zero = T::zero();
+        let e1 = tri.b - tri.a;
+        let e2 = tri.c - tri.a;
+        let p = self.dir.cross(e2);
+        let det = e1.dot(p);
+        if det == zero || (cull_backface && det < zero) {
+            return None;
+        }
+
+        let inv = T::one() / det;
+        let s = self.origin - tri.a;
+        let u = s.dot(p) * inv;
+        if u < zero || u > T::one() {
+            return None;
+        }
+        let q = s.cross(e1);
+        let v = self.dir.dot(q) * inv;
+        if v < zero || u + v > T::one() {
+            return None;
+        }
+        let t = e2.dot(q) * inv;
+        if t < zero {
+            return None;
+        }
+        Some(Hit { t, u, v })
+    }
+}
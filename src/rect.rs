@@ -0,0 +1,247 @@
+use super::float::Float;
+use super::{Axis2, Vec2};
+
+/// Rect is an axis-aligned rectangle in two dimensions, given by its minimum
+/// and maximum corners. A rectangle whose minimum is greater than its maximum
+/// along either axis is empty.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Rect, Vec2};
+///
+/// let r = Rect::from_min_size(Vec2(10.0, 20.0), Vec2(100.0, 50.0));
+/// assert_eq!(r.max, Vec2(110.0, 70.0));
+/// assert!(r.contains(Vec2(50.0, 50.0)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rect<T> {
+    /// The minimum corner of the rectangle.
+    pub min: Vec2<T>,
+
+    /// The maximum corner of the rectangle.
+    pub max: Vec2<T>,
+}
+
+impl<T: Float> Rect<T> {
+    /// new returns the rectangle with the given minimum and maximum corners.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Rect, Vec2};
+    ///
+    /// let r = Rect::new(Vec2(0.0, 0.0), Vec2(4.0, 3.0));
+    /// assert_eq!(r.size(), Vec2(4.0, 3.0));
+    /// ```
+    pub fn new(min: Vec2<T>, max: Vec2<T>) -> Self {
+        Rect {
+            min,
+            max,
+        }
+    }
+
+    /// from_min_size returns the rectangle with the given minimum corner and
+    /// size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Rect, Vec2};
+    ///
+    /// let r = Rect::from_min_size(Vec2(1.0, 2.0), Vec2(3.0, 4.0));
+    /// assert_eq!(r, Rect::new(Vec2(1.0, 2.0), Vec2(4.0, 6.0)));
+    /// ```
+    pub fn from_min_size(min: Vec2<T>, size: Vec2<T>) -> Self {
+        Rect::new(min, min + size)
+    }
+
+    /// to_min_size returns the minimum corner and the size of this rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Rect, Vec2};
+    ///
+    /// let r = Rect::new(Vec2(1.0, 2.0), Vec2(4.0, 6.0));
+    /// assert_eq!(r.to_min_size(), (Vec2(1.0, 2.0), Vec2(3.0, 4.0)));
+    /// ```
+    pub fn to_min_size(&self) -> (Vec2<T>, Vec2<T>) {
+        (self.min, self.size())
+    }
+
+    /// from_points returns the smallest rectangle containing all of the given
+    /// points, or None if there are none.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Rect, Vec2};
+    ///
+    /// let r = Rect::from_points(vec![Vec2(1.0, 5.0), Vec2(-2.0, 0.0)]).unwrap();
+    /// assert_eq!(r, Rect::new(Vec2(-2.0, 0.0), Vec2(1.0, 5.0)));
+    /// assert_eq!(Rect::<f32>::from_points(vec![]), None);
+    /// ```
+    pub fn from_points<I: IntoIterator<Item = Vec2<T>>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Rect::new(first, first), |r, p| r.extend(p)))
+    }
+
+    /// is_empty tells if this rectangle is empty, i.e. if its minimum is
+    /// greater than its maximum along either axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Rect, Vec2};
+    ///
+    /// assert!(!Rect::new(Vec2(0.0, 0.0), Vec2(1.0, 1.0)).is_empty());
+    /// assert!(Rect::new(Vec2(0.0, 1.0), Vec2(1.0, 0.0)).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.min.any_greater(self.max)
+    }
+
+    /// size returns the width and height of this rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Rect, Vec2};
+    ///
+    /// assert_eq!(Rect::new(Vec2(1.0, 1.0), Vec2(4.0, 3.0)).size(), Vec2(3.0, 2.0));
+    /// ```
+    pub fn size(&self) -> Vec2<T> {
+        self.max - self.min
+    }
+
+    /// center returns the center of this rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Rect, Vec2};
+    ///
+    /// assert_eq!(Rect::new(Vec2(0.0, 2.0), Vec2(4.0, 4.0)).center(), Vec2(2.0, 3.0));
+    /// ```
+    pub fn center(&self) -> Vec2<T> {
+        let two = T::one() + T::one();
+        (self.min + self.max).div_scalar(two)
+    }
+
+    /// union returns the smallest rectangle containing both this rectangle
+    /// and the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Rect, Vec2};
+    ///
+    /// let a = Rect::new(Vec2(0.0, 0.0), Vec2(1.0, 1.0));
+    /// let b = Rect::new(Vec2(2.0, -1.0), Vec2(3.0, 0.5));
+    /// assert_eq!(a.union(b), Rect::new(Vec2(0.0, -1.0), Vec2(3.0, 1.0)));
+    /// ```
+    pub fn union(&self, other: Self) -> Self {
+        Rect::new(self.min.min(other.min), self.max.max(other.max))
+    }
+
+    /// intersection returns the rectangle where this rectangle and the other
+    /// overlap, or None if they do not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Rect, Vec2};
+    ///
+    /// let a = Rect::new(Vec2(0.0, 0.0), Vec2(2.0, 2.0));
+    /// let b = Rect::new(Vec2(1.0, 1.0), Vec2(3.0, 3.0));
+    /// let c = Rect::new(Vec2(5.0, 5.0), Vec2(6.0, 6.0));
+    /// assert_eq!(a.intersection(b), Some(Rect::new(Vec2(1.0, 1.0), Vec2(2.0, 2.0))));
+    /// assert_eq!(a.intersection(c), None);
+    /// ```
+    pub fn intersection(&self, other: Self) -> Option<Self> {
+        let r = Rect::new(self.min.max(other.min), self.max.min(other.max));
+        if r.is_empty() { None } else { Some(r) }
+    }
+
+    /// extend returns the smallest rectangle containing both this rectangle
+    /// and the given point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Rect, Vec2};
+    ///
+    /// let r = Rect::new(Vec2(0.0, 0.0), Vec2(1.0, 1.0));
+    /// assert_eq!(r.extend(Vec2(2.0, -1.0)), Rect::new(Vec2(0.0, -1.0), Vec2(2.0, 1.0)));
+    /// ```
+    pub fn extend(&self, point: Vec2<T>) -> Self {
+        Rect::new(self.min.min(point), self.max.max(point))
+    }
+
+    /// inflate returns this rectangle grown by the given amount on every side
+    /// (or shrunk, if the amount is negative).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Rect, Vec2};
+    ///
+    /// let r = Rect::new(Vec2(0.0, 0.0), Vec2(10.0, 10.0));
+    /// assert_eq!(r.inflate(-2.0), Rect::new(Vec2(2.0, 2.0), Vec2(8.0, 8.0)));
+    /// ```
+    pub fn inflate(&self, amount: T) -> Self {
+        let v = Vec2(amount, amount);
+        Rect::new(self.min - v, self.max + v)
+    }
+
+    /// contains tells if the given point is inside this rectangle, or on its
+    /// boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Rect, Vec2};
+    ///
+    /// let r = Rect::new(Vec2(0.0, 0.0), Vec2(1.0, 1.0));
+    /// assert!(r.contains(Vec2(1.0, 0.5)));
+    /// assert!(!r.contains(Vec2(1.5, 0.5)));
+    /// ```
+    pub fn contains(&self, point: Vec2<T>) -> bool {
+        !self.min.any_greater(point) && !self.max.any_less(point)
+    }
+
+    /// split_at splits this rectangle in two along the given axis at the given
+    /// coordinate (which is clamped to the rectangle), returning the part
+    /// before the coordinate and the part after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Axis2, Rect, Vec2};
+    ///
+    /// let r = Rect::new(Vec2(0.0, 0.0), Vec2(10.0, 4.0));
+    /// let (left, right) = r.split_at(Axis2::X, 3.0);
+    /// assert_eq!(left, Rect::new(Vec2(0.0, 0.0), Vec2(3.0, 4.0)));
+    /// assert_eq!(right, Rect::new(Vec2(3.0, 0.0), Vec2(10.0, 4.0)));
+    ///
+    /// let (top, bottom) = r.split_at(Axis2::Y, 7.0);
+    /// assert_eq!(top, r);
+    /// assert_eq!(bottom, Rect::new(Vec2(0.0, 4.0), Vec2(10.0, 4.0)));
+    /// ```
+    pub fn split_at(&self, axis: Axis2, at: T) -> (Self, Self) {
+        match axis {
+            Axis2::X => {
+                let x = at.max(self.min.0).min(self.max.0);
+                (Rect::new(self.min, Vec2(x, self.max.1)),
+                 Rect::new(Vec2(x, self.min.1), self.max))
+            }
+            Axis2::Y => {
+                let y = at.max(self.min.1).min(self.max.1);
+                (Rect::new(self.min, Vec2(self.max.0, y)),
+                 Rect::new(Vec2(self.min.0, y), self.max))
+            }
+        }
+    }
+}
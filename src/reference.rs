@@ -0,0 +1,264 @@
+use super::math;
+use super::{Vec2, Vec3};
+
+// two_sum returns the sum of a and b rounded to a f64, along with the exact
+// rounding error of that sum (Knuth's TwoSum).
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+// two_prod returns the product of a and b rounded to a f64, along with the
+// exact rounding error of that product.
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, math::mul_add(a, b, -p))
+}
+
+// diff_of_products returns a*b - c*d accurate to within 1.5 ulps, using
+// Kahan's algorithm.
+fn diff_of_products(a: f64, b: f64, c: f64, d: f64) -> f64 {
+    let cd = c * d;
+    let err = math::mul_add(-c, d, cd);
+    math::mul_add(a, b, -cd) + err
+}
+
+/// dot returns the dot product of the two sequences of numbers, computed as if
+/// in twice the working precision and then rounded (the Dot2 algorithm of
+/// Ogita, Rump and Oishi). Unlike a naive dot product, it is not subject to
+/// catastrophic cancellation unless the condition number is extremely large.
+///
+/// The sequences must be of the same length.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::reference;
+///
+/// let a = Vec3(1.0e16, 1.0, -1.0e16);
+/// let b = Vec3(1.0, 1.0, 1.0);
+/// assert_eq!(a.dot(b), 0.0);
+/// assert_eq!(reference::dot(a, b), 1.0);
+/// assert_eq!(reference::dot(vec![1.0, 2.0], vec![3.0, 4.0]), 11.0);
+/// ```
+pub fn dot<A, B>(a: A, b: B) -> f64
+    where A: IntoIterator<Item = f64>,
+          B: IntoIterator<Item = f64>
+{
+    let mut a = a.into_iter();
+    let mut b = b.into_iter();
+    let (mut sum, mut err) = (0.0, 0.0);
+    loop {
+        match (a.next(), b.next()) {
+            (Some(x), Some(y)) => {
+                let (p, pe) = two_prod(x, y);
+                let (s, se) = two_sum(sum, p);
+                sum = s;
+                err += pe + se;
+            }
+            (None, None) => return sum + err,
+            _ => panic!("reference::dot: sequences of different lengths"),
+        }
+    }
+}
+
+/// cross returns the cross product of the two vectors, with each component
+/// accurate to within 1.5 ulps.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::reference;
+///
+/// // (1 + e)(1 - e) - 1 = -e², which cancels catastrophically.
+/// let e = 2.0f64.powi(-30);
+/// let a = Vec3(1.0 + e, 1.0, 0.0);
+/// let b = Vec3(1.0, 1.0 - e, 0.0);
+/// assert_eq!(a.cross(b), Vec3(0.0, 0.0, 0.0));
+/// assert_eq!(reference::cross(a, b), Vec3(0.0, 0.0, -e * e));
+/// ```
+pub fn cross(a: Vec3<f64>, b: Vec3<f64>) -> Vec3<f64> {
+    Vec3(diff_of_products(a.1, b.2, a.2, b.1),
+         diff_of_products(a.2, b.0, a.0, b.2),
+         diff_of_products(a.0, b.1, a.1, b.0))
+}
+
+/// determinant returns the determinant of the 3x3 row-major matrix `m`.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::reference;
+///
+/// let m = [[2.0, 0.0, 0.0], [0.0, 3.0, 0.0], [1.0, 0.0, 4.0]];
+/// assert_eq!(reference::determinant(m), 24.0);
+/// ```
+pub fn determinant(m: [[f64; 3]; 3]) -> f64 {
+    let rows = [Vec3(m[0][0], m[0][1], m[0][2]),
+                Vec3(m[1][0], m[1][1], m[1][2]),
+                Vec3(m[2][0], m[2][1], m[2][2])];
+    dot(rows[0], cross(rows[1], rows[2]))
+}
+
+/// inverse returns the inverse of the 3x3 row-major matrix `m`, or None if it
+/// is singular. The cofactors and determinant are computed using the accurate
+/// routines of this module.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::reference;
+///
+/// let m = [[2.0, 0.0, 0.0], [0.0, 4.0, 0.0], [0.0, 0.0, 8.0]];
+/// let inv = reference::inverse(m).unwrap();
+/// assert_eq!(inv, [[0.5, 0.0, 0.0], [0.0, 0.25, 0.0], [0.0, 0.0, 0.125]]);
+///
+/// assert_eq!(reference::inverse([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 0.0, 1.0]]), None);
+/// ```
+pub fn inverse(m: [[f64; 3]; 3]) -> Option<[[f64; 3]; 3]> {
+    let det = determinant(m);
+    if det == 0.0 || !det.is_finite() {
+        return None;
+    }
+    let c = |r0: usize, c0: usize, r1: usize, c1: usize| {
+        diff_of_products(m[r0][c0], m[r1][c1], m[r0][c1], m[r1][c0])
+    };
+    let cof = [[c(1, 1, 2, 2), c(0, 2, 2, 1), c(0, 1, 1, 2)],
+               [c(1, 2, 2, 0), c(0, 0, 2, 2), c(0, 2, 1, 0)],
+               [c(1, 0, 2, 1), c(0, 1, 2, 0), c(0, 0, 1, 1)]];
+    let mut inv = [[0.0; 3]; 3];
+    for r in 0..3 {
+        for col in 0..3 {
+            inv[r][col] = cof[r][col] / det;
+        }
+    }
+    Some(inv)
+}
+
+/// orient2d returns twice the signed area of the triangle a, b, c: positive if
+/// the points are in counter-clockwise order, negative if clockwise, and zero
+/// if they are (nearly) collinear.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec2;
+/// use fiz_math::reference::orient2d;
+///
+/// assert_eq!(orient2d(Vec2(0.0, 0.0), Vec2(1.0, 0.0), Vec2(0.0, 1.0)), 1.0);
+/// assert_eq!(orient2d(Vec2(0.0, 0.0), Vec2(1.0, 1.0), Vec2(2.0, 2.0)), 0.0);
+/// ```
+pub fn orient2d(a: Vec2<f64>, b: Vec2<f64>, c: Vec2<f64>) -> f64 {
+    diff_of_products(b.0 - a.0, c.1 - a.1, b.1 - a.1, c.0 - a.0)
+}
+
+/// orient3d returns six times the signed volume of the tetrahedron a, b, c, d:
+/// positive if d lies below the plane through a, b and c (which appear in
+/// counter-clockwise order when viewed from above), negative if above, and
+/// zero if the points are (nearly) coplanar.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::reference::orient3d;
+///
+/// let (a, b, c) = (Vec3(0.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0));
+/// assert_eq!(orient3d(a, b, c, Vec3(0.0, 0.0, -1.0)), 1.0);
+/// assert_eq!(orient3d(a, b, c, Vec3(0.3, 0.3, 0.0)), 0.0);
+/// ```
+pub fn orient3d(a: Vec3<f64>, b: Vec3<f64>, c: Vec3<f64>, d: Vec3<f64>) -> f64 {
+    dot(a - d, cross(b - d, c - d))
+}
+
+/// ray_triangle returns the distance along the ray (in units of the length of
+/// `dir`) at which the ray from `origin` in the direction `dir` hits the
+/// triangle a, b, c, or None if it misses. Hits exactly on an edge count.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::reference::ray_triangle;
+///
+/// let (a, b, c) = (Vec3(0.0, 0.0, 1.0), Vec3(1.0, 0.0, 1.0), Vec3(0.0, 1.0, 1.0));
+/// let dir = Vec3(0.0, 0.0, 1.0);
+/// assert_eq!(ray_triangle(Vec3(0.25, 0.25, 0.0), dir, a, b, c), Some(1.0));
+/// assert_eq!(ray_triangle(Vec3(0.5, 0.5, 0.0), dir, a, b, c), Some(1.0));
+/// assert_eq!(ray_triangle(Vec3(0.75, 0.75, 0.0), dir, a, b, c), None);
+/// assert_eq!(ray_triangle(Vec3(0.25, 0.25, 2.0), dir, a, b, c), None);
+/// ```
+pub fn ray_triangle(origin: Vec3<f64>,
+                    dir: Vec3<f64>,
+                    a: Vec3<f64>,
+                    b: Vec3<f64>,
+                    c: Vec3<f64>)
+                    -> Option<f64> {
+    // Möller-Trumbore, with the cross and dot products computed accurately.
+    let (e1, e2) = (b - a, c - a);
+    let p = cross(dir, e2);
+    let det = dot(e1, p);
+    if det == 0.0 {
+        return None;
+    }
+    let s = origin - a;
+    let u = dot(s, p) / det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+    let q = cross(s, e1);
+    let v = dot(dir, q) / det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+    let t = dot(e2, q) / det;
+    if t < 0.0 { None } else { Some(t) }
+}
+
+/// ray_aabb returns the range of distances along the ray (in units of the
+/// length of `dir`) over which the ray from `origin` in the direction `dir` is
+/// inside the axis-aligned box with the corners `min` and `max`, or None if it
+/// misses. Zero direction components and rays grazing a face are handled
+/// exactly.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::reference::ray_aabb;
+///
+/// let (min, max) = (Vec3(-1.0, -1.0, -1.0), Vec3(1.0, 1.0, 1.0));
+/// let origin = Vec3(-3.0, 0.0, 0.0);
+/// assert_eq!(ray_aabb(origin, Vec3(1.0, 0.0, 0.0), min, max), Some((2.0, 4.0)));
+/// assert_eq!(ray_aabb(origin, Vec3(-1.0, 0.0, 0.0), min, max), None);
+///
+/// // A ray along a face of the box touches it.
+/// let origin = Vec3(-3.0, 1.0, 0.0);
+/// assert_eq!(ray_aabb(origin, Vec3(1.0, 0.0, 0.0), min, max), Some((2.0, 4.0)));
+/// ```
+pub fn ray_aabb(origin: Vec3<f64>,
+                dir: Vec3<f64>,
+                min: Vec3<f64>,
+                max: Vec3<f64>)
+                -> Option<(f64, f64)> {
+    let (mut near, mut far) = (0.0f64, f64::INFINITY);
+    for ((o, d), (lo, hi)) in origin.into_iter().zip(dir).zip(min.into_iter().zip(max)) {
+        if d == 0.0 {
+            if o < lo || o > hi {
+                return None;
+            }
+            continue;
+        }
+        let (t0, t1) = ((lo - o) / d, (hi - o) / d);
+        let (t0, t1) = if t0 < t1 { (t0, t1) } else { (t1, t0) };
+        near = near.max(t0);
+        far = far.min(t1);
+        if near > far {
+            return None;
+        }
+    }
+    Some((near, far))
+}
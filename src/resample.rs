@@ -0,0 +1,123 @@
+use prelude::*;
+
+use super::float::Float;
+use super::lerp::Lerp;
+
+/// Interpolation selects how `resample` computes values in between the keys
+/// of a track.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Interpolation {
+    /// Linear interpolates linearly between the two surrounding keys.
+    Linear,
+
+    /// Cubic interpolates along a Catmull-Rom spline through the four
+    /// surrounding keys. The spline is parameterized by the key times, such
+    /// that unevenly spaced keys are handled correctly.
+    Cubic,
+}
+
+/// resample returns the given time-series `track` sampled at a fixed `rate`
+/// (samples per unit of time, e.g. Hz when the times are in seconds). The
+/// first sample is at the time of the first key, and the last sample is at or
+/// before the time of the last key.
+///
+/// The keys of the track must be sorted by time, and the rate must be
+/// positive.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Vec2, Interpolation, resample};
+///
+/// let track = [(0.0, Vec2(0.0, 0.0)), (1.0, Vec2(2.0, 4.0))];
+/// let r = resample(&track, 4.0, Interpolation::Linear);
+/// assert_eq!(r.len(), 5);
+/// assert_eq!(r[1], (0.25, Vec2(0.5, 1.0)));
+/// assert_eq!(r[4], (1.0, Vec2(2.0, 4.0)));
+/// ```
+///
+/// ```
+/// use fiz_math::{Float, Interpolation, resample};
+///
+/// // Catmull-Rom splines reproduce linear data exactly.
+/// let track = [(0.0, 0.0), (1.0, 1.0), (3.0, 3.0), (4.0, 4.0)];
+/// for &(t, v) in resample(&track, 10.0, Interpolation::Cubic).iter() {
+///     assert!(v.equal(t));
+/// }
+/// ```
+pub fn resample<T, V>(track: &[(T, V)], rate: T, interp: Interpolation) -> Vec<(T, V)>
+    where T: Float,
+          V: Lerp<T> + Copy
+{
+    assert!(rate > T::zero(), "resample: rate must be positive");
+
+    let mut out = Vec::new();
+    if track.is_empty() {
+        return out;
+    }
+    let start = track[0].0;
+    let end = track[track.len() - 1].0;
+
+    // i is the index of the last key at or before the current sample time.
+    let mut i = 0;
+    let mut n: usize = 0;
+    loop {
+        let t = start + T::from(n).unwrap() / rate;
+        if t > end {
+            break;
+        }
+        while i + 1 < track.len() && track[i + 1].0 <= t {
+            i += 1;
+        }
+        let v = if i + 1 == track.len() {
+            track[i].1
+        } else {
+            match interp {
+                Interpolation::Linear => linear(track, i, t),
+                Interpolation::Cubic => cubic(track, i, t),
+            }
+        };
+        out.push((t, v));
+        n += 1;
+    }
+    out
+}
+
+// linear interpolates between keys i and i+1 at time t.
+fn linear<T: Float, V: Lerp<T> + Copy>(track: &[(T, V)], i: usize, t: T) -> V {
+    let (t1, p1) = track[i];
+    let (t2, p2) = track[i + 1];
+    if t2 == t1 {
+        return p2;
+    }
+    p1.lerp(p2, (t - t1) / (t2 - t1))
+}
+
+// cubic interpolates between keys i and i+1 at time t using the Barry-Goldman
+// pyramidal formulation of a Catmull-Rom spline, which requires only linear
+// interpolation of the values. Missing end keys are extrapolated linearly from
+// the segment itself.
+fn cubic<T: Float, V: Lerp<T> + Copy>(track: &[(T, V)], i: usize, t: T) -> V {
+    let (t1, p1) = track[i];
+    let (t2, p2) = track[i + 1];
+    if t2 == t1 {
+        return p2;
+    }
+    let (t0, p0) = if i > 0 && track[i - 1].0 < t1 {
+        track[i - 1]
+    } else {
+        (t1 - (t2 - t1), p1.lerp(p2, -T::one()))
+    };
+    let (t3, p3) = if i + 2 < track.len() && track[i + 2].0 > t2 {
+        track[i + 2]
+    } else {
+        (t2 + (t2 - t1), p2.lerp(p1, -T::one()))
+    };
+
+    let a1 = p0.lerp(p1, (t - t0) / (t1 - t0));
+    let a2 = p1.lerp(p2, (t - t1) / (t2 - t1));
+    let a3 = p2.lerp(p3, (t - t2) / (t3 - t2));
+    let b1 = a1.lerp(a2, (t - t0) / (t2 - t0));
+    let b2 = a2.lerp(a3, (t - t1) / (t3 - t1));
+    b1.lerp(b2, (t - t1) / (t2 - t1))
+}
@@ -0,0 +1,594 @@
+use core::f64::consts::PI;
+use super::float::Float;
+use super::math;
+use super::{Vec2, Vec3};
+use super::basis3::orthonormal_basis;
+#[cfg(feature = "rand")]
+use super::mapping::concentric_square_to_disk;
+use super::unit::{Sr, ToM};
+
+#[cfg(feature = "rand")]
+use prelude::*;
+#[cfg(feature = "rand")]
+use rand::Rng;
+#[cfg(feature = "rand")]
+use rand::distributions::{Distribution, Standard};
+
+/// hg_phase evaluates the Henyey-Greenstein phase function, given the cosine of
+/// the angle between the direction that light is travelling in and the
+/// direction that it is scattered into, and the asymmetry parameter `g` in the
+/// range (-1, 1). Positive values of `g` favor forward scattering, negative
+/// values favor back scattering, and zero gives isotropic scattering.
+///
+/// # Examples
+///
+/// ```
+/// use std::f64::consts::PI;
+/// use fiz_math::Float;
+/// use fiz_math::sampling::hg_phase;
+///
+/// assert!(hg_phase(0.3, 0.0).equal(1.0 / (4.0 * PI)));
+/// assert!(hg_phase(1.0, 0.5) > hg_phase(-1.0, 0.5));
+/// ```
+pub fn hg_phase<T: Float>(cos_theta: T, g: T) -> T {
+    let one = T::one();
+    let denom = one + g * g - (g + g) * cos_theta;
+    (one - g * g) / (T::from(4.0 * PI).unwrap() * denom * math::sqrt(denom))
+}
+
+/// sample_hg_phase importance samples the Henyey-Greenstein phase function
+/// (see `hg_phase`) for light travelling in the unit direction `dir`, given a
+/// pair of uniformly distributed random numbers `u` in the range [0, 1). It
+/// returns the scattered direction and its probability density (with respect
+/// to solid angle), which is equal to the phase function itself.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Vec2, Vec3, Float};
+/// use fiz_math::sampling::{hg_phase, sample_hg_phase};
+///
+/// let dir = Vec3(0.0, 0.0, 1.0);
+/// let (w, pdf) = sample_hg_phase(dir, 0.7, Vec2(0.3, 0.8));
+/// assert!(w.length().equal(1.0));
+/// assert!(pdf.equal(hg_phase(w.dot(dir), 0.7)));
+///
+/// // The largest sample scatters straight ahead for forward scattering.
+/// let (w, _) = sample_hg_phase(dir, 0.7, Vec2(1.0, 0.0));
+/// assert!(w.almost_equal(dir, 1e-9));
+/// ```
+pub fn sample_hg_phase<T: Float>(dir: Vec3<T>, g: T, u: Vec2<T>) -> (Vec3<T>, T) {
+    let one = T::one();
+    let two = T::from(2).unwrap();
+    let cos_theta = if g.abs() < T::from(1e-3).unwrap() {
+        one - two * u.0
+    } else {
+        let s = (one - g * g) / (one - g + two * g * u.0);
+        (one + g * g - s * s) / (two * g)
+    };
+    let sin_theta = math::sqrt((one - cos_theta * cos_theta).max(T::zero()));
+    let (sin_phi, cos_phi) = math::sin_cos(T::from(2.0 * PI).unwrap() * u.1);
+    let (t, b) = orthonormal_basis(dir);
+    let w = t.mul_scalar(sin_theta * cos_phi) + b.mul_scalar(sin_theta * sin_phi) +
+            dir.mul_scalar(cos_theta);
+    (w, hg_phase(cos_theta, g))
+}
+
+/// sphere_solid_angle returns the solid angle subtended by the sphere with the
+/// given center and radius, as seen from the point `p`. If the point is inside
+/// the sphere, the entire sphere of directions (4π steradians) is returned.
+///
+/// # Examples
+///
+/// ```
+/// use std::f64::consts::PI;
+/// use fiz_math::{Vec3, Float};
+/// use fiz_math::unit::{M, Sr};
+/// use fiz_math::sampling::sphere_solid_angle;
+///
+/// let p = Vec3(0.0, 0.0, 0.0);
+/// let c = Vec3(0.0, 0.0, 2.0);
+/// let sr = sphere_solid_angle(p, c, M(2.0f64.sqrt()));
+/// assert!(sr.0.equal(2.0 * PI * (1.0 - 0.5f64.sqrt())));
+/// assert_eq!(sphere_solid_angle(c, c, M(1.0)), Sr(4.0 * PI));
+/// ```
+pub fn sphere_solid_angle<D, T>(p: Vec3<T>, center: Vec3<T>, radius: D) -> Sr<T>
+    where D: ToM<Output = T>,
+          T: Float
+{
+    let r = radius.to_m().0;
+    let dist_sq = (center - p).length_sq();
+    if dist_sq <= r * r {
+        return Sr(T::from(4.0 * PI).unwrap());
+    }
+    let cos_max = math::sqrt(T::one() - r * r / dist_sq);
+    Sr(T::from(2.0 * PI).unwrap() * (T::one() - cos_max))
+}
+
+/// rect_solid_angle returns the solid angle subtended by the rectangle with
+/// the given corner and the perpendicular edge vectors `ex` and `ey`, as seen
+/// from the point `p`. It uses the method of Ureña et al. (An Area-Preserving
+/// Parametrization for Spherical Rectangles).
+///
+/// # Examples
+///
+/// ```
+/// use std::f64::consts::PI;
+/// use fiz_math::{Vec3, Float};
+/// use fiz_math::sampling::{rect_solid_angle, triangle_solid_angle};
+///
+/// // A 2x2 square at a distance of one subtends 4 asin(1/2) steradians.
+/// let p = Vec3(0.0, 0.0, 0.0);
+/// let corner = Vec3(-1.0, -1.0, 1.0);
+/// let sr = rect_solid_angle(p, corner, Vec3(2.0, 0.0, 0.0), Vec3(0.0, 2.0, 0.0));
+/// assert!(sr.0.equal(2.0 * PI / 3.0));
+///
+/// // It matches the solid angle of the two triangles forming the rectangle.
+/// let p = Vec3(0.3, -0.7, 2.0);
+/// let (ex, ey) = (Vec3(2.0, 0.0, 1.0), Vec3(0.0, 1.5, 0.0));
+/// let sr = rect_solid_angle(p, corner, ex, ey);
+/// let tris = triangle_solid_angle(p, corner, corner + ex, corner + ex + ey).0 +
+///            triangle_solid_angle(p, corner, corner + ex + ey, corner + ey).0;
+/// assert!(sr.0.equal(tris));
+/// ```
+pub fn rect_solid_angle<T: Float>(p: Vec3<T>, corner: Vec3<T>, ex: Vec3<T>, ey: Vec3<T>) -> Sr<T> {
+    let (width, height) = (ex.length(), ey.length());
+    let x = ex.div_scalar(width);
+    let y = ey.div_scalar(height);
+    let z = x.cross(y);
+
+    // Express the rectangle in a local frame centered at p, with the
+    // rectangle lying in the plane z = z0 < 0.
+    let d = corner - p;
+    let z0 = -d.dot(z).abs();
+    let (x0, y0) = (d.dot(x), d.dot(y));
+    let (x1, y1) = (x0 + width, y0 + height);
+
+    // The normals of the planes through p and each edge of the rectangle.
+    let normal = |v: Vec3<T>| v.normalize().unwrap_or(v);
+    let n0 = normal(Vec3(T::zero(), z0, -y0));
+    let n1 = normal(Vec3(-z0, T::zero(), x1));
+    let n2 = normal(Vec3(T::zero(), -z0, y1));
+    let n3 = normal(Vec3(z0, T::zero(), -x0));
+
+    // The solid angle is the area of the spherical quad, which follows from
+    // its interior angles.
+    let angle = |a: Vec3<T>, b: Vec3<T>| math::acos((-a.dot(b)).max(-T::one()).min(T::one()));
+    let sum = angle(n0, n1) + angle(n1, n2) + angle(n2, n3) + angle(n3, n0);
+    Sr((sum - T::from(2.0 * PI).unwrap()).max(T::zero()))
+}
+
+/// triangle_solid_angle returns the solid angle subtended by the triangle with
+/// the vertices `a`, `b` and `c`, as seen from the point `p`. It uses the
+/// formula of Van Oosterom and Strackee (The Solid Angle of a Plane
+/// Triangle).
+///
+/// # Examples
+///
+/// ```
+/// use std::f64::consts::PI;
+/// use fiz_math::{Vec3, Float};
+/// use fiz_math::sampling::triangle_solid_angle;
+///
+/// // The triangle spanning an octant subtends an eighth of the sphere.
+/// let p = Vec3(0.0, 0.0, 0.0);
+/// let (a, b, c) = (Vec3(1.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0), Vec3(0.0, 0.0, 1.0));
+/// assert!(triangle_solid_angle(p, a, b, c).0.equal(PI / 2.0));
+/// ```
+pub fn triangle_solid_angle<T: Float>(p: Vec3<T>, a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Sr<T> {
+    let (a, b, c) = (a - p, b - p, c - p);
+    let (la, lb, lc) = (a.length(), b.length(), c.length());
+    let numer = a.dot(b.cross(c)).abs();
+    let denom = la * lb * lc + a.dot(b) * lc + a.dot(c) * lb + b.dot(c) * la;
+    let half = math::atan2(numer, denom);
+    Sr(half + half)
+}
+
+/// sample_unit_sphere returns a random direction, uniformly distributed over
+/// the surface of the unit sphere. Requires the `rand` feature.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rand;
+/// extern crate fiz_math;
+///
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use fiz_math::{Float, Vec3};
+/// use fiz_math::sampling::sample_unit_sphere;
+///
+/// # fn main() {
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let w: Vec3<f64> = sample_unit_sphere(&mut rng);
+/// assert!(w.length().almost_equal(1.0, 1e-12));
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn sample_unit_sphere<T, R>(rng: &mut R) -> Vec3<T>
+    where T: Float,
+          R: Rng + ?Sized,
+          Standard: Distribution<T>
+{
+    let one = T::one();
+    let z = one - T::from(2).unwrap() * rng.gen::<T>();
+    let r = math::sqrt((one - z * z).max(T::zero()));
+    let (sin_phi, cos_phi) = math::sin_cos(T::from(2.0 * PI).unwrap() * rng.gen::<T>());
+    Vec3(r * cos_phi, r * sin_phi, z)
+}
+
+/// sample_unit_hemisphere returns a random direction, uniformly distributed
+/// over the unit hemisphere around the unit vector `normal`. Requires the `rand`
+/// feature.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rand;
+/// extern crate fiz_math;
+///
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use fiz_math::{Float, Vec3};
+/// use fiz_math::sampling::sample_unit_hemisphere;
+///
+/// # fn main() {
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let n = Vec3(0.0, 1.0, 0.0);
+/// for _ in 0..100 {
+///     let w = sample_unit_hemisphere(n, &mut rng);
+///     assert!(w.length().almost_equal(1.0, 1e-12));
+///     assert!(w.dot(n) >= 0.0);
+/// }
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn sample_unit_hemisphere<T, R>(normal: Vec3<T>, rng: &mut R) -> Vec3<T>
+    where T: Float,
+          R: Rng + ?Sized,
+          Standard: Distribution<T>
+{
+    let w = sample_unit_sphere(rng);
+    if w.dot(normal) < T::zero() { -w } else { w }
+}
+
+/// sample_in_unit_sphere returns a random point, uniformly distributed over the
+/// volume of the unit sphere. Requires the `rand` feature.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rand;
+/// extern crate fiz_math;
+///
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use fiz_math::Vec3;
+/// use fiz_math::sampling::sample_in_unit_sphere;
+///
+/// # fn main() {
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let p: Vec3<f32> = sample_in_unit_sphere(&mut rng);
+/// assert!(p.length() <= 1.0);
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn sample_in_unit_sphere<T, R>(rng: &mut R) -> Vec3<T>
+    where T: Float,
+          R: Rng + ?Sized,
+          Standard: Distribution<T>
+{
+    let r = math::cbrt(rng.gen::<T>());
+    sample_unit_sphere(rng).mul_scalar(r)
+}
+
+/// sample_unit_disk returns a random point, uniformly distributed over the unit
+/// disk, using the concentric mapping (see `mapping::concentric_square_to_disk`)
+/// so that stratified or low discrepancy samples keep their structure. Requires
+/// the `rand` feature.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rand;
+/// extern crate fiz_math;
+///
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use fiz_math::Vec2;
+/// use fiz_math::sampling::sample_unit_disk;
+///
+/// # fn main() {
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let p: Vec2<f64> = sample_unit_disk(&mut rng);
+/// assert!(p.length() <= 1.0);
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn sample_unit_disk<T, R>(rng: &mut R) -> Vec2<T>
+    where T: Float,
+          R: Rng + ?Sized,
+          Standard: Distribution<T>
+{
+    concentric_square_to_disk(Vec2(rng.gen(), rng.gen()))
+}
+
+/// sample_annulus returns a random point, uniformly distributed over the
+/// annulus centered on the origin between the radii `inner` and `outer`. Like
+/// `sample_unit_disk`, it is based on the concentric mapping, with the radius
+/// of each point remapped to keep the distribution uniform. Requires the `rand`
+/// feature.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rand;
+/// extern crate fiz_math;
+///
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use fiz_math::Vec2;
+/// use fiz_math::sampling::sample_annulus;
+///
+/// # fn main() {
+/// let mut rng = StdRng::seed_from_u64(7);
+/// for _ in 0..100 {
+///     let p: Vec2<f64> = sample_annulus(0.5, 2.0, &mut rng);
+///     assert!(p.length() >= 0.5 - 1e-12 && p.length() <= 2.0 + 1e-12);
+/// }
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn sample_annulus<T, R>(inner: T, outer: T, rng: &mut R) -> Vec2<T>
+    where T: Float,
+          R: Rng + ?Sized,
+          Standard: Distribution<T>
+{
+    let p = sample_unit_disk(rng);
+    let r = p.length();
+    let radius = math::sqrt(inner * inner + r * r * (outer * outer - inner * inner));
+    if r == T::zero() {
+        Vec2(radius, T::zero())
+    } else {
+        p.mul_scalar(radius / r)
+    }
+}
+
+/// sample_cosine_hemisphere returns a random direction, distributed over the
+/// unit hemisphere around the unit vector `normal` in proportion to the cosine
+/// of its angle to the normal (by Malley's method), and its probability density
+/// (with respect to solid angle), cos θ / π. Requires the `rand` feature.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rand;
+/// extern crate fiz_math;
+///
+/// use std::f64::consts::PI;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use fiz_math::{Float, Vec3};
+/// use fiz_math::sampling::sample_cosine_hemisphere;
+///
+/// # fn main() {
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let n = Vec3(0.0, 0.6, 0.8);
+/// let (w, pdf) = sample_cosine_hemisphere(n, &mut rng);
+/// assert!(w.length().almost_equal(1.0, 1e-12));
+/// assert!(w.dot(n) >= 0.0);
+/// assert!(pdf.almost_equal(w.dot(n) / PI, 1e-12));
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn sample_cosine_hemisphere<T, R>(normal: Vec3<T>, rng: &mut R) -> (Vec3<T>, T)
+    where T: Float,
+          R: Rng + ?Sized,
+          Standard: Distribution<T>
+{
+    let p = sample_unit_disk(rng);
+    let cos_theta = math::sqrt((T::one() - p.0 * p.0 - p.1 * p.1).max(T::zero()));
+    let (t, b) = orthonormal_basis(normal);
+    let w = t.mul_scalar(p.0) + b.mul_scalar(p.1) + normal.mul_scalar(cos_theta);
+    (w, cos_theta / T::from(PI).unwrap())
+}
+
+// POISSON_ATTEMPTS is the number of candidates around an active point which
+// the Poisson disk samplers try before retiring the point.
+#[cfg(feature = "rand")]
+const POISSON_ATTEMPTS: usize = 30;
+
+// poisson_cell returns the index of the grid cell of size cell containing x,
+// clamped to the n cells of the grid.
+#[cfg(feature = "rand")]
+fn poisson_cell<T: Float>(x: T, cell: T, n: usize) -> usize {
+    (x / cell).to_usize().unwrap_or(0).min(n - 1)
+}
+
+/// poisson_disk_2d returns a random set of points in the rectangle from the
+/// origin to `extent`, no two of which are closer than `min_dist`, and with no
+/// room left for another (a blue noise distribution), using the algorithm of
+/// Bridson (Fast Poisson Disk Sampling in Arbitrary Dimensions). Requires the
+/// `rand` feature.
+///
+/// # Panics
+///
+/// Panics if `min_dist` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rand;
+/// extern crate fiz_math;
+///
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use fiz_math::Vec2;
+/// use fiz_math::sampling::poisson_disk_2d;
+///
+/// # fn main() {
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let points = poisson_disk_2d(Vec2(10.0, 5.0), 1.0, &mut rng);
+/// assert!(points.len() > 30);
+/// for (i, p) in points.iter().enumerate() {
+///     assert!(p.0 >= 0.0 && p.0 < 10.0 && p.1 >= 0.0 && p.1 < 5.0);
+///     assert!(points[i + 1..].iter().all(|q| (*q - *p).length() >= 1.0));
+/// }
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn poisson_disk_2d<T, R>(extent: Vec2<T>, min_dist: T, rng: &mut R) -> Vec<Vec2<T>>
+    where T: Float,
+          R: Rng + ?Sized,
+          Standard: Distribution<T>
+{
+    assert!(min_dist > T::zero(), "poisson_disk_2d: minimum distance must be positive");
+    let zero = T::zero();
+    if !(extent.0 > zero && extent.1 > zero) {
+        return Vec::new();
+    }
+    let min_dist_sq = min_dist * min_dist;
+    let cell = min_dist / math::sqrt(T::from(2).unwrap());
+    let nx = (extent.0 / cell).ceil().to_usize().unwrap().max(1);
+    let ny = (extent.1 / cell).ceil().to_usize().unwrap().max(1);
+    let mut grid: Vec<Option<usize>> = vec![None; nx * ny];
+    let mut points = vec![Vec2(rng.gen::<T>() * extent.0, rng.gen::<T>() * extent.1)];
+    let mut active = vec![0];
+    grid[poisson_cell(points[0].0, cell, nx) * ny + poisson_cell(points[0].1, cell, ny)] = Some(0);
+    let two = T::from(2).unwrap();
+    while !active.is_empty() {
+        let i = rng.gen_range(0..active.len());
+        let center = points[active[i]];
+        let mut found = false;
+        for _ in 0..POISSON_ATTEMPTS {
+            let q = center + sample_annulus(min_dist, two * min_dist, rng);
+            if q.0 < zero || q.0 >= extent.0 || q.1 < zero || q.1 >= extent.1 {
+                continue;
+            }
+            let (cx, cy) = (poisson_cell(q.0, cell, nx), poisson_cell(q.1, cell, ny));
+            let mut near = false;
+            'search: for x in cx.saturating_sub(2)..(cx + 3).min(nx) {
+                for y in cy.saturating_sub(2)..(cy + 3).min(ny) {
+                    if let Some(j) = grid[x * ny + y] {
+                        if (points[j] - q).length_sq() < min_dist_sq {
+                            near = true;
+                            break 'search;
+                        }
+                    }
+                }
+            }
+            if !near {
+                grid[cx * ny + cy] = Some(points.len());
+                active.push(points.len());
+                points.push(q);
+                found = true;
+                break;
+            }
+        }
+        if !found {
+            active.swap_remove(i);
+        }
+    }
+    points
+}
+
+/// poisson_disk_3d is the three dimensional version of `poisson_disk_2d`: it
+/// returns a random set of points in the box from the origin to `extent`, no two
+/// of which are closer than `min_dist`, and with no room left for another.
+/// Requires the `rand` feature.
+///
+/// # Panics
+///
+/// Panics if `min_dist` is not positive.
+///
+/// # Examples
+///
+/// ```
+/// extern crate rand;
+/// extern crate fiz_math;
+///
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use fiz_math::Vec3;
+/// use fiz_math::sampling::poisson_disk_3d;
+///
+/// # fn main() {
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let points = poisson_disk_3d(Vec3(4.0, 4.0, 4.0), 1.0, &mut rng);
+/// assert!(points.len() > 30);
+/// for (i, p) in points.iter().enumerate() {
+///     assert!(points[i + 1..].iter().all(|q| (*q - *p).length() >= 1.0));
+/// }
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn poisson_disk_3d<T, R>(extent: Vec3<T>, min_dist: T, rng: &mut R) -> Vec<Vec3<T>>
+    where T: Float,
+          R: Rng + ?Sized,
+          Standard: Distribution<T>
+{
+    assert!(min_dist > T::zero(), "poisson_disk_3d: minimum distance must be positive");
+    let zero = T::zero();
+    if !(extent.0 > zero && extent.1 > zero && extent.2 > zero) {
+        return Vec::new();
+    }
+    let min_dist_sq = min_dist * min_dist;
+    let cell = min_dist / math::sqrt(T::from(3).unwrap());
+    let nx = (extent.0 / cell).ceil().to_usize().unwrap().max(1);
+    let ny = (extent.1 / cell).ceil().to_usize().unwrap().max(1);
+    let nz = (extent.2 / cell).ceil().to_usize().unwrap().max(1);
+    let index = |p: Vec3<T>| {
+        let (cx, cy, cz) = (poisson_cell(p.0, cell, nx),
+                            poisson_cell(p.1, cell, ny),
+                            poisson_cell(p.2, cell, nz));
+        (cx, cy, cz)
+    };
+    let mut grid: Vec<Option<usize>> = vec![None; nx * ny * nz];
+    let mut points = vec![Vec3(rng.gen::<T>() * extent.0,
+                               rng.gen::<T>() * extent.1,
+                               rng.gen::<T>() * extent.2)];
+    let mut active = vec![0];
+    let (cx, cy, cz) = index(points[0]);
+    grid[(cx * ny + cy) * nz + cz] = Some(0);
+    let (r3, seven) = (min_dist_sq * min_dist, T::from(7).unwrap());
+    while !active.is_empty() {
+        let i = rng.gen_range(0..active.len());
+        let center = points[active[i]];
+        let mut found = false;
+        for _ in 0..POISSON_ATTEMPTS {
+            // A point uniformly distributed in the shell between min_dist and
+            // twice min_dist.
+            let r = math::cbrt(r3 + rng.gen::<T>() * seven * r3);
+            let q = center + sample_unit_sphere(rng).mul_scalar(r);
+            if q.0 < zero || q.0 >= extent.0 || q.1 < zero || q.1 >= extent.1 || q.2 < zero ||
+               q.2 >= extent.2 {
+                continue;
+            }
+            let (cx, cy, cz) = index(q);
+            let mut near = false;
+            'search: for x in cx.saturating_sub(2)..(cx + 3).min(nx) {
+                for y in cy.saturating_sub(2)..(cy + 3).min(ny) {
+                    for z in cz.saturating_sub(2)..(cz + 3).min(nz) {
+                        if let Some(j) = grid[(x * ny + y) * nz + z] {
+                            if (points[j] - q).length_sq() < min_dist_sq {
+                                near = true;
+                                break 'search;
+                            }
+                        }
+                    }
+                }
+            }
+            if !near {
+                grid[(cx * ny + cy) * nz + cz] = Some(points.len());
+                active.push(points.len());
+                points.push(q);
+                found = true;
+                break;
+            }
+        }
+        if !found {
+            active.swap_remove(i);
+        }
+    }
+    points
+}
@@ -0,0 +1,194 @@
+//! Signed distance functions (SDFs) of primitives, and combinators to build
+//! more complex shapes from them, e.g. for raymarching or procedural modeling.
+//! Each function returns the distance from the point `p` to the surface of the
+//! shape, which is negative inside of it. Primitives are centered on the
+//! origin; to place them elsewhere, transform `p` into their local frame.
+//!
+//! # Examples
+//!
+//! ```
+//! use fiz_math::{Float, Vec3};
+//! use fiz_math::sdf;
+//!
+//! // A box with a spherical bite taken out of its corner.
+//! let shape = |p: Vec3<f64>| {
+//!     sdf::subtraction(sdf::cuboid(p, Vec3(1.0, 1.0, 1.0)),
+//!                      sdf::sphere(p - Vec3(1.0, 1.0, 1.0), 0.5))
+//! };
+//! assert_eq!(shape(Vec3(0.0, 0.0, 0.0)), -1.0);
+//! assert!(shape(Vec3(0.9, 0.9, 0.9)).almost_equal(0.5 - 0.03f64.sqrt(), 1e-12));
+//!
+//! let n = sdf::normal(&shape, Vec3(0.0, 2.0, 0.0), 1e-6).unwrap();
+//! assert!(n.almost_equal(Vec3(0.0, 1.0, 0.0), 1e-6));
+//! ```
+
+use super::float::Float;
+use super::math;
+use super::{Plane, Vec3};
+
+// abs returns the componentwise absolute value of v.
+fn abs<T: Float>(v: Vec3<T>) -> Vec3<T> {
+    Vec3(v.0.abs(), v.1.abs(), v.2.abs())
+}
+
+/// sphere returns the signed distance to a sphere of the given radius.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::sdf;
+///
+/// assert_eq!(sdf::sphere(Vec3(0.0, 3.0, 4.0), 2.0), 3.0);
+/// assert_eq!(sdf::sphere(Vec3(0.0, 0.5, 0.0), 2.0), -1.5);
+/// ```
+pub fn sphere<T: Float>(p: Vec3<T>, radius: T) -> T {
+    p.length() - radius
+}
+
+/// cuboid returns the signed distance to a box with the given half extents.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::sdf;
+///
+/// let h = Vec3(1.0, 2.0, 3.0);
+/// assert_eq!(sdf::cuboid(Vec3(4.0, 6.0, 0.0), h), 5.0);
+/// assert_eq!(sdf::cuboid(Vec3(0.0, 1.5, 0.0), h), -0.5);
+/// ```
+pub fn cuboid<T: Float>(p: Vec3<T>, half_extents: Vec3<T>) -> T {
+    let q = abs(p) - half_extents;
+    let zero = T::zero();
+    q.max(Vec3(zero, zero, zero)).length() + q.0.max(q.1).max(q.2).min(zero)
+}
+
+/// rounded_cuboid returns the signed distance to a box with the given half
+/// extents whose edges and corners are rounded with the given radius. The
+/// rounding is inside of the box, such that the half extents are unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::sdf;
+///
+/// let h = Vec3(1.0, 1.0, 1.0);
+/// assert_eq!(sdf::rounded_cuboid(Vec3(3.0, 0.0, 0.0), h, 0.5), 2.0);
+///
+/// // The rounded corner is further away than the sharp corner.
+/// let p = Vec3(2.0, 2.0, 2.0);
+/// assert!(sdf::rounded_cuboid(p, h, 0.5) > sdf::cuboid(p, h));
+/// ```
+pub fn rounded_cuboid<T: Float>(p: Vec3<T>, half_extents: Vec3<T>, radius: T) -> T {
+    cuboid(p, half_extents.sub_scalar(radius)) - radius
+}
+
+/// capsule returns the signed distance to a capsule around the segment from
+/// a to b, with the given radius.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::sdf;
+///
+/// let (a, b) = (Vec3(0.0, 0.0, 0.0), Vec3(0.0, 4.0, 0.0));
+/// assert_eq!(sdf::capsule(Vec3(3.0, 2.0, 0.0), a, b, 1.0), 2.0);
+/// assert_eq!(sdf::capsule(Vec3(0.0, 7.0, 0.0), a, b, 1.0), 2.0);
+/// ```
+pub fn capsule<T: Float>(p: Vec3<T>, a: Vec3<T>, b: Vec3<T>, radius: T) -> T {
+    let (pa, ba) = (p - a, b - a);
+    let t = if ba.length_sq() == T::zero() {
+        T::zero()
+    } else {
+        (pa.dot(ba) / ba.length_sq()).max(T::zero()).min(T::one())
+    };
+    (pa - ba.mul_scalar(t)).length() - radius
+}
+
+/// torus returns the signed distance to a torus in the XZ plane (around the Y
+/// axis), with the given distance from its center to the center of its tube,
+/// and radius of its tube.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::sdf;
+///
+/// assert_eq!(sdf::torus(Vec3(0.0, 0.0, 0.0), 3.0, 1.0), 2.0);
+/// assert_eq!(sdf::torus(Vec3(3.0, 0.0, 0.0), 3.0, 1.0), -1.0);
+/// assert_eq!(sdf::torus(Vec3(0.0, 4.0, 3.0), 3.0, 1.0), 3.0);
+/// ```
+pub fn torus<T: Float>(p: Vec3<T>, major_radius: T, minor_radius: T) -> T {
+    let q = math::sqrt(p.0 * p.0 + p.2 * p.2) - major_radius;
+    math::sqrt(q * q + p.1 * p.1) - minor_radius
+}
+
+/// plane returns the signed distance to the given plane, which is positive on
+/// the side that its normal faces. The normal need not be normalized.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Plane, Vec3};
+/// use fiz_math::sdf;
+///
+/// let p = Plane::new(Vec3(0.0, 2.0, 0.0), -2.0);
+/// assert_eq!(sdf::plane(Vec3(5.0, 4.0, 5.0), &p), 3.0);
+/// ```
+pub fn plane<T: Float>(p: Vec3<T>, plane: &Plane<T>) -> T {
+    plane.signed_distance(p) / plane.normal.length()
+}
+
+/// union returns the signed distance to the union of two shapes, given the
+/// signed distance to each. Inside the shapes, the result is exact; outside,
+/// it is a lower bound.
+pub fn union<T: Float>(a: T, b: T) -> T {
+    a.min(b)
+}
+
+/// intersection returns the signed distance to the intersection of two
+/// shapes, given the signed distance to each. The result is a lower bound.
+pub fn intersection<T: Float>(a: T, b: T) -> T {
+    a.max(b)
+}
+
+/// subtraction returns the signed distance to the shape a with the shape b cut
+/// out of it, given the signed distance to each. The result is a lower bound.
+pub fn subtraction<T: Float>(a: T, b: T) -> T {
+    a.max(-b)
+}
+
+/// smooth_union returns the signed distance to the union of two shapes, given
+/// the signed distance to each, blended together where they are within about k
+/// of each other (using a polynomial smooth minimum).
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::sdf;
+///
+/// assert_eq!(sdf::smooth_union(1.0, 3.0, 0.5), 1.0);
+/// assert_eq!(sdf::smooth_union(1.0, 1.0, 0.5), 0.875);
+/// ```
+pub fn smooth_union<T: Float>(a: T, b: T, k: T) -> T {
+    let half = T::from(0.5).unwrap();
+    let h = (half + half * (b - a) / k).max(T::zero()).min(T::one());
+    b.lerp(a, h) - k * h * (T::one() - h)
+}
+
+/// normal estimates the unit normal of the surface of the shape with the
+/// signed distance function f at the point p, i.e. the normalized gradient of
+/// f, from central differences with the step eps. It returns None if the
+/// gradient is zero.
+pub fn normal<T, F>(f: &F, p: Vec3<T>, eps: T) -> Option<Vec3<T>>
+    where T: Float,
+          F: Fn(Vec3<T>) -> T
+{
+    let zero = T::zero();
+    let d = |e: Vec3<T>| f(p + e) - f(p - e);
+    Vec3(d(Vec3(eps, zero, zero)), d(Vec3(zero, eps, zero)), d(Vec3(zero, zero, eps))).normalize()
+}
@@ -0,0 +1,314 @@
+use super::float::Float;
+use super::{Vec2, Vec3};
+
+/// Segment2 is a line segment in two dimensions, between the points `a` and
+/// `b`.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Segment2, Vec2};
+///
+/// let s = Segment2::new(Vec2(0.0, 0.0), Vec2(2.0, 0.0));
+/// assert_eq!(s.length(), 2.0);
+/// assert_eq!(s.midpoint(), Vec2(1.0, 0.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Segment2<T> {
+    /// The start of the segment.
+    pub a: Vec2<T>,
+
+    /// The end of the segment.
+    pub b: Vec2<T>,
+}
+
+impl<T: Float> Segment2<T> {
+    /// new returns the segment between the points a and b.
+    pub fn new(a: Vec2<T>, b: Vec2<T>) -> Self {
+        Segment2 { a, b }
+    }
+
+    /// at returns the point at the parameter t along the segment, where zero
+    /// is `a` and one is `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Segment2, Vec2};
+    ///
+    /// let s = Segment2::new(Vec2(0.0, 0.0), Vec2(2.0, 0.0));
+    /// assert_eq!(s.at(0.25), Vec2(0.5, 0.0));
+    /// ```
+    pub fn at(&self, t: T) -> Vec2<T> {
+        self.a + (self.b - self.a).mul_scalar(t)
+    }
+
+    /// length returns the length of the segment.
+    pub fn length(&self) -> T {
+        (self.b - self.a).length()
+    }
+
+    /// length_sq returns the length of the segment squared.
+    pub fn length_sq(&self) -> T {
+        (self.b - self.a).length_sq()
+    }
+
+    /// midpoint returns the point half-way between the ends of the segment.
+    pub fn midpoint(&self) -> Vec2<T> {
+        self.at(T::from(0.5).unwrap())
+    }
+
+    /// closest_param returns the parameter (see `at`) of the point on the
+    /// segment closest to the given point. Degenerate segments (whose ends are
+    /// the same point) return zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Segment2, Vec2};
+    ///
+    /// let s = Segment2::new(Vec2(0.0, 0.0), Vec2(2.0, 0.0));
+    /// assert_eq!(s.closest_param(Vec2(0.5, 3.0)), 0.25);
+    /// assert_eq!(s.closest_param(Vec2(-1.0, 1.0)), 0.0);
+    /// ```
+    pub fn closest_param(&self, point: Vec2<T>) -> T {
+        let d = self.b - self.a;
+        let len_sq = d.length_sq();
+        if len_sq <= T::epsilon() {
+            return T::zero();
+        }
+        ((point - self.a).dot(d) / len_sq).max(T::zero()).min(T::one())
+    }
+
+    /// closest_point_to returns the point on the segment closest to the given
+    /// point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Segment2, Vec2};
+    ///
+    /// let s = Segment2::new(Vec2(0.0, 0.0), Vec2(2.0, 0.0));
+    /// assert_eq!(s.closest_point_to(Vec2(0.5, 3.0)), Vec2(0.5, 0.0));
+    /// assert_eq!(s.closest_point_to(Vec2(5.0, -1.0)), Vec2(2.0, 0.0));
+    /// ```
+    pub fn closest_point_to(&self, point: Vec2<T>) -> Vec2<T> {
+        self.at(self.closest_param(point))
+    }
+
+    /// distance_to_point returns the distance from the segment to the given
+    /// point.
+    pub fn distance_to_point(&self, point: Vec2<T>) -> T {
+        (point - self.closest_point_to(point)).length()
+    }
+
+    /// closest_points returns the closest pair of points between this segment
+    /// and the other, the first on this segment and the second on the other.
+    /// If there are several such pairs (e.g. for parallel segments), any one
+    /// of them is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Segment2, Vec2};
+    ///
+    /// let a = Segment2::new(Vec2(0.0, 0.0), Vec2(2.0, 0.0));
+    /// let b = Segment2::new(Vec2(3.0, 1.0), Vec2(3.0, 3.0));
+    /// assert_eq!(a.closest_points(&b), (Vec2(2.0, 0.0), Vec2(3.0, 1.0)));
+    /// assert_eq!(a.distance_to_segment(&b), 2.0f64.sqrt());
+    /// ```
+    pub fn closest_points(&self, other: &Self) -> (Vec2<T>, Vec2<T>) {
+        // From Ericson's Real-Time Collision Detection, section 5.1.9.
+        let (zero, one, eps) = (T::zero(), T::one(), T::epsilon());
+        let clamp = |x: T| x.max(zero).min(one);
+        let (d1, d2) = (self.b - self.a, other.b - other.a);
+        let r = self.a - other.a;
+        let (a, e, f) = (d1.length_sq(), d2.length_sq(), d2.dot(r));
+        let (s, t) = if a <= eps && e <= eps {
+            (zero, zero)
+        } else if a <= eps {
+            (zero, clamp(f / e))
+        } else {
+            let c = d1.dot(r);
+            if e <= eps {
+                (clamp(-c / a), zero)
+            } else {
+                let b = d1.dot(d2);
+                let denom = a * e - b * b;
+                let s = if denom != zero { clamp((b * f - c * e) / denom) } else { zero };
+                let t = (b * s + f) / e;
+                if t < zero {
+                    (clamp(-c / a), zero)
+                } else if t > one {
+                    (clamp((b - c) / a), one)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+        (self.at(s), other.at(t))
+    }
+
+    /// distance_to_segment returns the distance between this segment and the
+    /// other.
+    pub fn distance_to_segment(&self, other: &Self) -> T {
+        let (p, q) = self.closest_points(other);
+        (q - p).length()
+    }
+}
+
+/// Segment3 is a line segment in three dimensions, between the points `a` and
+/// `b`.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Segment3, Vec3};
+///
+/// let s = Segment3::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 0.0, 0.0));
+/// assert_eq!(s.length(), 2.0);
+/// assert_eq!(s.midpoint(), Vec3(1.0, 0.0, 0.0));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Segment3<T> {
+    /// The start of the segment.
+    pub a: Vec3<T>,
+
+    /// The end of the segment.
+    pub b: Vec3<T>,
+}
+
+impl<T: Float> Segment3<T> {
+    /// new returns the segment between the points a and b.
+    pub fn new(a: Vec3<T>, b: Vec3<T>) -> Self {
+        Segment3 { a, b }
+    }
+
+    /// at returns the point at the parameter t along the segment, where zero
+    /// is `a` and one is `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Segment3, Vec3};
+    ///
+    /// let s = Segment3::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 0.0, 0.0));
+    /// assert_eq!(s.at(0.25), Vec3(0.5, 0.0, 0.0));
+    /// ```
+    pub fn at(&self, t: T) -> Vec3<T> {
+        self.a + (self.b - self.a).mul_scalar(t)
+    }
+
+    /// length returns the length of the segment.
+    pub fn length(&self) -> T {
+        (self.b - self.a).length()
+    }
+
+    /// length_sq returns the length of the segment squared.
+    pub fn length_sq(&self) -> T {
+        (self.b - self.a).length_sq()
+    }
+
+    /// midpoint returns the point half-way between the ends of the segment.
+    pub fn midpoint(&self) -> Vec3<T> {
+        self.at(T::from(0.5).unwrap())
+    }
+
+    /// closest_param returns the parameter (see `at`) of the point on the
+    /// segment closest to the given point. Degenerate segments (whose ends are
+    /// the same point) return zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Segment3, Vec3};
+    ///
+    /// let s = Segment3::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 0.0, 0.0));
+    /// assert_eq!(s.closest_param(Vec3(0.5, 3.0, 4.0)), 0.25);
+    /// assert_eq!(s.closest_param(Vec3(-1.0, 1.0, 0.0)), 0.0);
+    /// ```
+    pub fn closest_param(&self, point: Vec3<T>) -> T {
+        let d = self.b - self.a;
+        let len_sq = d.length_sq();
+        if len_sq <= T::epsilon() {
+            return T::zero();
+        }
+        ((point - self.a).dot(d) / len_sq).max(T::zero()).min(T::one())
+    }
+
+    /// closest_point_to returns the point on the segment closest to the given
+    /// point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Segment3, Vec3};
+    ///
+    /// let s = Segment3::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 0.0, 0.0));
+    /// assert_eq!(s.closest_point_to(Vec3(0.5, 3.0, 4.0)), Vec3(0.5, 0.0, 0.0));
+    /// assert_eq!(s.closest_point_to(Vec3(5.0, -1.0, 0.0)), Vec3(2.0, 0.0, 0.0));
+    /// ```
+    pub fn closest_point_to(&self, point: Vec3<T>) -> Vec3<T> {
+        self.at(self.closest_param(point))
+    }
+
+    /// distance_to_point returns the distance from the segment to the given
+    /// point.
+    pub fn distance_to_point(&self, point: Vec3<T>) -> T {
+        (point - self.closest_point_to(point)).length()
+    }
+
+    /// closest_points returns the closest pair of points between this segment
+    /// and the other, the first on this segment and the second on the other.
+    /// If there are several such pairs (e.g. for parallel segments), any one
+    /// of them is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Segment3, Vec3};
+    ///
+    /// let a = Segment3::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 0.0, 0.0));
+    /// let b = Segment3::new(Vec3(1.0, -1.0, 3.0), Vec3(1.0, 1.0, 3.0));
+    /// assert_eq!(a.closest_points(&b), (Vec3(1.0, 0.0, 0.0), Vec3(1.0, 0.0, 3.0)));
+    /// assert_eq!(a.distance_to_segment(&b), 3.0);
+    /// ```
+    pub fn closest_points(&self, other: &Self) -> (Vec3<T>, Vec3<T>) {
+        // From Ericson's Real-Time Collision Detection, section 5.1.9.
+        let (zero, one, eps) = (T::zero(), T::one(), T::epsilon());
+        let clamp = |x: T| x.max(zero).min(one);
+        let (d1, d2) = (self.b - self.a, other.b - other.a);
+        let r = self.a - other.a;
+        let (a, e, f) = (d1.length_sq(), d2.length_sq(), d2.dot(r));
+        let (s, t) = if a <= eps && e <= eps {
+            (zero, zero)
+        } else if a <= eps {
+            (zero, clamp(f / e))
+        } else {
+            let c = d1.dot(r);
+            if e <= eps {
+                (clamp(-c / a), zero)
+            } else {
+                let b = d1.dot(d2);
+                let denom = a * e - b * b;
+                let s = if denom != zero { clamp((b * f - c * e) / denom) } else { zero };
+                let t = (b * s + f) / e;
+                if t < zero {
+                    (clamp(-c / a), zero)
+                } else if t > one {
+                    (clamp((b - c) / a), one)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+        (self.at(s), other.at(t))
+    }
+
+    /// distance_to_segment returns the distance between this segment and the
+    /// other.
+    pub fn distance_to_segment(&self, other: &Self) -> T {
+        let (p, q) = self.closest_points(other);
+        (q - p).length()
+    }
+}
@@ -0,0 +1,279 @@
+//! Low discrepancy (quasi-random) sequences, i.e. deterministic sequences of
+//! points in the unit square or cube [0, 1)ⁿ which cover it far more evenly
+//! than random points do, for sample placement and jitter patterns. Each
+//! sequence is a function from a point's index to the point, so any point can
+//! be computed directly, and `iter` turns a sequence into an iterator over its
+//! points in order.
+//!
+//! The sequences are Halton (using the prime bases 2, 3 and 5), Sobol (using
+//! the direction numbers of Joe and Kuo, in Gray code order) and the R2/R3
+//! sequences of Roberts (based on the generalized golden ratio).
+//!
+//! # Examples
+//!
+//! ```
+//! use fiz_math::{Float, Vec2};
+//! use fiz_math::sequences::{halton2, iter};
+//!
+//! // Sub-pixel jitter offsets in the range [-0.5, 0.5) for temporal AA.
+//! let jitter: Vec<Vec2<f32>> = iter(halton2)
+//!     .skip(1)
+//!     .take(8)
+//!     .map(|p| p - Vec2(0.5, 0.5))
+//!     .collect();
+//! assert!(jitter.iter().all(|j| j.0 >= -0.5 && j.0 < 0.5 && j.1 >= -0.5 && j.1 < 0.5));
+//! assert!(jitter[0].almost_equal(Vec2(0.0, -1.0 / 6.0), 1e-6));
+//! ```
+
+use super::float::Float;
+use super::{Vec2, Vec3};
+
+// SOBOL_DIM2 and SOBOL_DIM3 are the direction numbers of the second and third
+// Sobol dimensions (the first is the van der Corput sequence), generated from
+// the primitive polynomials and initial numbers of Joe and Kuo.
+const SOBOL_DIM2: [u32; 32] = [
+    0x80000000, 0xc0000000, 0xa0000000, 0xf0000000, 0x88000000, 0xcc000000, 0xaa000000,
+    0xff000000, 0x80800000, 0xc0c00000, 0xa0a00000, 0xf0f00000, 0x88880000, 0xcccc0000,
+    0xaaaa0000, 0xffff0000, 0x80008000, 0xc000c000, 0xa000a000, 0xf000f000, 0x88008800,
+    0xcc00cc00, 0xaa00aa00, 0xff00ff00, 0x80808080, 0xc0c0c0c0, 0xa0a0a0a0, 0xf0f0f0f0,
+    0x88888888, 0xcccccccc, 0xaaaaaaaa, 0xffffffff,
+];
+const SOBOL_DIM3: [u32; 32] = [
+    0x80000000, 0xc0000000, 0x60000000, 0x90000000, 0xe8000000, 0x5c000000, 0x8e000000,
+    0xc5000000, 0x68800000, 0x9cc00000, 0xee600000, 0x55900000, 0x80680000, 0xc09c0000,
+    0x60ee0000, 0x90550000, 0xe8808000, 0x5cc0c000, 0x8e606000, 0xc5909000, 0x6868e800,
+    0x9c9c5c00, 0xeeee8e00, 0x5555c500, 0x8000e880, 0xc0005cc0, 0x60008e60, 0x9000c590,
+    0xe8006868, 0x5c009c9c, 0x8e00eeee, 0xc5005555,
+];
+
+// R2 and R3 are the fractional parts of the powers of the reciprocals of the
+// generalized golden ratios for two and three dimensions, as 0.64 fixed point.
+const R2: [u64; 2] = [0xc13fa9a902a6328f, 0x91e10da5c79e7b1c];
+const R3: [u64; 3] = [0xd1b54a32d192ed03, 0xabc98388fb8fac02, 0x8cb92ba72f3d8dd7];
+
+// unit converts x in the range [0, 1] to T, rounding down to the largest value
+// less than one if it would otherwise round up to one.
+fn unit<T: Float>(x: f64) -> T {
+    T::from(x).unwrap().min(T::one() - T::epsilon() / T::from(2).unwrap())
+}
+
+// sobol returns the Sobol coordinate of the point at index with the given
+// direction numbers, as 0.32 fixed point.
+fn sobol(directions: &[u32; 32], index: u32) -> u32 {
+    let (mut gray, mut x, mut k) = (index ^ (index >> 1), 0, 0);
+    while gray != 0 {
+        if gray & 1 != 0 {
+            x ^= directions[k];
+        }
+        gray >>= 1;
+        k += 1;
+    }
+    x
+}
+
+// fixed32 converts x in 0.32 fixed point to T.
+fn fixed32<T: Float>(x: u32) -> T {
+    unit(x as f64 / 4294967296.0)
+}
+
+// fixed64 converts x in 0.64 fixed point to T.
+fn fixed64<T: Float>(x: u64) -> T {
+    unit(x as f64 / 18446744073709551616.0)
+}
+
+/// radical_inverse returns the radical inverse of `index` in `base`, i.e. the
+/// value in the range [0, 1) with the digits of `index` in that base mirrored
+/// about the radix point. It is the `index`th point of the van der Corput
+/// sequence in `base`, and the building block of the Halton sequences. `base`
+/// must be at least 2.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::sequences::radical_inverse;
+///
+/// assert_eq!(radical_inverse::<f64>(2, 6), 0.375);
+/// assert_eq!(radical_inverse::<f64>(3, 1), 1.0 / 3.0);
+/// assert_eq!(radical_inverse::<f64>(10, 1234), 0.4321);
+/// ```
+pub fn radical_inverse<T: Float>(base: u32, index: u32) -> T {
+    let base = base as u64;
+    let (mut index, mut reversed, mut denom) = (index as u64, 0, 1);
+    while index > 0 {
+        reversed = reversed * base + index % base;
+        denom *= base;
+        index /= base;
+    }
+    unit(reversed as f64 / denom as f64)
+}
+
+/// halton2 returns the point at `index` in the two dimensional Halton sequence,
+/// with the radical inverses in bases 2 and 3. The point at index zero is the
+/// origin, which is often skipped.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec2;
+/// use fiz_math::sequences::halton2;
+///
+/// assert_eq!(halton2::<f64>(0), Vec2(0.0, 0.0));
+/// assert_eq!(halton2::<f64>(1), Vec2(0.5, 1.0 / 3.0));
+/// assert_eq!(halton2::<f64>(2), Vec2(0.25, 2.0 / 3.0));
+/// ```
+pub fn halton2<T: Float>(index: u32) -> Vec2<T> {
+    Vec2(radical_inverse(2, index), radical_inverse(3, index))
+}
+
+/// halton3 returns the point at `index` in the three dimensional Halton
+/// sequence, with the radical inverses in bases 2, 3 and 5.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::sequences::halton3;
+///
+/// assert_eq!(halton3::<f64>(1), Vec3(0.5, 1.0 / 3.0, 0.2));
+/// assert_eq!(halton3::<f64>(5), Vec3(0.625, 7.0 / 9.0, 0.04));
+/// ```
+pub fn halton3<T: Float>(index: u32) -> Vec3<T> {
+    Vec3(radical_inverse(2, index),
+         radical_inverse(3, index),
+         radical_inverse(5, index))
+}
+
+/// sobol2 returns the point at `index` in the two dimensional Sobol sequence.
+/// The first 2ⁿ points form a (0, n, 2)-net: every box with area 2⁻ⁿ which
+/// tiles the unit square with power of two sides contains exactly one point.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec2;
+/// use fiz_math::sequences::sobol2;
+///
+/// assert_eq!(sobol2::<f64>(0), Vec2(0.0, 0.0));
+/// assert_eq!(sobol2::<f64>(1), Vec2(0.5, 0.5));
+/// assert_eq!(sobol2::<f64>(2), Vec2(0.75, 0.25));
+/// assert_eq!(sobol2::<f64>(3), Vec2(0.25, 0.75));
+/// ```
+pub fn sobol2<T: Float>(index: u32) -> Vec2<T> {
+    let gray = index ^ (index >> 1);
+    Vec2(fixed32(gray.reverse_bits()), fixed32(sobol(&SOBOL_DIM2, index)))
+}
+
+/// sobol3 returns the point at `index` in the three dimensional Sobol sequence,
+/// which extends `sobol2` with a third dimension.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::sequences::sobol3;
+///
+/// assert_eq!(sobol3::<f64>(1), Vec3(0.5, 0.5, 0.5));
+/// assert_eq!(sobol3::<f64>(4), Vec3(0.375, 0.375, 0.625));
+/// ```
+pub fn sobol3<T: Float>(index: u32) -> Vec3<T> {
+    let gray = index ^ (index >> 1);
+    Vec3(fixed32(gray.reverse_bits()),
+         fixed32(sobol(&SOBOL_DIM2, index)),
+         fixed32(sobol(&SOBOL_DIM3, index)))
+}
+
+/// r2 returns the point at `index` in Roberts' two dimensional R2 sequence, an
+/// additive recurrence based on the plastic number, offset by a half so that the
+/// point at index zero is the center of the square.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Float, Vec2};
+/// use fiz_math::sequences::r2;
+///
+/// assert_eq!(r2::<f64>(0), Vec2(0.5, 0.5));
+/// assert!(r2::<f64>(1).almost_equal(Vec2(0.2548776662466928, 0.06984029099805327), 1e-12));
+/// ```
+pub fn r2<T: Float>(index: u32) -> Vec2<T> {
+    let n = index as u64;
+    let half = 1u64 << 63;
+    Vec2(fixed64(half.wrapping_add(n.wrapping_mul(R2[0]))),
+         fixed64(half.wrapping_add(n.wrapping_mul(R2[1]))))
+}
+
+/// r3 returns the point at `index` in Roberts' three dimensional R3 sequence,
+/// which is like `r2` but based on the root of x⁴ = x + 1.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Float, Vec3};
+/// use fiz_math::sequences::r3;
+///
+/// assert_eq!(r3::<f64>(0), Vec3(0.5, 0.5, 0.5));
+/// let p = Vec3(0.31917251339616444, 0.1710436067037892, 0.04970047790197027);
+/// assert!(r3::<f64>(1).almost_equal(p, 1e-12));
+/// ```
+pub fn r3<T: Float>(index: u32) -> Vec3<T> {
+    let n = index as u64;
+    let half = 1u64 << 63;
+    Vec3(fixed64(half.wrapping_add(n.wrapping_mul(R3[0]))),
+         fixed64(half.wrapping_add(n.wrapping_mul(R3[1]))),
+         fixed64(half.wrapping_add(n.wrapping_mul(R3[2]))))
+}
+
+/// iter returns an iterator over the points of a sequence (e.g. `halton2` or
+/// `sobol3`), starting at index zero. The iterator is endless, so use `take` to
+/// limit it (its `nth` method skips ahead without computing the points in
+/// between).
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec2;
+/// use fiz_math::sequences::{iter, sobol2};
+///
+/// let points: Vec<Vec2<f64>> = iter(sobol2).take(4).collect();
+/// assert_eq!(points, vec![Vec2(0.0, 0.0), Vec2(0.5, 0.5), Vec2(0.75, 0.25), Vec2(0.25, 0.75)]);
+/// assert_eq!(iter(sobol2::<f64>).nth(2), Some(Vec2(0.75, 0.25)));
+/// ```
+pub fn iter<P>(sequence: fn(u32) -> P) -> Iter<P> {
+    Iter {
+        sequence,
+        index: 0,
+    }
+}
+
+/// Iter is an iterator over the points of a sequence (see `iter`).
+#[derive(Clone, Copy, Debug)]
+pub struct Iter<P> {
+    sequence: fn(u32) -> P,
+    index: u32,
+}
+
+impl<P> Iter<P> {
+    /// index returns the index of the next point to be returned.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+impl<P> Iterator for Iter<P> {
+    type Item = P;
+
+    fn next(&mut self) -> Option<P> {
+        let p = (self.sequence)(self.index);
+        self.index = self.index.wrapping_add(1);
+        Some(p)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<P> {
+        self.index = self.index.wrapping_add(n as u32);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
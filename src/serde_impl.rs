@@ -0,0 +1,273 @@
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::Error;
+
+use prelude::*;
+
+use super::float::Float;
+use super::lerp::Lerp;
+use super::vector::Vector;
+use super::{Aabb3, Basis3, BSpline, CatmullRom, Capsule, Color, CubicBezier, Cylinder, F16, Fixed,
+            FixedInt, Frustum, Hermite, Hit, Key, Obb, Plane, Polygon2, QuadraticBezier, Ray3,
+            Rect, Segment2, Segment3, Sphere, Track, Triangle3, Vec2, Vec3, Vec3A, Vec4};
+
+// Vectors are serialized as tuples, so that e.g. a Vec3<M<f64>> becomes
+// [1.0, 2.0, 3.0].
+
+impl<T: Serialize> Serialize for Vec2<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.0, &self.1).serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vec2<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (x, y) = Deserialize::deserialize(deserializer)?;
+        Ok(Vec2(x, y))
+    }
+}
+
+impl<T: Serialize> Serialize for Vec3<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.0, &self.1, &self.2).serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vec3<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (x, y, z) = Deserialize::deserialize(deserializer)?;
+        Ok(Vec3(x, y, z))
+    }
+}
+
+// Vec3A is serialized like a Vec3, without its padding.
+impl Serialize for Vec3A {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.0, &self.1, &self.2).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Vec3A {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (x, y, z) = Deserialize::deserialize(deserializer)?;
+        Ok(Vec3A::new(x, y, z))
+    }
+}
+
+impl<T: Serialize> Serialize for Vec4<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.0, &self.1, &self.2, &self.3).serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vec4<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (x, y, z, w) = Deserialize::deserialize(deserializer)?;
+        Ok(Vec4(x, y, z, w))
+    }
+}
+
+// F16 is serialized as its f32 value, as serialization formats have no half
+// precision numbers.
+impl Serialize for F16 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_f32().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for F16 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let x: f32 = Deserialize::deserialize(deserializer)?;
+        Ok(F16::from_f32(x))
+    }
+}
+
+// Fixed is serialized as its bits, so that it round-trips exactly.
+impl<I: FixedInt + Serialize, const FRAC: u32> Serialize for Fixed<I, FRAC> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_bits().serialize(serializer)
+    }
+}
+
+impl<'de, I: FixedInt + Deserialize<'de>, const FRAC: u32> Deserialize<'de> for Fixed<I, FRAC> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Fixed::from_bits(Deserialize::deserialize(deserializer)?))
+    }
+}
+
+// Basis3 is serialized as the nested arrays of its axes, i.e. the columns of
+// its rotation matrix (matching its column-major layout). It is not checked to
+// be orthonormal when deserialized.
+impl<T: Float + Serialize> Serialize for Basis3<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.x(), self.y(), self.z()).serialize(serializer)
+    }
+}
+
+impl<'de, T: Float + Deserialize<'de>> Deserialize<'de> for Basis3<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (x, y, z) = Deserialize::deserialize(deserializer)?;
+        Ok(Basis3::from_axes_unchecked(x, y, z))
+    }
+}
+
+// Obb is serialized as a tuple of its fields, like those below, but its
+// rotation needs T: Float.
+impl<T: Float + Serialize> Serialize for Obb<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.center, &self.half_extents, &self.rotation).serialize(serializer)
+    }
+}
+
+impl<'de, T: Float + Deserialize<'de>> Deserialize<'de> for Obb<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (center, half_extents, rotation) = Deserialize::deserialize(deserializer)?;
+        Ok(Obb {
+            center,
+            half_extents,
+            rotation,
+        })
+    }
+}
+
+// serde_fields implements Serialize and Deserialize for a struct whose fields
+// are all public, as a tuple of its fields in declaration order, e.g. a Ray3
+// becomes [[0.0, 0.0, 0.0], [0.0, 0.0, -1.0]].
+macro_rules! serde_fields {
+    ($($ty:ident<$t:ident> { $($field:ident),* };)*) => {$(
+        impl<$t: Serialize> Serialize for $ty<$t> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                ($(&self.$field),*).serialize(serializer)
+            }
+        }
+
+        impl<'de, $t: Deserialize<'de>> Deserialize<'de> for $ty<$t> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let ($($field),*) = Deserialize::deserialize(deserializer)?;
+                Ok($ty { $($field: $field),* })
+            }
+        }
+    )*}
+}
+
+serde_fields! {
+    Color<T> { r, g, b, a };
+    Ray3<T> { origin, dir };
+    Hit<T> { t, u, v };
+    Plane<T> { normal, d };
+    Aabb3<T> { min, max };
+    Rect<T> { min, max };
+    Segment2<T> { a, b };
+    Segment3<T> { a, b };
+    Sphere<T> { center, radius };
+    Capsule<T> { segment, radius };
+    Cylinder<T> { axis, radius };
+    Triangle3<T> { a, b, c };
+    QuadraticBezier<V> { p0, p1, p2 };
+    CubicBezier<V> { p0, p1, p2, p3 };
+    Hermite<V> { p0, m0, p1, m1 };
+}
+
+// Frustum and Polygon2 are serialized as their planes and vertices.
+impl<T: Serialize> Serialize for Frustum<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.planes.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Frustum<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Frustum { planes: Deserialize::deserialize(deserializer)? })
+    }
+}
+
+impl<T: Serialize> Serialize for Polygon2<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.vertices.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Polygon2<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Polygon2 { vertices: Deserialize::deserialize(deserializer)? })
+    }
+}
+
+impl<T: Serialize, V: Serialize> Serialize for Key<T, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.time, &self.value, &self.in_tangent, &self.out_tangent).serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, V: Deserialize<'de>> Deserialize<'de> for Key<T, V> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (time, value, in_tangent, out_tangent) = Deserialize::deserialize(deserializer)?;
+        Ok(Key {
+            time,
+            value,
+            in_tangent,
+            out_tangent,
+        })
+    }
+}
+
+// The splines and tracks are serialized as the arguments of their constructors,
+// which validate them when deserialized.
+
+impl<T, V> Serialize for CatmullRom<V>
+    where T: Float,
+          V: Vector<Scalar = T> + Lerp<T> + Serialize
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.points().serialize(serializer)
+    }
+}
+
+impl<'de, T, V> Deserialize<'de> for CatmullRom<V>
+    where T: Float,
+          V: Vector<Scalar = T> + Lerp<T> + Deserialize<'de>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let points: Vec<V> = Deserialize::deserialize(deserializer)?;
+        CatmullRom::new(&points)
+            .ok_or_else(|| D::Error::custom("CatmullRom: fewer than two distinct points"))
+    }
+}
+
+impl<T, V> Serialize for BSpline<V>
+    where T: Float,
+          V: Vector<Scalar = T> + Lerp<T> + Serialize
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.points(), self.degree()).serialize(serializer)
+    }
+}
+
+impl<'de, T, V> Deserialize<'de> for BSpline<V>
+    where T: Float,
+          V: Vector<Scalar = T> + Lerp<T> + Deserialize<'de>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (points, degree): (Vec<V>, usize) = Deserialize::deserialize(deserializer)?;
+        BSpline::new(&points, degree)
+            .ok_or_else(|| D::Error::custom("BSpline: degree not in 1..number of points"))
+    }
+}
+
+impl<T, V> Serialize for Track<V>
+    where T: Float + Serialize,
+          V: Vector<Scalar = T> + Serialize
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.keys().serialize(serializer)
+    }
+}
+
+impl<'de, T, V> Deserialize<'de> for Track<V>
+    where T: Float + Deserialize<'de>,
+          V: Vector<Scalar = T> + Deserialize<'de>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let keys: Vec<Key<T, V>> = Deserialize::deserialize(deserializer)?;
+        Track::new(keys).ok_or_else(|| D::Error::custom("Track: keys not in order of time"))
+    }
+}
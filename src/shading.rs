@@ -0,0 +1,172 @@
+use core::f64::consts::PI;
+use num::One;
+use super::float::Float;
+use super::math;
+use super::lerp::Lerp;
+use super::Vec3;
+
+/// triplanar_weights returns the blend weights of the X, Y and Z projections
+/// used in triplanar mapping for a surface with the given normal. The absolute
+/// normal components are raised to the power of `sharpness` (higher values
+/// give tighter transitions between the projections) and normalized to sum to
+/// one, matching the common shader formulation.
+///
+/// The normal need not be normalized. A zero normal gives equal weights.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::shading::triplanar_weights;
+///
+/// let w = triplanar_weights(Vec3(0.0, -2.0, 0.0), 4.0);
+/// assert_eq!(w, Vec3(0.0, 1.0, 0.0));
+///
+/// let w = triplanar_weights(Vec3(1.0, 1.0, 0.0), 1.0);
+/// assert_eq!(w, Vec3(0.5, 0.5, 0.0));
+///
+/// let w = triplanar_weights(Vec3(1.0, 2.0, 0.0), 2.0);
+/// assert!(w.almost_equal(Vec3(0.2, 0.8, 0.0), 1e-9));
+/// ```
+pub fn triplanar_weights<T: Float>(normal: Vec3<T>, sharpness: T) -> Vec3<T> {
+    let w = Vec3(math::powf(normal.0.abs(), sharpness),
+                 math::powf(normal.1.abs(), sharpness),
+                 math::powf(normal.2.abs(), sharpness));
+    let sum = w.0 + w.1 + w.2;
+    if sum == T::zero() {
+        let third = T::one() / T::from(3).unwrap();
+        return Vec3(third, third, third);
+    }
+    w.div_scalar(sum)
+}
+
+/// parallax_corrected_reflection returns the direction to sample a local
+/// reflection probe (i.e. a cubemap captured at `probe_pos`) with, such that
+/// the reflection ray cast from `world_pos` along `reflect_dir` is corrected
+/// for the probe's box-shaped proxy geometry, given by its `probe_min` and
+/// `probe_max` corners. This is the standard box projection used by local
+/// cubemaps; `world_pos` must lie inside of the box.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::shading::parallax_corrected_reflection;
+///
+/// let min = Vec3(-1.0, -1.0, -1.0);
+/// let max = Vec3(1.0, 1.0, 1.0);
+/// let center = Vec3(0.0, 0.0, 0.0);
+///
+/// // At the probe position, no correction occurs (other than scale).
+/// let dir = Vec3(0.0, 0.0, 1.0);
+/// let d = parallax_corrected_reflection(dir, center, min, max, center);
+/// assert_eq!(d, dir);
+///
+/// // Elsewhere, the direction points at where the ray hits the box.
+/// let p = Vec3(0.5, 0.0, 0.0);
+/// let d = parallax_corrected_reflection(dir, p, min, max, center);
+/// assert_eq!(d, Vec3(0.5, 0.0, 1.0));
+/// ```
+pub fn parallax_corrected_reflection<T: Float>(reflect_dir: Vec3<T>,
+                                               world_pos: Vec3<T>,
+                                               probe_min: Vec3<T>,
+                                               probe_max: Vec3<T>,
+                                               probe_pos: Vec3<T>)
+                                               -> Vec3<T> {
+    let first = (probe_max - world_pos) / reflect_dir;
+    let second = (probe_min - world_pos) / reflect_dir;
+    let furthest = first.max(second);
+    let dist = furthest.0.min(furthest.1).min(furthest.2);
+    world_pos + reflect_dir.mul_scalar(dist) - probe_pos
+}
+
+/// fresnel_schlick returns Schlick's approximation of the Fresnel reflectance
+/// given the cosine of the angle between the incident direction and the
+/// surface normal (or half vector), and the reflectance at normal incidence
+/// `f0`. The reflectance may be a scalar or e.g. an RGB vector.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Vec3;
+/// use fiz_math::shading::fresnel_schlick;
+///
+/// assert_eq!(fresnel_schlick(1.0, 0.04), 0.04);
+/// assert_eq!(fresnel_schlick(0.0, 0.04), 1.0);
+/// assert_eq!(fresnel_schlick(0.5, 0.0), 0.03125);
+///
+/// let f0 = Vec3(1.0, 0.71, 0.29);
+/// assert_eq!(fresnel_schlick(1.0, f0), f0);
+/// ```
+pub fn fresnel_schlick<T: Float, V: Lerp<T> + One>(cos_theta: T, f0: V) -> V {
+    f0.lerp(V::one(), math::powi(T::one() - cos_theta, 5))
+}
+
+/// ggx_distribution returns the GGX (Trowbridge-Reitz) normal distribution
+/// function for the cosine of the angle between the normal and the half
+/// vector, `n_dot_h`, and the roughness `alpha` (commonly the square of the
+/// artist-facing perceptual roughness).
+///
+/// # Examples
+///
+/// ```
+/// use std::f64::consts::PI;
+/// use fiz_math::Float;
+/// use fiz_math::shading::ggx_distribution;
+///
+/// assert!(ggx_distribution(1.0, 1.0).equal(1.0 / PI));
+/// assert!(ggx_distribution(1.0, 0.5).equal(4.0 / PI));
+/// assert!(ggx_distribution(0.0, 0.5).equal(0.25 / PI));
+/// ```
+pub fn ggx_distribution<T: Float>(n_dot_h: T, alpha: T) -> T {
+    let a2 = alpha * alpha;
+    let d = n_dot_h * n_dot_h * (a2 - T::one()) + T::one();
+    a2 / (T::from(PI).unwrap() * d * d)
+}
+
+/// smith_ggx_g1 returns the Smith masking function for the GGX distribution
+/// given the cosine of the angle between the normal and the view (or light)
+/// direction, and the roughness `alpha`.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Float;
+/// use fiz_math::shading::smith_ggx_g1;
+///
+/// assert_eq!(smith_ggx_g1(1.0, 0.5), 1.0);
+/// assert!(smith_ggx_g1(0.5, 1.0).equal(2.0 / 3.0));
+/// ```
+pub fn smith_ggx_g1<T: Float>(n_dot_v: T, alpha: T) -> T {
+    let a2 = alpha * alpha;
+    let two = T::from(2).unwrap();
+    two * n_dot_v / (n_dot_v + math::sqrt(a2 + (T::one() - a2) * n_dot_v * n_dot_v))
+}
+
+/// smith_ggx_visibility returns the height-correlated Smith visibility term
+/// for the GGX distribution, given the cosines of the angles between the
+/// normal and the view and light directions, and the roughness `alpha`. The
+/// visibility term is the geometric shadowing-masking term divided by the
+/// `4 * n_dot_l * n_dot_v` denominator of the microfacet BRDF, such that the
+/// specular BRDF is simply `D * V * F`.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::Float;
+/// use fiz_math::shading::{smith_ggx_g1, smith_ggx_visibility};
+///
+/// assert_eq!(smith_ggx_visibility(1.0, 1.0, 0.5), 0.25);
+/// assert!(smith_ggx_visibility(0.5, 0.8, 1.0).equal(0.5 / 1.3));
+///
+/// // Height-correlation shadows less than the separable form.
+/// let (v, l, a) = (0.5, 0.8, 0.3);
+/// let g = smith_ggx_g1(v, a) * smith_ggx_g1(l, a);
+/// assert!(smith_ggx_visibility(v, l, a) > g / (4.0 * v * l));
+/// ```
+pub fn smith_ggx_visibility<T: Float>(n_dot_v: T, n_dot_l: T, alpha: T) -> T {
+    let a2 = alpha * alpha;
+    let ggx_v = n_dot_l * math::sqrt(n_dot_v * n_dot_v * (T::one() - a2) + a2);
+    let ggx_l = n_dot_v * math::sqrt(n_dot_l * n_dot_l * (T::one() - a2) + a2);
+    T::from(0.5).unwrap() / (ggx_v + ggx_l)
+}
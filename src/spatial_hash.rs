@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use super::float::Float;
+use super::intersects::{aabb_aabb, sphere_aabb};
+use super::{Aabb3, Sphere, Vec3};
+
+/// SpatialHash is a broad phase container which stores values with bounding
+/// boxes in a uniform grid of cubic cells, hashed by their integer
+/// coordinates, such that the values near a region can be found quickly.
+/// Each value is stored in every cell its box overlaps, so the cell size
+/// should be comparable to the size of typical boxes.
+///
+/// Values are identified by the id returned when they are inserted, which
+/// stays valid until they are removed (after which it may be reused).
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Aabb3, SpatialHash, Sphere, Vec3};
+///
+/// let mut hash = SpatialHash::new(1.0);
+/// let a = hash.insert(Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.5, 0.5, 0.5)), "a");
+/// let b = hash.insert(Aabb3::new(Vec3(3.0, 0.0, 0.0), Vec3(3.5, 0.5, 0.5)), "b");
+///
+/// let region = Aabb3::new(Vec3(-1.0, -1.0, -1.0), Vec3(1.0, 1.0, 1.0));
+/// assert_eq!(hash.query_aabb(&region), vec![a]);
+/// assert_eq!(hash.query_sphere(&Sphere::new(Vec3(2.0, 0.25, 0.25), 1.0)), vec![b]);
+///
+/// assert_eq!(hash.remove(a), Some("a"));
+/// assert!(hash.query_aabb(&region).is_empty());
+/// assert_eq!(hash.get(b), Some(&"b"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct SpatialHash<T, F = f64> {
+    cell_size: F,
+    cells: HashMap<(i64, i64, i64), Vec<usize>>,
+    entries: Vec<Option<(Aabb3<F>, T)>>,
+    free: Vec<usize>,
+    len: usize,
+}
+
+impl<T, F: Float> SpatialHash<T, F> {
+    /// new returns an empty spatial hash with cells of the given size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell size is not positive.
+    pub fn new(cell_size: F) -> Self {
+        assert!(cell_size > F::zero(), "SpatialHash: cell size must be positive");
+        SpatialHash {
+            cell_size,
+            cells: HashMap::new(),
+            entries: Vec::new(),
+            free: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// len returns the number of values in this spatial hash.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// is_empty tells if this spatial hash holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // cell returns the coordinates of the cell containing the point.
+    fn cell(&self, p: Vec3<F>) -> (i64, i64, i64) {
+        let c = |x: F| (x / self.cell_size).floor().to_i64().unwrap_or(0);
+        (c(p.0), c(p.1), c(p.2))
+    }
+
+    // cells returns the coordinates of every cell that the box overlaps.
+    fn cells(&self, aabb: &Aabb3<F>) -> Vec<(i64, i64, i64)> {
+        let (lo, hi) = (self.cell(aabb.min), self.cell(aabb.max));
+        let mut out = Vec::new();
+        for x in lo.0..hi.0 + 1 {
+            for y in lo.1..hi.1 + 1 {
+                for z in lo.2..hi.2 + 1 {
+                    out.push((x, y, z));
+                }
+            }
+        }
+        out
+    }
+
+    /// insert adds the value with the given bounding box, returning its id.
+    pub fn insert(&mut self, aabb: Aabb3<F>, value: T) -> usize {
+        let id = match self.free.pop() {
+            Some(id) => {
+                self.entries[id] = Some((aabb, value));
+                id
+            }
+            None => {
+                self.entries.push(Some((aabb, value)));
+                self.entries.len() - 1
+            }
+        };
+        for cell in self.cells(&aabb) {
+            self.cells.entry(cell).or_default().push(id);
+        }
+        self.len += 1;
+        id
+    }
+
+    /// remove removes the value with the given id, returning it, or None if
+    /// there is no such value.
+    pub fn remove(&mut self, id: usize) -> Option<T> {
+        let (aabb, value) = self.entries.get_mut(id).and_then(|e| e.take())?;
+        for cell in self.cells(&aabb) {
+            let empty = match self.cells.get_mut(&cell) {
+                Some(ids) => {
+                    ids.retain(|&i| i != id);
+                    ids.is_empty()
+                }
+                None => false,
+            };
+            if empty {
+                self.cells.remove(&cell);
+            }
+        }
+        self.free.push(id);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// get returns the value with the given id, or None if there is no such
+    /// value.
+    pub fn get(&self, id: usize) -> Option<&T> {
+        self.entries.get(id).and_then(|e| e.as_ref()).map(|e| &e.1)
+    }
+
+    /// aabb returns the bounding box of the value with the given id, or None if
+    /// there is no such value.
+    pub fn aabb(&self, id: usize) -> Option<Aabb3<F>> {
+        self.entries.get(id).and_then(|e| e.as_ref()).map(|e| e.0)
+    }
+
+    // query returns the sorted ids of the values in the cells overlapping the
+    // region for whose boxes hit is true.
+    fn query<H: Fn(&Aabb3<F>) -> bool>(&self, region: &Aabb3<F>, hit: H) -> Vec<usize> {
+        let mut out = Vec::new();
+        for cell in self.cells(region) {
+            if let Some(ids) = self.cells.get(&cell) {
+                for &id in ids {
+                    if let Some((ref aabb, _)) = self.entries[id] {
+                        if hit(aabb) {
+                            out.push(id);
+                        }
+                    }
+                }
+            }
+        }
+        out.sort();
+        out.dedup();
+        out
+    }
+
+    /// query_aabb returns the ids of the values whose bounding boxes overlap
+    /// (or touch) the given box, in increasing order.
+    pub fn query_aabb(&self, aabb: &Aabb3<F>) -> Vec<usize> {
+        self.query(aabb, |b| aabb_aabb(b, aabb))
+    }
+
+    /// query_sphere returns the ids of the values whose bounding boxes overlap
+    /// (or touch) the given sphere, in increasing order.
+    pub fn query_sphere(&self, sphere: &Sphere<F>) -> Vec<usize> {
+        let r = Vec3(sphere.radius, sphere.radius, sphere.radius);
+        let region = Aabb3::new(sphere.center - r, sphere.center + r);
+        self.query(&region, |b| sphere_aabb(sphere, b))
+    }
+}
@@ -0,0 +1,274 @@
+use super::float::Float;
+use super::math;
+use super::{Aabb3, Vec3};
+
+/// Sphere is a solid sphere in three dimensions, given by its center and
+/// radius.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Sphere, Vec3};
+///
+/// let s = Sphere::new(Vec3(0.0, 0.0, 0.0), 2.0);
+/// assert!(s.contains_point(Vec3(1.0, 1.0, 1.0)));
+/// assert!(!s.contains_point(Vec3(2.0, 1.0, 0.0)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Sphere<T> {
+    /// The center of the sphere.
+    pub center: Vec3<T>,
+
+    /// The radius of the sphere.
+    pub radius: T,
+}
+
+impl<T: Float> Sphere<T> {
+    /// new returns the sphere with the given center and radius.
+    pub fn new(center: Vec3<T>, radius: T) -> Self {
+        Sphere {
+            center,
+            radius,
+        }
+    }
+
+    /// from_points returns a sphere containing all of the given points, or None
+    /// if there are none, using Ritter's algorithm. The sphere is found in
+    /// linear time and is usually within a few percent of the smallest such
+    /// sphere, see `from_points_welzl`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Sphere, Vec3};
+    ///
+    /// let points = [Vec3(-1.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.0), Vec3(0.0, 0.5, 0.5)];
+    /// let s = Sphere::from_points(&points).unwrap();
+    /// assert_eq!(s, Sphere::new(Vec3(0.0, 0.0, 0.0), 1.0));
+    /// assert_eq!(Sphere::<f64>::from_points(&[]), None);
+    /// ```
+    pub fn from_points(points: &[Vec3<T>]) -> Option<Self> {
+        let first = match points.first() {
+            Some(&p) => p,
+            None => return None,
+        };
+        let farthest = |from: Vec3<T>| {
+            points.iter().fold(from, |best, &p| {
+                if (p - from).length_sq() > (best - from).length_sq() { p } else { best }
+            })
+        };
+
+        // Start with the sphere through two points that are far apart, then
+        // grow it to include each point outside of it.
+        let a = farthest(first);
+        let b = farthest(a);
+        let two = T::from(2.0).unwrap();
+        let mut s = Sphere::new((a + b).div_scalar(two), (b - a).length() / two);
+        for &p in points {
+            let d = (p - s.center).length();
+            if d > s.radius {
+                let r = (s.radius + d) / two;
+                s.center = s.center + (p - s.center).mul_scalar((r - s.radius) / d);
+                s.radius = r;
+            }
+        }
+        Some(s)
+    }
+
+    /// from_points_welzl returns the smallest sphere containing all of the
+    /// given points, or None if there are none, using Welzl's algorithm. The
+    /// points are visited in a fixed pseudorandom order, so that it runs in
+    /// expected linear time whatever their order (e.g. sorted), while giving
+    /// the same result every time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Float, Sphere, Vec3};
+    ///
+    /// // The corners of a regular tetrahedron, and its center.
+    /// let points = [Vec3(1.0f64, 1.0, 1.0), Vec3(0.0, 0.0, 0.0), Vec3(1.0, -1.0, -1.0),
+    ///               Vec3(-1.0, 1.0, -1.0), Vec3(-1.0, -1.0, 1.0)];
+    /// let s = Sphere::from_points_welzl(&points).unwrap();
+    /// assert!(s.center.almost_equal(Vec3(0.0, 0.0, 0.0), 1e-12));
+    /// assert!(s.radius.almost_equal(3.0f64.sqrt(), 1e-12));
+    ///
+    /// // Ritter's algorithm finds a larger sphere for these points.
+    /// let points = [Vec3(0.0, 0.0, 0.0), Vec3(2.0, 0.0, 0.0), Vec3(1.0, 1.5, 0.0),
+    ///               Vec3(1.0, -0.5, 1.2)];
+    /// let exact = Sphere::from_points_welzl(&points).unwrap();
+    /// assert!(exact.radius < Sphere::from_points(&points).unwrap().radius);
+    /// assert!(points.iter().all(|&p| (p - exact.center).length() <= exact.radius + 1e-12));
+    /// ```
+    pub fn from_points_welzl(points: &[Vec3<T>]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+
+        // Shuffle with the SplitMix64 generator, as Welzl's algorithm takes
+        // quadratic time or worse for some orders of the points.
+        let mut state = 0u64;
+        let mut next = || {
+            state = state.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        };
+        let mut points = points.to_vec();
+        for i in (1..points.len()).rev() {
+            points.swap(i, (next() % (i as u64 + 1)) as usize);
+        }
+
+        // The iterative form of Welzl's algorithm: whenever a point is outside
+        // the sphere of the points before it, it must be on the boundary of
+        // the smallest sphere of those points and itself.
+        let mut s = Sphere::new(points[0], T::zero());
+        for i in 1..points.len() {
+            if welzl_contains(&s, points[i]) {
+                continue;
+            }
+            s = Sphere::new(points[i], T::zero());
+            for j in 0..i {
+                if welzl_contains(&s, points[j]) {
+                    continue;
+                }
+                s = sphere_from_2(points[i], points[j]);
+                for k in 0..j {
+                    if welzl_contains(&s, points[k]) {
+                        continue;
+                    }
+                    s = sphere_from_3(points[i], points[j], points[k]);
+                    for l in 0..k {
+                        if !welzl_contains(&s, points[l]) {
+                            s = sphere_from_4(points[i], points[j], points[k], points[l]);
+                        }
+                    }
+                }
+            }
+        }
+        Some(s)
+    }
+
+    /// contains_point tells if the given point is inside this sphere, or on
+    /// its surface.
+    pub fn contains_point(&self, point: Vec3<T>) -> bool {
+        (point - self.center).length_sq() <= self.radius * self.radius
+    }
+
+    /// closest_point returns the point inside this sphere (or on its surface)
+    /// closest to the given point, which is the point itself if it is inside.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Sphere, Vec3};
+    ///
+    /// let s = Sphere::new(Vec3(1.0, 0.0, 0.0), 2.0);
+    /// assert_eq!(s.closest_point(Vec3(1.0, 5.0, 0.0)), Vec3(1.0, 2.0, 0.0));
+    /// assert_eq!(s.closest_point(Vec3(1.0, 1.0, 0.0)), Vec3(1.0, 1.0, 0.0));
+    /// ```
+    pub fn closest_point(&self, point: Vec3<T>) -> Vec3<T> {
+        let d = point - self.center;
+        let len = d.length();
+        if len <= self.radius {
+            return point;
+        }
+        self.center + d.mul_scalar(self.radius / len)
+    }
+
+    /// intersects_sphere tells if this sphere and the other overlap (including
+    /// touching).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Sphere, Vec3};
+    ///
+    /// let a = Sphere::new(Vec3(0.0, 0.0, 0.0), 1.0);
+    /// assert!(a.intersects_sphere(&Sphere::new(Vec3(3.0, 0.0, 0.0), 2.0)));
+    /// assert!(!a.intersects_sphere(&Sphere::new(Vec3(3.0, 0.0, 0.0), 1.5)));
+    /// ```
+    pub fn intersects_sphere(&self, other: &Self) -> bool {
+        let r = self.radius + other.radius;
+        (other.center - self.center).length_sq() <= r * r
+    }
+
+    /// intersects_aabb tells if this sphere and the given axis-aligned box
+    /// overlap (including touching).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Aabb3, Sphere, Vec3};
+    ///
+    /// let b = Aabb3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0));
+    /// assert!(Sphere::new(Vec3(2.0, 2.0, 0.5), 1.5).intersects_aabb(&b));
+    /// assert!(!Sphere::new(Vec3(2.0, 2.0, 0.5), 1.4).intersects_aabb(&b));
+    /// ```
+    pub fn intersects_aabb(&self, aabb: &Aabb3<T>) -> bool {
+        self.contains_point(aabb.closest_point(self.center))
+    }
+}
+
+// welzl_contains tells if the point is inside the sphere, with a tolerance for
+// points on the boundary which were used to construct it.
+fn welzl_contains<T: Float>(s: &Sphere<T>, p: Vec3<T>) -> bool {
+    let tol = math::sqrt(T::epsilon()) * (T::one() + s.radius);
+    (p - s.center).length() <= s.radius + tol
+}
+
+// sphere_from_2 returns the smallest sphere through the two points.
+fn sphere_from_2<T: Float>(a: Vec3<T>, b: Vec3<T>) -> Sphere<T> {
+    let two = T::from(2.0).unwrap();
+    Sphere::new((a + b).div_scalar(two), (b - a).length() / two)
+}
+
+// sphere_from_3 returns the smallest sphere through the three points, which is
+// their circumsphere unless they are collinear.
+fn sphere_from_3<T: Float>(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Sphere<T> {
+    let (ab, ac) = (b - a, c - a);
+    let n = ab.cross(ac);
+    let denom = T::from(2.0).unwrap() * n.length_sq();
+    if denom <= T::epsilon() * ab.length_sq() * ac.length_sq() {
+        // Collinear: the sphere through the two points farthest apart.
+        let (ab, ac, bc) = (sphere_from_2(a, b), sphere_from_2(a, c), sphere_from_2(b, c));
+        return if ab.radius >= ac.radius && ab.radius >= bc.radius {
+            ab
+        } else if ac.radius >= bc.radius {
+            ac
+        } else {
+            bc
+        };
+    }
+    let offset = (n.cross(ab).mul_scalar(ac.length_sq()) +
+                  ac.cross(n).mul_scalar(ab.length_sq()))
+        .div_scalar(denom);
+    Sphere::new(a + offset, offset.length())
+}
+
+// sphere_from_4 returns the smallest sphere with the four points on its
+// boundary, which is their circumsphere unless they are coplanar.
+fn sphere_from_4<T: Float>(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>, d: Vec3<T>) -> Sphere<T> {
+    let (u, v, w) = (b - a, c - a, d - a);
+    let det = T::from(2.0).unwrap() * u.dot(v.cross(w));
+    let scale = u.length() * v.length() * w.length();
+    if det.abs() <= T::epsilon() * scale {
+        // Coplanar: the smallest sphere through three of the points that
+        // contains the fourth.
+        let candidates = [sphere_from_3(a, b, c), sphere_from_3(a, b, d),
+                          sphere_from_3(a, c, d), sphere_from_3(b, c, d)];
+        let mut best: Option<Sphere<T>> = None;
+        for s in candidates.iter() {
+            if [a, b, c, d].iter().all(|&p| welzl_contains(s, p)) &&
+               best.map_or(true, |b| s.radius < b.radius) {
+                best = Some(*s);
+            }
+        }
+        return best.unwrap_or(candidates[0]);
+    }
+    let offset = (v.cross(w).mul_scalar(u.length_sq()) + w.cross(u).mul_scalar(v.length_sq()) +
+                  u.cross(v).mul_scalar(w.length_sq()))
+        .div_scalar(det);
+    Sphere::new(a + offset, offset.length())
+}
@@ -0,0 +1,137 @@
+use core::cmp::Ordering;
+use prelude::*;
+
+use super::float::Float;
+use super::hermite::Hermite;
+use super::vector::Vector;
+
+/// Key is a keyframe of a `Track`, which has a value at a time, and the
+/// derivatives of the value with respect to time arriving at the key
+/// (`in_tangent`) and leaving it (`out_tangent`). Differing tangents allow
+/// sharp changes of direction.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Key<T, V> {
+    pub time: T,
+    pub value: V,
+    pub in_tangent: V,
+    pub out_tangent: V,
+}
+
+impl<T, V: Copy> Key<T, V> {
+    /// new returns a new key with the given time and value, whose value changes
+    /// smoothly through it with the given tangent.
+    pub fn new(time: T, value: V, tangent: V) -> Self {
+        Key {
+            time,
+            value,
+            in_tangent: tangent,
+            out_tangent: tangent,
+        }
+    }
+}
+
+/// Track is an animation curve, which interpolates between time-stamped keys
+/// with cubic Hermite segments. Before the first key and after the last key,
+/// the values of those keys are held.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Key, Track, Vec2};
+///
+/// let zero = Vec2(0.0, 0.0);
+/// let track = Track::new(vec![Key::new(0.0, Vec2(0.0, 0.0), zero),
+///                             Key::new(2.0, Vec2(4.0, 2.0), zero),
+///                             Key::new(3.0, Vec2(4.0, 2.0), zero)])
+///     .unwrap();
+/// assert_eq!(track.sample(-1.0), Vec2(0.0, 0.0));
+/// assert_eq!(track.sample(1.0), Vec2(2.0, 1.0));
+/// assert_eq!(track.sample(2.5), Vec2(4.0, 2.0));
+/// assert_eq!(track.sample(9.0), Vec2(4.0, 2.0));
+///
+/// // Keys must be in order of time.
+/// assert!(Track::new(vec![Key::new(1.0, zero, zero), Key::new(0.0, zero, zero)]).is_none());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Track<V: Vector> {
+    keys: Vec<Key<V::Scalar, V>>,
+}
+
+impl<T, V> Track<V>
+    where T: Float,
+          V: Vector<Scalar = T>
+{
+    /// new returns a track with the given keys, or None if there are no keys or
+    /// their times are not strictly increasing.
+    pub fn new(keys: Vec<Key<T, V>>) -> Option<Self> {
+        let increasing = |w: &[Key<T, V>]| {
+            w[0].time.partial_cmp(&w[1].time) == Some(Ordering::Less)
+        };
+        if keys.is_empty() || !keys.windows(2).all(increasing) {
+            return None;
+        }
+        Some(Track { keys })
+    }
+
+    /// keys returns the keys of this track.
+    pub fn keys(&self) -> &[Key<T, V>] {
+        &self.keys
+    }
+
+    /// start returns the time of the first key of this track.
+    pub fn start(&self) -> T {
+        self.keys[0].time
+    }
+
+    /// end returns the time of the last key of this track.
+    pub fn end(&self) -> T {
+        self.keys[self.keys.len() - 1].time
+    }
+
+    /// segment returns the Hermite segment between the keys `i` and `i + 1`,
+    /// with the tangents scaled to the duration between them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i + 1` is not the index of a key.
+    pub fn segment(&self, i: usize) -> Hermite<V> {
+        let (a, b) = (&self.keys[i], &self.keys[i + 1]);
+        let dt = b.time - a.time;
+        Hermite::new(a.value, a.out_tangent.mul_scalar(dt), b.value, b.in_tangent.mul_scalar(dt))
+    }
+
+    /// sample returns the value of this track at the given time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Key, Track};
+    ///
+    /// // A value rising at a constant rate of 2 per unit of time.
+    /// let track = Track::new(vec![Key::new(1.0, 0.0, 2.0), Key::new(3.0, 4.0, 2.0)]).unwrap();
+    /// assert_eq!(track.sample(1.5), 1.0);
+    /// assert_eq!(track.sample(2.0), 2.0);
+    /// ```
+    pub fn sample(&self, time: T) -> V {
+        let n = self.keys.len();
+        if time.partial_cmp(&self.keys[0].time) != Some(Ordering::Greater) {
+            return self.keys[0].value;
+        }
+        if time >= self.keys[n - 1].time {
+            return self.keys[n - 1].value;
+        }
+
+        // Find the last key at or before the time.
+        let (mut i, mut hi) = (0, n - 1);
+        while hi - i > 1 {
+            let mid = (i + hi) / 2;
+            if self.keys[mid].time <= time {
+                i = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let (t0, t1) = (self.keys[i].time, self.keys[i + 1].time);
+        self.segment(i).eval((time - t0) / (t1 - t0))
+    }
+}
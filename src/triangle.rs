@@ -0,0 +1,87 @@
+use super::float::Float;
+use super::Vec3;
+
+/// Triangle3 is a triangle in three dimensions, given by its three corners.
+/// Its front face is the one from which the corners appear in
+/// counter-clockwise order.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Triangle3, Vec3};
+///
+/// let t = Triangle3::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 0.0, 0.0), Vec3(0.0, 2.0, 0.0));
+/// assert_eq!(t.area(), 2.0);
+/// assert_eq!(t.normal(), Some(Vec3(0.0, 0.0, 1.0)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Triangle3<T> {
+    /// The first corner of the triangle.
+    pub a: Vec3<T>,
+
+    /// The second corner of the triangle.
+    pub b: Vec3<T>,
+
+    /// The third corner of the triangle.
+    pub c: Vec3<T>,
+}
+
+impl<T: Float> Triangle3<T> {
+    /// new returns the triangle with the given corners.
+    pub fn new(a: Vec3<T>, b: Vec3<T>, c: Vec3<T>) -> Self {
+        Triangle3 { a, b, c }
+    }
+
+    /// normal returns the unit normal of the front face of this triangle, or
+    /// None if the triangle is degenerate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Triangle3, Vec3};
+    ///
+    /// let t = Triangle3::new(Vec3(0.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0), Vec3(1.0, 0.0, 0.0));
+    /// assert_eq!(t.normal(), Some(Vec3(0.0, 0.0, -1.0)));
+    ///
+    /// let t = Triangle3::new(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0), Vec3(2.0, 2.0, 2.0));
+    /// assert_eq!(t.normal(), None);
+    /// ```
+    pub fn normal(&self) -> Option<Vec3<T>> {
+        (self.b - self.a).cross(self.c - self.a).normalize()
+    }
+
+    /// area returns the area of this triangle.
+    pub fn area(&self) -> T {
+        (self.b - self.a).cross(self.c - self.a).length() / T::from(2.0).unwrap()
+    }
+
+    /// centroid returns the center of mass of this triangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Triangle3, Vec3};
+    ///
+    /// let t = Triangle3::new(Vec3(0.0, 0.0, 0.0), Vec3(3.0, 0.0, 0.0), Vec3(0.0, 3.0, 3.0));
+    /// assert_eq!(t.centroid(), Vec3(1.0, 1.0, 1.0));
+    /// ```
+    pub fn centroid(&self) -> Vec3<T> {
+        (self.a + self.b + self.c).div_scalar(T::from(3.0).unwrap())
+    }
+
+    /// at returns the point with the barycentric coordinates (1 - u - v, u, v),
+    /// i.e. `a + (b - a) * u + (c - a) * v`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Triangle3, Vec3};
+    ///
+    /// let t = Triangle3::new(Vec3(0.0, 0.0, 0.0), Vec3(2.0, 0.0, 0.0), Vec3(0.0, 4.0, 0.0));
+    /// assert_eq!(t.at(0.0, 0.0), t.a);
+    /// assert_eq!(t.at(0.5, 0.25), Vec3(1.0, 1.0, 0.0));
+    /// ```
+    pub fn at(&self, u: T, v: T) -> Vec3<T> {
+        self.a + (self.b - self.a).mul_scalar(u) + (self.c - self.a).mul_scalar(v)
+    }
+}
@@ -0,0 +1,145 @@
+use num::{Num, NumCast};
+
+use super::kelvin::{Kelvin, ToKelvin};
+use super::fahrenheit::{Fahrenheit, ToFahrenheit};
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded, convert_unit};
+
+/// ToCelsius is the canonical trait to use for taking input in degrees Celsius.
+///
+/// For example the kelvins type (Kelvin) implements the ToCelsius trait and
+/// thus kelvins can be given as a parameter to any input that seeks
+/// degrees Celsius.
+///
+/// Unlike most units, temperature scales differ by an offset as well as a
+/// scale factor, so converting a temperature is not the same as converting a
+/// temperature difference.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::unit::{Celsius, Fahrenheit, ToCelsius};
+///
+/// let t: Fahrenheit<f64> = "-40°F".parse().unwrap();
+/// assert_eq!(t.to_celsius(), Celsius(-40.0));
+/// assert_eq!("21.5 degrees Celsius".parse(), Ok(Celsius(21.5)));
+/// ```
+pub trait ToCelsius{
+    type Output;
+
+    /// to_celsius returns these units in degrees Celsius, performing conversion if
+    /// needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_celsius).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Kelvin, ToCelsius};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn heat<T: ToCelsius<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_celsius().0)
+    /// }
+    /// heat(Kelvin(2.0));
+    /// heat(Kelvin::<i32>(2));
+    /// ```
+    fn to_celsius(self) -> Celsius<Self::Output>;
+
+    /// try_to_celsius is like to_celsius, except that it returns None if the
+    /// result is out of range for the value type, or if the value type is an
+    /// integer type and the result is not a whole number of degrees Celsius.
+    fn try_to_celsius(self) -> Option<Celsius<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToCelsius<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_celsius().0).map(Celsius),
+            None => None,
+        }
+    }
+
+    /// to_celsius_rounded is like to_celsius, except that if the value type is
+    /// an integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_celsius_rounded(self, mode: Rounding) -> Option<Celsius<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToCelsius<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_celsius().0, mode).map(Celsius),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// Celsius represents degrees Celsius (a temperature scale on which water freezes at
+    /// 0 and boils at 100 under standard pressure).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::Celsius;
+    ///
+    /// let x = Celsius(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    Celsius, "°C", "degrees Celsius"
+}
+
+impl<T: Num + NumCast> ToCelsius for Celsius<T> {
+    type Output = T;
+
+    /// to_celsius simply returns self.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Celsius, ToCelsius};
+    ///
+    /// assert_eq!(Celsius(1.0).to_celsius(), Celsius(1.0));
+    /// ```
+    fn to_celsius(self) -> Celsius<T> {
+        self
+    }
+}
+
+impl<T: Num + NumCast> ToKelvin for Celsius<T> {
+    type Output = T;
+
+    /// to_kelvin returns these degrees Celsius converted to kelvins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Celsius, Kelvin, ToKelvin};
+    ///
+    /// assert_eq!(Celsius(0.0).to_kelvin(), Kelvin(273.15));
+    /// ```
+    fn to_kelvin(self) -> Kelvin<T> {
+        Kelvin(convert_unit(self.0, |x| x + 273.15))
+    }
+}
+
+impl<T: Num + NumCast> ToFahrenheit for Celsius<T> {
+    type Output = T;
+
+    /// to_fahrenheit returns these degrees Celsius converted to degrees Fahrenheit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Celsius, Fahrenheit, ToFahrenheit};
+    ///
+    /// assert_eq!(Celsius(100.0).to_fahrenheit(), Fahrenheit(212.0));
+    /// ```
+    fn to_fahrenheit(self) -> Fahrenheit<T> {
+        Fahrenheit(convert_unit(self.0, |x| x * 9.0 / 5.0 + 32.0))
+    }
+}
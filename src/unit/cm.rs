@@ -1,8 +1,7 @@
-use num::traits::{Num, NumCast};
+use num::NumCast;
 
-use super::mm::{MM, ToMM};
-use super::m::{M, ToM};
-use super::km::{KM, ToKM};
+use super::cm2::CM2;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
 
 /// ToCM is the canonical trait to use for input in centimeters.
 ///
@@ -12,85 +11,78 @@ pub trait ToCM{
     type Output;
 
     /// to_cm returns these units in centimeters, performing conversion if needed.
-    fn to_cm(self) -> CM<Self::Output>;
-}
-
-/// CM represents centimeters (1/100th a meter).
-///
-/// # Examples
-///
-/// ```
-/// use fiz_math::unit::CM;
-///
-/// let x = CM(1.0);
-/// println!("{:?}", x);
-/// ```
-unit!(CM);
-
-impl<T: Num + NumCast> ToMM for CM<T> {
-    type Output = T;
-
-    /// to_mm returns these centimeters converted to millimeters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_cm).
     ///
     /// # Examples
     ///
     /// ```
-    /// use fiz_math::unit::{CM, MM, ToMM};
+    /// use fiz_math::unit::{MM, CM, M, KM, ToCM};
     ///
-    /// assert_eq!(CM(1.0).to_mm(), MM(10.0));
+    /// assert_eq!(MM(10.0).to_cm(), CM(1.0));
+    /// assert_eq!(CM(1.0).to_cm(), CM(1.0));
+    /// assert_eq!(M(1.0).to_cm(), CM(100.0));
+    /// assert_eq!(KM(1.0).to_cm(), CM(100000.0));
     /// ```
-    fn to_mm(self) -> MM<T> {
-        MM(self.0 * T::from(10).unwrap())
+    fn to_cm(self) -> CM<Self::Output>;
+
+    /// try_to_cm is like to_cm, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of centimeters.
+    fn try_to_cm(self) -> Option<CM<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToCM<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_cm().0).map(CM),
+            None => None,
+        }
     }
-}
 
-impl<T: Num + NumCast> ToCM for CM<T> {
-    type Output = T;
+    /// to_cm_rounded is like to_cm, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_cm_rounded(self, mode: Rounding) -> Option<CM<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToCM<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_cm().0, mode).map(CM),
+            None => None,
+        }
+    }
+}
 
-    /// to_cm simply returns self.
+unit! {
+    /// CM represents centimeters (1/100th a meter).
     ///
     /// # Examples
     ///
     /// ```
-    /// use fiz_math::unit::{CM, ToCM};
+    /// use fiz_math::unit::CM;
     ///
-    /// assert_eq!(CM(1.0).to_cm(), CM(1.0));
+    /// let x = CM(1.0);
+    /// println!("{:?}", x);
     /// ```
-    fn to_cm(self) -> CM<T> {
-        self
-    }
-}
-
-impl<T: Num + NumCast> ToM for CM<T> {
-    type Output = T;
-
-    /// to_m returns these centimeters converted to meters.
-    ///
-    /// # Examples
     ///
     /// ```
-    /// use fiz_math::unit::{CM, M, ToM};
+    /// use fiz_math::unit::CM;
     ///
-    /// assert_eq!(CM(100.0).to_m(), M(1.0));
+    /// assert_eq!(format!("{}", CM(4.0)), "4 cm");
+    /// assert_eq!(format!("{:#}", CM(4.0)), "4 centimeters");
     /// ```
-    fn to_m(self) -> M<T> {
-        M(self.0 / T::from(100).unwrap())
-    }
-}
-
-impl<T: Num + NumCast> ToKM for CM<T> {
-    type Output = T;
-
-    /// to_km returns these centimeters converted to kilometers.
     ///
-    /// # Examples
+    /// Multiplying centimeters by centimeters yields square centimeters:
     ///
     /// ```
-    /// use fiz_math::unit::{CM, KM, ToKM};
+    /// use fiz_math::unit::{CM, CM2};
     ///
-    /// assert_eq!(CM(100000.0).to_km(), KM(1.0));
+    /// assert_eq!(CM(2.0) * CM(3.0), CM2(6.0));
     /// ```
-    fn to_km(self) -> KM<T> {
-        KM(self.0 / T::from(100000).unwrap())
-    }
+    CM, "cm", "centimeters", squared = CM2
 }
@@ -0,0 +1,143 @@
+use num::NumCast;
+use core::ops::{Mul, Div};
+
+use super::cm::CM;
+use super::cm3::CM3;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToCM2 is the canonical trait to use for taking input in square centimeters.
+///
+/// For example the square meters type (M2) implements the ToCM2 trait and thus
+/// square meters can be given as a parameter to any input that seeks square
+/// centimeters.
+pub trait ToCM2{
+    type Output;
+
+    /// to_cm2 returns these units in square centimeters, performing conversion
+    /// if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_cm2).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{M2, ToCM2};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn paint<T: ToCM2<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_cm2().0)
+    /// }
+    /// paint(M2(2.0));
+    /// paint(M2::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM2, CM2, M2, KM2, ToCM2};
+    ///
+    /// assert_eq!(MM2(100.0).to_cm2(), CM2(1.0));
+    /// assert_eq!(CM2(1.0).to_cm2(), CM2(1.0));
+    /// assert_eq!(M2(1.0).to_cm2(), CM2(10000.0));
+    /// assert_eq!(KM2(1.0).to_cm2(), CM2(1.0e10));
+    /// ```
+    fn to_cm2(self) -> CM2<Self::Output>;
+
+    /// try_to_cm2 is like to_cm2, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of square centimeters.
+    fn try_to_cm2(self) -> Option<CM2<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToCM2<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_cm2().0).map(CM2),
+            None => None,
+        }
+    }
+
+    /// to_cm2_rounded is like to_cm2, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_cm2_rounded(self, mode: Rounding) -> Option<CM2<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToCM2<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_cm2().0, mode).map(CM2),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// CM2 represents square centimeters (the area of a 1x1 centimeter square).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::CM2;
+    ///
+    /// let x = CM2(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    CM2, "cm²", "square centimeters"
+}
+
+impl<T: Mul<Output = T>> Mul<CM<T>> for CM2<T> {
+    type Output = CM3<T>;
+
+    /// mul returns the volume of a prism of this base area and the given
+    /// height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{CM, CM2, CM3};
+    ///
+    /// assert_eq!(CM2(6.0) * CM(2.0), CM3(12.0));
+    /// ```
+    fn mul(self, rhs: CM<T>) -> CM3<T> {
+        CM3(self.0 * rhs.0)
+    }
+}
+
+impl<T: Mul<Output = T>> Mul<CM2<T>> for CM<T> {
+    type Output = CM3<T>;
+
+    /// mul returns the volume of a prism of this height and the given base
+    /// area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{CM, CM2, CM3};
+    ///
+    /// assert_eq!(CM(2.0) * CM2(6.0), CM3(12.0));
+    /// ```
+    fn mul(self, rhs: CM2<T>) -> CM3<T> {
+        CM3(self.0 * rhs.0)
+    }
+}
+
+impl<T: Div<Output = T>> Div<CM<T>> for CM2<T> {
+    type Output = CM<T>;
+
+    /// div returns the length of the other side of a rectangle of this area
+    /// with one side of the given length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{CM, CM2};
+    ///
+    /// assert_eq!(CM2(6.0) / CM(2.0), CM(3.0));
+    /// ```
+    fn div(self, rhs: CM<T>) -> CM<T> {
+        CM(self.0 / rhs.0)
+    }
+}
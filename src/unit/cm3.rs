@@ -0,0 +1,125 @@
+use num::NumCast;
+use core::ops::Div;
+
+use super::cm::CM;
+use super::cm2::CM2;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToCM3 is the canonical trait to use for taking input in cubic centimeters.
+///
+/// For example the cubic meters type (M3) implements the ToCM3 trait and thus
+/// cubic meters can be given as a parameter to any input that seeks cubic
+/// centimeters.
+pub trait ToCM3{
+    type Output;
+
+    /// to_cm3 returns these units in cubic centimeters, performing conversion
+    /// if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_cm3).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{M3, ToCM3};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn fill<T: ToCM3<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_cm3().0)
+    /// }
+    /// fill(M3(2.0));
+    /// fill(M3::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM3, CM3, M3, KM3, ToCM3};
+    ///
+    /// assert_eq!(MM3(1000.0).to_cm3(), CM3(1.0));
+    /// assert_eq!(CM3(1.0).to_cm3(), CM3(1.0));
+    /// assert_eq!(M3(1.0).to_cm3(), CM3(1000000.0));
+    /// assert_eq!(KM3(1.0).to_cm3(), CM3(1.0e15));
+    /// ```
+    fn to_cm3(self) -> CM3<Self::Output>;
+
+    /// try_to_cm3 is like to_cm3, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of cubic centimeters.
+    fn try_to_cm3(self) -> Option<CM3<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToCM3<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_cm3().0).map(CM3),
+            None => None,
+        }
+    }
+
+    /// to_cm3_rounded is like to_cm3, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_cm3_rounded(self, mode: Rounding) -> Option<CM3<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToCM3<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_cm3().0, mode).map(CM3),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// CM3 represents cubic centimeters (the volume of a 1x1x1 centimeter cube).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::CM3;
+    ///
+    /// let x = CM3(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    CM3, "cm³", "cubic centimeters"
+}
+
+impl<T: Div<Output = T>> Div<CM<T>> for CM3<T> {
+    type Output = CM2<T>;
+
+    /// div returns the base area of a prism of this volume and the given
+    /// height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{CM, CM2, CM3};
+    ///
+    /// assert_eq!(CM3(12.0) / CM(2.0), CM2(6.0));
+    /// ```
+    fn div(self, rhs: CM<T>) -> CM2<T> {
+        CM2(self.0 / rhs.0)
+    }
+}
+
+impl<T: Div<Output = T>> Div<CM2<T>> for CM3<T> {
+    type Output = CM<T>;
+
+    /// div returns the height of a prism of this volume and the given base
+    /// area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{CM, CM2, CM3};
+    ///
+    /// assert_eq!(CM3(12.0) / CM2(6.0), CM(2.0));
+    /// ```
+    fn div(self, rhs: CM2<T>) -> CM<T> {
+        CM(self.0 / rhs.0)
+    }
+}
@@ -1,7 +1,8 @@
-use num::traits::{Num, NumCast};
-use std::f64;
+use num::{Num, NumCast};
+use core::f64;
 
 use super::rad::{Rad, ToRad};
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
 
 /// ToDeg is the canonical trait to use for taking input in degrees.
 ///
@@ -12,6 +13,11 @@ pub trait ToDeg{
 
     /// to_deg returns these units in degrees, performing conversion if needed.
     ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_deg).
+    ///
     /// # Examples
     ///
     /// ```
@@ -26,20 +32,57 @@ pub trait ToDeg{
     /// walk(Rad::<i16>(2));
     /// ```
     fn to_deg(self) -> Deg<Self::Output>;
+
+    /// try_to_deg is like to_deg, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of degrees.
+    fn try_to_deg(self) -> Option<Deg<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToDeg<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_deg().0).map(Deg),
+            None => None,
+        }
+    }
+
+    /// to_deg_rounded is like to_deg, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_deg_rounded(self, mode: Rounding) -> Option<Deg<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToDeg<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_deg().0, mode).map(Deg),
+            None => None,
+        }
+    }
 }
 
-/// Deg represents degrees (a measurement of plane angle, representing 1/360th a
-// full rotation).
-///
-/// # Examples
-///
-/// ```
-/// use fiz_math::unit::Deg;
-///
-/// let x = Deg(1.0);
-/// println!("{:?}", x);
-/// ```
-unit!(Deg);
+unit! {
+    /// Deg represents degrees (a measurement of plane angle, representing 1/360th
+    /// a full rotation).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::Deg;
+    ///
+    /// let x = Deg(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::Deg;
+    ///
+    /// assert_eq!(format!("{}", Deg(90)), "90 deg");
+    /// assert_eq!(format!("{:#}", Deg(90)), "90 degrees");
+    /// ```
+    Deg, "deg", "degrees"
+}
 
 impl<T: Num + NumCast> ToDeg for Deg<T> {
     type Output = T;
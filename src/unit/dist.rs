@@ -0,0 +1,220 @@
+use num::{Num, NumCast};
+use core::str::FromStr;
+use prelude::*;
+use super::super::{math, Float, Vec2, Vec3, Vec4};
+
+use super::error::{ParseUnitError, split_unit};
+use super::mm::{MM, ToMM};
+use super::cm::{CM, ToCM};
+use super::m::{M, ToM};
+use super::km::{KM, ToKM};
+use super::inch::{IN, ToIN};
+
+// The conversions between each pair of distance units.
+units! {
+    MM: ToMM::to_mm = 0.001 m,
+    CM: ToCM::to_cm = 0.01 m,
+    M: ToM::to_m = 1 m,
+    KM: ToKM::to_km = 1000 m,
+    IN: ToIN::to_in = 0.0254 m,
+}
+
+/// Dist is a distance in any of the distance units, as returned by `parse`.
+/// It implements each of the distance conversion traits, so it can be given to
+/// any input that seeks e.g. meters.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::unit::{Dist, MM, M, ToM};
+///
+/// let d = Dist::MM(MM(1500.0));
+/// assert_eq!(d.to_m(), M(1.5));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Dist<T> {
+    MM(MM<T>),
+    CM(CM<T>),
+    M(M<T>),
+    KM(KM<T>),
+    IN(IN<T>),
+}
+
+/// parse parses a unit-suffixed distance (e.g. `"12.5mm"`, `"3 km"` or
+/// `"1.5 meters"`) from a string, such as those found in configuration files
+/// or command-line arguments.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::unit::{dist, Dist, CM, KM, M, ToM};
+///
+/// assert_eq!(dist::parse("12.5cm"), Ok(Dist::CM(CM(12.5))));
+/// assert_eq!(dist::parse::<i32>("3 kilometers"), Ok(Dist::KM(KM(3))));
+/// assert_eq!(dist::parse("250 mm").unwrap().to_m(), M(0.25));
+/// assert_eq!(dist::parse("2 in").unwrap().to_m(), M(0.0508));
+/// assert!(dist::parse::<f64>("12.5 parsecs").is_err());
+///
+/// use fiz_math::unit::ParseUnitError;
+/// assert_eq!(dist::parse::<f64>("twelve m"),
+///            Err(ParseUnitError::InvalidValue("twelve".to_string())));
+/// ```
+pub fn parse<T: FromStr>(s: &str) -> Result<Dist<T>, ParseUnitError> {
+    // Each unit rejects suffixes other than its own, so the only error worth
+    // reporting over an unknown unit is an invalid value for a known one.
+    let mut err = ParseUnitError::UnknownUnit(split_unit(s).1.to_string());
+    match s.parse() {
+        Ok(x) => return Ok(Dist::MM(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    match s.parse() {
+        Ok(x) => return Ok(Dist::CM(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    match s.parse() {
+        Ok(x) => return Ok(Dist::M(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    match s.parse() {
+        Ok(x) => return Ok(Dist::KM(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    match s.parse() {
+        Ok(x) => return Ok(Dist::IN(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    Err(err)
+}
+
+impl<T: FromStr> FromStr for Dist<T> {
+    type Err = ParseUnitError;
+
+    /// from_str is short-hand for `dist::parse`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Dist, M};
+    ///
+    /// assert_eq!("2 m".parse(), Ok(Dist::M(M(2.0))));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+impl<T: Num + NumCast> ToMM for Dist<T> {
+    type Output = T;
+
+    fn to_mm(self) -> MM<T> {
+        match self {
+            Dist::MM(x) => x.to_mm(),
+            Dist::CM(x) => x.to_mm(),
+            Dist::M(x) => x.to_mm(),
+            Dist::KM(x) => x.to_mm(),
+            Dist::IN(x) => x.to_mm(),
+        }
+    }
+}
+
+impl<T: Num + NumCast> ToCM for Dist<T> {
+    type Output = T;
+
+    fn to_cm(self) -> CM<T> {
+        match self {
+            Dist::MM(x) => x.to_cm(),
+            Dist::CM(x) => x.to_cm(),
+            Dist::M(x) => x.to_cm(),
+            Dist::KM(x) => x.to_cm(),
+            Dist::IN(x) => x.to_cm(),
+        }
+    }
+}
+
+impl<T: Num + NumCast> ToM for Dist<T> {
+    type Output = T;
+
+    fn to_m(self) -> M<T> {
+        match self {
+            Dist::MM(x) => x.to_m(),
+            Dist::CM(x) => x.to_m(),
+            Dist::M(x) => x.to_m(),
+            Dist::KM(x) => x.to_m(),
+            Dist::IN(x) => x.to_m(),
+        }
+    }
+}
+
+impl<T: Num + NumCast> ToKM for Dist<T> {
+    type Output = T;
+
+    fn to_km(self) -> KM<T> {
+        match self {
+            Dist::MM(x) => x.to_km(),
+            Dist::CM(x) => x.to_km(),
+            Dist::M(x) => x.to_km(),
+            Dist::KM(x) => x.to_km(),
+            Dist::IN(x) => x.to_km(),
+        }
+    }
+}
+
+impl<T: Num + NumCast> ToIN for Dist<T> {
+    type Output = T;
+
+    fn to_in(self) -> IN<T> {
+        match self {
+            Dist::MM(x) => x.to_in(),
+            Dist::CM(x) => x.to_in(),
+            Dist::M(x) => x.to_in(),
+            Dist::KM(x) => x.to_in(),
+            Dist::IN(x) => x.to_in(),
+        }
+    }
+}
+
+// Distances multiply to areas rather than to distances, so they do not
+// implement `Float`, and the vector methods which need it are implemented for
+// vectors of each distance unit instead. Dot products and squared lengths are
+// already given in the corresponding unit of area by the generic methods.
+macro_rules! impl_dist_vectors {
+    ($dist:ident) => {
+        impl_dist_vectors!($dist, Vec2, 0 1);
+        impl_dist_vectors!($dist, Vec3, 0 1 2);
+        impl_dist_vectors!($dist, Vec4, 0 1 2 3);
+    };
+
+    ($dist:ident, $vec:ident, $($i:tt)+) => {
+        impl<T: Float> $vec<$dist<T>> {
+            /// almost_equal tells if this vector is equal to the other given an
+            /// absolute tolerence value.
+            pub fn almost_equal<N: Float>(self, other: Self, abs_tol: N) -> bool {
+                $vec($((self.$i).0),+).almost_equal($vec($((other.$i).0),+), abs_tol)
+            }
+
+            /// length returns the magnitude of this vector, in the same unit as
+            /// its components.
+            pub fn length(self) -> $dist<T> {
+                $dist(math::sqrt(self.length_sq().0))
+            }
+
+            /// normalize returns the direction of this vector, i.e. the unitless
+            /// vector of length one pointing in the same direction. If the
+            /// vector's length is zero, then None is returned.
+            pub fn normalize(self) -> Option<$vec<T>> {
+                $vec($((self.$i).0),+).normalize()
+            }
+        }
+    };
+}
+
+impl_dist_vectors!(MM);
+impl_dist_vectors!(CM);
+impl_dist_vectors!(M);
+impl_dist_vectors!(KM);
+impl_dist_vectors!(IN);
@@ -0,0 +1,51 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use prelude::*;
+
+/// ParseUnitError is the error returned when parsing a unit-suffixed value
+/// (e.g. `"12.5mm"`) from a string fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseUnitError {
+    /// The unit suffix was missing or not recognized.
+    UnknownUnit(String),
+
+    /// The numeric value preceding the unit suffix could not be parsed.
+    InvalidValue(String),
+}
+
+impl fmt::Display for ParseUnitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseUnitError::UnknownUnit(ref u) => write!(f, "unknown unit {:?}", u),
+            ParseUnitError::InvalidValue(ref v) => write!(f, "invalid value {:?}", v),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for ParseUnitError {
+    fn description(&self) -> &str {
+        match *self {
+            ParseUnitError::UnknownUnit(_) => "unknown unit",
+            ParseUnitError::InvalidValue(_) => "invalid value",
+        }
+    }
+}
+
+/// split_unit splits a string such as `"12.5 mm"` into its value and unit
+/// suffix (`("12.5", "mm")`), trimming whitespace from both. The unit suffix is
+/// everything following the last digit or decimal point, such that suffixes
+/// like `"°C"` or `"degrees Celsius"` are supported. Values without digits
+/// (e.g. `"inf m"`) are split at the last whitespace instead. It is used by the
+/// `unit!` macro and is not part of the public API.
+#[doc(hidden)]
+pub fn split_unit(s: &str) -> (&str, &str) {
+    let s = s.trim();
+    let split = match s.rfind(|c: char| c.is_ascii_digit() || c == '.') {
+        Some(i) => i + 1,
+        None => s.rfind(char::is_whitespace).map_or(0, |i| i + 1),
+    };
+    (s[..split].trim(), s[split..].trim())
+}
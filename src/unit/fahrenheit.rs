@@ -0,0 +1,136 @@
+use num::{Num, NumCast};
+
+use super::celsius::{Celsius, ToCelsius};
+use super::kelvin::{Kelvin, ToKelvin};
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded, convert_unit};
+
+/// ToFahrenheit is the canonical trait to use for taking input in degrees Fahrenheit.
+///
+/// For example the degrees Celsius type (Celsius) implements the ToFahrenheit trait and
+/// thus degrees Celsius can be given as a parameter to any input that seeks
+/// degrees Fahrenheit.
+///
+/// Unlike most units, temperature scales differ by an offset as well as a
+/// scale factor, so converting a temperature is not the same as converting a
+/// temperature difference.
+pub trait ToFahrenheit{
+    type Output;
+
+    /// to_fahrenheit returns these units in degrees Fahrenheit, performing conversion if
+    /// needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_fahrenheit).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Celsius, ToFahrenheit};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn heat<T: ToFahrenheit<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_fahrenheit().0)
+    /// }
+    /// heat(Celsius(2.0));
+    /// heat(Celsius::<i32>(2));
+    /// ```
+    fn to_fahrenheit(self) -> Fahrenheit<Self::Output>;
+
+    /// try_to_fahrenheit is like to_fahrenheit, except that it returns None if
+    /// the result is out of range for the value type, or if the value type is
+    /// an integer type and the result is not a whole number of degrees
+    /// Fahrenheit.
+    fn try_to_fahrenheit(self) -> Option<Fahrenheit<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToFahrenheit<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_fahrenheit().0).map(Fahrenheit),
+            None => None,
+        }
+    }
+
+    /// to_fahrenheit_rounded is like to_fahrenheit, except that if the value
+    /// type is an integer type the result is rounded using the given mode, and
+    /// None is returned if the result is out of range for the value type.
+    fn to_fahrenheit_rounded(self, mode: Rounding) -> Option<Fahrenheit<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToFahrenheit<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_fahrenheit().0, mode).map(Fahrenheit),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// Fahrenheit represents degrees Fahrenheit (a temperature scale on which water
+    /// freezes at 32 and boils at 212 under standard pressure).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::Fahrenheit;
+    ///
+    /// let x = Fahrenheit(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    Fahrenheit, "°F", "degrees Fahrenheit"
+}
+
+impl<T: Num + NumCast> ToCelsius for Fahrenheit<T> {
+    type Output = T;
+
+    /// to_celsius returns these degrees Fahrenheit converted to degrees Celsius.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Fahrenheit, Celsius, ToCelsius};
+    ///
+    /// assert_eq!(Fahrenheit(212.0).to_celsius(), Celsius(100.0));
+    /// ```
+    fn to_celsius(self) -> Celsius<T> {
+        Celsius(convert_unit(self.0, |x| (x - 32.0) * 5.0 / 9.0))
+    }
+}
+
+impl<T: Num + NumCast> ToKelvin for Fahrenheit<T> {
+    type Output = T;
+
+    /// to_kelvin returns these degrees Fahrenheit converted to kelvins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Fahrenheit, Kelvin, ToKelvin};
+    ///
+    /// assert_eq!(Fahrenheit(32.0).to_kelvin(), Kelvin(273.15));
+    /// ```
+    fn to_kelvin(self) -> Kelvin<T> {
+        Kelvin(convert_unit(self.0, |x| (x - 32.0) * 5.0 / 9.0 + 273.15))
+    }
+}
+
+impl<T: Num + NumCast> ToFahrenheit for Fahrenheit<T> {
+    type Output = T;
+
+    /// to_fahrenheit simply returns self.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Fahrenheit, ToFahrenheit};
+    ///
+    /// assert_eq!(Fahrenheit(1.0).to_fahrenheit(), Fahrenheit(1.0));
+    /// ```
+    fn to_fahrenheit(self) -> Fahrenheit<T> {
+        self
+    }
+}
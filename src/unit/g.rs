@@ -0,0 +1,86 @@
+use num::NumCast;
+
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToG is the canonical trait to use for taking input in grams.
+///
+/// For example the kilograms type (KG) implements the ToG trait and thus
+/// kilograms can be given as a parameter to any input that seeks grams.
+pub trait ToG{
+    type Output;
+
+    /// to_g returns these units in grams, performing conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_g).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{KG, ToG};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn wait<T: ToG<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_g().0)
+    /// }
+    /// wait(KG(2.0));
+    /// wait(KG::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MG, G, KG, Tonne, LB, OZ, ToG};
+    ///
+    /// assert_eq!(MG(1000.0).to_g(), G(1.0));
+    /// assert_eq!(G(1.0).to_g(), G(1.0));
+    /// assert_eq!(KG(1.0).to_g(), G(1000.0));
+    /// assert_eq!(Tonne(1.0).to_g(), G(1000000.0));
+    /// assert_eq!(LB(1.0).to_g(), G(453.59237));
+    /// assert_eq!(OZ(1.0).to_g(), G(28.349523125));
+    /// ```
+    fn to_g(self) -> G<Self::Output>;
+
+    /// try_to_g is like to_g, except that it returns None if the result is out
+    /// of range for the value type, or if the value type is an integer type and
+    /// the result is not a whole number of grams.
+    fn try_to_g(self) -> Option<G<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToG<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_g().0).map(G),
+            None => None,
+        }
+    }
+
+    /// to_g_rounded is like to_g, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_g_rounded(self, mode: Rounding) -> Option<G<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToG<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_g().0, mode).map(G),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// G represents grams (1/1000th a kilogram).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::G;
+    ///
+    /// let x = G(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    G, "g", "grams"
+}
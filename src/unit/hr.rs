@@ -0,0 +1,86 @@
+use num::NumCast;
+
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToHr is the canonical trait to use for taking input in hours.
+///
+/// For example the seconds type (S) implements the ToHr trait and thus
+/// seconds can be given as a parameter to any input that seeks hours.
+pub trait ToHr{
+    type Output;
+
+    /// to_hr returns these units in hours, performing conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_hr).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{S, ToHr};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn wait<T: ToHr<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_hr().0)
+    /// }
+    /// wait(S(2.0));
+    /// wait(S::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{NS, US, MS, S, Min, Hr, ToHr};
+    ///
+    /// assert_eq!(NS(3.6e12).to_hr(), Hr(1.0));
+    /// assert_eq!(US(3.6e9).to_hr(), Hr(1.0));
+    /// assert_eq!(MS(3600000.0).to_hr(), Hr(1.0));
+    /// assert_eq!(S(3600.0).to_hr(), Hr(1.0));
+    /// assert_eq!(Min(60.0).to_hr(), Hr(1.0));
+    /// assert_eq!(Hr(1.0).to_hr(), Hr(1.0));
+    /// ```
+    fn to_hr(self) -> Hr<Self::Output>;
+
+    /// try_to_hr is like to_hr, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of hours.
+    fn try_to_hr(self) -> Option<Hr<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToHr<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_hr().0).map(Hr),
+            None => None,
+        }
+    }
+
+    /// to_hr_rounded is like to_hr, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_hr_rounded(self, mode: Rounding) -> Option<Hr<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToHr<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_hr().0, mode).map(Hr),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// Hr represents hours (60 minutes).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::Hr;
+    ///
+    /// let x = Hr(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    Hr, "h", "hours"
+}
@@ -0,0 +1,235 @@
+use num::NumCast;
+
+use super::super::Float;
+use super::ns::NS;
+use super::us::US;
+use super::ms::MS;
+use super::s::{S, ToS};
+use super::min::Min;
+use super::hr::Hr;
+use super::khz::{KHz, ToKHz};
+use super::mhz::{MHz, ToMHz};
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+// The conversions between each pair of frequency units.
+units! {
+    Hz: ToHz::to_hz = 1 hz,
+    KHz: ToKHz::to_khz = 1000 hz,
+    MHz: ToMHz::to_mhz = 1000000 hz,
+}
+
+/// ToHz is the canonical trait to use for taking input in hertz.
+///
+/// For example the kilohertz type (KHz) implements the ToHz trait and thus
+/// kilohertz can be given as a parameter to any input that seeks hertz.
+pub trait ToHz{
+    type Output;
+
+    /// to_hz returns these units in hertz, performing conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_hz).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{KHz, ToHz};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn sample<T: ToHz<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_hz().0)
+    /// }
+    /// sample(KHz(2.0));
+    /// sample(KHz::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{Hz, KHz, MHz, ToHz};
+    ///
+    /// assert_eq!(Hz(1.0).to_hz(), Hz(1.0));
+    /// assert_eq!(KHz(1.0).to_hz(), Hz(1000.0));
+    /// assert_eq!(MHz(1.0).to_hz(), Hz(1000000.0));
+    /// ```
+    fn to_hz(self) -> Hz<Self::Output>;
+
+    /// try_to_hz is like to_hz, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of hertz.
+    fn try_to_hz(self) -> Option<Hz<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToHz<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_hz().0).map(Hz),
+            None => None,
+        }
+    }
+
+    /// to_hz_rounded is like to_hz, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_hz_rounded(self, mode: Rounding) -> Option<Hz<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToHz<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_hz().0, mode).map(Hz),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// Hz represents hertz (the SI unit representing frequency, one cycle per
+    /// second).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::Hz;
+    ///
+    /// let x = Hz(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    Hz, "Hz", "hertz"
+}
+
+// Frequencies and periods are reciprocals of each other. Rather than
+// conversions, which would let a frequency be given to any input that seeks a
+// time, each time unit has a frequency method giving the frequency in hertz,
+// and each frequency unit a period method giving the period in seconds, from
+// which the other units are reached by conversion. Both are only implemented
+// for floats, as the reciprocal of an integer is rarely one.
+macro_rules! impl_frequency {
+    ($(#[$attr:meta])* $ident:ident) => {
+        impl<T: Float> $ident<T> {
+            /// frequency returns the frequency, in hertz, of an event that
+            /// repeats with this period (i.e. the reciprocal of this period).
+            ///
+            $(#[$attr])*
+            pub fn frequency(self) -> Hz<T> {
+                Hz(T::one() / self.to_s().0)
+            }
+        }
+    };
+}
+
+macro_rules! impl_period {
+    ($(#[$attr:meta])* $ident:ident) => {
+        impl<T: Float> $ident<T> {
+            /// period returns the period, in seconds, of an event that repeats
+            /// at this frequency (i.e. the reciprocal of this frequency).
+            ///
+            $(#[$attr])*
+            pub fn period(self) -> S<T> {
+                S(T::one() / self.to_hz().0)
+            }
+        }
+    };
+}
+
+impl_frequency! {
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{NS, MHz, ToMHz};
+    ///
+    /// assert_eq!(NS(500.0).frequency().to_mhz(), MHz(2.0));
+    /// ```
+    NS
+}
+
+impl_frequency! {
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{US, Hz};
+    ///
+    /// assert_eq!(US(4.0).frequency(), Hz(250000.0));
+    /// ```
+    US
+}
+
+impl_frequency! {
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MS, Hz, KHz, ToKHz};
+    ///
+    /// assert_eq!(MS(2.0).frequency(), Hz(500.0));
+    /// assert_eq!(MS(0.25).frequency().to_khz(), KHz(4.0));
+    /// ```
+    MS
+}
+
+impl_frequency! {
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{S, Hz};
+    ///
+    /// assert_eq!(S(0.5).frequency(), Hz(2.0));
+    /// ```
+    S
+}
+
+impl_frequency! {
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Min, Hz};
+    ///
+    /// assert_eq!(Min(1.0).frequency(), Hz(1.0 / 60.0));
+    /// ```
+    Min
+}
+
+impl_frequency! {
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Hr, Hz};
+    ///
+    /// assert_eq!(Hr(1.0).frequency(), Hz(1.0 / 3600.0));
+    /// ```
+    Hr
+}
+
+impl_period! {
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{S, MS, Hz, ToMS};
+    ///
+    /// assert_eq!(Hz(2.0).period(), S(0.5));
+    /// assert_eq!(Hz(2.0).period().to_ms(), MS(500.0));
+    /// ```
+    Hz
+}
+
+impl_period! {
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{KHz, MS, ToMS};
+    ///
+    /// assert_eq!(KHz(2.0).period().to_ms(), MS(0.5));
+    /// ```
+    KHz
+}
+
+impl_period! {
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MHz, US, ToUS};
+    ///
+    /// assert_eq!(MHz(4.0).period().to_us(), US(0.25));
+    /// ```
+    MHz
+}
@@ -0,0 +1,106 @@
+use num::NumCast;
+use core::ops::Div;
+
+use super::inch::IN;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToIN2 is the canonical trait to use for taking input in square inches.
+///
+/// For example the square millimeters type (MM2) implements the ToIN2 trait and
+/// thus square millimeters can be given as a parameter to any input that seeks
+/// square inches.
+pub trait ToIN2{
+    type Output;
+
+    /// to_in2 returns these units in square inches, performing conversion if
+    /// needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_in2).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM2, ToIN2};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn paint<T: ToIN2<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_in2().0)
+    /// }
+    /// paint(MM2(2.0));
+    /// paint(MM2::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM2, CM2, M2, IN2, ToIN2};
+    ///
+    /// assert_eq!(MM2(645.16).to_in2(), IN2(1.0));
+    /// assert_eq!(CM2(6.4516).to_in2(), IN2(1.0));
+    /// assert_eq!(M2(0.00064516).to_in2(), IN2(1.0));
+    /// assert_eq!(IN2(1.0).to_in2(), IN2(1.0));
+    /// ```
+    fn to_in2(self) -> IN2<Self::Output>;
+
+    /// try_to_in2 is like to_in2, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of square inches.
+    fn try_to_in2(self) -> Option<IN2<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToIN2<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_in2().0).map(IN2),
+            None => None,
+        }
+    }
+
+    /// to_in2_rounded is like to_in2, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_in2_rounded(self, mode: Rounding) -> Option<IN2<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToIN2<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_in2().0, mode).map(IN2),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// IN2 represents square inches (the area of a 1x1 inch square).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::IN2;
+    ///
+    /// let x = IN2(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    IN2, "in²", "square inches"
+}
+
+impl<T: Div<Output = T>> Div<IN<T>> for IN2<T> {
+    type Output = IN<T>;
+
+    /// div returns the length of the other side of a rectangle of this area
+    /// with one side of the given length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{IN, IN2};
+    ///
+    /// assert_eq!(IN2(6.0) / IN(2.0), IN(3.0));
+    /// ```
+    fn div(self, rhs: IN<T>) -> IN<T> {
+        IN(self.0 / rhs.0)
+    }
+}
@@ -0,0 +1,107 @@
+use num::NumCast;
+
+use super::in2::IN2;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToIN is the canonical trait to use for taking input in inches.
+///
+/// For example the millimeters type (MM) implements the ToIN trait and thus
+/// millimeters can be given as a parameter to any input that seeks inches.
+pub trait ToIN{
+    type Output;
+
+    /// to_in returns these units in inches, performing conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_in).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM, CM, M, IN, ToIN};
+    ///
+    /// assert_eq!(MM(25.4).to_in(), IN(1.0));
+    /// assert_eq!(CM(254.0).to_in(), IN(100.0));
+    /// assert_eq!(M(0.0254).to_in(), IN(1.0));
+    /// assert_eq!(MM(254).to_in(), IN(10));
+    /// assert_eq!(IN(1.0).to_in(), IN(1.0));
+    /// ```
+    fn to_in(self) -> IN<Self::Output>;
+
+    /// try_to_in is like to_in, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of inches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM, IN, ToIN};
+    ///
+    /// assert_eq!(MM(254).try_to_in(), Some(IN(10)));
+    /// assert_eq!(MM(250).try_to_in(), None);
+    /// ```
+    fn try_to_in(self) -> Option<IN<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToIN<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_in().0).map(IN),
+            None => None,
+        }
+    }
+
+    /// to_in_rounded is like to_in, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM, IN, ToIN, Rounding};
+    ///
+    /// assert_eq!(MM(250).to_in_rounded(Rounding::Nearest), Some(IN(10)));
+    /// assert_eq!(MM(250).to_in_rounded(Rounding::Floor), Some(IN(9)));
+    /// ```
+    fn to_in_rounded(self, mode: Rounding) -> Option<IN<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToIN<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_in().0, mode).map(IN),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// IN represents inches (25.4 millimeters).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::IN;
+    ///
+    /// let x = IN(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::IN;
+    ///
+    /// assert_eq!(format!("{}", IN(2.5)), "2.5 in");
+    /// assert_eq!(format!("{:#}", IN(2.5)), "2.5 inches");
+    /// ```
+    ///
+    /// Multiplying inches by inches yields square inches:
+    ///
+    /// ```
+    /// use fiz_math::unit::{IN, IN2};
+    ///
+    /// assert_eq!(IN(2.0) * IN(3.0), IN2(6.0));
+    /// ```
+    IN, "in", "inches", squared = IN2
+}
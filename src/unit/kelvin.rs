@@ -0,0 +1,135 @@
+use num::{Num, NumCast};
+
+use super::celsius::{Celsius, ToCelsius};
+use super::fahrenheit::{Fahrenheit, ToFahrenheit};
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded, convert_unit};
+
+/// ToKelvin is the canonical trait to use for taking input in kelvins.
+///
+/// For example the degrees Celsius type (Celsius) implements the ToKelvin trait and
+/// thus degrees Celsius can be given as a parameter to any input that seeks
+/// kelvins.
+///
+/// Unlike most units, temperature scales differ by an offset as well as a
+/// scale factor, so converting a temperature is not the same as converting a
+/// temperature difference.
+pub trait ToKelvin{
+    type Output;
+
+    /// to_kelvin returns these units in kelvins, performing conversion if
+    /// needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_kelvin).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Celsius, ToKelvin};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn heat<T: ToKelvin<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_kelvin().0)
+    /// }
+    /// heat(Celsius(2.0));
+    /// heat(Celsius::<i32>(2));
+    /// ```
+    fn to_kelvin(self) -> Kelvin<Self::Output>;
+
+    /// try_to_kelvin is like to_kelvin, except that it returns None if the
+    /// result is out of range for the value type, or if the value type is an
+    /// integer type and the result is not a whole number of kelvins.
+    fn try_to_kelvin(self) -> Option<Kelvin<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToKelvin<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_kelvin().0).map(Kelvin),
+            None => None,
+        }
+    }
+
+    /// to_kelvin_rounded is like to_kelvin, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_kelvin_rounded(self, mode: Rounding) -> Option<Kelvin<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToKelvin<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_kelvin().0, mode).map(Kelvin),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// Kelvin represents kelvins (the SI base unit representing thermodynamic
+    /// temperature, which is zero at absolute zero).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::Kelvin;
+    ///
+    /// let x = Kelvin(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    Kelvin, "K", "kelvins"
+}
+
+impl<T: Num + NumCast> ToCelsius for Kelvin<T> {
+    type Output = T;
+
+    /// to_celsius returns these kelvins converted to degrees Celsius.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Kelvin, Celsius, ToCelsius};
+    ///
+    /// assert_eq!(Kelvin(0.0).to_celsius(), Celsius(-273.15));
+    /// ```
+    fn to_celsius(self) -> Celsius<T> {
+        Celsius(convert_unit(self.0, |x| x - 273.15))
+    }
+}
+
+impl<T: Num + NumCast> ToKelvin for Kelvin<T> {
+    type Output = T;
+
+    /// to_kelvin simply returns self.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Kelvin, ToKelvin};
+    ///
+    /// assert_eq!(Kelvin(1.0).to_kelvin(), Kelvin(1.0));
+    /// ```
+    fn to_kelvin(self) -> Kelvin<T> {
+        self
+    }
+}
+
+impl<T: Num + NumCast> ToFahrenheit for Kelvin<T> {
+    type Output = T;
+
+    /// to_fahrenheit returns these kelvins converted to degrees Fahrenheit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Kelvin, Fahrenheit, ToFahrenheit};
+    ///
+    /// assert_eq!(Kelvin(373.15).to_fahrenheit(), Fahrenheit(212.0));
+    /// ```
+    fn to_fahrenheit(self) -> Fahrenheit<T> {
+        Fahrenheit(convert_unit(self.0, |x| (x - 273.15) * 9.0 / 5.0 + 32.0))
+    }
+}
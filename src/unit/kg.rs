@@ -0,0 +1,86 @@
+use num::NumCast;
+
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToKG is the canonical trait to use for taking input in kilograms.
+///
+/// For example the grams type (G) implements the ToKG trait and thus
+/// grams can be given as a parameter to any input that seeks kilograms.
+pub trait ToKG{
+    type Output;
+
+    /// to_kg returns these units in kilograms, performing conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_kg).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{G, ToKG};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn wait<T: ToKG<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_kg().0)
+    /// }
+    /// wait(G(2.0));
+    /// wait(G::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MG, G, KG, Tonne, LB, OZ, ToKG};
+    ///
+    /// assert_eq!(MG(1000000.0).to_kg(), KG(1.0));
+    /// assert_eq!(G(1000.0).to_kg(), KG(1.0));
+    /// assert_eq!(KG(1.0).to_kg(), KG(1.0));
+    /// assert_eq!(Tonne(1.0).to_kg(), KG(1000.0));
+    /// assert_eq!(LB(1.0).to_kg(), KG(0.45359237));
+    /// assert_eq!(OZ(1.0).to_kg(), KG(0.028349523125));
+    /// ```
+    fn to_kg(self) -> KG<Self::Output>;
+
+    /// try_to_kg is like to_kg, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of kilograms.
+    fn try_to_kg(self) -> Option<KG<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToKG<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_kg().0).map(KG),
+            None => None,
+        }
+    }
+
+    /// to_kg_rounded is like to_kg, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_kg_rounded(self, mode: Rounding) -> Option<KG<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToKG<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_kg().0, mode).map(KG),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// KG represents kilograms (the SI base unit representing mass).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::KG;
+    ///
+    /// let x = KG(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    KG, "kg", "kilograms"
+}
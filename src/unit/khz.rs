@@ -0,0 +1,84 @@
+use num::NumCast;
+
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToKHz is the canonical trait to use for taking input in kilohertz.
+///
+/// For example the hertz type (Hz) implements the ToKHz trait and thus
+/// hertz can be given as a parameter to any input that seeks kilohertz.
+pub trait ToKHz{
+    type Output;
+
+    /// to_khz returns these units in kilohertz, performing conversion if
+    /// needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_khz).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Hz, ToKHz};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn sample<T: ToKHz<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_khz().0)
+    /// }
+    /// sample(Hz(2.0));
+    /// sample(Hz::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{Hz, KHz, MHz, ToKHz};
+    ///
+    /// assert_eq!(Hz(1000.0).to_khz(), KHz(1.0));
+    /// assert_eq!(KHz(1.0).to_khz(), KHz(1.0));
+    /// assert_eq!(MHz(1.0).to_khz(), KHz(1000.0));
+    /// ```
+    fn to_khz(self) -> KHz<Self::Output>;
+
+    /// try_to_khz is like to_khz, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of kilohertz.
+    fn try_to_khz(self) -> Option<KHz<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToKHz<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_khz().0).map(KHz),
+            None => None,
+        }
+    }
+
+    /// to_khz_rounded is like to_khz, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_khz_rounded(self, mode: Rounding) -> Option<KHz<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToKHz<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_khz().0, mode).map(KHz),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// KHz represents kilohertz (1000 hertz).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::KHz;
+    ///
+    /// let x = KHz(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    KHz, "kHz", "kilohertz"
+}
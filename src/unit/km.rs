@@ -1,8 +1,7 @@
-use num::traits::{Num, NumCast};
+use num::NumCast;
 
-use super::cm::{CM, ToCM};
-use super::mm::{MM, ToMM};
-use super::m::{M, ToM};
+use super::km2::KM2;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
 
 /// ToKM is the canonical trait to use for taking input in kilometers.
 ///
@@ -13,6 +12,11 @@ pub trait ToKM{
 
     /// to_km returns these units in kilometers, performing conversion if needed.
     ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_km).
+    ///
     /// # Examples
     ///
     /// ```
@@ -26,85 +30,91 @@ pub trait ToKM{
     /// walk(M(2.0));
     /// walk(M::<i32>(2));
     /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM, CM, M, KM, ToKM};
+    ///
+    /// assert_eq!(MM(1000000.0).to_km(), KM(1.0));
+    /// assert_eq!(CM(100000.0).to_km(), KM(1.0));
+    /// assert_eq!(M(1000.0).to_km(), KM(1.0));
+    /// assert_eq!(KM(1.0).to_km(), KM(1.0));
+    /// ```
     fn to_km(self) -> KM<Self::Output>;
-}
 
-/// KM represents kilometers (1000 meters).
-///
-/// # Examples
-///
-/// ```
-/// use fiz_math::unit::KM;
-///
-/// let x = KM(1.0);
-/// println!("{:?}", x);
-/// ```
-unit!(KM);
-
-impl<T: Num + NumCast> ToMM for KM<T> {
-    type Output = T;
-
-    /// to_mm returns these kilometers converted to millimeters.
+    /// try_to_km is like to_km, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of kilometers.
     ///
     /// # Examples
     ///
     /// ```
-    /// use fiz_math::unit::{KM, MM, ToMM};
+    /// use fiz_math::unit::{M, KM, ToKM};
     ///
-    /// assert_eq!(KM(1.0).to_mm(), MM(1000000.0));
+    /// assert_eq!(M(2000).try_to_km(), Some(KM(2)));
+    /// assert_eq!(M(1500).try_to_km(), None);
+    /// assert_eq!(M(1500.0).try_to_km(), Some(KM(1.5)));
     /// ```
-    fn to_mm(self) -> MM<T> {
-        MM(self.0 * T::from(1000000).unwrap())
+    fn try_to_km(self) -> Option<KM<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToKM<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_km().0).map(KM),
+            None => None,
+        }
     }
-}
 
-impl<T: Num + NumCast> ToCM for KM<T> {
-    type Output = T;
-
-    /// to_cm returns these kilometers converted to centimeters.
+    /// to_km_rounded is like to_km, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
     ///
     /// # Examples
     ///
     /// ```
-    /// use fiz_math::unit::{KM, CM, ToCM};
+    /// use fiz_math::unit::{M, KM, ToKM, Rounding};
     ///
-    /// assert_eq!(KM(1.0).to_cm(), CM(100000.0));
+    /// assert_eq!(M(1500).to_km_rounded(Rounding::Nearest), Some(KM(2)));
+    /// assert_eq!(M(1500).to_km_rounded(Rounding::Floor), Some(KM(1)));
+    /// assert_eq!(M(-1500).to_km_rounded(Rounding::Trunc), Some(KM(-1)));
     /// ```
-    fn to_cm(self) -> CM<T> {
-        CM(self.0 * T::from(100000).unwrap())
+    fn to_km_rounded(self, mode: Rounding) -> Option<KM<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToKM<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_km().0, mode).map(KM),
+            None => None,
+        }
     }
 }
 
-impl<T: Num + NumCast> ToM for KM<T> {
-    type Output = T;
-
-    /// to_m returns these kilometers converted to meters.
+unit! {
+    /// KM represents kilometers (1000 meters).
     ///
     /// # Examples
     ///
     /// ```
-    /// use fiz_math::unit::{KM, M, ToM};
+    /// use fiz_math::unit::KM;
     ///
-    /// assert_eq!(KM(1.0).to_m(), M(1000.0));
+    /// let x = KM(1.0);
+    /// println!("{:?}", x);
     /// ```
-    fn to_m(self) -> M<T> {
-        M(self.0 * T::from(1000).unwrap())
-    }
-}
-
-impl<T: Num + NumCast> ToKM for KM<T> {
-    type Output = T;
-
-    /// to_km simply returns self.
     ///
-    /// # Examples
+    /// ```
+    /// use fiz_math::unit::KM;
     ///
+    /// assert_eq!(format!("{}", KM(3)), "3 km");
+    /// assert_eq!(format!("{:#}", KM(3)), "3 kilometers");
     /// ```
-    /// use fiz_math::unit::{KM, ToKM};
     ///
-    /// assert_eq!(KM(1.0).to_km(), KM(1.0));
+    /// Multiplying kilometers by kilometers yields square kilometers:
+    ///
     /// ```
-    fn to_km(self) -> KM<T> {
-        self
-    }
+    /// use fiz_math::unit::{KM, KM2};
+    ///
+    /// assert_eq!(KM(2.0) * KM(3.0), KM2(6.0));
+    /// ```
+    KM, "km", "kilometers", squared = KM2
 }
@@ -0,0 +1,143 @@
+use num::NumCast;
+use core::ops::{Mul, Div};
+
+use super::km::KM;
+use super::km3::KM3;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToKM2 is the canonical trait to use for taking input in square kilometers.
+///
+/// For example the square meters type (M2) implements the ToKM2 trait and thus
+/// square meters can be given as a parameter to any input that seeks square
+/// kilometers.
+pub trait ToKM2{
+    type Output;
+
+    /// to_km2 returns these units in square kilometers, performing conversion
+    /// if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_km2).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{M2, ToKM2};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn paint<T: ToKM2<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_km2().0)
+    /// }
+    /// paint(M2(2.0));
+    /// paint(M2::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM2, CM2, M2, KM2, ToKM2};
+    ///
+    /// assert_eq!(MM2(1.0e12).to_km2(), KM2(1.0));
+    /// assert_eq!(CM2(1.0e10).to_km2(), KM2(1.0));
+    /// assert_eq!(M2(1000000.0).to_km2(), KM2(1.0));
+    /// assert_eq!(KM2(1.0).to_km2(), KM2(1.0));
+    /// ```
+    fn to_km2(self) -> KM2<Self::Output>;
+
+    /// try_to_km2 is like to_km2, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of square kilometers.
+    fn try_to_km2(self) -> Option<KM2<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToKM2<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_km2().0).map(KM2),
+            None => None,
+        }
+    }
+
+    /// to_km2_rounded is like to_km2, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_km2_rounded(self, mode: Rounding) -> Option<KM2<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToKM2<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_km2().0, mode).map(KM2),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// KM2 represents square kilometers (the area of a 1x1 kilometer square).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::KM2;
+    ///
+    /// let x = KM2(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    KM2, "km²", "square kilometers"
+}
+
+impl<T: Mul<Output = T>> Mul<KM<T>> for KM2<T> {
+    type Output = KM3<T>;
+
+    /// mul returns the volume of a prism of this base area and the given
+    /// height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{KM, KM2, KM3};
+    ///
+    /// assert_eq!(KM2(6.0) * KM(2.0), KM3(12.0));
+    /// ```
+    fn mul(self, rhs: KM<T>) -> KM3<T> {
+        KM3(self.0 * rhs.0)
+    }
+}
+
+impl<T: Mul<Output = T>> Mul<KM2<T>> for KM<T> {
+    type Output = KM3<T>;
+
+    /// mul returns the volume of a prism of this height and the given base
+    /// area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{KM, KM2, KM3};
+    ///
+    /// assert_eq!(KM(2.0) * KM2(6.0), KM3(12.0));
+    /// ```
+    fn mul(self, rhs: KM2<T>) -> KM3<T> {
+        KM3(self.0 * rhs.0)
+    }
+}
+
+impl<T: Div<Output = T>> Div<KM<T>> for KM2<T> {
+    type Output = KM<T>;
+
+    /// div returns the length of the other side of a rectangle of this area
+    /// with one side of the given length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{KM, KM2};
+    ///
+    /// assert_eq!(KM2(6.0) / KM(2.0), KM(3.0));
+    /// ```
+    fn div(self, rhs: KM<T>) -> KM<T> {
+        KM(self.0 / rhs.0)
+    }
+}
@@ -0,0 +1,125 @@
+use num::NumCast;
+use core::ops::Div;
+
+use super::km::KM;
+use super::km2::KM2;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToKM3 is the canonical trait to use for taking input in cubic kilometers.
+///
+/// For example the cubic meters type (M3) implements the ToKM3 trait and thus
+/// cubic meters can be given as a parameter to any input that seeks cubic
+/// kilometers.
+pub trait ToKM3{
+    type Output;
+
+    /// to_km3 returns these units in cubic kilometers, performing conversion if
+    /// needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_km3).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{M3, ToKM3};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn fill<T: ToKM3<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_km3().0)
+    /// }
+    /// fill(M3(2.0));
+    /// fill(M3::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM3, CM3, M3, KM3, ToKM3};
+    ///
+    /// assert_eq!(MM3(1.0e18).to_km3(), KM3(1.0));
+    /// assert_eq!(CM3(1.0e15).to_km3(), KM3(1.0));
+    /// assert_eq!(M3(1.0e9).to_km3(), KM3(1.0));
+    /// assert_eq!(KM3(1.0).to_km3(), KM3(1.0));
+    /// ```
+    fn to_km3(self) -> KM3<Self::Output>;
+
+    /// try_to_km3 is like to_km3, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of cubic kilometers.
+    fn try_to_km3(self) -> Option<KM3<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToKM3<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_km3().0).map(KM3),
+            None => None,
+        }
+    }
+
+    /// to_km3_rounded is like to_km3, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_km3_rounded(self, mode: Rounding) -> Option<KM3<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToKM3<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_km3().0, mode).map(KM3),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// KM3 represents cubic kilometers (the volume of a 1x1x1 kilometer cube).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::KM3;
+    ///
+    /// let x = KM3(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    KM3, "km³", "cubic kilometers"
+}
+
+impl<T: Div<Output = T>> Div<KM<T>> for KM3<T> {
+    type Output = KM2<T>;
+
+    /// div returns the base area of a prism of this volume and the given
+    /// height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{KM, KM2, KM3};
+    ///
+    /// assert_eq!(KM3(12.0) / KM(2.0), KM2(6.0));
+    /// ```
+    fn div(self, rhs: KM<T>) -> KM2<T> {
+        KM2(self.0 / rhs.0)
+    }
+}
+
+impl<T: Div<Output = T>> Div<KM2<T>> for KM3<T> {
+    type Output = KM<T>;
+
+    /// div returns the height of a prism of this volume and the given base
+    /// area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{KM, KM2, KM3};
+    ///
+    /// assert_eq!(KM3(12.0) / KM2(6.0), KM(2.0));
+    /// ```
+    fn div(self, rhs: KM2<T>) -> KM<T> {
+        KM(self.0 / rhs.0)
+    }
+}
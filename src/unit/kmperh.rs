@@ -0,0 +1,153 @@
+use num::{Num, NumCast};
+use core::ops::{Mul, Div};
+
+use super::mpers::{MPerS, ToMPerS};
+use super::km::KM;
+use super::hr::Hr;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded, convert_unit};
+
+/// ToKMPerH is the canonical trait to use for taking input in kilometers per
+/// hour.
+///
+/// For example the meters per second type (MPerS) implements the ToKMPerH trait
+/// and thus meters per second can be given as a parameter to any input that
+/// seeks kilometers per hour.
+pub trait ToKMPerH{
+    type Output;
+
+    /// to_kmperh returns these units in kilometers per hour, performing
+    /// conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_kmperh).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MPerS, ToKMPerH};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn drive<T: ToKMPerH<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_kmperh().0)
+    /// }
+    /// drive(MPerS(2.0));
+    /// drive(MPerS::<i64>(2));
+    /// ```
+    fn to_kmperh(self) -> KMPerH<Self::Output>;
+
+    /// try_to_kmperh is like to_kmperh, except that it returns None if the
+    /// result is out of range for the value type, or if the value type is an
+    /// integer type and the result is not a whole number of kilometers per
+    /// hour.
+    fn try_to_kmperh(self) -> Option<KMPerH<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToKMPerH<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_kmperh().0).map(KMPerH),
+            None => None,
+        }
+    }
+
+    /// to_kmperh_rounded is like to_kmperh, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_kmperh_rounded(self, mode: Rounding) -> Option<KMPerH<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToKMPerH<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_kmperh().0, mode).map(KMPerH),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// KMPerH represents kilometers per hour (1/3.6th a meter per second).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::KMPerH;
+    ///
+    /// let x = KMPerH(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    KMPerH, "km/h", "kilometers per hour"
+}
+
+impl<T: Num + NumCast> ToMPerS for KMPerH<T> {
+    type Output = T;
+
+    /// to_mpers returns these kilometers per hour converted to meters per
+    /// second.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{KMPerH, MPerS, ToMPerS};
+    ///
+    /// assert_eq!(KMPerH(36.0).to_mpers(), MPerS(10.0));
+    /// ```
+    fn to_mpers(self) -> MPerS<T> {
+        MPerS(convert_unit(self.0, |x| x / 3.6))
+    }
+}
+
+impl<T: Num + NumCast> ToKMPerH for KMPerH<T> {
+    type Output = T;
+
+    /// to_kmperh simply returns self.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{KMPerH, ToKMPerH};
+    ///
+    /// assert_eq!(KMPerH(1.0).to_kmperh(), KMPerH(1.0));
+    /// ```
+    fn to_kmperh(self) -> KMPerH<T> {
+        self
+    }
+}
+
+impl<T: Div<Output = T>> Div<Hr<T>> for KM<T> {
+    type Output = KMPerH<T>;
+
+    /// div returns the speed at which this distance is covered in the given
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{KM, Hr, KMPerH};
+    ///
+    /// assert_eq!(KM(120.0) / Hr(1.5), KMPerH(80.0));
+    /// ```
+    fn div(self, rhs: Hr<T>) -> KMPerH<T> {
+        KMPerH(self.0 / rhs.0)
+    }
+}
+
+impl<T: Mul<Output = T>> Mul<Hr<T>> for KMPerH<T> {
+    type Output = KM<T>;
+
+    /// mul returns the distance covered at this speed in the given time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{KM, Hr, KMPerH};
+    ///
+    /// assert_eq!(KMPerH(80.0) * Hr(1.5), KM(120.0));
+    /// ```
+    fn mul(self, rhs: Hr<T>) -> KM<T> {
+        KM(self.0 * rhs.0)
+    }
+}
@@ -0,0 +1,86 @@
+use num::NumCast;
+
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToLB is the canonical trait to use for taking input in pounds.
+///
+/// For example the grams type (G) implements the ToLB trait and thus
+/// grams can be given as a parameter to any input that seeks pounds.
+pub trait ToLB{
+    type Output;
+
+    /// to_lb returns these units in pounds, performing conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_lb).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{G, ToLB};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn wait<T: ToLB<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_lb().0)
+    /// }
+    /// wait(G(2.0));
+    /// wait(G::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MG, G, KG, Tonne, LB, OZ, ToLB};
+    ///
+    /// assert_eq!(MG(1.0).to_lb(), LB(2.204622621848776e-06));
+    /// assert_eq!(G(1.0).to_lb(), LB(0.002204622621848776));
+    /// assert_eq!(KG(1.0).to_lb(), LB(2.2046226218487757));
+    /// assert_eq!(Tonne(1.0).to_lb(), LB(2204.622621848776));
+    /// assert_eq!(LB(1.0).to_lb(), LB(1.0));
+    /// assert_eq!(OZ(16.0).to_lb(), LB(1.0));
+    /// ```
+    fn to_lb(self) -> LB<Self::Output>;
+
+    /// try_to_lb is like to_lb, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of pounds.
+    fn try_to_lb(self) -> Option<LB<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToLB<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_lb().0).map(LB),
+            None => None,
+        }
+    }
+
+    /// to_lb_rounded is like to_lb, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_lb_rounded(self, mode: Rounding) -> Option<LB<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToLB<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_lb().0, mode).map(LB),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// LB represents international avoirdupois pounds (exactly 0.45359237 kilograms).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::LB;
+    ///
+    /// let x = LB(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    LB, "lb", "pounds"
+}
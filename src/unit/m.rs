@@ -1,8 +1,7 @@
-use num::traits::{Num, NumCast};
+use num::NumCast;
 
-use super::cm::{CM, ToCM};
-use super::mm::{MM, ToMM};
-use super::km::{KM, ToKM};
+use super::m2::M2;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
 
 /// ToM is the canonical trait to use for taking input in meters.
 ///
@@ -13,6 +12,11 @@ pub trait ToM{
 
     /// to_m returns these units in meters, performing conversion if needed.
     ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_m).
+    ///
     /// # Examples
     ///
     /// ```
@@ -26,85 +30,71 @@ pub trait ToM{
     /// walk(MM(2.0));
     /// walk(MM::<i16>(2));
     /// ```
-    fn to_m(self) -> M<Self::Output>;
-}
-
-/// M represents meters (the SI base unit representing distance).
-///
-/// # Examples
-///
-/// ```
-/// use fiz_math::unit::M;
-///
-/// let x = M(1.0);
-/// println!("{:?}", x);
-/// ```
-unit!(M);
-
-impl<T: Num + NumCast> ToMM for M<T> {
-    type Output = T;
-
-    /// to_mm returns these meters converted to millimeters.
-    ///
-    /// # Examples
     ///
     /// ```
-    /// use fiz_math::unit::{M, MM, ToMM};
+    /// use fiz_math::unit::{MM, CM, M, KM, ToM};
     ///
-    /// assert_eq!(M(1.0).to_mm(), MM(1000.0));
+    /// assert_eq!(MM(1000.0).to_m(), M(1.0));
+    /// assert_eq!(CM(100.0).to_m(), M(1.0));
+    /// assert_eq!(M(1.0).to_m(), M(1.0));
+    /// assert_eq!(KM(1.0).to_m(), M(1000.0));
     /// ```
-    fn to_mm(self) -> MM<T> {
-        MM(self.0 * T::from(1000).unwrap())
+    fn to_m(self) -> M<Self::Output>;
+
+    /// try_to_m is like to_m, except that it returns None if the result is out
+    /// of range for the value type, or if the value type is an integer type and
+    /// the result is not a whole number of meters.
+    fn try_to_m(self) -> Option<M<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToM<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_m().0).map(M),
+            None => None,
+        }
     }
-}
 
-impl<T: Num + NumCast> ToCM for M<T> {
-    type Output = T;
+    /// to_m_rounded is like to_m, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_m_rounded(self, mode: Rounding) -> Option<M<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToM<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_m().0, mode).map(M),
+            None => None,
+        }
+    }
+}
 
-    /// to_cm returns these meters converted to centimeters.
+unit! {
+    /// M represents meters (the SI base unit representing distance).
     ///
     /// # Examples
     ///
     /// ```
-    /// use fiz_math::unit::{M, CM, ToCM};
+    /// use fiz_math::unit::M;
     ///
-    /// assert_eq!(M(1.0).to_cm(), CM(100.0));
+    /// let x = M(1.0);
+    /// println!("{:?}", x);
     /// ```
-    fn to_cm(self) -> CM<T> {
-        CM(self.0 * T::from(100).unwrap())
-    }
-}
-
-impl<T: Num + NumCast> ToM for M<T> {
-    type Output = T;
-
-    /// to_m simply returns self.
-    ///
-    /// # Examples
     ///
     /// ```
-    /// use fiz_math::unit::{M, ToM};
+    /// use fiz_math::unit::M;
     ///
-    /// assert_eq!(M(1.0).to_m(), M(1.0));
+    /// assert_eq!(format!("{}", M(1.5)), "1.5 m");
+    /// assert_eq!(format!("{:#}", M(1.5)), "1.5 meters");
     /// ```
-    fn to_m(self) -> M<T> {
-        self
-    }
-}
-
-impl<T: Num + NumCast> ToKM for M<T> {
-    type Output = T;
-
-    /// to_km returns these meters converted to kilometers.
     ///
-    /// # Examples
+    /// Multiplying meters by meters yields square meters:
     ///
     /// ```
-    /// use fiz_math::unit::{M, KM, ToKM};
+    /// use fiz_math::unit::{M, M2};
     ///
-    /// assert_eq!(M(1000.0).to_km(), KM(1.0));
+    /// assert_eq!(M(2.0) * M(3.0), M2(6.0));
     /// ```
-    fn to_km(self) -> KM<T> {
-        KM(self.0 / T::from(1000).unwrap())
-    }
+    M, "m", "meters", squared = M2
 }
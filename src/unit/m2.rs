@@ -0,0 +1,156 @@
+use num::NumCast;
+use core::ops::{Mul, Div};
+
+use super::mm2::{MM2, ToMM2};
+use super::cm2::{CM2, ToCM2};
+use super::km2::{KM2, ToKM2};
+use super::in2::{IN2, ToIN2};
+use super::m::M;
+use super::m3::M3;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+// The conversions between each pair of area units.
+units! {
+    MM2: ToMM2::to_mm2 = 0.000001 m2,
+    CM2: ToCM2::to_cm2 = 0.0001 m2,
+    M2: ToM2::to_m2 = 1 m2,
+    KM2: ToKM2::to_km2 = 1000000 m2,
+    IN2: ToIN2::to_in2 = 0.00064516 m2,
+}
+
+/// ToM2 is the canonical trait to use for taking input in square meters.
+///
+/// For example the square centimeters type (CM2) implements the ToM2 trait and
+/// thus square centimeters can be given as a parameter to any input that seeks
+/// square meters.
+pub trait ToM2{
+    type Output;
+
+    /// to_m2 returns these units in square meters, performing conversion if
+    /// needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_m2).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{CM2, ToM2};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn paint<T: ToM2<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_m2().0)
+    /// }
+    /// paint(CM2(2.0));
+    /// paint(CM2::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM2, CM2, M2, KM2, ToM2};
+    ///
+    /// assert_eq!(MM2(1000000.0).to_m2(), M2(1.0));
+    /// assert_eq!(CM2(10000.0).to_m2(), M2(1.0));
+    /// assert_eq!(M2(1.0).to_m2(), M2(1.0));
+    /// assert_eq!(KM2(1.0).to_m2(), M2(1000000.0));
+    /// ```
+    fn to_m2(self) -> M2<Self::Output>;
+
+    /// try_to_m2 is like to_m2, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of square meters.
+    fn try_to_m2(self) -> Option<M2<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToM2<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_m2().0).map(M2),
+            None => None,
+        }
+    }
+
+    /// to_m2_rounded is like to_m2, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_m2_rounded(self, mode: Rounding) -> Option<M2<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToM2<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_m2().0, mode).map(M2),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// M2 represents square meters (the SI unit representing area).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::M2;
+    ///
+    /// let x = M2(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    M2, "m²", "square meters"
+}
+
+impl<T: Mul<Output = T>> Mul<M<T>> for M2<T> {
+    type Output = M3<T>;
+
+    /// mul returns the volume of a prism of this base area and the given
+    /// height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{M, M2, M3};
+    ///
+    /// assert_eq!(M2(6.0) * M(2.0), M3(12.0));
+    /// ```
+    fn mul(self, rhs: M<T>) -> M3<T> {
+        M3(self.0 * rhs.0)
+    }
+}
+
+impl<T: Mul<Output = T>> Mul<M2<T>> for M<T> {
+    type Output = M3<T>;
+
+    /// mul returns the volume of a prism of this height and the given base
+    /// area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{M, M2, M3};
+    ///
+    /// assert_eq!(M(2.0) * M2(6.0), M3(12.0));
+    /// ```
+    fn mul(self, rhs: M2<T>) -> M3<T> {
+        M3(self.0 * rhs.0)
+    }
+}
+
+impl<T: Div<Output = T>> Div<M<T>> for M2<T> {
+    type Output = M<T>;
+
+    /// div returns the length of the other side of a rectangle of this area
+    /// with one side of the given length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{M, M2};
+    ///
+    /// assert_eq!(M2(6.0) / M(2.0), M(3.0));
+    /// ```
+    fn div(self, rhs: M<T>) -> M<T> {
+        M(self.0 / rhs.0)
+    }
+}
@@ -0,0 +1,136 @@
+use num::NumCast;
+use core::ops::Div;
+
+use super::mm3::{MM3, ToMM3};
+use super::cm3::{CM3, ToCM3};
+use super::km3::{KM3, ToKM3};
+use super::m::M;
+use super::m2::M2;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+// The conversions between each pair of volume units.
+units! {
+    MM3: ToMM3::to_mm3 = 0.000000001 m3,
+    CM3: ToCM3::to_cm3 = 0.000001 m3,
+    M3: ToM3::to_m3 = 1 m3,
+    KM3: ToKM3::to_km3 = 1000000000 m3,
+}
+
+/// ToM3 is the canonical trait to use for taking input in cubic meters.
+///
+/// For example the cubic centimeters type (CM3) implements the ToM3 trait and
+/// thus cubic centimeters can be given as a parameter to any input that seeks
+/// cubic meters.
+pub trait ToM3{
+    type Output;
+
+    /// to_m3 returns these units in cubic meters, performing conversion if
+    /// needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_m3).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{CM3, ToM3};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn fill<T: ToM3<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_m3().0)
+    /// }
+    /// fill(CM3(2.0));
+    /// fill(CM3::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM3, CM3, M3, KM3, ToM3};
+    ///
+    /// assert_eq!(MM3(1.0e9).to_m3(), M3(1.0));
+    /// assert_eq!(CM3(1000000.0).to_m3(), M3(1.0));
+    /// assert_eq!(M3(1.0).to_m3(), M3(1.0));
+    /// assert_eq!(KM3(1.0).to_m3(), M3(1.0e9));
+    /// ```
+    fn to_m3(self) -> M3<Self::Output>;
+
+    /// try_to_m3 is like to_m3, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of cubic meters.
+    fn try_to_m3(self) -> Option<M3<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToM3<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_m3().0).map(M3),
+            None => None,
+        }
+    }
+
+    /// to_m3_rounded is like to_m3, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_m3_rounded(self, mode: Rounding) -> Option<M3<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToM3<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_m3().0, mode).map(M3),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// M3 represents cubic meters (the SI unit representing volume).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::M3;
+    ///
+    /// let x = M3(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    M3, "m³", "cubic meters"
+}
+
+impl<T: Div<Output = T>> Div<M<T>> for M3<T> {
+    type Output = M2<T>;
+
+    /// div returns the base area of a prism of this volume and the given
+    /// height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{M, M2, M3};
+    ///
+    /// assert_eq!(M3(12.0) / M(2.0), M2(6.0));
+    /// ```
+    fn div(self, rhs: M<T>) -> M2<T> {
+        M2(self.0 / rhs.0)
+    }
+}
+
+impl<T: Div<Output = T>> Div<M2<T>> for M3<T> {
+    type Output = M<T>;
+
+    /// div returns the height of a prism of this volume and the given base
+    /// area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{M, M2, M3};
+    ///
+    /// assert_eq!(M3(12.0) / M2(6.0), M(2.0));
+    /// ```
+    fn div(self, rhs: M2<T>) -> M<T> {
+        M(self.0 / rhs.0)
+    }
+}
@@ -0,0 +1,201 @@
+use num::{Num, NumCast};
+use core::str::FromStr;
+use prelude::*;
+
+use super::error::{ParseUnitError, split_unit};
+use super::mg::{MG, ToMG};
+use super::g::{G, ToG};
+use super::kg::{KG, ToKG};
+use super::tonne::{Tonne, ToTonne};
+use super::lb::{LB, ToLB};
+use super::oz::{OZ, ToOZ};
+
+// The conversions between each pair of mass units.
+units! {
+    MG: ToMG::to_mg = 0.001 g,
+    G: ToG::to_g = 1 g,
+    KG: ToKG::to_kg = 1000 g,
+    Tonne: ToTonne::to_tonne = 1000000 g,
+    LB: ToLB::to_lb = 453.59237 g,
+    OZ: ToOZ::to_oz = 28.349523125 g,
+}
+
+/// Mass is a mass in any of the mass units, as returned by `parse`. It
+/// implements each of the mass conversion traits, so it can be given to any
+/// input that seeks e.g. kilograms.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::unit::{Mass, G, KG, ToKG};
+///
+/// let m = Mass::G(G(1500.0));
+/// assert_eq!(m.to_kg(), KG(1.5));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Mass<T> {
+    MG(MG<T>),
+    G(G<T>),
+    KG(KG<T>),
+    Tonne(Tonne<T>),
+    LB(LB<T>),
+    OZ(OZ<T>),
+}
+
+/// parse parses a unit-suffixed mass (e.g. `"12.5kg"`, `"3 lb"` or `"1.5 tonnes"`)
+/// from a string, such as those found in configuration files or command-line
+/// arguments.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::unit::{mass, Mass, G, LB, KG, ToKG};
+///
+/// assert_eq!(mass::parse("12.5g"), Ok(Mass::G(G(12.5))));
+/// assert_eq!(mass::parse::<i32>("2 pounds"), Ok(Mass::LB(LB(2))));
+/// assert_eq!(mass::parse("250 g").unwrap().to_kg(), KG(0.25));
+/// assert!(mass::parse::<f64>("3 stone").is_err());
+/// ```
+pub fn parse<T: FromStr>(s: &str) -> Result<Mass<T>, ParseUnitError> {
+    // Each unit rejects suffixes other than its own, so the only error worth
+    // reporting over an unknown unit is an invalid value for a known one.
+    let mut err = ParseUnitError::UnknownUnit(split_unit(s).1.to_string());
+    match s.parse() {
+        Ok(x) => return Ok(Mass::MG(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    match s.parse() {
+        Ok(x) => return Ok(Mass::G(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    match s.parse() {
+        Ok(x) => return Ok(Mass::KG(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    match s.parse() {
+        Ok(x) => return Ok(Mass::Tonne(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    match s.parse() {
+        Ok(x) => return Ok(Mass::LB(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    match s.parse() {
+        Ok(x) => return Ok(Mass::OZ(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    Err(err)
+}
+
+impl<T: FromStr> FromStr for Mass<T> {
+    type Err = ParseUnitError;
+
+    /// from_str is short-hand for `mass::parse`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Mass, KG};
+    ///
+    /// assert_eq!("2 kg".parse(), Ok(Mass::KG(KG(2.0))));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+impl<T: Num + NumCast> ToMG for Mass<T> {
+    type Output = T;
+
+    fn to_mg(self) -> MG<T> {
+        match self {
+            Mass::MG(x) => x.to_mg(),
+            Mass::G(x) => x.to_mg(),
+            Mass::KG(x) => x.to_mg(),
+            Mass::Tonne(x) => x.to_mg(),
+            Mass::LB(x) => x.to_mg(),
+            Mass::OZ(x) => x.to_mg(),
+        }
+    }
+}
+
+impl<T: Num + NumCast> ToG for Mass<T> {
+    type Output = T;
+
+    fn to_g(self) -> G<T> {
+        match self {
+            Mass::MG(x) => x.to_g(),
+            Mass::G(x) => x.to_g(),
+            Mass::KG(x) => x.to_g(),
+            Mass::Tonne(x) => x.to_g(),
+            Mass::LB(x) => x.to_g(),
+            Mass::OZ(x) => x.to_g(),
+        }
+    }
+}
+
+impl<T: Num + NumCast> ToKG for Mass<T> {
+    type Output = T;
+
+    fn to_kg(self) -> KG<T> {
+        match self {
+            Mass::MG(x) => x.to_kg(),
+            Mass::G(x) => x.to_kg(),
+            Mass::KG(x) => x.to_kg(),
+            Mass::Tonne(x) => x.to_kg(),
+            Mass::LB(x) => x.to_kg(),
+            Mass::OZ(x) => x.to_kg(),
+        }
+    }
+}
+
+impl<T: Num + NumCast> ToTonne for Mass<T> {
+    type Output = T;
+
+    fn to_tonne(self) -> Tonne<T> {
+        match self {
+            Mass::MG(x) => x.to_tonne(),
+            Mass::G(x) => x.to_tonne(),
+            Mass::KG(x) => x.to_tonne(),
+            Mass::Tonne(x) => x.to_tonne(),
+            Mass::LB(x) => x.to_tonne(),
+            Mass::OZ(x) => x.to_tonne(),
+        }
+    }
+}
+
+impl<T: Num + NumCast> ToLB for Mass<T> {
+    type Output = T;
+
+    fn to_lb(self) -> LB<T> {
+        match self {
+            Mass::MG(x) => x.to_lb(),
+            Mass::G(x) => x.to_lb(),
+            Mass::KG(x) => x.to_lb(),
+            Mass::Tonne(x) => x.to_lb(),
+            Mass::LB(x) => x.to_lb(),
+            Mass::OZ(x) => x.to_lb(),
+        }
+    }
+}
+
+impl<T: Num + NumCast> ToOZ for Mass<T> {
+    type Output = T;
+
+    fn to_oz(self) -> OZ<T> {
+        match self {
+            Mass::MG(x) => x.to_oz(),
+            Mass::G(x) => x.to_oz(),
+            Mass::KG(x) => x.to_oz(),
+            Mass::Tonne(x) => x.to_oz(),
+            Mass::LB(x) => x.to_oz(),
+            Mass::OZ(x) => x.to_oz(),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use num::NumCast;
+
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToMG is the canonical trait to use for taking input in milligrams.
+///
+/// For example the grams type (G) implements the ToMG trait and thus
+/// grams can be given as a parameter to any input that seeks milligrams.
+pub trait ToMG{
+    type Output;
+
+    /// to_mg returns these units in milligrams, performing conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_mg).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{G, ToMG};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn wait<T: ToMG<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_mg().0)
+    /// }
+    /// wait(G(2.0));
+    /// wait(G::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MG, G, KG, Tonne, LB, OZ, ToMG};
+    ///
+    /// assert_eq!(MG(1.0).to_mg(), MG(1.0));
+    /// assert_eq!(G(1.0).to_mg(), MG(1000.0));
+    /// assert_eq!(KG(1.0).to_mg(), MG(1000000.0));
+    /// assert_eq!(Tonne(1.0).to_mg(), MG(1.0e9));
+    /// assert_eq!(LB(1.0).to_mg(), MG(453592.37));
+    /// assert_eq!(OZ(1.0).to_mg(), MG(28349.523125));
+    /// ```
+    fn to_mg(self) -> MG<Self::Output>;
+
+    /// try_to_mg is like to_mg, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of milligrams.
+    fn try_to_mg(self) -> Option<MG<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMG<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_mg().0).map(MG),
+            None => None,
+        }
+    }
+
+    /// to_mg_rounded is like to_mg, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_mg_rounded(self, mode: Rounding) -> Option<MG<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMG<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_mg().0, mode).map(MG),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// MG represents milligrams (1/1000th a gram).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::MG;
+    ///
+    /// let x = MG(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    MG, "mg", "milligrams"
+}
@@ -0,0 +1,84 @@
+use num::NumCast;
+
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToMHz is the canonical trait to use for taking input in megahertz.
+///
+/// For example the hertz type (Hz) implements the ToMHz trait and thus
+/// hertz can be given as a parameter to any input that seeks megahertz.
+pub trait ToMHz{
+    type Output;
+
+    /// to_mhz returns these units in megahertz, performing conversion if
+    /// needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_mhz).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Hz, ToMHz};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn sample<T: ToMHz<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_mhz().0)
+    /// }
+    /// sample(Hz(2.0));
+    /// sample(Hz::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{Hz, KHz, MHz, ToMHz};
+    ///
+    /// assert_eq!(Hz(1000000.0).to_mhz(), MHz(1.0));
+    /// assert_eq!(KHz(1000.0).to_mhz(), MHz(1.0));
+    /// assert_eq!(MHz(1.0).to_mhz(), MHz(1.0));
+    /// ```
+    fn to_mhz(self) -> MHz<Self::Output>;
+
+    /// try_to_mhz is like to_mhz, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of megahertz.
+    fn try_to_mhz(self) -> Option<MHz<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMHz<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_mhz().0).map(MHz),
+            None => None,
+        }
+    }
+
+    /// to_mhz_rounded is like to_mhz, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_mhz_rounded(self, mode: Rounding) -> Option<MHz<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMHz<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_mhz().0, mode).map(MHz),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// MHz represents megahertz (1000 kilohertz).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::MHz;
+    ///
+    /// let x = MHz(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    MHz, "MHz", "megahertz"
+}
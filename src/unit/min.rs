@@ -0,0 +1,86 @@
+use num::NumCast;
+
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToMin is the canonical trait to use for taking input in minutes.
+///
+/// For example the seconds type (S) implements the ToMin trait and thus
+/// seconds can be given as a parameter to any input that seeks minutes.
+pub trait ToMin{
+    type Output;
+
+    /// to_min returns these units in minutes, performing conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_min).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{S, ToMin};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn wait<T: ToMin<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_min().0)
+    /// }
+    /// wait(S(2.0));
+    /// wait(S::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{NS, US, MS, S, Min, Hr, ToMin};
+    ///
+    /// assert_eq!(NS(6.0e10).to_min(), Min(1.0));
+    /// assert_eq!(US(6.0e7).to_min(), Min(1.0));
+    /// assert_eq!(MS(60000.0).to_min(), Min(1.0));
+    /// assert_eq!(S(60.0).to_min(), Min(1.0));
+    /// assert_eq!(Min(1.0).to_min(), Min(1.0));
+    /// assert_eq!(Hr(1.0).to_min(), Min(60.0));
+    /// ```
+    fn to_min(self) -> Min<Self::Output>;
+
+    /// try_to_min is like to_min, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of minutes.
+    fn try_to_min(self) -> Option<Min<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMin<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_min().0).map(Min),
+            None => None,
+        }
+    }
+
+    /// to_min_rounded is like to_min, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_min_rounded(self, mode: Rounding) -> Option<Min<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMin<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_min().0, mode).map(Min),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// Min represents minutes (60 seconds).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::Min;
+    ///
+    /// let x = Min(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    Min, "min", "minutes"
+}
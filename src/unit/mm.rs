@@ -1,8 +1,7 @@
-use num::traits::{Num, NumCast};
+use num::NumCast;
 
-use super::cm::{CM, ToCM};
-use super::m::{M, ToM};
-use super::km::{KM, ToKM};
+use super::mm2::MM2;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
 
 /// ToMM is the canonical trait to use for input in millimeters.
 ///
@@ -15,6 +14,11 @@ pub trait ToMM{
     /// to_mm returns these units in millimeters, performing conversion if
     /// needed.
     ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_mm).
+    ///
     /// # Examples
     ///
     /// ```
@@ -28,85 +32,99 @@ pub trait ToMM{
     /// walk(MM(2.0));
     /// walk(MM::<i8>(2));
     /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM, CM, M, KM, ToMM};
+    ///
+    /// assert_eq!(MM(1.0).to_mm(), MM(1.0));
+    /// assert_eq!(CM(1.0).to_mm(), MM(10.0));
+    /// assert_eq!(M(1.0).to_mm(), MM(1000.0));
+    /// assert_eq!(KM(1.0).to_mm(), MM(1000000.0));
+    /// ```
     fn to_mm(self) -> MM<Self::Output>;
-}
 
-/// MM represents millimeters (1/10th a centimeter).
-///
-/// # Examples
-///
-/// ```
-/// use fiz_math::unit::MM;
-///
-/// let x = MM(1.0);
-/// println!("{:?}", x);
-/// ```
-unit!(MM);
-
-impl<T: Num + NumCast> ToMM for MM<T> {
-    type Output = T;
-
-    /// to_mm simply returns self.
+    /// try_to_mm is like to_mm, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of millimeters.
     ///
     /// # Examples
     ///
     /// ```
-    /// use fiz_math::unit::{MM, ToMM};
+    /// use fiz_math::unit::{M, MM, ToMM};
     ///
-    /// assert_eq!(MM(1.0).to_mm(), MM(1.0));
+    /// assert_eq!(M::<i16>(4).try_to_mm(), Some(MM(4000)));
+    /// assert_eq!(M::<i16>(40).try_to_mm(), None);
     /// ```
-    fn to_mm(self) -> MM<T> {
-        self
+    fn try_to_mm(self) -> Option<MM<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMM<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_mm().0).map(MM),
+            None => None,
+        }
     }
-}
-
-impl<T: Num + NumCast> ToCM for MM<T> {
-    type Output = T;
 
-    /// to_cm returns these millimeters converted to centimeters.
+    /// to_mm_rounded is like to_mm, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
     ///
     /// # Examples
     ///
     /// ```
-    /// use fiz_math::unit::{MM, CM, ToCM};
+    /// use fiz_math::unit::{M, MM, ToMM, Rounding};
     ///
-    /// assert_eq!(MM(10.0).to_cm(), CM(1.0));
+    /// assert_eq!(M::<i16>(4).to_mm_rounded(Rounding::Nearest), Some(MM(4000)));
+    /// assert_eq!(M::<i16>(40).to_mm_rounded(Rounding::Nearest), None);
     /// ```
-    fn to_cm(self) -> CM<T> {
-        CM(self.0 / T::from(10).unwrap())
+    fn to_mm_rounded(self, mode: Rounding) -> Option<MM<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMM<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_mm().0, mode).map(MM),
+            None => None,
+        }
     }
 }
 
-impl<T: Num + NumCast> ToM for MM<T> {
-    type Output = T;
-
-    /// to_m returns these millimeters converted to meters.
+unit! {
+    /// MM represents millimeters (1/10th a centimeter).
     ///
     /// # Examples
     ///
     /// ```
-    /// use fiz_math::unit::{MM, M, ToM};
+    /// use fiz_math::unit::MM;
     ///
-    /// assert_eq!(MM(1000.0).to_m(), M(1.0));
+    /// let x = MM(1.0);
+    /// println!("{:?}", x);
     /// ```
-    fn to_m(self) -> M<T> {
-        M(self.0 / T::from(1000).unwrap())
-    }
-}
-
-impl<T: Num + NumCast> ToKM for MM<T> {
-    type Output = T;
-
-    /// to_km returns these millimeters converted to kilometers.
     ///
-    /// # Examples
+    /// ```
+    /// use fiz_math::unit::MM;
     ///
+    /// assert_eq!(format!("{}", MM(12.5)), "12.5 mm");
+    /// assert_eq!(format!("{:#}", MM(12.5)), "12.5 millimeters");
     /// ```
-    /// use fiz_math::unit::{MM, KM, ToKM};
     ///
-    /// assert_eq!(MM(1000000.0).to_km(), KM(1.0));
+    /// Multiplying millimeters by millimeters yields square millimeters, so
+    /// unlike most units millimeters do not implement `Num` or `Float`. Vectors
+    /// of millimeters still have dot products and squared lengths (in square
+    /// millimeters), lengths, directions and interpolation:
+    ///
     /// ```
-    fn to_km(self) -> KM<T> {
-        KM(self.0 / T::from(1000000).unwrap())
-    }
+    /// use fiz_math::{Lerp, Vec2};
+    /// use fiz_math::unit::{MM, MM2};
+    ///
+    /// assert_eq!(MM(2.0) * MM(3.0), MM2(6.0));
+    ///
+    /// let v = Vec2(MM(3.0), MM(4.0));
+    /// assert_eq!(v.length_sq(), MM2(25.0));
+    /// assert_eq!(v.length(), MM(5.0));
+    /// assert_eq!(v.normalize(), Some(Vec2(0.6, 0.8)));
+    /// assert_eq!(Lerp::lerp(v, Vec2(MM(5.0), MM(4.0)), 0.5), Vec2(MM(4.0), MM(4.0)));
+    /// ```
+    MM, "mm", "millimeters", squared = MM2
 }
@@ -0,0 +1,143 @@
+use num::NumCast;
+use core::ops::{Mul, Div};
+
+use super::mm::MM;
+use super::mm3::MM3;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToMM2 is the canonical trait to use for taking input in square millimeters.
+///
+/// For example the square meters type (M2) implements the ToMM2 trait and thus
+/// square meters can be given as a parameter to any input that seeks square
+/// millimeters.
+pub trait ToMM2{
+    type Output;
+
+    /// to_mm2 returns these units in square millimeters, performing conversion
+    /// if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_mm2).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{M2, ToMM2};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn paint<T: ToMM2<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_mm2().0)
+    /// }
+    /// paint(M2(2.0));
+    /// paint(M2::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM2, CM2, M2, KM2, ToMM2};
+    ///
+    /// assert_eq!(MM2(1.0).to_mm2(), MM2(1.0));
+    /// assert_eq!(CM2(1.0).to_mm2(), MM2(100.0));
+    /// assert_eq!(M2(1.0).to_mm2(), MM2(1000000.0));
+    /// assert_eq!(KM2(1.0).to_mm2(), MM2(1.0e12));
+    /// ```
+    fn to_mm2(self) -> MM2<Self::Output>;
+
+    /// try_to_mm2 is like to_mm2, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of square millimeters.
+    fn try_to_mm2(self) -> Option<MM2<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMM2<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_mm2().0).map(MM2),
+            None => None,
+        }
+    }
+
+    /// to_mm2_rounded is like to_mm2, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_mm2_rounded(self, mode: Rounding) -> Option<MM2<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMM2<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_mm2().0, mode).map(MM2),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// MM2 represents square millimeters (the area of a 1x1 millimeter square).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::MM2;
+    ///
+    /// let x = MM2(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    MM2, "mm²", "square millimeters"
+}
+
+impl<T: Mul<Output = T>> Mul<MM<T>> for MM2<T> {
+    type Output = MM3<T>;
+
+    /// mul returns the volume of a prism of this base area and the given
+    /// height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM, MM2, MM3};
+    ///
+    /// assert_eq!(MM2(6.0) * MM(2.0), MM3(12.0));
+    /// ```
+    fn mul(self, rhs: MM<T>) -> MM3<T> {
+        MM3(self.0 * rhs.0)
+    }
+}
+
+impl<T: Mul<Output = T>> Mul<MM2<T>> for MM<T> {
+    type Output = MM3<T>;
+
+    /// mul returns the volume of a prism of this height and the given base
+    /// area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM, MM2, MM3};
+    ///
+    /// assert_eq!(MM(2.0) * MM2(6.0), MM3(12.0));
+    /// ```
+    fn mul(self, rhs: MM2<T>) -> MM3<T> {
+        MM3(self.0 * rhs.0)
+    }
+}
+
+impl<T: Div<Output = T>> Div<MM<T>> for MM2<T> {
+    type Output = MM<T>;
+
+    /// div returns the length of the other side of a rectangle of this area
+    /// with one side of the given length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM, MM2};
+    ///
+    /// assert_eq!(MM2(6.0) / MM(2.0), MM(3.0));
+    /// ```
+    fn div(self, rhs: MM<T>) -> MM<T> {
+        MM(self.0 / rhs.0)
+    }
+}
@@ -0,0 +1,125 @@
+use num::NumCast;
+use core::ops::Div;
+
+use super::mm::MM;
+use super::mm2::MM2;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToMM3 is the canonical trait to use for taking input in cubic millimeters.
+///
+/// For example the cubic meters type (M3) implements the ToMM3 trait and thus
+/// cubic meters can be given as a parameter to any input that seeks cubic
+/// millimeters.
+pub trait ToMM3{
+    type Output;
+
+    /// to_mm3 returns these units in cubic millimeters, performing conversion
+    /// if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_mm3).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{M3, ToMM3};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn fill<T: ToMM3<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_mm3().0)
+    /// }
+    /// fill(M3(2.0));
+    /// fill(M3::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM3, CM3, M3, KM3, ToMM3};
+    ///
+    /// assert_eq!(MM3(1.0).to_mm3(), MM3(1.0));
+    /// assert_eq!(CM3(1.0).to_mm3(), MM3(1000.0));
+    /// assert_eq!(M3(1.0).to_mm3(), MM3(1.0e9));
+    /// assert_eq!(KM3(1.0).to_mm3(), MM3(1.0e18));
+    /// ```
+    fn to_mm3(self) -> MM3<Self::Output>;
+
+    /// try_to_mm3 is like to_mm3, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of cubic millimeters.
+    fn try_to_mm3(self) -> Option<MM3<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMM3<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_mm3().0).map(MM3),
+            None => None,
+        }
+    }
+
+    /// to_mm3_rounded is like to_mm3, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_mm3_rounded(self, mode: Rounding) -> Option<MM3<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMM3<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_mm3().0, mode).map(MM3),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// MM3 represents cubic millimeters (the volume of a 1x1x1 millimeter cube).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::MM3;
+    ///
+    /// let x = MM3(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    MM3, "mm³", "cubic millimeters"
+}
+
+impl<T: Div<Output = T>> Div<MM<T>> for MM3<T> {
+    type Output = MM2<T>;
+
+    /// div returns the base area of a prism of this volume and the given
+    /// height.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM, MM2, MM3};
+    ///
+    /// assert_eq!(MM3(12.0) / MM(2.0), MM2(6.0));
+    /// ```
+    fn div(self, rhs: MM<T>) -> MM2<T> {
+        MM2(self.0 / rhs.0)
+    }
+}
+
+impl<T: Div<Output = T>> Div<MM2<T>> for MM3<T> {
+    type Output = MM<T>;
+
+    /// div returns the height of a prism of this volume and the given base
+    /// area.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MM, MM2, MM3};
+    ///
+    /// assert_eq!(MM3(12.0) / MM2(6.0), MM(2.0));
+    /// ```
+    fn div(self, rhs: MM2<T>) -> MM<T> {
+        MM(self.0 / rhs.0)
+    }
+}
@@ -3,12 +3,97 @@ pub mod mm;
 pub mod cm;
 pub mod m;
 pub mod km;
+pub mod inch;
+pub mod mm2;
+pub mod cm2;
+pub mod m2;
+pub mod km2;
+pub mod in2;
+pub mod mm3;
+pub mod cm3;
+pub mod m3;
+pub mod km3;
 pub mod rad;
 pub mod deg;
+pub mod sr;
+pub mod ns;
+pub mod us;
+pub mod ms;
+pub mod s;
+pub mod min;
+pub mod hr;
+pub mod mg;
+pub mod g;
+pub mod kg;
+pub mod tonne;
+pub mod lb;
+pub mod oz;
+pub mod celsius;
+pub mod kelvin;
+pub mod fahrenheit;
+pub mod mpers;
+pub mod kmperh;
+pub mod mpers2;
+pub mod hz;
+pub mod khz;
+pub mod mhz;
+pub mod px;
+pub mod dist;
+pub mod time;
+pub mod mass;
+pub mod quantity;
+mod error;
+mod round;
+mod named;
+#[cfg(feature = "serde")]
+pub mod tagged;
 
 pub use self::mm::*;
 pub use self::cm::*;
 pub use self::m::*;
 pub use self::km::*;
+pub use self::inch::*;
+pub use self::mm2::*;
+pub use self::cm2::*;
+pub use self::m2::*;
+pub use self::km2::*;
+pub use self::in2::*;
+pub use self::mm3::*;
+pub use self::cm3::*;
+pub use self::m3::*;
+pub use self::km3::*;
 pub use self::rad::{Rad, ToRad};
 pub use self::deg::{Deg, ToDeg};
+pub use self::sr::{Sr, ToSr};
+pub use self::ns::*;
+pub use self::us::*;
+pub use self::ms::*;
+pub use self::s::*;
+pub use self::min::*;
+pub use self::hr::*;
+pub use self::mg::*;
+pub use self::g::*;
+pub use self::kg::*;
+pub use self::tonne::*;
+pub use self::lb::*;
+pub use self::oz::*;
+pub use self::celsius::*;
+pub use self::kelvin::*;
+pub use self::fahrenheit::*;
+pub use self::mpers::*;
+pub use self::kmperh::*;
+pub use self::mpers2::*;
+pub use self::hz::*;
+pub use self::khz::*;
+pub use self::mhz::*;
+pub use self::px::*;
+pub use self::dist::Dist;
+pub use self::time::Time;
+pub use self::mass::Mass;
+pub use self::error::ParseUnitError;
+pub use self::round::{Rounding, IntoF64};
+pub use self::named::Named;
+#[doc(hidden)]
+pub use self::error::split_unit;
+#[doc(hidden)]
+pub use self::round::scale_unit;
@@ -0,0 +1,151 @@
+use num::{Num, NumCast};
+use core::ops::{Mul, Div};
+
+use super::kmperh::{KMPerH, ToKMPerH};
+use super::m::M;
+use super::s::S;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded, convert_unit};
+
+/// ToMPerS is the canonical trait to use for taking input in meters per second.
+///
+/// For example the kilometers per hour type (KMPerH) implements the ToMPerS
+/// trait and thus kilometers per hour can be given as a parameter to any input
+/// that seeks meters per second.
+pub trait ToMPerS{
+    type Output;
+
+    /// to_mpers returns these units in meters per second, performing conversion
+    /// if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_mpers).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{KMPerH, ToMPerS};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn drive<T: ToMPerS<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_mpers().0)
+    /// }
+    /// drive(KMPerH(2.0));
+    /// drive(KMPerH::<i64>(2));
+    /// ```
+    fn to_mpers(self) -> MPerS<Self::Output>;
+
+    /// try_to_mpers is like to_mpers, except that it returns None if the result
+    /// is out of range for the value type, or if the value type is an integer
+    /// type and the result is not a whole number of meters per second.
+    fn try_to_mpers(self) -> Option<MPerS<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMPerS<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_mpers().0).map(MPerS),
+            None => None,
+        }
+    }
+
+    /// to_mpers_rounded is like to_mpers, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_mpers_rounded(self, mode: Rounding) -> Option<MPerS<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMPerS<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_mpers().0, mode).map(MPerS),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// MPerS represents meters per second (the SI unit representing speed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::MPerS;
+    ///
+    /// let x = MPerS(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    MPerS, "m/s", "meters per second"
+}
+
+impl<T: Num + NumCast> ToMPerS for MPerS<T> {
+    type Output = T;
+
+    /// to_mpers simply returns self.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MPerS, ToMPerS};
+    ///
+    /// assert_eq!(MPerS(1.0).to_mpers(), MPerS(1.0));
+    /// ```
+    fn to_mpers(self) -> MPerS<T> {
+        self
+    }
+}
+
+impl<T: Num + NumCast> ToKMPerH for MPerS<T> {
+    type Output = T;
+
+    /// to_kmperh returns these meters per second converted to kilometers per
+    /// hour.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MPerS, KMPerH, ToKMPerH};
+    ///
+    /// assert_eq!(MPerS(1.0).to_kmperh(), KMPerH(3.6));
+    /// ```
+    fn to_kmperh(self) -> KMPerH<T> {
+        KMPerH(convert_unit(self.0, |x| x * 3.6))
+    }
+}
+
+impl<T: Div<Output = T>> Div<S<T>> for M<T> {
+    type Output = MPerS<T>;
+
+    /// div returns the speed at which this distance is covered in the given
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{M, S, MPerS};
+    ///
+    /// assert_eq!(M(10.0) / S(2.0), MPerS(5.0));
+    /// ```
+    fn div(self, rhs: S<T>) -> MPerS<T> {
+        MPerS(self.0 / rhs.0)
+    }
+}
+
+impl<T: Mul<Output = T>> Mul<S<T>> for MPerS<T> {
+    type Output = M<T>;
+
+    /// mul returns the distance covered at this speed in the given time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{M, S, MPerS};
+    ///
+    /// assert_eq!(MPerS(5.0) * S(2.0), M(10.0));
+    /// ```
+    fn mul(self, rhs: S<T>) -> M<T> {
+        M(self.0 * rhs.0)
+    }
+}
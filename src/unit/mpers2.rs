@@ -0,0 +1,132 @@
+use num::{Num, NumCast};
+use core::ops::{Mul, Div};
+
+use super::mpers::MPerS;
+use super::s::S;
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToMPerS2 is the canonical trait to use for taking input in meters per
+/// second squared.
+pub trait ToMPerS2{
+    type Output;
+
+    /// to_mpers2 returns these units in meters per second squared, performing
+    /// conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_mpers2).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MPerS2, ToMPerS2};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn accelerate<T: ToMPerS2<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_mpers2().0)
+    /// }
+    /// accelerate(MPerS2(9.81));
+    /// accelerate(MPerS2::<i64>(2));
+    /// ```
+    fn to_mpers2(self) -> MPerS2<Self::Output>;
+
+    /// try_to_mpers2 is like to_mpers2, except that it returns None if the
+    /// result is out of range for the value type, or if the value type is an
+    /// integer type and the result is not a whole number of meters per second
+    /// squared.
+    fn try_to_mpers2(self) -> Option<MPerS2<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMPerS2<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_mpers2().0).map(MPerS2),
+            None => None,
+        }
+    }
+
+    /// to_mpers2_rounded is like to_mpers2, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_mpers2_rounded(self, mode: Rounding) -> Option<MPerS2<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMPerS2<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_mpers2().0, mode).map(MPerS2),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// MPerS2 represents meters per second squared (the SI unit representing
+    /// acceleration).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::MPerS2;
+    ///
+    /// let x = MPerS2(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    MPerS2, "m/s²", "meters per second squared"
+}
+
+impl<T: Num + NumCast> ToMPerS2 for MPerS2<T> {
+    type Output = T;
+
+    /// to_mpers2 simply returns self.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MPerS2, ToMPerS2};
+    ///
+    /// assert_eq!(MPerS2(1.0).to_mpers2(), MPerS2(1.0));
+    /// ```
+    fn to_mpers2(self) -> MPerS2<T> {
+        self
+    }
+}
+
+impl<T: Div<Output = T>> Div<S<T>> for MPerS<T> {
+    type Output = MPerS2<T>;
+
+    /// div returns the acceleration at which this change in speed occurs in the
+    /// given time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{S, MPerS, MPerS2};
+    ///
+    /// assert_eq!(MPerS(10.0) / S(4.0), MPerS2(2.5));
+    /// ```
+    fn div(self, rhs: S<T>) -> MPerS2<T> {
+        MPerS2(self.0 / rhs.0)
+    }
+}
+
+impl<T: Mul<Output = T>> Mul<S<T>> for MPerS2<T> {
+    type Output = MPerS<T>;
+
+    /// mul returns the change in speed caused by this acceleration over the
+    /// given time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{S, MPerS, MPerS2};
+    ///
+    /// assert_eq!(MPerS2(2.5) * S(4.0), MPerS(10.0));
+    /// ```
+    fn mul(self, rhs: S<T>) -> MPerS<T> {
+        MPerS(self.0 * rhs.0)
+    }
+}
@@ -0,0 +1,86 @@
+use num::NumCast;
+
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToMS is the canonical trait to use for taking input in milliseconds.
+///
+/// For example the seconds type (S) implements the ToMS trait and thus
+/// seconds can be given as a parameter to any input that seeks milliseconds.
+pub trait ToMS{
+    type Output;
+
+    /// to_ms returns these units in milliseconds, performing conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_ms).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{S, ToMS};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn wait<T: ToMS<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_ms().0)
+    /// }
+    /// wait(S(2.0));
+    /// wait(S::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{NS, US, MS, S, Min, Hr, ToMS};
+    ///
+    /// assert_eq!(NS(1000000.0).to_ms(), MS(1.0));
+    /// assert_eq!(US(1000.0).to_ms(), MS(1.0));
+    /// assert_eq!(MS(1.0).to_ms(), MS(1.0));
+    /// assert_eq!(S(1.0).to_ms(), MS(1000.0));
+    /// assert_eq!(Min(1.0).to_ms(), MS(60000.0));
+    /// assert_eq!(Hr(1.0).to_ms(), MS(3600000.0));
+    /// ```
+    fn to_ms(self) -> MS<Self::Output>;
+
+    /// try_to_ms is like to_ms, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of milliseconds.
+    fn try_to_ms(self) -> Option<MS<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMS<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_ms().0).map(MS),
+            None => None,
+        }
+    }
+
+    /// to_ms_rounded is like to_ms, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_ms_rounded(self, mode: Rounding) -> Option<MS<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToMS<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_ms().0, mode).map(MS),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// MS represents milliseconds (1/1000th a second).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::MS;
+    ///
+    /// let x = MS(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    MS, "ms", "milliseconds"
+}
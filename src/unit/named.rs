@@ -0,0 +1,35 @@
+/// Named is implemented by unit types that were given a suffix and a full name
+/// (see the `unit!` macro), allowing generic code to access both along with
+/// the value of the unit.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::unit::{Named, KM};
+///
+/// fn describe<U: Named>(x: U) -> String
+///     where U::Value: ToString
+/// {
+///     format!("{} ({})", x.value_ref().to_string(), U::NAME)
+/// }
+///
+/// assert_eq!(describe(KM(3)), "3 (kilometers)");
+/// assert_eq!(KM::<i32>::SUFFIX, "km");
+/// assert_eq!(KM::from_value(3), KM(3));
+/// ```
+pub trait Named {
+    /// Value is the type of the value of the unit.
+    type Value;
+
+    /// SUFFIX is the short suffix of the unit, e.g. "km".
+    const SUFFIX: &'static str;
+
+    /// NAME is the full name of the unit, e.g. "kilometers".
+    const NAME: &'static str;
+
+    /// value_ref returns a reference to the value of the unit.
+    fn value_ref(&self) -> &Self::Value;
+
+    /// from_value returns the unit with the given value.
+    fn from_value(value: Self::Value) -> Self;
+}
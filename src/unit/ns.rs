@@ -0,0 +1,86 @@
+use num::NumCast;
+
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToNS is the canonical trait to use for taking input in nanoseconds.
+///
+/// For example the seconds type (S) implements the ToNS trait and thus
+/// seconds can be given as a parameter to any input that seeks nanoseconds.
+pub trait ToNS{
+    type Output;
+
+    /// to_ns returns these units in nanoseconds, performing conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_ns).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{S, ToNS};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn wait<T: ToNS<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_ns().0)
+    /// }
+    /// wait(S(2.0));
+    /// wait(S::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{NS, US, MS, S, Min, Hr, ToNS};
+    ///
+    /// assert_eq!(NS(1.0).to_ns(), NS(1.0));
+    /// assert_eq!(US(1.0).to_ns(), NS(1000.0));
+    /// assert_eq!(MS(1.0).to_ns(), NS(1000000.0));
+    /// assert_eq!(S(1.0).to_ns(), NS(1.0e9));
+    /// assert_eq!(Min(1.0).to_ns(), NS(6.0e10));
+    /// assert_eq!(Hr(1.0).to_ns(), NS(3.6e12));
+    /// ```
+    fn to_ns(self) -> NS<Self::Output>;
+
+    /// try_to_ns is like to_ns, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of nanoseconds.
+    fn try_to_ns(self) -> Option<NS<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToNS<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_ns().0).map(NS),
+            None => None,
+        }
+    }
+
+    /// to_ns_rounded is like to_ns, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_ns_rounded(self, mode: Rounding) -> Option<NS<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToNS<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_ns().0, mode).map(NS),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// NS represents nanoseconds (1/1000th a microsecond).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::NS;
+    ///
+    /// let x = NS(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    NS, "ns", "nanoseconds"
+}
@@ -0,0 +1,86 @@
+use num::NumCast;
+
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToOZ is the canonical trait to use for taking input in ounces.
+///
+/// For example the grams type (G) implements the ToOZ trait and thus
+/// grams can be given as a parameter to any input that seeks ounces.
+pub trait ToOZ{
+    type Output;
+
+    /// to_oz returns these units in ounces, performing conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_oz).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{G, ToOZ};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn wait<T: ToOZ<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_oz().0)
+    /// }
+    /// wait(G(2.0));
+    /// wait(G::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MG, G, KG, Tonne, LB, OZ, ToOZ};
+    ///
+    /// assert_eq!(MG(1.0).to_oz(), OZ(3.5273961949580415e-05));
+    /// assert_eq!(G(1.0).to_oz(), OZ(0.035273961949580414));
+    /// assert_eq!(KG(1.0).to_oz(), OZ(35.27396194958041));
+    /// assert_eq!(Tonne(1.0).to_oz(), OZ(35273.961949580415));
+    /// assert_eq!(LB(1.0).to_oz(), OZ(16.0));
+    /// assert_eq!(OZ(1.0).to_oz(), OZ(1.0));
+    /// ```
+    fn to_oz(self) -> OZ<Self::Output>;
+
+    /// try_to_oz is like to_oz, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of ounces.
+    fn try_to_oz(self) -> Option<OZ<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToOZ<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_oz().0).map(OZ),
+            None => None,
+        }
+    }
+
+    /// to_oz_rounded is like to_oz, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_oz_rounded(self, mode: Rounding) -> Option<OZ<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToOZ<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_oz().0, mode).map(OZ),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// OZ represents international avoirdupois ounces (1/16th a pound).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::OZ;
+    ///
+    /// let x = OZ(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    OZ, "oz", "ounces"
+}
@@ -0,0 +1,153 @@
+use num::{Num, NumCast};
+use core::ops::{Mul, Div};
+
+use super::inch::{IN, ToIN};
+use super::mm::{MM, ToMM};
+
+unit! {
+    /// Px represents pixels, for example screen coordinates in UI layout code. The
+    /// physical size of a pixel depends on the display, and is given by a `Dpi`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::Px;
+    ///
+    /// let x = Px(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::Px;
+    ///
+    /// assert_eq!(format!("{}", Px(12)), "12 px");
+    /// assert_eq!(format!("{:#}", Px(12)), "12 pixels");
+    /// ```
+    Px, "px", "pixels"
+}
+
+unit! {
+    /// Dpi represents the resolution of a display in dots (pixels) per inch, and
+    /// converts between pixels and physical units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Dpi, Px, IN, MM};
+    ///
+    /// let dpi = Dpi(96.0);
+    ///
+    /// assert_eq!(IN(2.0) * dpi, Px(192.0));
+    /// assert_eq!(Px(48.0) / dpi, IN(0.5));
+    /// assert_eq!(Px(192.0) / IN(2.0), dpi);
+    ///
+    /// assert_eq!(dpi.to_px(MM(25.4)), Px(96.0));
+    /// assert_eq!(dpi.px_to_mm(Px(96.0)), MM(25.4));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::Dpi;
+    ///
+    /// assert_eq!(format!("{}", Dpi(96)), "96 dpi");
+    /// assert_eq!(format!("{:#}", Dpi(96)), "96 dots per inch");
+    /// ```
+    Dpi, "dpi", "dots per inch"
+}
+
+impl<T: Num + NumCast> Dpi<T> {
+    /// to_px returns the given physical distance in pixels at this resolution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Dpi, Px, IN};
+    ///
+    /// assert_eq!(Dpi(300.0).to_px(IN(0.5)), Px(150.0));
+    /// assert_eq!(Dpi(96).to_px(IN(2)), Px(192));
+    /// ```
+    pub fn to_px<D: ToIN<Output = T>>(self, d: D) -> Px<T> {
+        d.to_in() * self
+    }
+
+    /// px_to_in returns the given number of pixels in inches at this
+    /// resolution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Dpi, Px, IN};
+    ///
+    /// assert_eq!(Dpi(96.0).px_to_in(Px(24.0)), IN(0.25));
+    /// ```
+    pub fn px_to_in(self, px: Px<T>) -> IN<T> {
+        px / self
+    }
+
+    /// px_to_mm returns the given number of pixels in millimeters at this
+    /// resolution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Dpi, Px, MM};
+    ///
+    /// assert_eq!(Dpi(100.0).px_to_mm(Px(50.0)), MM(12.7));
+    /// ```
+    pub fn px_to_mm(self, px: Px<T>) -> MM<T> {
+        self.px_to_in(px).to_mm()
+    }
+}
+
+impl<T: Mul<Output = T>> Mul<Dpi<T>> for IN<T> {
+    type Output = Px<T>;
+
+    /// mul returns the number of pixels spanned by this distance at the given
+    /// resolution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Dpi, Px, IN};
+    ///
+    /// assert_eq!(IN(2.0) * Dpi(96.0), Px(192.0));
+    /// ```
+    fn mul(self, rhs: Dpi<T>) -> Px<T> {
+        Px(self.0 * rhs.0)
+    }
+}
+
+impl<T: Div<Output = T>> Div<Dpi<T>> for Px<T> {
+    type Output = IN<T>;
+
+    /// div returns the physical distance spanned by these pixels at the given
+    /// resolution.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Dpi, Px, IN};
+    ///
+    /// assert_eq!(Px(192.0) / Dpi(96.0), IN(2.0));
+    /// ```
+    fn div(self, rhs: Dpi<T>) -> IN<T> {
+        IN(self.0 / rhs.0)
+    }
+}
+
+impl<T: Div<Output = T>> Div<IN<T>> for Px<T> {
+    type Output = Dpi<T>;
+
+    /// div returns the resolution at which these pixels span the given
+    /// distance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Dpi, Px, IN};
+    ///
+    /// assert_eq!(Px(192.0) / IN(2.0), Dpi(96.0));
+    /// ```
+    fn div(self, rhs: IN<T>) -> Dpi<T> {
+        Dpi(self.0 / rhs.0)
+    }
+}
@@ -0,0 +1,418 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Add, Sub, Mul, Div, Neg};
+use core::cmp::Ordering;
+
+use super::m::M;
+use super::m2::M2;
+use super::m3::M3;
+use super::kg::KG;
+use super::s::S;
+use super::hz::Hz;
+use super::mpers::MPerS;
+use super::mpers2::MPerS2;
+
+/// Exp is a type-level integer, used as the exponent of a dimension of a
+/// `Quantity`. Exponents in the range -4 to 4 are supported.
+pub trait Exp {
+    /// value returns the integer that this type represents.
+    fn value() -> i8;
+}
+
+/// ExpInc increments a type-level integer.
+pub trait ExpInc {
+    type Output;
+}
+
+/// ExpDec decrements a type-level integer.
+pub trait ExpDec {
+    type Output;
+}
+
+/// ExpNeg negates a type-level integer.
+pub trait ExpNeg {
+    type Output;
+}
+
+/// ExpAdd adds two type-level integers.
+pub trait ExpAdd<Rhs> {
+    type Output;
+}
+
+/// ExpSub subtracts two type-level integers.
+pub trait ExpSub<Rhs> {
+    type Output;
+}
+
+macro_rules! exps {
+    ($($name:ident = $value:expr, -$neg:ident;)*) => {
+        $(
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub enum $name {}
+
+            impl Exp for $name {
+                fn value() -> i8 {
+                    $value
+                }
+            }
+
+            impl ExpNeg for $name {
+                type Output = $neg;
+            }
+        )*
+    }
+}
+
+macro_rules! exp_chain {
+    ($a:ident, $b:ident) => {
+        impl ExpInc for $a {
+            type Output = $b;
+        }
+
+        impl ExpDec for $b {
+            type Output = $a;
+        }
+    };
+    ($a:ident, $b:ident, $($rest:ident),+) => {
+        exp_chain!($a, $b);
+        exp_chain!($b, $($rest),+);
+    };
+}
+
+exps! {
+    N4 = -4, -P4;
+    N3 = -3, -P3;
+    N2 = -2, -P2;
+    N1 = -1, -P1;
+    Z0 = 0, -Z0;
+    P1 = 1, -N1;
+    P2 = 2, -N2;
+    P3 = 3, -N3;
+    P4 = 4, -N4;
+}
+exp_chain!(N4, N3, N2, N1, Z0, P1, P2, P3, P4);
+
+// Addition is defined recursively, by moving one step at a time from the left
+// hand side towards zero while moving the right hand side in the opposite
+// direction.
+impl<R> ExpAdd<R> for Z0 {
+    type Output = R;
+}
+
+macro_rules! exp_add {
+    ($($name:ident => $toward_zero:ident, $step:ident;)*) => {
+        $(
+            impl<R> ExpAdd<R> for $name
+                where $toward_zero: ExpAdd<R>,
+                      <$toward_zero as ExpAdd<R>>::Output: $step
+            {
+                type Output = <<$toward_zero as ExpAdd<R>>::Output as $step>::Output;
+            }
+        )*
+    }
+}
+
+exp_add! {
+    P1 => Z0, ExpInc;
+    P2 => P1, ExpInc;
+    P3 => P2, ExpInc;
+    P4 => P3, ExpInc;
+    N1 => Z0, ExpDec;
+    N2 => N1, ExpDec;
+    N3 => N2, ExpDec;
+    N4 => N3, ExpDec;
+}
+
+impl<L, R> ExpSub<R> for L
+    where R: ExpNeg,
+          L: ExpAdd<<R as ExpNeg>::Output>
+{
+    type Output = <L as ExpAdd<<R as ExpNeg>::Output>>::Output;
+}
+
+/// Quantity is a value in SI base units with the dimensions length^L, mass^Ms
+/// and time^Ti, where each exponent is a type-level integer (see `Exp`).
+/// Multiplying and dividing quantities adds and subtracts their exponents, so
+/// that e.g. a mass times an acceleration is a force, while adding a force to
+/// an energy does not compile.
+///
+/// The single-dimension unit types (e.g. `M`, `KG` and `S`) convert into the
+/// matching quantity using `From`; other units can be converted into those
+/// first (e.g. using `to_m`).
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::unit::{KG, MPerS2};
+/// use fiz_math::unit::quantity::{Quantity, Force, Mass, Acceleration};
+///
+/// let m: Mass<f64> = KG(2.0).into();
+/// let a: Acceleration<f64> = MPerS2(9.0).into();
+/// let f: Force<f64> = m * a;
+/// assert_eq!(f, Quantity::new(18.0));
+/// assert_eq!(format!("{}", f), "18 m kg s^-2");
+/// ```
+pub struct Quantity<T, L, Ms, Ti> {
+    pub value: T,
+    dims: PhantomData<(L, Ms, Ti)>,
+}
+
+/// Scalar is a dimensionless quantity.
+pub type Scalar<T> = Quantity<T, Z0, Z0, Z0>;
+
+/// Length is a quantity in meters.
+pub type Length<T> = Quantity<T, P1, Z0, Z0>;
+
+/// Area is a quantity in square meters.
+pub type Area<T> = Quantity<T, P2, Z0, Z0>;
+
+/// Volume is a quantity in cubic meters.
+pub type Volume<T> = Quantity<T, P3, Z0, Z0>;
+
+/// Mass is a quantity in kilograms.
+pub type Mass<T> = Quantity<T, Z0, P1, Z0>;
+
+/// Time is a quantity in seconds.
+pub type Time<T> = Quantity<T, Z0, Z0, P1>;
+
+/// Frequency is a quantity in hertz (per second).
+pub type Frequency<T> = Quantity<T, Z0, Z0, N1>;
+
+/// Velocity is a quantity in meters per second.
+pub type Velocity<T> = Quantity<T, P1, Z0, N1>;
+
+/// Acceleration is a quantity in meters per second squared.
+pub type Acceleration<T> = Quantity<T, P1, Z0, N2>;
+
+/// Momentum is a quantity in kilogram meters per second.
+pub type Momentum<T> = Quantity<T, P1, P1, N1>;
+
+/// Force is a quantity in newtons (kilogram meters per second squared).
+pub type Force<T> = Quantity<T, P1, P1, N2>;
+
+/// Energy is a quantity in joules (newton meters).
+pub type Energy<T> = Quantity<T, P2, P1, N2>;
+
+/// Power is a quantity in watts (joules per second).
+pub type Power<T> = Quantity<T, P2, P1, N3>;
+
+impl<T, L, Ms, Ti> Quantity<T, L, Ms, Ti> {
+    /// new returns a quantity with the given value in SI base units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::quantity::Length;
+    ///
+    /// let x = Length::new(2.0);
+    /// assert_eq!(x.value, 2.0);
+    /// ```
+    pub fn new(value: T) -> Self {
+        Quantity {
+            value,
+            dims: PhantomData,
+        }
+    }
+}
+
+impl<T: Mul<Output = T>, L, Ms, Ti> Quantity<T, L, Ms, Ti> {
+    /// mul_scalar returns this quantity scaled by a dimensionless value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::quantity::Length;
+    ///
+    /// assert_eq!(Length::new(2.0).mul_scalar(3.0), Length::new(6.0));
+    /// ```
+    pub fn mul_scalar(self, rhs: T) -> Self {
+        Quantity::new(self.value * rhs)
+    }
+}
+
+impl<T: Div<Output = T>, L, Ms, Ti> Quantity<T, L, Ms, Ti> {
+    /// div_scalar returns this quantity divided by a dimensionless value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::quantity::Length;
+    ///
+    /// assert_eq!(Length::new(6.0).div_scalar(3.0), Length::new(2.0));
+    /// ```
+    pub fn div_scalar(self, rhs: T) -> Self {
+        Quantity::new(self.value / rhs)
+    }
+}
+
+impl<T: Copy, L, Ms, Ti> Copy for Quantity<T, L, Ms, Ti> {}
+
+impl<T: Clone, L, Ms, Ti> Clone for Quantity<T, L, Ms, Ti> {
+    fn clone(&self) -> Self {
+        Quantity::new(self.value.clone())
+    }
+}
+
+impl<T: PartialEq, L, Ms, Ti> PartialEq for Quantity<T, L, Ms, Ti> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: PartialOrd, L, Ms, Ti> PartialOrd for Quantity<T, L, Ms, Ti> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+// write_dim writes a single dimension with the given exponent, omitting it if
+// the exponent is zero.
+fn write_dim(f: &mut fmt::Formatter, sym: &str, exp: i8) -> fmt::Result {
+    match exp {
+        0 => Ok(()),
+        1 => write!(f, " {}", sym),
+        _ => write!(f, " {}^{}", sym, exp),
+    }
+}
+
+impl<T: fmt::Debug, L: Exp, Ms: Exp, Ti: Exp> fmt::Debug for Quantity<T, L, Ms, Ti> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f,
+               "Quantity({:?}, m^{}, kg^{}, s^{})",
+               self.value,
+               L::value(),
+               Ms::value(),
+               Ti::value())
+    }
+}
+
+impl<T: fmt::Display, L: Exp, Ms: Exp, Ti: Exp> fmt::Display for Quantity<T, L, Ms, Ti> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.value.fmt(f)?;
+        write_dim(f, "m", L::value())?;
+        write_dim(f, "kg", Ms::value())?;
+        write_dim(f, "s", Ti::value())
+    }
+}
+
+impl<T: Add<Output = T>, L, Ms, Ti> Add for Quantity<T, L, Ms, Ti> {
+    type Output = Self;
+
+    /// add adds two quantities of the same dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::quantity::Length;
+    ///
+    /// assert_eq!(Length::new(1.0) + Length::new(2.0), Length::new(3.0));
+    /// ```
+    fn add(self, rhs: Self) -> Self {
+        Quantity::new(self.value + rhs.value)
+    }
+}
+
+impl<T: Sub<Output = T>, L, Ms, Ti> Sub for Quantity<T, L, Ms, Ti> {
+    type Output = Self;
+
+    /// sub subtracts two quantities of the same dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::quantity::Length;
+    ///
+    /// assert_eq!(Length::new(3.0) - Length::new(2.0), Length::new(1.0));
+    /// ```
+    fn sub(self, rhs: Self) -> Self {
+        Quantity::new(self.value - rhs.value)
+    }
+}
+
+impl<T: Neg<Output = T>, L, Ms, Ti> Neg for Quantity<T, L, Ms, Ti> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Quantity::new(-self.value)
+    }
+}
+
+impl<T, L1, Ms1, Ti1, L2, Ms2, Ti2> Mul<Quantity<T, L2, Ms2, Ti2>> for Quantity<T, L1, Ms1, Ti1>
+    where T: Mul<Output = T>,
+          L1: ExpAdd<L2>,
+          Ms1: ExpAdd<Ms2>,
+          Ti1: ExpAdd<Ti2>
+{
+    type Output = Quantity<T, L1::Output, Ms1::Output, Ti1::Output>;
+
+    /// mul multiplies two quantities, adding the exponents of their
+    /// dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::quantity::{Length, Area};
+    ///
+    /// let a: Area<f64> = Length::new(2.0) * Length::new(3.0);
+    /// assert_eq!(a, Area::new(6.0));
+    /// ```
+    fn mul(self, rhs: Quantity<T, L2, Ms2, Ti2>) -> Self::Output {
+        Quantity::new(self.value * rhs.value)
+    }
+}
+
+impl<T, L1, Ms1, Ti1, L2, Ms2, Ti2> Div<Quantity<T, L2, Ms2, Ti2>> for Quantity<T, L1, Ms1, Ti1>
+    where T: Div<Output = T>,
+          L1: ExpSub<L2>,
+          Ms1: ExpSub<Ms2>,
+          Ti1: ExpSub<Ti2>
+{
+    type Output = Quantity<T, L1::Output, Ms1::Output, Ti1::Output>;
+
+    /// div divides two quantities, subtracting the exponents of their
+    /// dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::quantity::{Length, Time, Velocity, Frequency, Scalar};
+    ///
+    /// let v: Velocity<f64> = Length::new(10.0) / Time::new(2.0);
+    /// assert_eq!(v, Velocity::new(5.0));
+    ///
+    /// let f: Frequency<f64> = Scalar::new(1.0) / Time::new(0.5);
+    /// assert_eq!(f, Frequency::new(2.0));
+    /// ```
+    fn div(self, rhs: Quantity<T, L2, Ms2, Ti2>) -> Self::Output {
+        Quantity::new(self.value / rhs.value)
+    }
+}
+
+macro_rules! quantity_from {
+    ($($unit:ident => $quantity:ident;)*) => {
+        $(
+            impl<T> From<$unit<T>> for $quantity<T> {
+                fn from(x: $unit<T>) -> Self {
+                    Quantity::new(x.0)
+                }
+            }
+
+            impl<T> From<$quantity<T>> for $unit<T> {
+                fn from(x: $quantity<T>) -> Self {
+                    $unit(x.value)
+                }
+            }
+        )*
+    }
+}
+
+quantity_from! {
+    M => Length;
+    M2 => Area;
+    M3 => Volume;
+    KG => Mass;
+    S => Time;
+    Hz => Frequency;
+    MPerS => Velocity;
+    MPerS2 => Acceleration;
+}
@@ -1,7 +1,8 @@
-use num::traits::{Num, NumCast};
-use std::f64;
+use num::{Num, NumCast};
+use core::f64;
 
 use super::deg::{Deg, ToDeg};
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
 
 /// ToRad is the canonical trait to use for taking input in radians.
 ///
@@ -12,6 +13,11 @@ pub trait ToRad{
 
     /// to_rad returns these units in radians, performing conversion if needed.
     ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_rad).
+    ///
     /// # Examples
     ///
     /// ```
@@ -26,19 +32,56 @@ pub trait ToRad{
     /// walk(Deg::<i16>(2));
     /// ```
     fn to_rad(self) -> Rad<Self::Output>;
+
+    /// try_to_rad is like to_rad, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of radians.
+    fn try_to_rad(self) -> Option<Rad<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToRad<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_rad().0).map(Rad),
+            None => None,
+        }
+    }
+
+    /// to_rad_rounded is like to_rad, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_rad_rounded(self, mode: Rounding) -> Option<Rad<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToRad<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_rad().0, mode).map(Rad),
+            None => None,
+        }
+    }
 }
 
-/// Rad represents radians (the standard unit of angular measure).
-///
-/// # Examples
-///
-/// ```
-/// use fiz_math::unit::Rad;
-///
-/// let x = Rad(1.0);
-/// println!("{:?}", x);
-/// ```
-unit!(Rad);
+unit! {
+    /// Rad represents radians (the standard unit of angular measure).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::Rad;
+    ///
+    /// let x = Rad(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::Rad;
+    ///
+    /// assert_eq!(format!("{}", Rad(0.5)), "0.5 rad");
+    /// assert_eq!(format!("{:#}", Rad(0.5)), "0.5 radians");
+    /// ```
+    Rad, "rad", "radians"
+}
 
 impl<T: Num + NumCast> ToRad for Rad<T> {
     type Output = T;
@@ -0,0 +1,148 @@
+use num::{Float, Num, NumCast};
+
+/// Rounding selects how a unit conversion rounds a result that is not exactly
+/// representable, e.g. when converting integer meters to kilometers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Floor rounds towards negative infinity.
+    Floor,
+
+    /// Ceil rounds towards positive infinity.
+    Ceil,
+
+    /// Nearest rounds to the nearest integer, and half-way cases away from
+    /// zero.
+    Nearest,
+
+    /// Trunc rounds towards zero, like the plain (unchecked) conversions do
+    /// for integer types.
+    Trunc,
+}
+
+impl Rounding {
+    /// round rounds the given number to an integer using this mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::Rounding;
+    ///
+    /// assert_eq!(Rounding::Floor.round(-1.5), -2.0);
+    /// assert_eq!(Rounding::Ceil.round(-1.5), -1.0);
+    /// assert_eq!(Rounding::Nearest.round(-1.5), -2.0);
+    /// assert_eq!(Rounding::Trunc.round(-1.5), -1.0);
+    /// ```
+    pub fn round(self, x: f64) -> f64 {
+        match self {
+            Rounding::Floor => Float::floor(x),
+            Rounding::Ceil => Float::ceil(x),
+            Rounding::Nearest => Float::round(x),
+            Rounding::Trunc => Float::trunc(x),
+        }
+    }
+}
+
+/// IntoF64 is implemented by every unit type (see the `unit!` macro), and is
+/// used by the checked and rounding conversions (e.g. `try_to_mm`), which
+/// perform the conversion in double precision.
+pub trait IntoF64 {
+    /// F64 is the same unit type, holding a f64.
+    type F64;
+
+    /// into_f64 converts this value to a f64 of the same unit, or returns None
+    /// if it cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{IntoF64, MM};
+    ///
+    /// assert_eq!(MM(2u8).into_f64(), Some(MM(2.0)));
+    /// ```
+    fn into_f64(self) -> Option<Self::F64>;
+}
+
+// is_integer tells if the type T is an integer type.
+fn is_integer<T: NumCast>() -> bool {
+    match T::from(0.5) {
+        Some(half) => half.to_f64() != Some(0.5),
+        None => true,
+    }
+}
+
+// cast_checked converts the result of a conversion to T, returning None if it
+// is out of range, or if T is an integer type and the result is not a whole
+// number.
+pub fn cast_checked<T: NumCast>(x: f64) -> Option<T> {
+    if is_integer::<T>() && Float::fract(x) != 0.0 {
+        return None;
+    }
+    cast_rounded(x, Rounding::Trunc)
+}
+
+// cast_rounded converts the result of a conversion to T, rounding it with the
+// given mode if T is an integer type. It returns None if the result is out of
+// range.
+pub fn cast_rounded<T: NumCast>(x: f64, mode: Rounding) -> Option<T> {
+    if !x.is_finite() {
+        return None;
+    }
+    let x = if is_integer::<T>() { mode.round(x) } else { x };
+    T::from(x)
+}
+
+// near_whole returns the nearest whole number to x if x is within rounding
+// error of it.
+fn near_whole(x: f64) -> Option<f64> {
+    if (x - Float::round(x)).abs() <= x.abs() * 1e-9 {
+        Some(Float::round(x))
+    } else {
+        None
+    }
+}
+
+// OUT_OF_RANGE is the message of the panic of a conversion whose result is out
+// of range for an integer value type.
+const OUT_OF_RANGE: &str = "unit conversion out of range for the value type";
+
+// convert_unit converts x in double precision with the function f, for the
+// conversions which are not a change of scale (e.g. between temperature
+// scales). It panics if the result is out of range for T.
+pub fn convert_unit<T: NumCast, F: FnOnce(f64) -> f64>(x: T, f: F) -> T {
+    T::from(f(x.to_f64().expect(OUT_OF_RANGE))).expect(OUT_OF_RANGE)
+}
+
+// scale_unit converts x from a unit of the given size to a unit of another
+// size, for the units! macro. Sizes such as 0.001 are inexact, so a little
+// error is allowed when deciding whether one size is a whole multiple of the
+// other. It panics if the result is out of range for T, rather than letting an
+// integer multiplication overflow.
+pub fn scale_unit<T: Num + NumCast>(x: T, from: f64, to: f64) -> T {
+    if from >= to {
+        if let Some(r) = near_whole(from / to) {
+            // Check the range in double precision first, so that the integer
+            // multiplication cannot overflow. A factor too large for T then
+            // leaves only zero.
+            let y = x.to_f64().expect(OUT_OF_RANGE) * r;
+            assert!(T::from(y).is_some(), "{}", OUT_OF_RANGE);
+            return match T::from(r) {
+                Some(r) => x * r,
+                None => T::zero(),
+            };
+        }
+    } else if let Some(r) = near_whole(to / from) {
+        // A divisor too large for T is larger than any value of it, so only
+        // zero is left.
+        return match T::from(r) {
+            Some(r) => x / r,
+            None => T::zero(),
+        };
+    }
+    convert_unit(x, |x| {
+        let y = x * from / to;
+        match near_whole(y) {
+            Some(w) if is_integer::<T>() => w,
+            _ => y,
+        }
+    })
+}
@@ -0,0 +1,104 @@
+use num::NumCast;
+
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToS is the canonical trait to use for taking input in seconds.
+///
+/// For example the milliseconds type (MS) implements the ToS trait and thus
+/// milliseconds can be given as a parameter to any input that seeks seconds.
+pub trait ToS{
+    type Output;
+
+    /// to_s returns these units in seconds, performing conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_s).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MS, ToS};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn wait<T: ToS<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_s().0)
+    /// }
+    /// wait(MS(2.0));
+    /// wait(MS::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{NS, US, MS, S, Min, Hr, ToS};
+    ///
+    /// assert_eq!(NS(1.0e9).to_s(), S(1.0));
+    /// assert_eq!(US(1000000.0).to_s(), S(1.0));
+    /// assert_eq!(MS(1000.0).to_s(), S(1.0));
+    /// assert_eq!(S(1.0).to_s(), S(1.0));
+    /// assert_eq!(Min(1.0).to_s(), S(60.0));
+    /// assert_eq!(Hr(1.0).to_s(), S(3600.0));
+    /// ```
+    fn to_s(self) -> S<Self::Output>;
+
+    /// try_to_s is like to_s, except that it returns None if the result is out
+    /// of range for the value type, or if the value type is an integer type and
+    /// the result is not a whole number of seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MS, S, ToS};
+    ///
+    /// assert_eq!(MS(3000u32).try_to_s(), Some(S(3)));
+    /// assert_eq!(MS(3001u32).try_to_s(), None);
+    /// ```
+    fn try_to_s(self) -> Option<S<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToS<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_s().0).map(S),
+            None => None,
+        }
+    }
+
+    /// to_s_rounded is like to_s, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{MS, S, ToS, Rounding};
+    ///
+    /// assert_eq!(MS(1500u32).to_s_rounded(Rounding::Ceil), Some(S(2)));
+    /// assert_eq!(MS(1500u32).to_s_rounded(Rounding::Floor), Some(S(1)));
+    /// ```
+    fn to_s_rounded(self, mode: Rounding) -> Option<S<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToS<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_s().0, mode).map(S),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// S represents seconds (the SI base unit representing time).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::S;
+    ///
+    /// let x = S(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    S, "s", "seconds"
+}
@@ -0,0 +1,90 @@
+use num::{Num, NumCast};
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToSr is the canonical trait to use for taking input in steradians.
+pub trait ToSr{
+    type Output;
+
+    /// to_sr returns these units in steradians, performing conversion if
+    /// needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_sr).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Sr, ToSr};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn subtend<T: ToSr<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_sr().0)
+    /// }
+    /// subtend(Sr(2.0));
+    /// subtend(Sr::<i64>(2));
+    /// ```
+    fn to_sr(self) -> Sr<Self::Output>;
+
+    /// try_to_sr is like to_sr, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of steradians.
+    fn try_to_sr(self) -> Option<Sr<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToSr<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_sr().0).map(Sr),
+            None => None,
+        }
+    }
+
+    /// to_sr_rounded is like to_sr, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_sr_rounded(self, mode: Rounding) -> Option<Sr<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToSr<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_sr().0, mode).map(Sr),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// Sr represents steradians (the SI unit representing solid angle). The
+    /// entire sphere subtends 4π steradians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::Sr;
+    ///
+    /// let x = Sr(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    Sr, "sr", "steradians"
+}
+
+impl<T: Num + NumCast> ToSr for Sr<T> {
+    type Output = T;
+
+    /// to_sr simply returns self.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Sr, ToSr};
+    ///
+    /// assert_eq!(Sr(1.0).to_sr(), Sr(1.0));
+    /// ```
+    fn to_sr(self) -> Sr<T> {
+        self
+    }
+}
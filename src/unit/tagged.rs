@@ -0,0 +1,62 @@
+//! The tagged module serializes units as a `{value, unit}` map rather than as
+//! bare numbers, for use with `#[serde(with = "fiz_math::unit::tagged")]`:
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Track {
+//!     #[serde(with = "fiz_math::unit::tagged")]
+//!     length: KM<f64>, // {"value": 4.2, "unit": "km"}
+//! }
+//! ```
+//!
+//! Deserialization accepts either the suffix or the full name of the unit, and
+//! rejects any other unit.
+
+use serde::{Serialize, Serializer, Deserialize, Deserializer};
+use serde::de::Error;
+
+use prelude::*;
+
+use super::Named;
+
+#[derive(Serialize)]
+struct TaggedRef<'a, T: 'a> {
+    value: &'a T,
+    unit: &'static str,
+}
+
+#[derive(Deserialize)]
+struct Tagged<T> {
+    value: T,
+    unit: String,
+}
+
+/// serialize serializes the unit as a `{value, unit}` map, with the suffix of
+/// the unit (e.g. "km") as the unit.
+pub fn serialize<U, S>(x: &U, serializer: S) -> Result<S::Ok, S::Error>
+    where U: Named,
+          U::Value: Serialize,
+          S: Serializer
+{
+    TaggedRef {
+        value: x.value_ref(),
+        unit: U::SUFFIX,
+    }
+    .serialize(serializer)
+}
+
+/// deserialize deserializes a unit from a `{value, unit}` map, returning an
+/// error if the unit is neither the suffix nor the full name of `U`.
+pub fn deserialize<'de, U, D>(deserializer: D) -> Result<U, D::Error>
+    where U: Named,
+          U::Value: Deserialize<'de>,
+          D: Deserializer<'de>
+{
+    let t = Tagged::<U::Value>::deserialize(deserializer)?;
+    if t.unit != U::SUFFIX && t.unit != U::NAME {
+        return Err(D::Error::custom(format!("expected unit \"{}\", found \"{}\"",
+                                            U::SUFFIX,
+                                            t.unit)));
+    }
+    Ok(U::from_value(t.value))
+}
@@ -0,0 +1,284 @@
+use num::{Num, NumCast};
+use core::str::FromStr;
+use core::time::Duration;
+use prelude::*;
+
+use super::error::{ParseUnitError, split_unit};
+use super::ns::{NS, ToNS};
+use super::us::{US, ToUS};
+use super::ms::{MS, ToMS};
+use super::s::{S, ToS};
+use super::min::{Min, ToMin};
+use super::hr::{Hr, ToHr};
+
+// The conversions between each pair of time units.
+units! {
+    NS: ToNS::to_ns = 0.000000001 s,
+    US: ToUS::to_us = 0.000001 s,
+    MS: ToMS::to_ms = 0.001 s,
+    S: ToS::to_s = 1 s,
+    Min: ToMin::to_min = 60 s,
+    Hr: ToHr::to_hr = 3600 s,
+}
+
+/// Time is a duration in any of the time units, as returned by `parse`. It
+/// implements each of the time conversion traits, so it can be given to any
+/// input that seeks e.g. seconds.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::unit::{Time, MS, S, ToS};
+///
+/// let t = Time::MS(MS(1500.0));
+/// assert_eq!(t.to_s(), S(1.5));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Time<T> {
+    NS(NS<T>),
+    US(US<T>),
+    MS(MS<T>),
+    S(S<T>),
+    Min(Min<T>),
+    Hr(Hr<T>),
+}
+
+/// parse parses a unit-suffixed time (e.g. `"16ms"`, `"2 h"` or `"1.5 minutes"`)
+/// from a string, such as those found in configuration files or command-line
+/// arguments.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::unit::{time, Time, MS, Min, S, ToS};
+///
+/// assert_eq!(time::parse("16ms"), Ok(Time::MS(MS(16.0))));
+/// assert_eq!(time::parse::<i32>("2 minutes"), Ok(Time::Min(Min(2))));
+/// assert_eq!(time::parse("250 ms").unwrap().to_s(), S(0.25));
+/// assert!(time::parse::<f64>("3 fortnights").is_err());
+/// ```
+pub fn parse<T: FromStr>(s: &str) -> Result<Time<T>, ParseUnitError> {
+    // Each unit rejects suffixes other than its own, so the only error worth
+    // reporting over an unknown unit is an invalid value for a known one.
+    let mut err = ParseUnitError::UnknownUnit(split_unit(s).1.to_string());
+    match s.parse() {
+        Ok(x) => return Ok(Time::NS(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    match s.parse() {
+        Ok(x) => return Ok(Time::US(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    match s.parse() {
+        Ok(x) => return Ok(Time::MS(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    match s.parse() {
+        Ok(x) => return Ok(Time::S(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    match s.parse() {
+        Ok(x) => return Ok(Time::Min(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    match s.parse() {
+        Ok(x) => return Ok(Time::Hr(x)),
+        Err(e @ ParseUnitError::InvalidValue(_)) => err = e,
+        Err(_) => {}
+    }
+    Err(err)
+}
+
+impl<T: FromStr> FromStr for Time<T> {
+    type Err = ParseUnitError;
+
+    /// from_str is short-hand for `time::parse`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{Time, S};
+    ///
+    /// assert_eq!("2 s".parse(), Ok(Time::S(S(2.0))));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+impl<T: Num + NumCast> ToNS for Time<T> {
+    type Output = T;
+
+    fn to_ns(self) -> NS<T> {
+        match self {
+            Time::NS(x) => x.to_ns(),
+            Time::US(x) => x.to_ns(),
+            Time::MS(x) => x.to_ns(),
+            Time::S(x) => x.to_ns(),
+            Time::Min(x) => x.to_ns(),
+            Time::Hr(x) => x.to_ns(),
+        }
+    }
+}
+
+impl<T: Num + NumCast> ToUS for Time<T> {
+    type Output = T;
+
+    fn to_us(self) -> US<T> {
+        match self {
+            Time::NS(x) => x.to_us(),
+            Time::US(x) => x.to_us(),
+            Time::MS(x) => x.to_us(),
+            Time::S(x) => x.to_us(),
+            Time::Min(x) => x.to_us(),
+            Time::Hr(x) => x.to_us(),
+        }
+    }
+}
+
+impl<T: Num + NumCast> ToMS for Time<T> {
+    type Output = T;
+
+    fn to_ms(self) -> MS<T> {
+        match self {
+            Time::NS(x) => x.to_ms(),
+            Time::US(x) => x.to_ms(),
+            Time::MS(x) => x.to_ms(),
+            Time::S(x) => x.to_ms(),
+            Time::Min(x) => x.to_ms(),
+            Time::Hr(x) => x.to_ms(),
+        }
+    }
+}
+
+impl<T: Num + NumCast> ToS for Time<T> {
+    type Output = T;
+
+    fn to_s(self) -> S<T> {
+        match self {
+            Time::NS(x) => x.to_s(),
+            Time::US(x) => x.to_s(),
+            Time::MS(x) => x.to_s(),
+            Time::S(x) => x.to_s(),
+            Time::Min(x) => x.to_s(),
+            Time::Hr(x) => x.to_s(),
+        }
+    }
+}
+
+impl<T: Num + NumCast> ToMin for Time<T> {
+    type Output = T;
+
+    fn to_min(self) -> Min<T> {
+        match self {
+            Time::NS(x) => x.to_min(),
+            Time::US(x) => x.to_min(),
+            Time::MS(x) => x.to_min(),
+            Time::S(x) => x.to_min(),
+            Time::Min(x) => x.to_min(),
+            Time::Hr(x) => x.to_min(),
+        }
+    }
+}
+
+impl<T: Num + NumCast> ToHr for Time<T> {
+    type Output = T;
+
+    fn to_hr(self) -> Hr<T> {
+        match self {
+            Time::NS(x) => x.to_hr(),
+            Time::US(x) => x.to_hr(),
+            Time::MS(x) => x.to_hr(),
+            Time::S(x) => x.to_hr(),
+            Time::Min(x) => x.to_hr(),
+            Time::Hr(x) => x.to_hr(),
+        }
+    }
+}
+
+impl From<NS<u64>> for Duration {
+    /// from converts nanoseconds into a `std::time::Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use fiz_math::unit::NS;
+    ///
+    /// assert_eq!(Duration::from(NS(1500u64)), Duration::new(0, 1500));
+    /// ```
+    fn from(x: NS<u64>) -> Duration {
+        Duration::from_nanos(x.0)
+    }
+}
+
+impl From<US<u64>> for Duration {
+    /// from converts microseconds into a `std::time::Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use fiz_math::unit::US;
+    ///
+    /// assert_eq!(Duration::from(US(1500u64)), Duration::new(0, 1500000));
+    /// ```
+    fn from(x: US<u64>) -> Duration {
+        Duration::from_micros(x.0)
+    }
+}
+
+impl From<MS<u64>> for Duration {
+    /// from converts milliseconds into a `std::time::Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use fiz_math::unit::MS;
+    ///
+    /// assert_eq!(Duration::from(MS(1500u64)), Duration::new(1, 500000000));
+    /// ```
+    fn from(x: MS<u64>) -> Duration {
+        Duration::from_millis(x.0)
+    }
+}
+
+impl From<S<u64>> for Duration {
+    /// from converts seconds into a `std::time::Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use fiz_math::unit::S;
+    ///
+    /// assert_eq!(Duration::from(S(15u64)), Duration::new(15, 0));
+    /// ```
+    fn from(x: S<u64>) -> Duration {
+        Duration::from_secs(x.0)
+    }
+}
+
+impl From<Duration> for NS<u128> {
+    /// from converts a `std::time::Duration` into nanoseconds. Nanoseconds are
+    /// the resolution of a duration, and 128 bits can hold any duration, so no
+    /// precision is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use fiz_math::unit::NS;
+    ///
+    /// assert_eq!(NS::from(Duration::new(2, 5)), NS(2000000005u128));
+    /// ```
+    fn from(x: Duration) -> NS<u128> {
+        NS(x.as_nanos())
+    }
+}
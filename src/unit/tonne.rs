@@ -0,0 +1,86 @@
+use num::NumCast;
+
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToTonne is the canonical trait to use for taking input in tonnes.
+///
+/// For example the grams type (G) implements the ToTonne trait and thus
+/// grams can be given as a parameter to any input that seeks tonnes.
+pub trait ToTonne{
+    type Output;
+
+    /// to_tonne returns these units in tonnes, performing conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_tonne).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{G, ToTonne};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn wait<T: ToTonne<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_tonne().0)
+    /// }
+    /// wait(G(2.0));
+    /// wait(G::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{MG, G, KG, Tonne, LB, OZ, ToTonne};
+    ///
+    /// assert_eq!(MG(1.0e9).to_tonne(), Tonne(1.0));
+    /// assert_eq!(G(1000000.0).to_tonne(), Tonne(1.0));
+    /// assert_eq!(KG(1000.0).to_tonne(), Tonne(1.0));
+    /// assert_eq!(Tonne(1.0).to_tonne(), Tonne(1.0));
+    /// assert_eq!(LB(1000.0).to_tonne(), Tonne(0.45359237));
+    /// assert_eq!(OZ(1000.0).to_tonne(), Tonne(0.028349523125));
+    /// ```
+    fn to_tonne(self) -> Tonne<Self::Output>;
+
+    /// try_to_tonne is like to_tonne, except that it returns None if the result
+    /// is out of range for the value type, or if the value type is an integer
+    /// type and the result is not a whole number of tonnes.
+    fn try_to_tonne(self) -> Option<Tonne<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToTonne<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_tonne().0).map(Tonne),
+            None => None,
+        }
+    }
+
+    /// to_tonne_rounded is like to_tonne, except that if the value type is an
+    /// integer type the result is rounded using the given mode, and None is
+    /// returned if the result is out of range for the value type.
+    fn to_tonne_rounded(self, mode: Rounding) -> Option<Tonne<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToTonne<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_tonne().0, mode).map(Tonne),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// Tonne represents metric tonnes (1000 kilograms).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::Tonne;
+    ///
+    /// let x = Tonne(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    Tonne, "t", "tonnes"
+}
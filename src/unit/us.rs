@@ -0,0 +1,86 @@
+use num::NumCast;
+
+use super::round::{IntoF64, Rounding, cast_checked, cast_rounded};
+
+/// ToUS is the canonical trait to use for taking input in microseconds.
+///
+/// For example the seconds type (S) implements the ToUS trait and thus
+/// seconds can be given as a parameter to any input that seeks microseconds.
+pub trait ToUS{
+    type Output;
+
+    /// to_us returns these units in microseconds, performing conversion if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value type is an integer type and the result is out
+    /// of range for it (see try_to_us).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::{S, ToUS};
+    /// use fiz_math::Num;
+    /// use std::fmt::Debug;
+    ///
+    /// fn wait<T: ToUS<Output=U>, U: Num+Debug>(x: T) {
+    ///     println!("{:?}", x.to_us().0)
+    /// }
+    /// wait(S(2.0));
+    /// wait(S::<i64>(2));
+    /// ```
+    ///
+    /// ```
+    /// use fiz_math::unit::{NS, US, MS, S, Min, Hr, ToUS};
+    ///
+    /// assert_eq!(NS(1000.0).to_us(), US(1.0));
+    /// assert_eq!(US(1.0).to_us(), US(1.0));
+    /// assert_eq!(MS(1.0).to_us(), US(1000.0));
+    /// assert_eq!(S(1.0).to_us(), US(1000000.0));
+    /// assert_eq!(Min(1.0).to_us(), US(6.0e7));
+    /// assert_eq!(Hr(1.0).to_us(), US(3.6e9));
+    /// ```
+    fn to_us(self) -> US<Self::Output>;
+
+    /// try_to_us is like to_us, except that it returns None if the result is
+    /// out of range for the value type, or if the value type is an integer type
+    /// and the result is not a whole number of microseconds.
+    fn try_to_us(self) -> Option<US<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToUS<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_checked(x.to_us().0).map(US),
+            None => None,
+        }
+    }
+
+    /// to_us_rounded is like to_us, except that if the value type is an integer
+    /// type the result is rounded using the given mode, and None is returned if
+    /// the result is out of range for the value type.
+    fn to_us_rounded(self, mode: Rounding) -> Option<US<Self::Output>>
+        where Self: Sized + IntoF64,
+              <Self as IntoF64>::F64: ToUS<Output = f64>,
+              Self::Output: NumCast
+    {
+        match self.into_f64() {
+            Some(x) => cast_rounded(x.to_us().0, mode).map(US),
+            None => None,
+        }
+    }
+}
+
+unit! {
+    /// US represents microseconds (1/1000th a millisecond).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::unit::US;
+    ///
+    /// let x = US(1.0);
+    /// println!("{:?}", x);
+    /// ```
+    US, "us", "microseconds"
+}
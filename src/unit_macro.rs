@@ -35,21 +35,31 @@
 ///     assert_eq!(y * SilverCoins(3), SilverCoins(18));
 ///     assert_eq!(y / SilverCoins(2), SilverCoins(3));
 ///
+///     // scale by plain numbers:
+///     assert_eq!(y * 2, SilverCoins(12));
+///     assert_eq!(y / 2, SilverCoins(3));
+///
 ///     // ordering and equality:
 ///     assert!(y < SilverCoins(7));
 ///     assert!(y > SilverCoins(5));
 ///     assert!(y <= SilverCoins(6));
 ///     assert!(y >= SilverCoins(6));
 ///     assert!(y == SilverCoins(6));
+///
+///     // access the value without reaching into the tuple field:
+///     assert_eq!(y.value(), 6);
+///     assert_eq!(*y.as_ref(), 6);
+///     assert_eq!(y.map(|v| v as f64 / 4.0), SilverCoins(1.5));
+///     assert_eq!(y.into_inner(), 6);
 /// }
 /// ```
 ///
 /// ```
 /// #[macro_use(unit)]
 /// extern crate fiz_math;
-/// extern crate num;
+/// extern crate num_traits;
 ///
-/// use num::traits::*;
+/// use num_traits::*;
 /// use std::ops::*;
 /// use std::cmp::*;
 ///
@@ -85,21 +95,197 @@
 /// }
 /// ```
 ///
+/// A unit may optionally be given a short suffix and a full name, in which
+/// case it also implements `std::fmt::Display` and `std::str::FromStr`. The
+/// alternate format (`{:#}`) prints the full name instead of the suffix, and
+/// either may be used when parsing:
+///
+/// ```
+/// #[macro_use(unit)]
+/// extern crate fiz_math;
+///
+/// unit!(Furlongs, "fur", "furlongs");
+///
+/// fn main() {
+///     assert_eq!(format!("{}", Furlongs(2.5)), "2.5 fur");
+///     assert_eq!(format!("{:#}", Furlongs(2.5)), "2.5 furlongs");
+///     assert_eq!(format!("{:.2}", Furlongs(1.0)), "1.00 fur");
+///
+///     assert_eq!("2.5fur".parse(), Ok(Furlongs(2.5)));
+///     assert_eq!(" 3 furlongs ".parse(), Ok(Furlongs(3)));
+///     assert!("3 m".parse::<Furlongs<f64>>().is_err());
+/// }
+/// ```
+///
+/// A unit may instead name the unit that the product of two of its values is
+/// in, as distances multiply to areas. Such a unit does not implement `Num`,
+/// `Float` or `PrimInt`, which require the product to be of the same type, but
+/// vectors of it still have dot products and squared lengths (in the squared
+/// unit) and may be interpolated:
+///
+/// ```
+/// #[macro_use(unit)]
+/// extern crate fiz_math;
+///
+/// unit!(SquareChains);
+/// unit!(Chains, "ch", "chains", squared = SquareChains);
+///
+/// use fiz_math::{Lerp, Vec2};
+///
+/// fn main() {
+///     assert_eq!(Chains(2) * Chains(3), SquareChains(6));
+///     assert_eq!(Vec2(Chains(1), Chains(2)).length_sq(), SquareChains(5));
+///
+///     let (a, b) = (Vec2(Chains(0.0), Chains(2.0)), Vec2(Chains(2.0), Chains(4.0)));
+///     assert_eq!(Lerp::lerp(a, b, 0.5), Vec2(Chains(1.0), Chains(3.0)));
+///
+///     assert_eq!(Chains(2) + Chains(3), Chains(5));
+///     assert_eq!(format!("{}", Chains(4)), "4 ch");
+/// }
+/// ```
+///
+/// Doc comments and other attributes given before the name apply to the type:
+///
+/// ```
+/// #[macro_use(unit)]
+/// extern crate fiz_math;
+///
+/// unit! {
+///     /// Chains are 66 feet long.
+///     #[derive(Default)]
+///     Chains, "ch", "chains"
+/// }
+///
+/// fn main() {
+///     assert_eq!(Chains::<i32>::default(), Chains(0));
+/// }
+/// ```
+///
 #[macro_export]
 macro_rules! unit {
-    ( $ident:ident ) => {
+    ( $(#[$attr:meta])* $ident:ident ) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug)]
+        pub struct $ident<T>(pub T);
+
+        unit!(impl_common, $ident);
+        unit!(impl_std_ops_Mul, $ident => $ident);
+        unit!(impl_num_traits_Num, $ident);
+        unit!(impl_num_traits_Float, $ident);
+        unit!(impl_num_traits_PrimInt, $ident);
+    };
+
+    ( $(#[$attr:meta])* $ident:ident, squared = $square:ident ) => {
+        $(#[$attr])*
         #[derive(Copy, Clone, Debug)]
         pub struct $ident<T>(pub T);
 
+        unit!(impl_common, $ident);
+        unit!(impl_std_ops_Mul, $ident => $square);
+    };
+
+    ( $(#[$attr:meta])* $ident:ident, $suffix:expr, $name:expr, squared = $square:ident ) => {
+        unit!($(#[$attr])* $ident, squared = $square);
+        unit!(impl_named, $ident, $suffix, $name);
+        unit!(impl_fmt_Display, $ident, $suffix, $name);
+        unit!(impl_str_FromStr, $ident, $suffix, $name);
+    };
+
+    ( $(#[$attr:meta])* $ident:ident, $suffix:expr, $name:expr ) => {
+        unit!($(#[$attr])* $ident);
+        unit!(impl_named, $ident, $suffix, $name);
+        unit!(impl_fmt_Display, $ident, $suffix, $name);
+        unit!(impl_str_FromStr, $ident, $suffix, $name);
+    };
+
+    (impl_common, $ident:ident ) => {
+        unit!(impl_accessors, $ident);
         unit!(impl_std_ops, $ident);
+        unit!(impl_scalar_ops, $ident);
         unit!(impl_std_cmp, $ident);
         unit!(impl_num_traits, $ident);
-        unit!(impl_num_traits_Float, $ident);
-        unit!(impl_num_traits_PrimInt, $ident);
+        unit!(impl_into_f64, $ident);
+        unit!(impl_lerp, $ident);
+        $crate::__unit_impl_serde!($ident);
+    };
+
+    (impl_accessors, $ident:ident ) => {
+        impl<T> $ident<T> {
+            /// value returns the value of this unit.
+            pub fn value(&self) -> T
+                where T: Copy
+            {
+                self.0
+            }
+
+            /// into_inner consumes this unit, returning its value.
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+
+            /// map returns the unit whose value is the result of calling `f`
+            /// with the value of this unit.
+            pub fn map<U, F: FnOnce(T) -> U>(self, f: F) -> $ident<U> {
+                $ident(f(self.0))
+            }
+        }
+
+        impl<T> $crate::core_export::convert::AsRef<T> for $ident<T> {
+            fn as_ref(&self) -> &T {
+                &self.0
+            }
+        }
+    };
+
+    (impl_named, $ident:ident, $suffix:expr, $name:expr ) => {
+        impl<T> $crate::unit::Named for $ident<T> {
+            type Value = T;
+
+            const SUFFIX: &'static str = $suffix;
+            const NAME: &'static str = $name;
+
+            fn value_ref(&self) -> &T {
+                &self.0
+            }
+
+            fn from_value(value: T) -> Self {
+                $ident(value)
+            }
+        }
+    };
+
+    (impl_fmt_Display, $ident:ident, $suffix:expr, $name:expr ) => {
+        impl<T: $crate::core_export::fmt::Display> $crate::core_export::fmt::Display for $ident<T> {
+            fn fmt(&self, f: &mut $crate::core_export::fmt::Formatter) -> $crate::core_export::fmt::Result {
+                $crate::core_export::fmt::Display::fmt(&self.0, f)?;
+                if f.alternate() {
+                    write!(f, " {}", $name)
+                } else {
+                    write!(f, " {}", $suffix)
+                }
+            }
+        }
+    };
+
+    (impl_str_FromStr, $ident:ident, $suffix:expr, $name:expr ) => {
+        impl<T: $crate::core_export::str::FromStr> $crate::core_export::str::FromStr for $ident<T> {
+            type Err = $crate::unit::ParseUnitError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let (value, unit) = $crate::unit::split_unit(s);
+                if unit != $suffix && unit != $name {
+                    return Err($crate::unit::ParseUnitError::UnknownUnit(unit.into()));
+                }
+                match value.parse() {
+                    Ok(x) => Ok($ident(x)),
+                    Err(_) => Err($crate::unit::ParseUnitError::InvalidValue(value.into())),
+                }
+            }
+        }
     };
 
     (impl_std_ops, $ident:ident ) => {
-        impl<T: ::std::ops::Add<Output = T>> ::std::ops::Add for $ident<T> {
+        impl<T: $crate::core_export::ops::Add<Output = T>> $crate::core_export::ops::Add for $ident<T> {
             type Output = Self;
 
             fn add(self, _rhs: Self) -> Self {
@@ -107,7 +293,7 @@ macro_rules! unit {
             }
         }
 
-        impl<T: ::std::ops::Sub<Output = T>> ::std::ops::Sub for $ident<T> {
+        impl<T: $crate::core_export::ops::Sub<Output = T>> $crate::core_export::ops::Sub for $ident<T> {
             type Output = Self;
 
             fn sub(self, _rhs: Self) -> Self {
@@ -115,15 +301,8 @@ macro_rules! unit {
             }
         }
 
-        impl<T: ::std::ops::Mul<Output = T>> ::std::ops::Mul for $ident<T> {
-            type Output = Self;
 
-            fn mul(self, _rhs: Self) -> Self {
-                $ident(self.0 * _rhs.0)
-            }
-        }
-
-        impl<T: ::std::ops::Div<Output = T>> ::std::ops::Div for $ident<T> {
+        impl<T: $crate::core_export::ops::Div<Output = T>> $crate::core_export::ops::Div for $ident<T> {
             type Output = Self;
 
             fn div(self, _rhs: Self) -> Self {
@@ -131,112 +310,144 @@ macro_rules! unit {
             }
         }
 
-        impl<T: ::std::ops::Rem<Output = T>> ::std::ops::Rem for $ident<T> {
+        impl<T: $crate::core_export::ops::Rem<Output = T>> $crate::core_export::ops::Rem for $ident<T> {
             type Output = Self;
 
             fn rem(self, rhs: Self) -> Self { $ident(self.0 % rhs.0) }
         }
 
-        impl<T: ::std::ops::Neg<Output = T>> ::std::ops::Neg for $ident<T> {
+        impl<T: $crate::core_export::ops::Neg<Output = T>> $crate::core_export::ops::Neg for $ident<T> {
             type Output = Self;
 
             fn neg(self) -> Self::Output { $ident(-self.0) }
         }
 
-        impl<T: ::std::ops::Not<Output = T>> ::std::ops::Not for $ident<T> {
+        impl<T: $crate::core_export::ops::Not<Output = T>> $crate::core_export::ops::Not for $ident<T> {
             type Output = Self;
 
             fn not(self) -> Self::Output { $ident(!self.0) }
         }
 
-        impl<T: ::std::ops::BitAnd<Output = T>> ::std::ops::BitAnd for $ident<T> {
+        impl<T: $crate::core_export::ops::BitAnd<Output = T>> $crate::core_export::ops::BitAnd for $ident<T> {
             type Output = Self;
 
             fn bitand(self, rhs: Self) -> Self::Output { $ident(self.0 & rhs.0) }
         }
 
-        impl<T: ::std::ops::BitOr<Output = T>> ::std::ops::BitOr for $ident<T> {
+        impl<T: $crate::core_export::ops::BitOr<Output = T>> $crate::core_export::ops::BitOr for $ident<T> {
             type Output = Self;
 
             fn bitor(self, rhs: Self) -> Self::Output { $ident(self.0 | rhs.0) }
         }
 
-        impl<T: ::std::ops::BitXor<Output = T>> ::std::ops::BitXor for $ident<T> {
+        impl<T: $crate::core_export::ops::BitXor<Output = T>> $crate::core_export::ops::BitXor for $ident<T> {
             type Output = Self;
 
             fn bitxor(self, rhs: Self) -> Self::Output { $ident(self.0 ^ rhs.0) }
         }
 
-        impl<T: ::std::ops::Shl<usize>> ::std::ops::Shl<usize> for $ident<T> {
+        impl<T: $crate::core_export::ops::Shl<usize>> $crate::core_export::ops::Shl<usize> for $ident<T> {
             type Output = $ident<T::Output>;
 
             fn shl(self, rhs: usize) -> Self::Output { $ident(self.0 << rhs) }
         }
 
-        impl<T: ::std::ops::Shr<usize>> ::std::ops::Shr<usize> for $ident<T> {
+        impl<T: $crate::core_export::ops::Shr<usize>> $crate::core_export::ops::Shr<usize> for $ident<T> {
             type Output = $ident<T::Output>;
 
             fn shr(self, rhs: usize) -> Self::Output { $ident(self.0 >> rhs) }
         }
     };
 
+    (impl_std_ops_Mul, $ident:ident => $product:ident ) => {
+        impl<T: $crate::core_export::ops::Mul<Output = T>> $crate::core_export::ops::Mul for $ident<T> {
+            type Output = $product<T>;
+
+            fn mul(self, _rhs: Self) -> $product<T> {
+                $product(self.0 * _rhs.0)
+            }
+        }
+    };
+
+    (impl_scalar_ops, $ident:ident ) => {
+        impl<T: $crate::core_export::ops::Mul<Output = T>> $crate::core_export::ops::Mul<T> for $ident<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                $ident(self.0 * rhs)
+            }
+        }
+
+        impl<T: $crate::core_export::ops::Div<Output = T>> $crate::core_export::ops::Div<T> for $ident<T> {
+            type Output = Self;
+
+            fn div(self, rhs: T) -> Self {
+                $ident(self.0 / rhs)
+            }
+        }
+    };
+
+    (impl_into_f64, $ident:ident ) => {
+        impl<T: $crate::num_export::ToPrimitive> $crate::unit::IntoF64 for $ident<T> {
+            type F64 = $ident<f64>;
+
+            fn into_f64(self) -> Option<$ident<f64>> {
+                $crate::num_export::ToPrimitive::to_f64(&self.0).map($ident)
+            }
+        }
+    };
+
+    (impl_lerp, $ident:ident ) => {
+        impl<T: $crate::Float> $crate::Lerp<T> for $ident<T> {
+            fn lerp(self, other: Self, t: T) -> Self {
+                $ident($crate::Float::lerp(self.0, other.0, t))
+            }
+        }
+    };
+
     (impl_std_cmp, $ident:ident ) => {
-        impl<T: ::std::cmp::PartialEq> ::std::cmp::PartialEq for $ident<T> {
+        impl<T: $crate::core_export::cmp::PartialEq> $crate::core_export::cmp::PartialEq for $ident<T> {
             fn eq(&self, _rhs: &Self) -> bool {
                 self.0 == _rhs.0
             }
         }
 
-        impl<T: ::std::cmp::Eq> ::std::cmp::Eq for $ident<T> {}
+        impl<T: $crate::core_export::cmp::Eq> $crate::core_export::cmp::Eq for $ident<T> {}
 
-        impl<T: ::std::cmp::PartialOrd> ::std::cmp::PartialOrd for $ident<T> {
-            fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+        impl<T: $crate::core_export::cmp::PartialOrd> $crate::core_export::cmp::PartialOrd for $ident<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<$crate::core_export::cmp::Ordering> {
                 if self.0 < other.0 {
-                    Some(::std::cmp::Ordering::Less)
+                    Some($crate::core_export::cmp::Ordering::Less)
                 } else if self.0 > other.0 {
-                    Some(::std::cmp::Ordering::Greater)
+                    Some($crate::core_export::cmp::Ordering::Greater)
                 } else if self == other {
-                    Some(::std::cmp::Ordering::Equal)
+                    Some($crate::core_export::cmp::Ordering::Equal)
                 } else {
                     None
                 }
             }
         }
 
-        impl<T: ::std::cmp::Ord> ::std::cmp::Ord for $ident<T> {
-            fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+        impl<T: $crate::core_export::cmp::Ord> $crate::core_export::cmp::Ord for $ident<T> {
+            fn cmp(&self, other: &Self) -> $crate::core_export::cmp::Ordering {
                 if self.0 < other.0 {
-                    ::std::cmp::Ordering::Less
+                    $crate::core_export::cmp::Ordering::Less
                 } else if self.0 > other.0 {
-                    ::std::cmp::Ordering::Greater
+                    $crate::core_export::cmp::Ordering::Greater
                 } else {
-                    ::std::cmp::Ordering::Equal
+                    $crate::core_export::cmp::Ordering::Equal
                 }
             }
         }
     };
 
     (impl_num_traits, $ident:ident ) => {
-        impl<T: $crate::num_export::traits::Zero> $crate::num_export::traits::Zero for $ident<T> {
+        impl<T: $crate::num_export::Zero> $crate::num_export::Zero for $ident<T> {
             fn zero() -> Self { $ident(T::zero()) }
             fn is_zero(&self) -> bool { self.0.is_zero() }
         }
 
-        impl<T: $crate::num_export::traits::One> $crate::num_export::traits::One for $ident<T> {
-            fn one() -> Self { $ident(T::one()) }
-        }
-
-        impl<T: $crate::num_export::traits::Num> $crate::num_export::traits::Num for $ident<T> {
-            type FromStrRadixErr = T::FromStrRadixErr;
-            fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-                match T::from_str_radix(str, radix) {
-                    Ok(x) => { Ok($ident(x)) }
-                    Err(e) => { Err(e) }
-                }
-            }
-        }
-
-        impl<T: $crate::num_export::traits::ToPrimitive> $crate::num_export::traits::ToPrimitive for $ident<T> {
+        impl<T: $crate::num_export::ToPrimitive> $crate::num_export::ToPrimitive for $ident<T> {
             fn to_i64(&self) -> Option<i64> { self.0.to_i64() }
             fn to_u64(&self) -> Option<u64> { self.0.to_u64() }
             fn to_isize(&self) -> Option<isize> { self.0.to_isize() }
@@ -251,8 +462,8 @@ macro_rules! unit {
             fn to_f64(&self) -> Option<f64> { self.0.to_f64() }
         }
 
-        impl<T: $crate::num_export::traits::NumCast> $crate::num_export::traits::NumCast for $ident<T> {
-            fn from<X: $crate::num_export::traits::ToPrimitive>(n: X) -> Option<Self> {
+        impl<T: $crate::num_export::NumCast> $crate::num_export::NumCast for $ident<T> {
+            fn from<X: $crate::num_export::ToPrimitive>(n: X) -> Option<Self> {
                 match T::from(n) {
                     Some(x) => { Some($ident(x)) }
                     None => { None }
@@ -260,12 +471,12 @@ macro_rules! unit {
             }
         }
 
-        impl<T: $crate::num_export::traits::Bounded> $crate::num_export::traits::Bounded for $ident<T> {
+        impl<T: $crate::num_export::Bounded> $crate::num_export::Bounded for $ident<T> {
             fn min_value() -> Self { $ident(T::min_value()) }
             fn max_value() -> Self { $ident(T::max_value()) }
         }
 
-        impl<T: $crate::num_export::traits::CheckedAdd<Output = T>> $crate::num_export::traits::CheckedAdd for $ident<T> {
+        impl<T: $crate::num_export::CheckedAdd<Output = T>> $crate::num_export::CheckedAdd for $ident<T> {
             fn checked_add(&self, _rhs: &Self) -> Option<Self> {
                 match self.0.checked_add(&_rhs.0) {
                     Some(x) => { Some($ident(x)) }
@@ -274,7 +485,7 @@ macro_rules! unit {
             }
         }
 
-        impl<T: $crate::num_export::traits::CheckedSub<Output = T>> $crate::num_export::traits::CheckedSub for $ident<T> {
+        impl<T: $crate::num_export::CheckedSub<Output = T>> $crate::num_export::CheckedSub for $ident<T> {
             fn checked_sub(&self, _rhs: &Self) -> Option<Self> {
                 match self.0.checked_sub(&_rhs.0) {
                     Some(x) => { Some($ident(x)) }
@@ -283,18 +494,34 @@ macro_rules! unit {
             }
         }
 
-        impl<T: $crate::num_export::traits::CheckedMul<Output = T>> $crate::num_export::traits::CheckedMul for $ident<T> {
-            fn checked_mul(&self, _rhs: &Self) -> Option<Self> {
-                match self.0.checked_mul(&_rhs.0) {
+        impl<T: $crate::num_export::CheckedDiv<Output = T>> $crate::num_export::CheckedDiv for $ident<T> {
+            fn checked_div(&self, _rhs: &Self) -> Option<Self> {
+                match self.0.checked_div(&_rhs.0) {
                     Some(x) => { Some($ident(x)) }
                     None => { None }
                 }
             }
         }
+    };
 
-        impl<T: $crate::num_export::traits::CheckedDiv<Output = T>> $crate::num_export::traits::CheckedDiv for $ident<T> {
-            fn checked_div(&self, _rhs: &Self) -> Option<Self> {
-                match self.0.checked_div(&_rhs.0) {
+    (impl_num_traits_Num, $ident:ident ) => {
+        impl<T: $crate::num_export::One> $crate::num_export::One for $ident<T> {
+            fn one() -> Self { $ident(T::one()) }
+        }
+
+        impl<T: $crate::num_export::Num> $crate::num_export::Num for $ident<T> {
+            type FromStrRadixErr = T::FromStrRadixErr;
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                match T::from_str_radix(str, radix) {
+                    Ok(x) => { Ok($ident(x)) }
+                    Err(e) => { Err(e) }
+                }
+            }
+        }
+
+        impl<T: $crate::num_export::CheckedMul<Output = T>> $crate::num_export::CheckedMul for $ident<T> {
+            fn checked_mul(&self, _rhs: &Self) -> Option<Self> {
+                match self.0.checked_mul(&_rhs.0) {
                     Some(x) => { Some($ident(x)) }
                     None => { None }
                 }
@@ -303,7 +530,7 @@ macro_rules! unit {
     };
 
     (impl_num_traits_Float, $ident:ident ) => {
-        impl<T: $crate::num_export::traits::Float> $crate::num_export::traits::Float for $ident<T> {
+        impl<T: $crate::num_export::Float> $crate::num_export::Float for $ident<T> {
             fn nan() -> Self { $ident(T::nan()) }
             fn infinity() -> Self { $ident(T::infinity()) }
             fn neg_infinity() -> Self { $ident(T::neg_infinity()) }
@@ -315,7 +542,7 @@ macro_rules! unit {
             fn is_infinite(self) -> bool { self.0.is_infinite() }
             fn is_finite(self) -> bool { self.0.is_finite() }
             fn is_normal(self) -> bool { self.0.is_normal() }
-            fn classify(self) -> ::std::num::FpCategory { self.0.classify() }
+            fn classify(self) -> $crate::core_export::num::FpCategory { self.0.classify() }
             fn floor(self) -> Self { $ident(self.0.floor()) }
             fn ceil(self) -> Self { $ident(self.0.ceil()) }
             fn round(self) -> Self { $ident(self.0.round()) }
@@ -325,35 +552,35 @@ macro_rules! unit {
             fn signum(self) -> Self { $ident(self.0.signum()) }
             fn is_sign_positive(self) -> bool { self.0.is_sign_positive() }
             fn is_sign_negative(self) -> bool { self.0.is_sign_negative() }
-            fn mul_add(self, a: Self, b: Self) -> Self { $ident(self.0.mul_add(a.0, b.0)) }
+            fn mul_add(self, a: Self, b: Self) -> Self { $ident($crate::math::mul_add(self.0, a.0, b.0)) }
             fn recip(self) -> Self { $ident(self.0.recip()) }
-            fn powi(self, n: i32) -> Self { $ident(self.0.powi(n)) }
-            fn powf(self, n: Self) -> Self { $ident(self.0.powf(n.0)) }
-            fn sqrt(self) -> Self { $ident(self.0.sqrt()) }
-            fn exp(self) -> Self { $ident(self.0.exp()) }
-            fn exp2(self) -> Self { $ident(self.0.exp2()) }
-            fn ln(self) -> Self { $ident(self.0.ln()) }
-            fn log(self, base: Self) -> Self { $ident(self.0.log(base.0)) }
-            fn log2(self) -> Self { $ident(self.0.log2()) }
-            fn log10(self) -> Self { $ident(self.0.log10()) }
+            fn powi(self, n: i32) -> Self { $ident($crate::math::powi(self.0, n)) }
+            fn powf(self, n: Self) -> Self { $ident($crate::math::powf(self.0, n.0)) }
+            fn sqrt(self) -> Self { $ident($crate::math::sqrt(self.0)) }
+            fn exp(self) -> Self { $ident($crate::math::exp(self.0)) }
+            fn exp2(self) -> Self { $ident($crate::math::exp2(self.0)) }
+            fn ln(self) -> Self { $ident($crate::math::ln(self.0)) }
+            fn log(self, base: Self) -> Self { $ident($crate::math::log(self.0, base.0)) }
+            fn log2(self) -> Self { $ident($crate::math::log2(self.0)) }
+            fn log10(self) -> Self { $ident($crate::math::log10(self.0)) }
             fn max(self, other: Self) -> Self { $ident(self.0.max(other.0)) }
             fn min(self, other: Self) -> Self { $ident(self.0.min(other.0)) }
             fn abs_sub(self, other: Self) -> Self { $ident(self.0.abs_sub(other.0)) }
-            fn cbrt(self) -> Self { $ident(self.0.cbrt()) }
-            fn hypot(self, other: Self) -> Self { $ident(self.0.hypot(other.0)) }
-            fn sin(self) -> Self { $ident(self.0.sin()) }
-            fn cos(self) -> Self { $ident(self.0.cos()) }
-            fn tan(self) -> Self { $ident(self.0.tan()) }
-            fn asin(self) -> Self { $ident(self.0.asin()) }
-            fn acos(self) -> Self { $ident(self.0.acos()) }
-            fn atan(self) -> Self { $ident(self.0.atan()) }
-            fn atan2(self, other: Self) -> Self { $ident(self.0.atan2(other.0)) }
-            fn sin_cos(self) -> (Self, Self) { let (x, y) = self.0.sin_cos(); ($ident(x), $ident(y)) }
-            fn exp_m1(self) -> Self { $ident(self.0.exp_m1()) }
-            fn ln_1p(self) -> Self { $ident(self.0.ln_1p()) }
-            fn sinh(self) -> Self { $ident(self.0.sinh()) }
-            fn cosh(self) -> Self { $ident(self.0.cosh()) }
-            fn tanh(self) -> Self { $ident(self.0.tanh()) }
+            fn cbrt(self) -> Self { $ident($crate::math::cbrt(self.0)) }
+            fn hypot(self, other: Self) -> Self { $ident($crate::math::hypot(self.0, other.0)) }
+            fn sin(self) -> Self { $ident($crate::math::sin(self.0)) }
+            fn cos(self) -> Self { $ident($crate::math::cos(self.0)) }
+            fn tan(self) -> Self { $ident($crate::math::tan(self.0)) }
+            fn asin(self) -> Self { $ident($crate::math::asin(self.0)) }
+            fn acos(self) -> Self { $ident($crate::math::acos(self.0)) }
+            fn atan(self) -> Self { $ident($crate::math::atan(self.0)) }
+            fn atan2(self, other: Self) -> Self { $ident($crate::math::atan2(self.0, other.0)) }
+            fn sin_cos(self) -> (Self, Self) { let (x, y) = $crate::math::sin_cos(self.0); ($ident(x), $ident(y)) }
+            fn exp_m1(self) -> Self { $ident($crate::math::exp_m1(self.0)) }
+            fn ln_1p(self) -> Self { $ident($crate::math::ln_1p(self.0)) }
+            fn sinh(self) -> Self { $ident($crate::math::sinh(self.0)) }
+            fn cosh(self) -> Self { $ident($crate::math::cosh(self.0)) }
+            fn tanh(self) -> Self { $ident($crate::math::tanh(self.0)) }
             fn asinh(self) -> Self { $ident(self.0.asinh()) }
             fn acosh(self) -> Self { $ident(self.0.acosh()) }
             fn atanh(self) -> Self { $ident(self.0.atanh()) }
@@ -362,7 +589,7 @@ macro_rules! unit {
     };
 
     (impl_num_traits_PrimInt, $ident:ident ) => {
-        impl<T: $crate::num_export::traits::PrimInt> $crate::num_export::traits::PrimInt for $ident<T> {
+        impl<T: $crate::num_export::PrimInt> $crate::num_export::PrimInt for $ident<T> {
             fn count_ones(self) -> u32 { self.0.count_ones() }
             fn count_zeros(self) -> u32 { self.0.count_zeros() }
             fn leading_zeros(self) -> u32 { self.0.leading_zeros() }
@@ -380,5 +607,251 @@ macro_rules! unit {
             fn to_le(self) -> Self { $ident(self.0.to_le()) }
             fn pow(self, exp: u32) -> Self { $ident(self.0.pow(exp)) }
         }
+
+        impl<T: $crate::num_export::Saturating> $crate::num_export::Saturating for $ident<T> {
+            fn saturating_add(self, v: Self) -> Self { $ident(self.0.saturating_add(v.0)) }
+            fn saturating_sub(self, v: Self) -> Self { $ident(self.0.saturating_sub(v.0)) }
+        }
+    };
+}
+
+/// custom_unit creates a type representing a single unit, like `unit!`, which
+/// is defined in terms of one of the SI units of this crate by a conversion
+/// factor. The unit implements the conversion traits of the SI unit and of all
+/// the other units measuring the same quantity, and gains a constructor
+/// converting from them.
+///
+/// The supported base units are meters (`to_m`), seconds (`to_s`), kilograms
+/// (`to_kg`) and radians (`to_rad`). As with `unit!`, a suffix and full name
+/// may optionally be given.
+///
+/// # Panics
+///
+/// The conversions are performed in double precision, and panic if the result
+/// is out of range for the value type (e.g. `Furlong::<i8>(100).to_m()`).
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate fiz_math;
+///
+/// use fiz_math::unit::{M, KM, ToM, ToKM};
+///
+/// // Furlongs are 201.168 meters.
+/// custom_unit!(Furlong, "fur", "furlongs", to_m = 201.168);
+///
+/// // Fortnights are 14 days.
+/// custom_unit!(Fortnight, to_s = 1209600.0);
+///
+/// fn main() {
+///     assert_eq!(Furlong(2.0).to_m(), M(402.336));
+///     assert!(Furlong(5.0).to_km() < KM(1.006));
+///     assert_eq!(Furlong::from_m(M(402.336)), Furlong(2.0));
+///     assert_eq!(format!("{:#}", Furlong(8)), "8 furlongs");
+///
+///     use fiz_math::unit::{Hr, ToHr};
+///     assert_eq!(Fortnight(1).to_hr(), Hr(336));
+///     assert_eq!(Fortnight::from_s(Hr(672.0)), Fortnight(2.0));
+/// }
+/// ```
+#[macro_export]
+macro_rules! custom_unit {
+    (impl_base, $ident:ident, $factor:expr, $base:ident, $base_trait:ident, $to_base:ident,
+     $from_base:ident) => {
+        impl<T: $crate::num_export::Num + $crate::num_export::NumCast>
+            $crate::unit::$base_trait for $ident<T> {
+            type Output = T;
+
+            fn $to_base(self) -> $crate::unit::$base<T> {
+                let x = $crate::num_export::ToPrimitive::to_f64(&self.0)
+                    .expect("unit value not representable as f64");
+                $crate::unit::$base(<T as $crate::num_export::NumCast>::from(x * $factor)
+                    .expect("unit conversion out of range for the value type"))
+            }
+        }
+
+        impl<T: $crate::num_export::Num + $crate::num_export::NumCast> $ident<T> {
+            /// Converts the given value into this unit.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the result is out of range for the value type.
+            pub fn $from_base<U: $crate::unit::$base_trait<Output = T>>(x: U) -> Self {
+                let x = $crate::unit::$base_trait::$to_base(x).0;
+                let x = $crate::num_export::ToPrimitive::to_f64(&x)
+                    .expect("unit value not representable as f64");
+                $ident(<T as $crate::num_export::NumCast>::from(x / $factor)
+                    .expect("unit conversion out of range for the value type"))
+            }
+        }
+    };
+
+    (impl_via_base, $ident:ident, $base_trait:ident, $to_base:ident;
+     $($trait:ident $to:ident $ty:ident),*) => {
+        $(
+            impl<T: $crate::num_export::Num + $crate::num_export::NumCast>
+                $crate::unit::$trait for $ident<T> {
+                type Output = T;
+
+                fn $to(self) -> $crate::unit::$ty<T> {
+                    $crate::unit::$trait::$to($crate::unit::$base_trait::$to_base(self))
+                }
+            }
+        )*
+    };
+
+    (impl_conversions, $ident:ident, to_m, $factor:expr) => {
+        custom_unit!(impl_base, $ident, $factor, M, ToM, to_m, from_m);
+        custom_unit!(impl_via_base, $ident, ToM, to_m;
+                     ToMM to_mm MM, ToCM to_cm CM, ToKM to_km KM, ToIN to_in IN);
+    };
+
+    (impl_conversions, $ident:ident, to_s, $factor:expr) => {
+        custom_unit!(impl_base, $ident, $factor, S, ToS, to_s, from_s);
+        custom_unit!(impl_via_base, $ident, ToS, to_s;
+                     ToNS to_ns NS, ToUS to_us US, ToMS to_ms MS, ToMin to_min Min,
+                     ToHr to_hr Hr);
+    };
+
+    (impl_conversions, $ident:ident, to_kg, $factor:expr) => {
+        custom_unit!(impl_base, $ident, $factor, KG, ToKG, to_kg, from_kg);
+        custom_unit!(impl_via_base, $ident, ToKG, to_kg;
+                     ToMG to_mg MG, ToG to_g G, ToTonne to_tonne Tonne, ToLB to_lb LB,
+                     ToOZ to_oz OZ);
+    };
+
+    (impl_conversions, $ident:ident, to_rad, $factor:expr) => {
+        custom_unit!(impl_base, $ident, $factor, Rad, ToRad, to_rad, from_rad);
+        custom_unit!(impl_via_base, $ident, ToRad, to_rad; ToDeg to_deg Deg);
+    };
+
+    ( $(#[$attr:meta])* $ident:ident, $base:ident = $factor:expr ) => {
+        unit!($(#[$attr])* $ident);
+        custom_unit!(impl_conversions, $ident, $base, $factor);
+    };
+
+    ( $(#[$attr:meta])* $ident:ident, $suffix:expr, $name:expr, $base:ident = $factor:expr ) => {
+        unit!($(#[$attr])* $ident, $suffix, $name);
+        custom_unit!(impl_conversions, $ident, $base, $factor);
+    };
+}
+
+/// units implements the conversion traits between each pair of a set of units
+/// measuring the same quantity (including conversion of each unit to itself),
+/// given a table of the units, their conversion traits, and their sizes in
+/// terms of a common unit.
+///
+/// Where one unit is a whole multiple of the other, the conversion multiplies
+/// or divides by that multiple, so integer conversions are exact (or truncate,
+/// like integer division). Otherwise the conversion is performed in double
+/// precision.
+///
+/// # Panics
+///
+/// The conversions panic if the value type is an integer type and the result
+/// is out of range for it, where the `try_to_*` conversions return None.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate fiz_math;
+///
+/// pub trait ToInch {
+///     type Output;
+///     fn to_inch(self) -> Inch<Self::Output>;
+/// }
+///
+/// pub trait ToFoot {
+///     type Output;
+///     fn to_foot(self) -> Foot<Self::Output>;
+/// }
+///
+/// pub trait ToRod {
+///     type Output;
+///     fn to_rod(self) -> Rod<Self::Output>;
+/// }
+///
+/// unit!(Inch);
+/// unit!(Foot);
+/// unit!(Rod);
+///
+/// units! {
+///     Inch: ToInch::to_inch = 1 inch,
+///     Foot: ToFoot::to_foot = 12 inch,
+///     Rod: ToRod::to_rod = 198 inch,
+/// }
+///
+/// fn main() {
+///     assert_eq!(Foot(3).to_inch(), Inch(36));
+///     assert_eq!(Inch(18).to_foot(), Foot(1));
+///     assert_eq!(Inch(18.0).to_foot(), Foot(1.5));
+///     assert_eq!(Rod(2).to_foot(), Foot(33));
+///     assert_eq!(Foot(4.0).to_foot(), Foot(4.0));
+/// }
+/// ```
+#[macro_export]
+macro_rules! units {
+    (impl_each [$($all:tt)*]) => {};
+
+    (impl_each [$($all:tt)*] ($from:ident, $from_size:expr) $($rest:tt)*) => {
+        units!(impl_from, $from, $from_size; $($all)*);
+        units!(impl_each [$($all)*] $($rest)*);
+    };
+
+    (impl_from, $from:ident, $from_size:expr;
+     $(($ident:ident, $trait:ident, $to:ident, $size:expr))*) => {
+        $(
+            impl<T: $crate::num_export::Num + $crate::num_export::NumCast>
+                $trait for $from<T> {
+                type Output = T;
+
+                fn $to(self) -> $ident<T> {
+                    $ident($crate::unit::scale_unit(self.0, $from_size, $size))
+                }
+            }
+        )*
+    };
+
+    ( $($ident:ident: $trait:ident::$to:ident = $size:tt $base:ident),+ $(,)* ) => {
+        units!(impl_each [$(($ident, $trait, $to, $size as f64))+] $(($ident, $size as f64))+);
+    };
+}
+
+// __unit_impl_serde implements serde's Serialize and Deserialize for a unit
+// type (as a bare number) when the serde feature is enabled. It is defined
+// here, rather than as an arm of unit!, so that the feature of this crate is
+// checked instead of the feature of the crate invoking unit!.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __unit_impl_serde {
+    ( $ident:ident ) => {
+        impl<T: $crate::serde_export::Serialize> $crate::serde_export::Serialize for $ident<T> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where S: $crate::serde_export::Serializer
+            {
+                $crate::serde_export::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<'de, T> $crate::serde_export::Deserialize<'de> for $ident<T>
+            where T: $crate::serde_export::Deserialize<'de>
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where D: $crate::serde_export::Deserializer<'de>
+            {
+                let value = <T as $crate::serde_export::Deserialize<'de>>::deserialize(deserializer)?;
+                Ok($ident(value))
+            }
+        }
     };
 }
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __unit_impl_serde {
+    ( $ident:ident ) => {};
+}
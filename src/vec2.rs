@@ -1,18 +1,22 @@
 #![allow(dead_code)]
 
-use std::ops::{Add, Sub, Neg, Mul, Div};
-use std::cmp::{PartialEq, PartialOrd, Ordering};
-pub use num::{Zero, One, Num};
+use core::ops::{Add, Sub, Neg, Mul, Div};
+use core::cmp::{PartialEq, PartialOrd, Ordering};
+use core::hash::{Hash, Hasher};
+pub use num::{Zero, One};
 use num;
 use super::float::Float;
+use super::math;
 use super::Vec3;
 use super::unit::{ToRad, Rad};
-use std::fmt;
+use core::fmt;
 use clamp::Clamp;
-use std::iter::IntoIterator;
+use core::iter::IntoIterator;
 
 /// Vec2 is a generic two-component vector type.
 ///
+/// Vec2 is `#[repr(C)]`, so it has the same layout as `[T; 2]`.
+///
 /// # Examples
 ///
 /// ```
@@ -34,6 +38,7 @@ use std::iter::IntoIterator;
 /// assert!(x.almost_equal(y, 0.1));
 /// ```
 #[derive(Copy, Clone, Debug)]
+#[repr(C)]
 pub struct Vec2<T>(pub T, pub T);
 
 impl<T: Copy> IntoIterator for Vec2<T> {
@@ -66,16 +71,24 @@ impl<T: Copy> Iterator for Vec2Iterator<T> {
 }
 
 impl<T: fmt::Display> fmt::Display for Vec2<T> {
-    /// fmt formats the vector.
+    /// fmt formats the vector, applying the formatter's flags (width, precision
+    /// and so on) to each of its components.
     ///
     /// # Examples
     ///
     /// ```
     /// let x = fiz_math::Vec2(1u8, 5u8);
     /// assert_eq!(format!("{}", x), "Vec2(1, 5)");
+    ///
+    /// let y = fiz_math::Vec2(1.0, 2.5);
+    /// assert_eq!(format!("{:.2}", y), "Vec2(1.00, 2.50)");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Vec2({}, {})", self.0, self.1)
+        write!(f, "Vec2(")?;
+        fmt::Display::fmt(&self.0, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.1, f)?;
+        write!(f, ")")
     }
 }
 
@@ -124,9 +137,9 @@ impl<T: Float> Vec2<T> {
     /// # Examples
     ///
     /// ```
-    /// # extern crate num;
+    /// # extern crate num_traits;
     /// # extern crate fiz_math;
-    /// use num::traits::Float;
+    /// use num_traits::Float;
     /// use fiz_math::Vec2;
     ///
     /// # fn main() {
@@ -311,19 +324,62 @@ impl<T: Div<Output = T> + Copy> Vec2<T> {
     }
 }
 
+impl<T> Vec2<T> {
+    /// mul_unitless multiplies each component of the vector by a plain number.
+    /// Unlike mul_scalar, the number need not be of the component type, which
+    /// allows scaling vectors of units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    /// use fiz_math::unit::MM;
+    ///
+    /// let v = Vec2(MM(1.0), MM(2.0));
+    /// assert_eq!(v.mul_unitless(2.0), Vec2(MM(2.0), MM(4.0)));
+    /// ```
+    pub fn mul_unitless<S: Copy>(self, _rhs: S) -> Self
+        where T: Mul<S, Output = T>
+    {
+        Vec2(self.0 * _rhs, self.1 * _rhs)
+    }
+
+    /// div_unitless divides each component of the vector by a plain number.
+    /// Unlike div_scalar, the number need not be of the component type, which
+    /// allows scaling vectors of units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    /// use fiz_math::unit::MM;
+    ///
+    /// let v = Vec2(MM(2.0), MM(4.0));
+    /// assert_eq!(v.div_unitless(2.0), Vec2(MM(1.0), MM(2.0)));
+    /// ```
+    pub fn div_unitless<S: Copy>(self, _rhs: S) -> Self
+        where T: Div<S, Output = T>
+    {
+        Vec2(self.0 / _rhs, self.1 / _rhs)
+    }
+}
+
 impl<T: Clamp<Elem = T> + Copy> Clamp for Vec2<T> {
     type Elem = T;
 
     /// clamp returns the vector with each element clamped to the range of
     /// [min, max].
     ///
+    /// Vectors of integers also implement `Ord`, whose `clamp` method takes
+    /// vectors as bounds, so this one must be called through the trait.
+    ///
     /// # Examples
     ///
     /// ```
     /// use fiz_math::{Vec2, Clamp};
     ///
     /// let a = Vec2(-2, 4);
-    /// assert_eq!(a.clamp(-1, 2), Vec2(-1, 2));
+    /// assert_eq!(Clamp::clamp(a, -1, 2), Vec2(-1, 2));
     /// ```
     fn clamp(self, min: T, max: T) -> Self {
         Vec2(self.0.clamp(min, max), self.1.clamp(min, max))
@@ -396,7 +452,8 @@ impl<T: PartialEq> PartialEq for Vec2<T> {
 }
 
 impl<T: PartialOrd> PartialOrd for Vec2<T> {
-    /// partial_cmp compares the two vectors component-wise.
+    /// partial_cmp compares the two vectors lexicographically, i.e. by their
+    /// first components, or if those are equal by their second ones, and so on.
     ///
     /// # Examples
     ///
@@ -405,17 +462,84 @@ impl<T: PartialOrd> PartialOrd for Vec2<T> {
     ///
     /// let a = Vec2(1.0, 2.0);
     /// assert!(a < Vec2(1.1, 2.1));
+    /// assert!(Vec2(1, 5) < Vec2(2, 0));
+    /// assert!(Vec2(1, 2) < Vec2(1, 3));
     /// ```
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.0 < other.0 && self.1 < other.1 {
-            Some(Ordering::Less)
-        } else if self.0 > other.0 && self.1 > other.1 {
-            Some(Ordering::Greater)
-        } else if self == other {
-            Some(Ordering::Equal)
-        } else {
-            None
-        }
+        (&self.0, &self.1).partial_cmp(&(&other.0, &other.1))
+    }
+}
+
+impl<T: Eq> Eq for Vec2<T> {}
+
+impl<T: Ord> Ord for Vec2<T> {
+    /// cmp compares the two vectors lexicographically (see `partial_cmp`), so
+    /// that vectors of integers can be sorted and used as the keys of a
+    /// `BTreeMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use fiz_math::Vec2;
+    ///
+    /// let mut v = vec![Vec2(1, 1), Vec2(0, 2), Vec2(0, 0)];
+    /// v.sort();
+    /// assert_eq!(v, vec![Vec2(0, 0), Vec2(0, 2), Vec2(1, 1)]);
+    ///
+    /// let mut chunks = BTreeMap::new();
+    /// chunks.insert(Vec2(-1, 2), "spawn");
+    /// assert_eq!(chunks.get(&Vec2(-1, 2)), Some(&"spawn"));
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.0, &self.1).cmp(&(&other.0, &other.1))
+    }
+}
+
+impl<T: Hash> Hash for Vec2<T> {
+    /// hash hashes the components of the vector in order, so that vectors of
+    /// integers can be used as the keys of a `HashMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use fiz_math::Vec2;
+    ///
+    /// let mut tiles = HashMap::new();
+    /// tiles.insert(Vec2(-1, 2), "grass");
+    /// tiles.insert(Vec2(3, 0), "water");
+    /// assert_eq!(tiles[&Vec2(-1, 2)], "grass");
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+        self.1.hash(state);
+    }
+}
+
+impl<T: Float> Vec2<T> {
+    /// cmp_lex compares the two vectors lexicographically, comparing their
+    /// components with `Float::total_cmp`. Unlike `partial_cmp` it always
+    /// returns an ordering (-0.0 sorts before 0.0 and NaN after infinity), so it
+    /// can be used to sort float vectors with `sort_by`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use fiz_math::Vec2;
+    ///
+    /// assert_eq!(Vec2(1.0, 0.0).cmp_lex(&Vec2(1.0, -0.0)), Ordering::Greater);
+    ///
+    /// let n = std::f64::NAN;
+    /// let mut v = vec![Vec2(1.0, n), Vec2(2.0, 0.0), Vec2(1.0, -0.0)];
+    /// v.sort_by(Vec2::cmp_lex);
+    /// assert_eq!(v[0], Vec2(1.0, -0.0));
+    /// assert_eq!(v[2], Vec2(2.0, 0.0));
+    /// ```
+    pub fn cmp_lex(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(other.0)
+            .then_with(|| self.1.total_cmp(other.1))
     }
 }
 
@@ -501,9 +625,12 @@ impl<T: Zero> Zero for Vec2<T> {
     }
 }
 
-impl<T: Num + Copy> Vec2<T> {
-    /// dot returns the dot product of self and b. For length calculations use
-    /// length or length_sq functions instead (for clarity).
+impl<T: Mul + Copy> Vec2<T>
+    where T::Output: Add<Output = T::Output>
+{
+    /// dot returns the dot product of self and b, of the type of the product of
+    /// two components. For length calculations use length or length_sq
+    /// functions instead (for clarity).
     ///
     /// # Examples
     ///
@@ -513,12 +640,23 @@ impl<T: Num + Copy> Vec2<T> {
     /// let x = Vec2(1, 2);
     /// assert_eq!(x.dot(x), 5);
     /// ```
-    pub fn dot(self, b: Self) -> T {
+    ///
+    /// Units may be used as the component type, e.g. the dot product of vectors
+    /// of distances is an area:
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    /// use fiz_math::unit::{MM, MM2};
+    ///
+    /// let x = Vec2(MM(1), MM(2));
+    /// assert_eq!(x.dot(x), MM2(5));
+    /// ```
+    pub fn dot(self, b: Self) -> T::Output {
         self.0 * b.0 + self.1 * b.1
     }
 
-    /// length_sq returns the magnitude squared of this vector, useful primarily
-    /// for comparing distances.
+    /// length_sq returns the magnitude squared of this vector, of the type of
+    /// the product of two components, useful primarily for comparing distances.
     ///
     /// # Examples
     ///
@@ -527,7 +665,7 @@ impl<T: Num + Copy> Vec2<T> {
     ///
     /// assert_eq!(Vec2(1, 2).length_sq(), 5);
     /// ```
-    pub fn length_sq(self) -> T {
+    pub fn length_sq(self) -> T::Output {
         self.dot(self)
     }
 }
@@ -541,12 +679,17 @@ impl<T: Float> Vec2<T> {
     ///
     /// ```
     /// use fiz_math::{Vec2, Float};
+    /// use fiz_math::unit::MPerS;
     ///
     /// let l = Vec2(1.0, 2.0).length();
     /// assert!(l.equal(2.23606797));
+    ///
+    /// // The length of a vector of units is in the same units, e.g. the speed
+    /// // of a velocity.
+    /// assert_eq!(Vec2(MPerS(3.0), MPerS(4.0)).length(), MPerS(5.0));
     /// ```
     pub fn length(self) -> T {
-        self.length_sq().sqrt()
+        math::sqrt(self.length_sq())
     }
 
     /// normalize returns the normalized (i.e. length/magnitude == 1) vector
@@ -602,6 +745,38 @@ impl<T: Float> Vec2<T> {
     pub fn lerp(self, other: Self, t: T) -> Self {
         self * other.mul_scalar(t)
     }
+
+    /// inverse_lerp returns the component-wise amounts by which `self` must be
+    /// interpolated towards `b` to yield `value` (see `Float::inverse_lerp`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let (a, b) = (Vec2(0.0, 10.0), Vec2(10.0, 20.0));
+    /// assert_eq!(a.inverse_lerp(b, Vec2(5.0, 12.5)), Vec2(0.5, 0.25));
+    /// ```
+    pub fn inverse_lerp(self, b: Self, value: Self) -> Self {
+        Vec2(self.0.inverse_lerp(b.0, value.0), self.1.inverse_lerp(b.1, value.1))
+    }
+
+    /// remap maps each component of `self` from the range `from` onto the range
+    /// `to` (see `Float::remap`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec2;
+    ///
+    /// let from = (Vec2(0.0, 0.0), Vec2(1.0, 2.0));
+    /// let to = (Vec2(-1.0, 10.0), Vec2(1.0, 20.0));
+    /// assert_eq!(Vec2(0.5, 0.5).remap(from, to), Vec2(0.0, 12.5));
+    /// ```
+    pub fn remap(self, from: (Self, Self), to: (Self, Self)) -> Self {
+        Vec2(self.0.remap(((from.0).0, (from.1).0), ((to.0).0, (to.1).0)),
+             self.1.remap(((from.0).1, (from.1).1), ((to.0).1, (to.1).1)))
+    }
 }
 
 impl<F, T> Vec2<T>
@@ -626,9 +801,9 @@ impl<F, T> Vec2<T>
     pub fn sphere_to_cart(self, r: F) -> Vec3<Rad<F>> {
         let i = self.0.to_rad().0;
         let a = self.1.to_rad().0;
-        Vec3(Rad(r * i.sin() * a.cos()),
-             Rad(r * i.sin() * a.sin()),
-             Rad(r * i.cos()))
+        Vec3(Rad(r * math::sin(i) * math::cos(a)),
+             Rad(r * math::sin(i) * math::sin(a)),
+             Rad(r * math::cos(i)))
     }
 }
 
@@ -1,18 +1,23 @@
 #![allow(dead_code)]
 
-use std::ops::{Add, Sub, Neg, Mul, Div};
-use std::cmp::{PartialEq, PartialOrd, Ordering};
+use core::ops::{Add, Sub, Neg, Mul, Div};
+use core::cmp::{PartialEq, PartialOrd, Ordering};
+use core::hash::{Hash, Hasher};
 pub use num::{Zero, One, Num};
 use num;
 use super::float::Float;
+use super::math;
 use super::Vec2;
+use super::axis::Axis;
 use super::unit::ToRad;
-use std::fmt;
+use core::fmt;
 use clamp::Clamp;
-use std::iter::IntoIterator;
+use core::iter::IntoIterator;
 
 /// Vec3 is a generic three-component (3D) vector type.
 ///
+/// Vec3 is `#[repr(C)]`, so it has the same layout as `[T; 3]`.
+///
 /// # Examples
 ///
 /// ```
@@ -34,6 +39,7 @@ use std::iter::IntoIterator;
 /// assert!(x.almost_equal(y, 0.1));
 /// ```
 #[derive(Copy, Clone, Debug)]
+#[repr(C)]
 pub struct Vec3<T>(pub T, pub T, pub T);
 
 impl<T: Copy> IntoIterator for Vec3<T> {
@@ -67,16 +73,26 @@ impl<T: Copy> Iterator for Vec3Iterator<T> {
 }
 
 impl<T: fmt::Display> fmt::Display for Vec3<T> {
-    /// fmt formats the vector.
+    /// fmt formats the vector, applying the formatter's flags (width, precision
+    /// and so on) to each of its components.
     ///
     /// # Examples
     ///
     /// ```
     /// let x = fiz_math::Vec3(1u8, 5u8, 2u8);
     /// assert_eq!(format!("{}", x), "Vec3(1, 5, 2)");
+    ///
+    /// let y = fiz_math::Vec3(1.0, 2.5, -3.14159);
+    /// assert_eq!(format!("{:.2}", y), "Vec3(1.00, 2.50, -3.14)");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Vec3({}, {}, {})", self.0, self.1, self.2)
+        write!(f, "Vec3(")?;
+        fmt::Display::fmt(&self.0, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.1, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.2, f)?;
+        write!(f, ")")
     }
 }
 
@@ -126,9 +142,9 @@ impl<T: Float> Vec3<T> {
     /// # Examples
     ///
     /// ```
-    /// # extern crate num;
+    /// # extern crate num_traits;
     /// # extern crate fiz_math;
-    /// use num::traits::Float;
+    /// use num_traits::Float;
     /// use fiz_math::Vec3;
     ///
     /// # fn main() {
@@ -313,19 +329,62 @@ impl<T: Div<Output = T> + Copy> Vec3<T> {
     }
 }
 
+impl<T> Vec3<T> {
+    /// mul_unitless multiplies each component of the vector by a plain number.
+    /// Unlike mul_scalar, the number need not be of the component type, which
+    /// allows scaling vectors of units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    /// use fiz_math::unit::MM;
+    ///
+    /// let v = Vec3(MM(1.0), MM(2.0), MM(3.0));
+    /// assert_eq!(v.mul_unitless(2.0), Vec3(MM(2.0), MM(4.0), MM(6.0)));
+    /// ```
+    pub fn mul_unitless<S: Copy>(self, _rhs: S) -> Self
+        where T: Mul<S, Output = T>
+    {
+        Vec3(self.0 * _rhs, self.1 * _rhs, self.2 * _rhs)
+    }
+
+    /// div_unitless divides each component of the vector by a plain number.
+    /// Unlike div_scalar, the number need not be of the component type, which
+    /// allows scaling vectors of units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    /// use fiz_math::unit::MM;
+    ///
+    /// let v = Vec3(MM(2.0), MM(4.0), MM(6.0));
+    /// assert_eq!(v.div_unitless(2.0), Vec3(MM(1.0), MM(2.0), MM(3.0)));
+    /// ```
+    pub fn div_unitless<S: Copy>(self, _rhs: S) -> Self
+        where T: Div<S, Output = T>
+    {
+        Vec3(self.0 / _rhs, self.1 / _rhs, self.2 / _rhs)
+    }
+}
+
 impl<T: Clamp<Elem = T> + Copy> Clamp for Vec3<T> {
     type Elem = T;
 
     /// clamp returns the vector with each element clamped to the range of
     /// [min, max].
     ///
+    /// Vectors of integers also implement `Ord`, whose `clamp` method takes
+    /// vectors as bounds, so this one must be called through the trait.
+    ///
     /// # Examples
     ///
     /// ```
     /// use fiz_math::{Vec3, Clamp};
     ///
     /// let a = Vec3(-2, 4, -6);
-    /// assert_eq!(a.clamp(-1, 2), Vec3(-1, 2, -1));
+    /// assert_eq!(Clamp::clamp(a, -1, 2), Vec3(-1, 2, -1));
     /// ```
     fn clamp(self, min: T, max: T) -> Self {
         Vec3(self.0.clamp(min, max),
@@ -400,7 +459,8 @@ impl<T: PartialEq> PartialEq for Vec3<T> {
 }
 
 impl<T: PartialOrd> PartialOrd for Vec3<T> {
-    /// partial_cmp compares the two vectors component-wise.
+    /// partial_cmp compares the two vectors lexicographically, i.e. by their
+    /// first components, or if those are equal by their second ones, and so on.
     ///
     /// # Examples
     ///
@@ -409,17 +469,86 @@ impl<T: PartialOrd> PartialOrd for Vec3<T> {
     ///
     /// let a = Vec3(1.0, 2.0, 3.0);
     /// assert!(a < Vec3(1.1, 2.1, 3.1));
+    /// assert!(Vec3(1, 5, 5) < Vec3(2, 0, 0));
+    /// assert!(Vec3(1, 2, 3) < Vec3(1, 2, 4));
     /// ```
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.0 < other.0 && self.1 < other.1 && self.2 < other.2 {
-            Some(Ordering::Less)
-        } else if self.0 > other.0 && self.1 > other.1 && self.2 > other.2 {
-            Some(Ordering::Greater)
-        } else if self == other {
-            Some(Ordering::Equal)
-        } else {
-            None
-        }
+        (&self.0, &self.1, &self.2).partial_cmp(&(&other.0, &other.1, &other.2))
+    }
+}
+
+impl<T: Eq> Eq for Vec3<T> {}
+
+impl<T: Ord> Ord for Vec3<T> {
+    /// cmp compares the two vectors lexicographically (see `partial_cmp`), so
+    /// that vectors of integers can be sorted and used as the keys of a
+    /// `BTreeMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use fiz_math::Vec3;
+    ///
+    /// let mut v = vec![Vec3(1, 1, 1), Vec3(0, 2, 2), Vec3(0, 0, 0)];
+    /// v.sort();
+    /// assert_eq!(v, vec![Vec3(0, 0, 0), Vec3(0, 2, 2), Vec3(1, 1, 1)]);
+    ///
+    /// let mut chunks = BTreeMap::new();
+    /// chunks.insert(Vec3(-1, 2, 2), "spawn");
+    /// assert_eq!(chunks.get(&Vec3(-1, 2, 2)), Some(&"spawn"));
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.0, &self.1, &self.2).cmp(&(&other.0, &other.1, &other.2))
+    }
+}
+
+impl<T: Hash> Hash for Vec3<T> {
+    /// hash hashes the components of the vector in order, so that vectors of
+    /// integers can be used as the keys of a `HashMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use fiz_math::Vec3;
+    ///
+    /// let mut tiles = HashMap::new();
+    /// tiles.insert(Vec3(-1, 2, 2), "grass");
+    /// tiles.insert(Vec3(3, 0, 0), "water");
+    /// assert_eq!(tiles[&Vec3(-1, 2, 2)], "grass");
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+        self.1.hash(state);
+        self.2.hash(state);
+    }
+}
+
+impl<T: Float> Vec3<T> {
+    /// cmp_lex compares the two vectors lexicographically, comparing their
+    /// components with `Float::total_cmp`. Unlike `partial_cmp` it always
+    /// returns an ordering (-0.0 sorts before 0.0 and NaN after infinity), so it
+    /// can be used to sort float vectors with `sort_by`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1.0, 0.0, -5.0).cmp_lex(&Vec3(1.0, -0.0, 2.0)), Ordering::Greater);
+    ///
+    /// let n = std::f64::NAN;
+    /// let mut v = vec![Vec3(1.0, n, n), Vec3(2.0, 0.0, 0.0), Vec3(1.0, -0.0, 2.0)];
+    /// v.sort_by(Vec3::cmp_lex);
+    /// assert_eq!(v[0], Vec3(1.0, -0.0, 2.0));
+    /// assert_eq!(v[2], Vec3(2.0, 0.0, 0.0));
+    /// ```
+    pub fn cmp_lex(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(other.0)
+            .then_with(|| self.1.total_cmp(other.1))
+            .then_with(|| self.2.total_cmp(other.2))
     }
 }
 
@@ -515,9 +644,12 @@ impl<T: Zero> Zero for Vec3<T> {
     }
 }
 
-impl<T: Num + Copy> Vec3<T> {
-    /// dot returns the dot product of self and b. For length calculations use
-    /// length or length_sq functions instead (for clarity).
+impl<T: Mul + Copy> Vec3<T>
+    where T::Output: Add<Output = T::Output>
+{
+    /// dot returns the dot product of self and b, of the type of the product of
+    /// two components. For length calculations use length or length_sq
+    /// functions instead (for clarity).
     ///
     /// # Examples
     ///
@@ -527,12 +659,23 @@ impl<T: Num + Copy> Vec3<T> {
     /// let x = Vec3(1, 2, 3);
     /// assert_eq!(x.dot(x), 14);
     /// ```
-    pub fn dot(self, b: Self) -> T {
+    ///
+    /// Units may be used as the component type, e.g. the dot product of vectors
+    /// of distances is an area:
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    /// use fiz_math::unit::{MM, MM2};
+    ///
+    /// let x = Vec3(MM(1), MM(2), MM(3));
+    /// assert_eq!(x.dot(x), MM2(14));
+    /// ```
+    pub fn dot(self, b: Self) -> T::Output {
         self.0 * b.0 + self.1 * b.1 + self.2 * b.2
     }
 
-    /// length_sq returns the magnitude squared of this vector, useful primarily
-    /// for comparing distances.
+    /// length_sq returns the magnitude squared of this vector, of the type of
+    /// the product of two components, useful primarily for comparing distances.
     ///
     /// # Examples
     ///
@@ -540,12 +683,37 @@ impl<T: Num + Copy> Vec3<T> {
     /// use fiz_math::Vec3;
     ///
     /// assert_eq!(Vec3(1, 2, 3).length_sq(), 14);
+    ///
+    /// use fiz_math::Zero;
+    /// use fiz_math::unit::{M, M2};
+    ///
+    /// assert_eq!(Vec3(M(1.0), M(2.0), M(3.0)).length_sq(), M2(14.0));
+    /// assert!(Vec3::<M<f64>>::zero().is_zero());
     /// ```
-    pub fn length_sq(self) -> T {
+    pub fn length_sq(self) -> T::Output {
         self.dot(self)
     }
 }
 
+impl<T: Num + Copy> Vec3<T> {
+    /// cross returns the cross product of self and b, which is perpendicular
+    /// to both vectors (following the right-hand rule).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// assert_eq!(Vec3(1, 0, 0).cross(Vec3(0, 1, 0)), Vec3(0, 0, 1));
+    /// assert_eq!(Vec3(1, 2, 3).cross(Vec3(4, 5, 6)), Vec3(-3, 6, -3));
+    /// ```
+    pub fn cross(self, b: Self) -> Self {
+        Vec3(self.1 * b.2 - self.2 * b.1,
+             self.2 * b.0 - self.0 * b.2,
+             self.0 * b.1 - self.1 * b.0)
+    }
+}
+
 impl<T: Float> Vec3<T> {
     /// length returns the magnitude of this vector. Use length_sq instead when
     /// comparing distances, because it avoids the extra sqrt operation needed
@@ -555,12 +723,17 @@ impl<T: Float> Vec3<T> {
     ///
     /// ```
     /// use fiz_math::{Vec3, Float};
+    /// use fiz_math::unit::MPerS;
     ///
     /// let l = Vec3(1.0, 2.0, 3.0).length();
     /// assert!(l.equal(3.74165738));
+    ///
+    /// // The length of a vector of units is in the same units, e.g. the speed
+    /// // of a velocity.
+    /// assert_eq!(Vec3(MPerS(3.0), MPerS(4.0), MPerS(0.0)).length(), MPerS(5.0));
     /// ```
     pub fn length(self) -> T {
-        self.length_sq().sqrt()
+        math::sqrt(self.length_sq())
     }
 
     /// normalize returns the normalized (i.e. length/magnitude == 1) vector
@@ -584,6 +757,31 @@ impl<T: Float> Vec3<T> {
         }
     }
 
+    /// fast_normalize is like `normalize`, but scales this vector by the
+    /// approximate reciprocal of its length (see `Float::fast_rsqrt`) instead of
+    /// dividing it by its exact length, which is faster for normal-heavy loops
+    /// (e.g. vertex skinning). For f32 vectors, the length of the result is
+    /// within 1e-5 of one (within 1e-6 with SSE).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Float, Vec3};
+    ///
+    /// let n = Vec3(3.0f32, 0.0, 4.0).fast_normalize().unwrap();
+    /// assert!(n.almost_equal(Vec3(0.6, 0.0, 0.8), 1e-5));
+    /// assert!(n.length().almost_equal(1.0, 1e-5));
+    /// assert_eq!(Vec3(0.0f32, 0.0, 0.0).fast_normalize(), None);
+    /// ```
+    pub fn fast_normalize(self) -> Option<Self> {
+        let length_sq = self.length_sq();
+        if length_sq == T::zero() {
+            None
+        } else {
+            Some(self.mul_scalar(length_sq.fast_rsqrt()))
+        }
+    }
+
     /// project returns a vector representing the projection of the `self` vector
     /// onto the `other` vector.
     ///
@@ -616,6 +814,67 @@ impl<T: Float> Vec3<T> {
     pub fn lerp(self, other: Self, t: T) -> Self {
         self * other.mul_scalar(t)
     }
+
+    /// inverse_lerp returns the component-wise amounts by which `self` must be
+    /// interpolated towards `b` to yield `value` (see `Float::inverse_lerp`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let (a, b) = (Vec3(0.0, 10.0, -4.0), Vec3(10.0, 20.0, 4.0));
+    /// assert_eq!(a.inverse_lerp(b, Vec3(5.0, 12.5, 0.0)), Vec3(0.5, 0.25, 0.5));
+    /// ```
+    pub fn inverse_lerp(self, b: Self, value: Self) -> Self {
+        Vec3(self.0.inverse_lerp(b.0, value.0),
+             self.1.inverse_lerp(b.1, value.1),
+             self.2.inverse_lerp(b.2, value.2))
+    }
+
+    /// remap maps each component of `self` from the range `from` onto the range
+    /// `to` (see `Float::remap`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3;
+    ///
+    /// let from = (Vec3(0.0, 0.0, 0.0), Vec3(1.0, 2.0, 4.0));
+    /// let to = (Vec3(-1.0, 10.0, 0.0), Vec3(1.0, 20.0, 1.0));
+    /// assert_eq!(Vec3(0.5, 0.5, 0.5).remap(from, to), Vec3(0.0, 12.5, 0.125));
+    /// ```
+    pub fn remap(self, from: (Self, Self), to: (Self, Self)) -> Self {
+        Vec3(self.0.remap(((from.0).0, (from.1).0), ((to.0).0, (to.1).0)),
+             self.1.remap(((from.0).1, (from.1).1), ((to.0).1, (to.1).1)),
+             self.2.remap(((from.0).2, (from.1).2), ((to.0).2, (to.1).2)))
+    }
+}
+
+impl<T: Zero + Neg<Output = T> + PartialOrd + Copy> Vec3<T> {
+    /// dominant_axis returns the axis along which this vector has the largest
+    /// absolute component. Ties are broken in favor of X, then Y.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Vec3, Axis};
+    ///
+    /// assert_eq!(Vec3(0.1, -0.9, 0.5).dominant_axis(), Axis::Y);
+    /// assert_eq!(Vec3(1, 0, -2).dominant_axis(), Axis::Z);
+    /// assert_eq!(Vec3(1, 1, 1).dominant_axis(), Axis::X);
+    /// ```
+    pub fn dominant_axis(&self) -> Axis {
+        let abs = |v: T| if v < T::zero() { -v } else { v };
+        let (x, y, z) = (abs(self.0), abs(self.1), abs(self.2));
+        if x >= y && x >= z {
+            Axis::X
+        } else if y >= z {
+            Axis::Y
+        } else {
+            Axis::Z
+        }
+    }
 }
 
 impl<F, T> Vec3<T>
@@ -648,8 +907,8 @@ impl<F, T> Vec3<T>
     /// ```
     pub fn cart_to_sphere(self) -> (F, Vec2<T>) {
         let r = self.length();
-        let theta = (self.2 / r).acos();
-        let phi = self.1.atan2(self.0);
+        let theta = math::acos(self.2 / r);
+        let phi = math::atan2(self.1, self.0);
         (r.to_rad().0, Vec2(theta, phi))
     }
 }
@@ -0,0 +1,332 @@
+use core::ops::{Add, Sub, Neg, Mul, Div};
+use core::fmt;
+use super::math;
+
+use super::Vec3;
+
+/// Vec3A is a three-component vector of f32 which is aligned and padded to 16
+/// bytes, matching the layout of a `vec3` in std140 and std430 GPU buffers (and
+/// of a `float3` in HLSL constant buffers), so that uniform and storage buffer
+/// structs can be built from it without manual padding fields.
+///
+/// Vec3A is `#[repr(C, align(16))]`, and its padding is an explicit component
+/// which is always zero, so it has the same layout as `[f32; 4]` (in contrast
+/// to GLSL, a scalar following it cannot be packed into its padding). Its
+/// components are public, but a Vec3A is constructed with `new` or from a
+/// `Vec3<f32>`.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Vec3, Vec3A};
+/// use std::mem::{align_of, size_of};
+///
+/// #[repr(C)]
+/// struct Light {
+///     position: Vec3A,
+///     color: Vec3A,
+/// }
+/// assert_eq!(size_of::<Light>(), 32);
+/// assert_eq!(align_of::<Light>(), 16);
+///
+/// let p = Vec3A::new(1.0, 2.0, 3.0);
+/// assert_eq!((p.0, p.1, p.2), (1.0, 2.0, 3.0));
+/// assert_eq!(Vec3::from(p + p), Vec3(2.0, 4.0, 6.0));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(C, align(16))]
+pub struct Vec3A(pub f32, pub f32, pub f32, f32);
+
+impl Vec3A {
+    /// new returns a new vector with the given components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Vec3, Vec3A};
+    ///
+    /// assert_eq!(Vec3A::new(1.0, 2.0, 3.0), Vec3A::from(Vec3(1.0, 2.0, 3.0)));
+    /// ```
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Vec3A(x, y, z, 0.0)
+    }
+
+    /// to_vec3 returns this vector as an unaligned `Vec3<f32>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Vec3, Vec3A};
+    ///
+    /// assert_eq!(Vec3A::new(1.0, 2.0, 3.0).to_vec3(), Vec3(1.0, 2.0, 3.0));
+    /// ```
+    pub fn to_vec3(self) -> Vec3<f32> {
+        Vec3(self.0, self.1, self.2)
+    }
+
+    // map2 returns the vector with f applied to each pair of components of
+    // self and other.
+    fn map2<F: Fn(f32, f32) -> f32>(self, other: Self, f: F) -> Self {
+        Vec3A::new(f(self.0, other.0), f(self.1, other.1), f(self.2, other.2))
+    }
+
+    /// add_scalar adds `rhs` to each component of this vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3A;
+    ///
+    /// assert_eq!(Vec3A::new(1.0, 2.0, 3.0).add_scalar(1.0), Vec3A::new(2.0, 3.0, 4.0));
+    /// ```
+    pub fn add_scalar(self, rhs: f32) -> Self {
+        Vec3A::new(self.0 + rhs, self.1 + rhs, self.2 + rhs)
+    }
+
+    /// sub_scalar subtracts `rhs` from each component of this vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3A;
+    ///
+    /// assert_eq!(Vec3A::new(1.0, 2.0, 3.0).sub_scalar(1.0), Vec3A::new(0.0, 1.0, 2.0));
+    /// ```
+    pub fn sub_scalar(self, rhs: f32) -> Self {
+        Vec3A::new(self.0 - rhs, self.1 - rhs, self.2 - rhs)
+    }
+
+    /// mul_scalar multiplies each component of this vector by `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3A;
+    ///
+    /// assert_eq!(Vec3A::new(1.0, 2.0, 3.0).mul_scalar(2.0), Vec3A::new(2.0, 4.0, 6.0));
+    /// ```
+    pub fn mul_scalar(self, rhs: f32) -> Self {
+        Vec3A::new(self.0 * rhs, self.1 * rhs, self.2 * rhs)
+    }
+
+    /// div_scalar divides each component of this vector by `rhs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3A;
+    ///
+    /// assert_eq!(Vec3A::new(2.0, 4.0, 6.0).div_scalar(2.0), Vec3A::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn div_scalar(self, rhs: f32) -> Self {
+        Vec3A::new(self.0 / rhs, self.1 / rhs, self.2 / rhs)
+    }
+
+    /// min returns the component-wise minimum of this vector and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3A;
+    ///
+    /// let a = Vec3A::new(1.0, 5.0, 3.0);
+    /// assert_eq!(a.min(Vec3A::new(2.0, 4.0, 3.0)), Vec3A::new(1.0, 4.0, 3.0));
+    /// ```
+    pub fn min(self, other: Self) -> Self {
+        self.map2(other, f32::min)
+    }
+
+    /// max returns the component-wise maximum of this vector and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3A;
+    ///
+    /// let a = Vec3A::new(1.0, 5.0, 3.0);
+    /// assert_eq!(a.max(Vec3A::new(2.0, 4.0, 3.0)), Vec3A::new(2.0, 5.0, 3.0));
+    /// ```
+    pub fn max(self, other: Self) -> Self {
+        self.map2(other, f32::max)
+    }
+
+    /// dot returns the dot product of this vector and `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3A;
+    ///
+    /// assert_eq!(Vec3A::new(1.0, 2.0, 3.0).dot(Vec3A::new(4.0, 5.0, 6.0)), 32.0);
+    /// ```
+    pub fn dot(self, b: Self) -> f32 {
+        self.0 * b.0 + self.1 * b.1 + self.2 * b.2
+    }
+
+    /// cross returns the cross product of this vector and `b`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3A;
+    ///
+    /// let x = Vec3A::new(1.0, 0.0, 0.0);
+    /// assert_eq!(x.cross(Vec3A::new(0.0, 1.0, 0.0)), Vec3A::new(0.0, 0.0, 1.0));
+    /// ```
+    pub fn cross(self, b: Self) -> Self {
+        Vec3A::new(self.1 * b.2 - self.2 * b.1,
+                   self.2 * b.0 - self.0 * b.2,
+                   self.0 * b.1 - self.1 * b.0)
+    }
+
+    /// length_sq returns the squared length of this vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3A;
+    ///
+    /// assert_eq!(Vec3A::new(1.0, 2.0, 2.0).length_sq(), 9.0);
+    /// ```
+    pub fn length_sq(self) -> f32 {
+        self.dot(self)
+    }
+
+    /// length returns the length of this vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3A;
+    ///
+    /// assert_eq!(Vec3A::new(1.0, 2.0, 2.0).length(), 3.0);
+    /// ```
+    pub fn length(self) -> f32 {
+        math::sqrt(self.length_sq())
+    }
+
+    /// normalize returns this vector scaled to unit length, or none if its
+    /// length is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3A;
+    ///
+    /// assert_eq!(Vec3A::new(0.0, 3.0, 4.0).normalize(), Some(Vec3A::new(0.0, 0.6, 0.8)));
+    /// assert_eq!(Vec3A::new(0.0, 0.0, 0.0).normalize(), None);
+    /// ```
+    pub fn normalize(self) -> Option<Self> {
+        let length = self.length();
+        if length == 0.0 {
+            None
+        } else {
+            Some(self.div_scalar(length))
+        }
+    }
+
+    /// lerp returns the linear interpolation between this vector and `other` by
+    /// the factor `t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3A;
+    ///
+    /// let a = Vec3A::new(0.0, 2.0, 4.0);
+    /// assert_eq!(a.lerp(Vec3A::new(2.0, 4.0, 8.0), 0.5), Vec3A::new(1.0, 3.0, 6.0));
+    /// ```
+    pub fn lerp(self, other: Self, t: f32) -> Self {
+        self.map2(other, |a, b| a + (b - a) * t)
+    }
+
+    /// almost_equal tells if this vector and `other` are component-wise equal
+    /// within the absolute tolerance `abs_tol` (see `Float::almost_equal`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec3A;
+    ///
+    /// let a = Vec3A::new(1.0, 2.0, 3.0);
+    /// assert!(a.almost_equal(Vec3A::new(1.0, 2.0, 3.05), 0.1));
+    /// assert!(!a.almost_equal(Vec3A::new(1.0, 2.0, 3.5), 0.1));
+    /// ```
+    pub fn almost_equal(self, other: Self, abs_tol: f32) -> bool {
+        self.to_vec3().almost_equal(other.to_vec3(), abs_tol)
+    }
+}
+
+impl From<Vec3<f32>> for Vec3A {
+    fn from(v: Vec3<f32>) -> Self {
+        Vec3A::new(v.0, v.1, v.2)
+    }
+}
+
+impl From<Vec3A> for Vec3<f32> {
+    fn from(v: Vec3A) -> Self {
+        v.to_vec3()
+    }
+}
+
+impl fmt::Display for Vec3A {
+    /// fmt formats the vector, applying the formatter's flags (width, precision
+    /// and so on) to each of its components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let x = fiz_math::Vec3A::new(1.0, 5.0, 2.5);
+    /// assert_eq!(format!("{}", x), "Vec3A(1, 5, 2.5)");
+    /// assert_eq!(format!("{:.1}", x), "Vec3A(1.0, 5.0, 2.5)");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Vec3A(")?;
+        fmt::Display::fmt(&self.0, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.1, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.2, f)?;
+        write!(f, ")")
+    }
+}
+
+impl Add for Vec3A {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.map2(rhs, |a, b| a + b)
+    }
+}
+
+impl Sub for Vec3A {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.map2(rhs, |a, b| a - b)
+    }
+}
+
+impl Mul for Vec3A {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.map2(rhs, |a, b| a * b)
+    }
+}
+
+impl Div for Vec3A {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.map2(rhs, |a, b| a / b)
+    }
+}
+
+impl Neg for Vec3A {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Vec3A::new(-self.0, -self.1, -self.2)
+    }
+}
@@ -1,17 +1,21 @@
 #![allow(dead_code)]
 
-use std::ops::{Add, Sub, Neg, Mul, Div};
-use std::cmp::{PartialEq, PartialOrd, Ordering};
+use core::ops::{Add, Sub, Neg, Mul, Div};
+use core::cmp::{PartialEq, PartialOrd, Ordering};
+use core::hash::{Hash, Hasher};
 pub use num::{Zero, One, Num};
 use num;
 use super::float::Float;
+use super::math;
 use super::{Vec2, Vec3};
-use std::fmt;
+use core::fmt;
 use clamp::Clamp;
-use std::iter::IntoIterator;
+use core::iter::IntoIterator;
 
 /// Vec4 is a generic four-component (3D) vector type.
 ///
+/// Vec4 is `#[repr(C)]`, so it has the same layout as `[T; 4]`.
+///
 /// # Examples
 ///
 /// ```
@@ -31,6 +35,7 @@ use std::iter::IntoIterator;
 /// assert!(x.almost_equal(y, 0.1));
 /// ```
 #[derive(Copy, Clone, Debug)]
+#[repr(C)]
 pub struct Vec4<T>(pub T, pub T, pub T, pub T);
 
 impl<T: Copy> IntoIterator for Vec4<T> {
@@ -65,16 +70,28 @@ impl<T: Copy> Iterator for Vec4Iterator<T> {
 }
 
 impl<T: fmt::Display> fmt::Display for Vec4<T> {
-    /// fmt formats the vector.
+    /// fmt formats the vector, applying the formatter's flags (width, precision
+    /// and so on) to each of its components.
     ///
     /// # Examples
     ///
     /// ```
     /// let x = fiz_math::Vec4(1u8, 5u8, 2u8, 3u8);
     /// assert_eq!(format!("{}", x), "Vec4(1, 5, 2, 3)");
+    ///
+    /// let y = fiz_math::Vec4(1.0, 2.5, -3.14159, 3.0);
+    /// assert_eq!(format!("{:.2}", y), "Vec4(1.00, 2.50, -3.14, 3.00)");
     /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Vec4({}, {}, {}, {})", self.0, self.1, self.2, self.3)
+        write!(f, "Vec4(")?;
+        fmt::Display::fmt(&self.0, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.1, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.2, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.3, f)?;
+        write!(f, ")")
     }
 }
 
@@ -124,9 +141,9 @@ impl<T: Float> Vec4<T> {
     /// # Examples
     ///
     /// ```
-    /// # extern crate num;
+    /// # extern crate num_traits;
     /// # extern crate fiz_math;
-    /// use num::traits::Float;
+    /// use num_traits::Float;
     /// use fiz_math::Vec4;
     ///
     /// # fn main() {
@@ -326,19 +343,62 @@ impl<T: Div<Output = T> + Copy> Vec4<T> {
     }
 }
 
+impl<T> Vec4<T> {
+    /// mul_unitless multiplies each component of the vector by a plain number.
+    /// Unlike mul_scalar, the number need not be of the component type, which
+    /// allows scaling vectors of units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    /// use fiz_math::unit::MM;
+    ///
+    /// let v = Vec4(MM(1.0), MM(2.0), MM(3.0), MM(4.0));
+    /// assert_eq!(v.mul_unitless(2.0), Vec4(MM(2.0), MM(4.0), MM(6.0), MM(8.0)));
+    /// ```
+    pub fn mul_unitless<S: Copy>(self, _rhs: S) -> Self
+        where T: Mul<S, Output = T>
+    {
+        Vec4(self.0 * _rhs, self.1 * _rhs, self.2 * _rhs, self.3 * _rhs)
+    }
+
+    /// div_unitless divides each component of the vector by a plain number.
+    /// Unlike div_scalar, the number need not be of the component type, which
+    /// allows scaling vectors of units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    /// use fiz_math::unit::MM;
+    ///
+    /// let v = Vec4(MM(2.0), MM(4.0), MM(6.0), MM(8.0));
+    /// assert_eq!(v.div_unitless(2.0), Vec4(MM(1.0), MM(2.0), MM(3.0), MM(4.0)));
+    /// ```
+    pub fn div_unitless<S: Copy>(self, _rhs: S) -> Self
+        where T: Div<S, Output = T>
+    {
+        Vec4(self.0 / _rhs, self.1 / _rhs, self.2 / _rhs, self.3 / _rhs)
+    }
+}
+
 impl<T: Clamp<Elem = T> + Copy> Clamp for Vec4<T> {
     type Elem = T;
 
     /// clamp returns the vector with each element clamped to the range of
     /// [min, max].
     ///
+    /// Vectors of integers also implement `Ord`, whose `clamp` method takes
+    /// vectors as bounds, so this one must be called through the trait.
+    ///
     /// # Examples
     ///
     /// ```
     /// use fiz_math::{Vec4, Clamp};
     ///
     /// let a = Vec4(-2, 4, -6, 8);
-    /// assert_eq!(a.clamp(-1, 2), Vec4(-1, 2, -1, 2));
+    /// assert_eq!(Clamp::clamp(a, -1, 2), Vec4(-1, 2, -1, 2));
     /// ```
     fn clamp(self, min: T, max: T) -> Self {
         Vec4(self.0.clamp(min, max),
@@ -414,7 +474,8 @@ impl<T: PartialEq> PartialEq for Vec4<T> {
 }
 
 impl<T: PartialOrd> PartialOrd for Vec4<T> {
-    /// partial_cmp compares the two vectors component-wise.
+    /// partial_cmp compares the two vectors lexicographically, i.e. by their
+    /// first components, or if those are equal by their second ones, and so on.
     ///
     /// # Examples
     ///
@@ -423,17 +484,88 @@ impl<T: PartialOrd> PartialOrd for Vec4<T> {
     ///
     /// let a = Vec4(1.0, 2.0, 3.0, 4.0);
     /// assert!(a < Vec4(1.1, 2.1, 3.1, 4.1));
+    /// assert!(Vec4(1, 5, 5, 5) < Vec4(2, 0, 0, 0));
+    /// assert!(Vec4(1, 2, 3, 4) < Vec4(1, 2, 3, 5));
     /// ```
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.0 < other.0 && self.1 < other.1 && self.2 < other.2 && self.3 < other.3 {
-            Some(Ordering::Less)
-        } else if self.0 > other.0 && self.1 > other.1 && self.2 > other.2 && self.3 > other.3 {
-            Some(Ordering::Greater)
-        } else if self == other {
-            Some(Ordering::Equal)
-        } else {
-            None
-        }
+        (&self.0, &self.1, &self.2, &self.3).partial_cmp(&(&other.0, &other.1, &other.2, &other.3))
+    }
+}
+
+impl<T: Eq> Eq for Vec4<T> {}
+
+impl<T: Ord> Ord for Vec4<T> {
+    /// cmp compares the two vectors lexicographically (see `partial_cmp`), so
+    /// that vectors of integers can be sorted and used as the keys of a
+    /// `BTreeMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use fiz_math::Vec4;
+    ///
+    /// let mut v = vec![Vec4(1, 1, 1, 1), Vec4(0, 2, 2, 2), Vec4(0, 0, 0, 0)];
+    /// v.sort();
+    /// assert_eq!(v, vec![Vec4(0, 0, 0, 0), Vec4(0, 2, 2, 2), Vec4(1, 1, 1, 1)]);
+    ///
+    /// let mut chunks = BTreeMap::new();
+    /// chunks.insert(Vec4(-1, 2, 2, 2), "spawn");
+    /// assert_eq!(chunks.get(&Vec4(-1, 2, 2, 2)), Some(&"spawn"));
+    /// ```
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.0, &self.1, &self.2, &self.3).cmp(&(&other.0, &other.1, &other.2, &other.3))
+    }
+}
+
+impl<T: Hash> Hash for Vec4<T> {
+    /// hash hashes the components of the vector in order, so that vectors of
+    /// integers can be used as the keys of a `HashMap`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use fiz_math::Vec4;
+    ///
+    /// let mut tiles = HashMap::new();
+    /// tiles.insert(Vec4(-1, 2, 2, 2), "grass");
+    /// tiles.insert(Vec4(3, 0, 0, 0), "water");
+    /// assert_eq!(tiles[&Vec4(-1, 2, 2, 2)], "grass");
+    /// ```
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+        self.1.hash(state);
+        self.2.hash(state);
+        self.3.hash(state);
+    }
+}
+
+impl<T: Float> Vec4<T> {
+    /// cmp_lex compares the two vectors lexicographically, comparing their
+    /// components with `Float::total_cmp`. Unlike `partial_cmp` it always
+    /// returns an ordering (-0.0 sorts before 0.0 and NaN after infinity), so it
+    /// can be used to sort float vectors with `sort_by`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use fiz_math::Vec4;
+    ///
+    /// assert_eq!(Vec4(1.0, 0.0, -5.0, -5.0).cmp_lex(&Vec4(1.0, -0.0, 2.0, 4.0)), Ordering::Greater);
+    ///
+    /// let n = std::f64::NAN;
+    /// let mut v = vec![Vec4(1.0, n, n, n), Vec4(2.0, 0.0, 0.0, 0.0), Vec4(1.0, -0.0, 2.0, 4.0)];
+    /// v.sort_by(Vec4::cmp_lex);
+    /// assert_eq!(v[0], Vec4(1.0, -0.0, 2.0, 4.0));
+    /// assert_eq!(v[2], Vec4(2.0, 0.0, 0.0, 0.0));
+    /// ```
+    pub fn cmp_lex(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(other.0)
+            .then_with(|| self.1.total_cmp(other.1))
+            .then_with(|| self.2.total_cmp(other.2))
+            .then_with(|| self.3.total_cmp(other.3))
     }
 }
 
@@ -539,9 +671,12 @@ impl<T: Zero> Zero for Vec4<T> {
     }
 }
 
-impl<T: Num + Copy> Vec4<T> {
-    /// dot returns the dot product of self and b. For length calculations use
-    /// length or length_sq functions instead (for clarity).
+impl<T: Mul + Copy> Vec4<T>
+    where T::Output: Add<Output = T::Output>
+{
+    /// dot returns the dot product of self and b, of the type of the product of
+    /// two components. For length calculations use length or length_sq
+    /// functions instead (for clarity).
     ///
     /// # Examples
     ///
@@ -551,12 +686,23 @@ impl<T: Num + Copy> Vec4<T> {
     /// let x = Vec4(1, 2, 3, 4);
     /// assert_eq!(x.dot(x), 30);
     /// ```
-    pub fn dot(self, b: Self) -> T {
+    ///
+    /// Units may be used as the component type, e.g. the dot product of vectors
+    /// of distances is an area:
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    /// use fiz_math::unit::{MM, MM2};
+    ///
+    /// let x = Vec4(MM(1), MM(2), MM(3), MM(4));
+    /// assert_eq!(x.dot(x), MM2(30));
+    /// ```
+    pub fn dot(self, b: Self) -> T::Output {
         self.0 * b.0 + self.1 * b.1 + self.2 * b.2 + self.3 * b.3
     }
 
-    /// length_sq returns the magnitude squared of this vector, useful primarily
-    /// for comparing distances.
+    /// length_sq returns the magnitude squared of this vector, of the type of
+    /// the product of two components, useful primarily for comparing distances.
     ///
     /// # Examples
     ///
@@ -565,10 +711,12 @@ impl<T: Num + Copy> Vec4<T> {
     ///
     /// assert_eq!(Vec4(1, 2, 3, 4).length_sq(), 30);
     /// ```
-    pub fn length_sq(self) -> T {
+    pub fn length_sq(self) -> T::Output {
         self.dot(self)
     }
+}
 
+impl<T: Num + Copy> Vec4<T> {
     /// project returns a vector representing the projection of the `self` vector
     /// onto the `other` vector.
     ///
@@ -595,12 +743,17 @@ impl<T: Float> Vec4<T> {
     ///
     /// ```
     /// use fiz_math::{Vec4, Float};
+    /// use fiz_math::unit::MPerS;
     ///
     /// let l = Vec4(1.0, 2.0, 3.0, 4.0).length();
     /// assert!(l.equal(5.47722557));
+    ///
+    /// // The length of a vector of units is in the same units, e.g. the speed
+    /// // of a velocity.
+    /// assert_eq!(Vec4(MPerS(3.0), MPerS(4.0), MPerS(0.0), MPerS(0.0)).length(), MPerS(5.0));
     /// ```
     pub fn length(self) -> T {
-        self.length_sq().sqrt()
+        math::sqrt(self.length_sq())
     }
 
     /// normalize returns the normalized (i.e. length/magnitude == 1) vector
@@ -640,6 +793,43 @@ impl<T: Float> Vec4<T> {
     pub fn lerp(self, other: Self, t: T) -> Self {
         self * other.mul_scalar(t)
     }
+
+    /// inverse_lerp returns the component-wise amounts by which `self` must be
+    /// interpolated towards `b` to yield `value` (see `Float::inverse_lerp`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let (a, b) = (Vec4(0.0, 10.0, -4.0, 1.0), Vec4(10.0, 20.0, 4.0, 2.0));
+    /// assert_eq!(a.inverse_lerp(b, Vec4(5.0, 12.5, 0.0, 3.0)), Vec4(0.5, 0.25, 0.5, 2.0));
+    /// ```
+    pub fn inverse_lerp(self, b: Self, value: Self) -> Self {
+        Vec4(self.0.inverse_lerp(b.0, value.0),
+             self.1.inverse_lerp(b.1, value.1),
+             self.2.inverse_lerp(b.2, value.2),
+             self.3.inverse_lerp(b.3, value.3))
+    }
+
+    /// remap maps each component of `self` from the range `from` onto the range
+    /// `to` (see `Float::remap`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::Vec4;
+    ///
+    /// let from = (Vec4(0.0, 0.0, 0.0, 0.0), Vec4(1.0, 2.0, 4.0, 8.0));
+    /// let to = (Vec4(-1.0, 10.0, 0.0, 1.0), Vec4(1.0, 20.0, 1.0, 3.0));
+    /// assert_eq!(Vec4(0.5, 0.5, 0.5, 0.5).remap(from, to), Vec4(0.0, 12.5, 0.125, 1.125));
+    /// ```
+    pub fn remap(self, from: (Self, Self), to: (Self, Self)) -> Self {
+        Vec4(self.0.remap(((from.0).0, (from.1).0), ((to.0).0, (to.1).0)),
+             self.1.remap(((from.0).1, (from.1).1), ((to.0).1, (to.1).1)),
+             self.2.remap(((from.0).2, (from.1).2), ((to.0).2, (to.1).2)),
+             self.3.remap(((from.0).3, (from.1).3), ((to.0).3, (to.1).3)))
+    }
 }
 
 swizzle!(x, Vec4);
@@ -0,0 +1,220 @@
+use core::ops::{Add, Div, Mul, Sub};
+
+use super::float::Float;
+use super::{Vec2, Vec3, Vec3A, Vec4};
+
+/// Vector is implemented by the scalar and vector types, allowing generic code
+/// (e.g. curves) to operate on points in any number of dimensions. The `Mul`
+/// and `Div` operators are applied component-wise.
+///
+/// # Examples
+///
+/// ```
+/// use fiz_math::{Float, Vec2, Vector};
+///
+/// fn midpoint<T: Float, V: Vector<Scalar = T>>(a: V, b: V) -> V {
+///     (a + b).mul_scalar(T::from(0.5).unwrap())
+/// }
+///
+/// assert_eq!(midpoint(2.0f64, 4.0), 3.0);
+/// assert_eq!(midpoint(Vec2(0.0, 2.0), Vec2(4.0, 4.0)), Vec2(2.0, 3.0));
+/// assert_eq!(Vector::distance(Vec2(1.0, 1.0), Vec2(4.0, 5.0)), 5.0);
+/// ```
+pub trait Vector
+    where Self: Copy + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> +
+                Div<Output = Self>
+{
+    /// Scalar is the type of the components of this vector.
+    type Scalar: Float;
+
+    /// splat returns the vector whose components are all `s`.
+    fn splat(s: Self::Scalar) -> Self;
+
+    /// mul_scalar returns this vector scaled by `s`.
+    fn mul_scalar(self, s: Self::Scalar) -> Self;
+
+    /// dot returns the dot product of this vector and `other`.
+    fn dot(self, other: Self) -> Self::Scalar;
+
+    /// min returns the component-wise minimum of this vector and `other`.
+    fn min(self, other: Self) -> Self;
+
+    /// max returns the component-wise maximum of this vector and `other`.
+    fn max(self, other: Self) -> Self;
+
+    /// length returns the length of this vector.
+    fn length(self) -> Self::Scalar;
+
+    /// distance returns the distance between this vector and `other`.
+    fn distance(self, other: Self) -> Self::Scalar {
+        (self - other).length()
+    }
+}
+
+impl Vector for f32 {
+    type Scalar = f32;
+
+    fn splat(s: f32) -> f32 {
+        s
+    }
+
+    fn mul_scalar(self, s: f32) -> f32 {
+        self * s
+    }
+
+    fn dot(self, other: f32) -> f32 {
+        self * other
+    }
+
+    fn min(self, other: f32) -> f32 {
+        f32::min(self, other)
+    }
+
+    fn max(self, other: f32) -> f32 {
+        f32::max(self, other)
+    }
+
+    fn length(self) -> f32 {
+        self.abs()
+    }
+}
+
+impl Vector for f64 {
+    type Scalar = f64;
+
+    fn splat(s: f64) -> f64 {
+        s
+    }
+
+    fn mul_scalar(self, s: f64) -> f64 {
+        self * s
+    }
+
+    fn dot(self, other: f64) -> f64 {
+        self * other
+    }
+
+    fn min(self, other: f64) -> f64 {
+        f64::min(self, other)
+    }
+
+    fn max(self, other: f64) -> f64 {
+        f64::max(self, other)
+    }
+
+    fn length(self) -> f64 {
+        self.abs()
+    }
+}
+
+impl<T: Float> Vector for Vec2<T> {
+    type Scalar = T;
+
+    fn splat(s: T) -> Self {
+        Vec2(s, s)
+    }
+
+    fn mul_scalar(self, s: T) -> Self {
+        Vec2::<T>::mul_scalar(self, s)
+    }
+
+    fn dot(self, other: Self) -> T {
+        Vec2::<T>::dot(self, other)
+    }
+
+    fn min(self, other: Self) -> Self {
+        Vec2::<T>::min(self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Vec2::<T>::max(self, other)
+    }
+
+    fn length(self) -> T {
+        Vec2::<T>::length(self)
+    }
+}
+
+impl<T: Float> Vector for Vec3<T> {
+    type Scalar = T;
+
+    fn splat(s: T) -> Self {
+        Vec3(s, s, s)
+    }
+
+    fn mul_scalar(self, s: T) -> Self {
+        Vec3::<T>::mul_scalar(self, s)
+    }
+
+    fn dot(self, other: Self) -> T {
+        Vec3::<T>::dot(self, other)
+    }
+
+    fn min(self, other: Self) -> Self {
+        Vec3::<T>::min(self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Vec3::<T>::max(self, other)
+    }
+
+    fn length(self) -> T {
+        Vec3::<T>::length(self)
+    }
+}
+
+impl<T: Float> Vector for Vec4<T> {
+    type Scalar = T;
+
+    fn splat(s: T) -> Self {
+        Vec4(s, s, s, s)
+    }
+
+    fn mul_scalar(self, s: T) -> Self {
+        Vec4::<T>::mul_scalar(self, s)
+    }
+
+    fn dot(self, other: Self) -> T {
+        Vec4::<T>::dot(self, other)
+    }
+
+    fn min(self, other: Self) -> Self {
+        Vec4::<T>::min(self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Vec4::<T>::max(self, other)
+    }
+
+    fn length(self) -> T {
+        Vec4::<T>::length(self)
+    }
+}
+
+impl Vector for Vec3A {
+    type Scalar = f32;
+
+    fn splat(s: f32) -> Self {
+        Vec3A::new(s, s, s)
+    }
+
+    fn mul_scalar(self, s: f32) -> Self {
+        Vec3A::mul_scalar(self, s)
+    }
+
+    fn dot(self, other: Self) -> f32 {
+        Vec3A::dot(self, other)
+    }
+
+    fn min(self, other: Self) -> Self {
+        Vec3A::min(self, other)
+    }
+
+    fn max(self, other: Self) -> Self {
+        Vec3A::max(self, other)
+    }
+
+    fn length(self) -> f32 {
+        Vec3A::length(self)
+    }
+}
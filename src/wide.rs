@@ -0,0 +1,307 @@
+use core::ops::{Add, Sub, Neg, Mul, Div};
+use super::math;
+
+use super::Vec3;
+
+// wide_vec3 defines a wide vector type, holding the given number of lanes of
+// each component, with the arithmetic of Vec3 applied to every lane. Each
+// operation is a loop over the lanes, for the compiler to vectorize.
+macro_rules! wide_vec3 {
+    ($(#[$attr:meta])* $name:ident, $lanes:expr) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        pub struct $name {
+            /// x holds the x components of the lanes.
+            pub x: [f32; $lanes],
+            /// y holds the y components of the lanes.
+            pub y: [f32; $lanes],
+            /// z holds the z components of the lanes.
+            pub z: [f32; $lanes],
+        }
+
+        impl $name {
+            /// LANES is the number of vectors held.
+            pub const LANES: usize = $lanes;
+
+            // lanes returns the lanes of f applied to each lane index.
+            #[inline(always)]
+            fn lanes<F: Fn(usize) -> f32>(f: F) -> [f32; $lanes] {
+                let mut out = [0.0; $lanes];
+                for (i, out) in out.iter_mut().enumerate() {
+                    *out = f(i);
+                }
+                out
+            }
+
+            // map returns the wide vector with f applied to each component of
+            // each lane.
+            #[inline(always)]
+            fn map<F: Fn(f32) -> f32>(self, f: F) -> Self {
+                $name {
+                    x: Self::lanes(|i| f(self.x[i])),
+                    y: Self::lanes(|i| f(self.y[i])),
+                    z: Self::lanes(|i| f(self.z[i])),
+                }
+            }
+
+            // zip returns the wide vector with f applied to each pair of
+            // components of each lane of self and other.
+            #[inline(always)]
+            fn zip<F: Fn(f32, f32) -> f32>(self, other: Self, f: F) -> Self {
+                $name {
+                    x: Self::lanes(|i| f(self.x[i], other.x[i])),
+                    y: Self::lanes(|i| f(self.y[i], other.y[i])),
+                    z: Self::lanes(|i| f(self.z[i], other.z[i])),
+                }
+            }
+
+            /// splat returns the wide vector with the vector `v` in every lane.
+            pub fn splat(v: Vec3<f32>) -> Self {
+                $name {
+                    x: [v.0; $lanes],
+                    y: [v.1; $lanes],
+                    z: [v.2; $lanes],
+                }
+            }
+
+            /// from_array returns the wide vector with the vectors of `v` in its
+            /// lanes.
+            pub fn from_array(v: [Vec3<f32>; $lanes]) -> Self {
+                $name {
+                    x: Self::lanes(|i| v[i].0),
+                    y: Self::lanes(|i| v[i].1),
+                    z: Self::lanes(|i| v[i].2),
+                }
+            }
+
+            /// to_array returns the vectors in the lanes.
+            pub fn to_array(self) -> [Vec3<f32>; $lanes] {
+                let mut out = [Vec3(0.0, 0.0, 0.0); $lanes];
+                for (i, out) in out.iter_mut().enumerate() {
+                    *out = self.lane(i);
+                }
+                out
+            }
+
+            /// pack returns the wide vector with the vectors of `v` in its first
+            /// lanes, and zero vectors in the rest (e.g. for the remainder of a
+            /// slice processed in chunks).
+            ///
+            /// # Panics
+            ///
+            /// Panics if `v` holds more vectors than there are lanes.
+            pub fn pack(v: &[Vec3<f32>]) -> Self {
+                assert!(v.len() <= $lanes,
+                        concat!(stringify!($name), "::pack: too many vectors"));
+                let mut out = Self::splat(Vec3(0.0, 0.0, 0.0));
+                for (i, v) in v.iter().enumerate() {
+                    out.set_lane(i, *v);
+                }
+                out
+            }
+
+            /// unpack stores the vectors in the first lanes into `out`, as many as
+            /// it holds.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `out` holds more vectors than there are lanes.
+            pub fn unpack(self, out: &mut [Vec3<f32>]) {
+                assert!(out.len() <= $lanes,
+                        concat!(stringify!($name), "::unpack: too many vectors"));
+                for (i, out) in out.iter_mut().enumerate() {
+                    *out = self.lane(i);
+                }
+            }
+
+            /// lane returns the vector in lane `i`.
+            pub fn lane(&self, i: usize) -> Vec3<f32> {
+                Vec3(self.x[i], self.y[i], self.z[i])
+            }
+
+            /// set_lane sets the vector in lane `i`.
+            pub fn set_lane(&mut self, i: usize, v: Vec3<f32>) {
+                self.x[i] = v.0;
+                self.y[i] = v.1;
+                self.z[i] = v.2;
+            }
+
+            /// add_scalar adds `rhs` to each component of each lane.
+            pub fn add_scalar(self, rhs: f32) -> Self {
+                self.map(|a| a + rhs)
+            }
+
+            /// sub_scalar subtracts `rhs` from each component of each lane.
+            pub fn sub_scalar(self, rhs: f32) -> Self {
+                self.map(|a| a - rhs)
+            }
+
+            /// mul_scalar multiplies each component of each lane by `rhs`.
+            pub fn mul_scalar(self, rhs: f32) -> Self {
+                self.map(|a| a * rhs)
+            }
+
+            /// div_scalar divides each component of each lane by `rhs`.
+            pub fn div_scalar(self, rhs: f32) -> Self {
+                self.map(|a| a / rhs)
+            }
+
+            /// mul_lanes multiplies the vector in each lane by the scalar in the
+            /// same lane of `rhs`.
+            pub fn mul_lanes(self, rhs: [f32; $lanes]) -> Self {
+                $name {
+                    x: Self::lanes(|i| self.x[i] * rhs[i]),
+                    y: Self::lanes(|i| self.y[i] * rhs[i]),
+                    z: Self::lanes(|i| self.z[i] * rhs[i]),
+                }
+            }
+
+            /// min returns the component-wise minimum of the lanes of `self` and
+            /// `other`.
+            pub fn min(self, other: Self) -> Self {
+                self.zip(other, f32::min)
+            }
+
+            /// max returns the component-wise maximum of the lanes of `self` and
+            /// `other`.
+            pub fn max(self, other: Self) -> Self {
+                self.zip(other, f32::max)
+            }
+
+            /// dot returns the dot products of the lanes of `self` and `other`.
+            pub fn dot(self, other: Self) -> [f32; $lanes] {
+                Self::lanes(|i| {
+                    self.x[i] * other.x[i] + self.y[i] * other.y[i] + self.z[i] * other.z[i]
+                })
+            }
+
+            /// cross returns the cross products of the lanes of `self` and
+            /// `other`.
+            pub fn cross(self, other: Self) -> Self {
+                $name {
+                    x: Self::lanes(|i| self.y[i] * other.z[i] - self.z[i] * other.y[i]),
+                    y: Self::lanes(|i| self.z[i] * other.x[i] - self.x[i] * other.z[i]),
+                    z: Self::lanes(|i| self.x[i] * other.y[i] - self.y[i] * other.x[i]),
+                }
+            }
+
+            /// length_sq returns the squared lengths of the lanes.
+            pub fn length_sq(self) -> [f32; $lanes] {
+                self.dot(self)
+            }
+
+            /// length returns the lengths of the lanes.
+            pub fn length(self) -> [f32; $lanes] {
+                let length_sq = self.length_sq();
+                Self::lanes(|i| math::sqrt(length_sq[i]))
+            }
+
+            /// normalize returns the lanes scaled to unit length. Lanes of zero
+            /// length stay zero (unlike `Vec3::normalize`, which returns none).
+            pub fn normalize(self) -> Self {
+                let length = self.length();
+                self.mul_lanes(Self::lanes(|i| {
+                    if length[i] == 0.0 { 0.0 } else { 1.0 / length[i] }
+                }))
+            }
+
+            /// lerp returns the linear interpolations between the lanes of `self`
+            /// and `other` by the factor `t`.
+            pub fn lerp(self, other: Self, t: f32) -> Self {
+                self.zip(other, |a, b| a + (b - a) * t)
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                self.zip(rhs, |a, b| a + b)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                self.zip(rhs, |a, b| a - b)
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                self.zip(rhs, |a, b| a * b)
+            }
+        }
+
+        impl Div for $name {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self {
+                self.zip(rhs, |a, b| a / b)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                self.map(|a| -a)
+            }
+        }
+
+        impl From<[Vec3<f32>; $lanes]> for $name {
+            fn from(v: [Vec3<f32>; $lanes]) -> Self {
+                $name::from_array(v)
+            }
+        }
+    }
+}
+
+wide_vec3! {
+    /// Vec3x4 is a wide vector holding four `Vec3<f32>` lanes in structure of
+    /// arrays form, with the arithmetic of `Vec3` applied to all the lanes at
+    /// once, so that loops over many vectors (e.g. rays or particles) can
+    /// process four per instruction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Vec3, Vec3x4};
+    ///
+    /// let mut positions = [Vec3(0.0, 0.0, 0.0); 4];
+    /// let velocities = Vec3x4::from_array([Vec3(1.0, 0.0, 0.0),
+    ///                                      Vec3(0.0, 2.0, 0.0),
+    ///                                      Vec3(0.0, 0.0, 3.0),
+    ///                                      Vec3(1.0, 1.0, 1.0)]);
+    /// let p = Vec3x4::from_array(positions) + velocities.mul_scalar(0.5);
+    /// p.unpack(&mut positions);
+    /// assert_eq!(positions[1], Vec3(0.0, 1.0, 0.0));
+    /// assert_eq!(velocities.dot(velocities), [1.0, 4.0, 9.0, 3.0]);
+    /// ```
+    #[repr(C, align(16))]
+    Vec3x4, 4
+}
+
+wide_vec3! {
+    /// Vec3x8 is a wide vector holding eight `Vec3<f32>` lanes, like `Vec3x4`
+    /// but sized for 256-bit vector instructions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fiz_math::{Vec3, Vec3x8};
+    ///
+    /// let points = [Vec3(3.0, 4.0, 0.0), Vec3(0.0, 0.0, 2.0), Vec3(0.0, 0.0, 0.0)];
+    /// let wide = Vec3x8::pack(&points);
+    /// assert_eq!(&wide.length()[..3], &[5.0, 2.0, 0.0]);
+    ///
+    /// let mut unit = [Vec3(0.0, 0.0, 0.0); 3];
+    /// wide.normalize().unpack(&mut unit);
+    /// assert_eq!(unit, [Vec3(0.6, 0.8, 0.0), Vec3(0.0, 0.0, 1.0), Vec3(0.0, 0.0, 0.0)]);
+    /// ```
+    #[repr(C, align(32))]
+    Vec3x8, 8
+}